@@ -14,4 +14,12 @@ pub enum Change {
         old: std::ops::RangeInclusive<tombi_syntax::SyntaxElement>,
         new: Vec<tombi_syntax::SyntaxElement>,
     },
+    /// Add or remove the comma after the last value of an array or inline table.
+    ///
+    /// The last value is looked up when the change is applied,
+    /// so that the comma follows the values reordered by earlier changes.
+    TrailingComma {
+        target: tombi_syntax::SyntaxNode,
+        trailing_comma: bool,
+    },
 }
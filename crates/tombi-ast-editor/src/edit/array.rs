@@ -6,7 +6,7 @@ use tombi_future::{BoxFuture, Boxable};
 use tombi_schema_store::{Accessor, AllOfSchema, AnyOfSchema, OneOfSchema, SchemaView};
 use tombi_validator::Validate;
 
-use crate::rule::{array_comma_trailing_comment, array_values_order};
+use crate::rule::{array_comma_trailing_comment, array_layout, array_values_order};
 
 impl crate::Edit for tombi_ast::Array {
     fn edit<'a: 'b, 'b>(
@@ -121,6 +121,18 @@ impl crate::Edit for tombi_ast::Array {
                 );
             }
 
+            // Applied after sorting so that the trailing comma follows the new last value.
+            changes.extend(array_layout(
+                self,
+                current_schema.and_then(|current_schema| {
+                    if let SchemaView::Array(array_schema) = current_schema.schema_view.as_ref() {
+                        array_schema.layout
+                    } else {
+                        None
+                    }
+                }),
+            ));
+
             changes
         }
         .boxed()
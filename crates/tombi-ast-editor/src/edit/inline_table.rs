@@ -3,9 +3,11 @@ use tombi_ast::{AstNode, DanglingCommentGroupOr};
 use tombi_comment_directive::value::{TableCommonFormatRules, TableCommonLintRules};
 use tombi_comment_directive_serde::get_comment_directive_content;
 use tombi_future::{BoxFuture, Boxable};
-use tombi_schema_store::Accessor;
+use tombi_schema_store::{Accessor, SchemaView};
 
-use crate::rule::{inline_table_comma_trailing_comment, inline_table_keys_order};
+use crate::rule::{
+    inline_table_comma_trailing_comment, inline_table_keys_order, inline_table_layout,
+};
 
 impl crate::Edit for tombi_ast::InlineTable {
     fn edit<'a: 'b, 'b>(
@@ -79,6 +81,19 @@ impl crate::Edit for tombi_ast::InlineTable {
                 );
             }
 
+            // Applied after sorting so that the trailing comma follows the new last key-value.
+            changes.extend(inline_table_layout(
+                self,
+                current_schema.and_then(|current_schema| {
+                    if let SchemaView::Table(table_schema) = current_schema.schema_view.as_ref() {
+                        table_schema.inline_table_layout
+                    } else {
+                        None
+                    }
+                }),
+                schema_context.toml_version,
+            ));
+
             changes
         }
         .boxed()
@@ -4,7 +4,7 @@ use tombi_ast::AstNode;
 use tombi_document_tree::TryIntoDocumentTree;
 use tombi_schema_store::{CurrentSchema, SchemaContext};
 
-use crate::{Edit, change::Change};
+use crate::{Edit, change::Change, node::make_comma};
 
 pub struct Editor<'a> {
     root: tombi_ast::Root,
@@ -90,9 +90,62 @@ impl<'a> Editor<'a> {
                         node.splice_children(start..end + 1, new);
                     }
                 }
+                Change::TrailingComma {
+                    target,
+                    trailing_comma,
+                } => set_trailing_comma(&target, trailing_comma),
             }
         }
 
         new_root
     }
 }
+
+fn set_trailing_comma(target: &tombi_syntax::SyntaxNode, trailing_comma: bool) {
+    let last_item = if let Some(array) = tombi_ast::Array::cast(target.clone()) {
+        array.values_with_comma().last().map(|(value, comma)| {
+            (
+                value.syntax().clone(),
+                value.trailing_comment().is_some(),
+                comma,
+            )
+        })
+    } else if let Some(inline_table) = tombi_ast::InlineTable::cast(target.clone()) {
+        inline_table
+            .key_values_with_comma()
+            .last()
+            .map(|(key_value, comma)| {
+                (
+                    key_value.syntax().clone(),
+                    key_value.trailing_comment().is_some(),
+                    comma,
+                )
+            })
+    } else {
+        None
+    };
+    let Some((item, has_trailing_comment, comma)) = last_item else {
+        return;
+    };
+
+    match comma {
+        // A trailing comment already keeps the layout multi-line.
+        None if trailing_comma && !has_trailing_comment => {
+            if let Some(parent) = item.parent() {
+                let index = item.index() + 1;
+                parent.splice_children(index..index, vec![make_comma().into()]);
+            }
+        }
+        Some(comma)
+            if !trailing_comma
+                && comma.leading_comments().next().is_none()
+                && comma.trailing_comment().is_none() =>
+        {
+            if let Some(parent) = comma.syntax().parent() {
+                let index = comma.syntax().index();
+                parent.splice_children(index..index + 1, Vec::new());
+            }
+        }
+        _ => {}
+    }
+}
@@ -1,15 +1,20 @@
 mod array_comma_trailing_comment;
+mod array_layout;
+mod array_of_tables_order;
 mod array_values_order;
 mod inline_table_comma_trailing_comment;
 mod inline_table_keys_order;
+mod inline_table_layout;
 mod order_override;
 mod root_table_keys_order;
 mod table_keys_order;
 
 pub use array_comma_trailing_comment::array_comma_trailing_comment;
+pub use array_layout::array_layout;
 pub use array_values_order::array_values_order;
 pub use inline_table_comma_trailing_comment::inline_table_comma_trailing_comment;
 pub use inline_table_keys_order::inline_table_keys_order;
+pub use inline_table_layout::inline_table_layout;
 pub use order_override::{ArrayOrderOverrides, TableOrderOverride, TableOrderOverrides};
 pub use root_table_keys_order::root_table_keys_order;
 pub use table_keys_order::table_keys_order;
@@ -0,0 +1,27 @@
+use tombi_ast::AstNode;
+use tombi_x_keyword::ArrayLayout;
+
+use crate::change::Change;
+
+/// Request the trailing comma that makes the formatter use the `x-tombi-array-layout`.
+///
+/// The formatter keeps an array with a trailing comma multi-line,
+/// so the layout is expressed by adding or removing that comma.
+pub fn array_layout(array: &tombi_ast::Array, layout: Option<ArrayLayout>) -> Vec<Change> {
+    let Some(layout) = layout else {
+        return Vec::new();
+    };
+
+    let values_count = array.values().count();
+    let trailing_comma = match layout {
+        ArrayLayout::AlwaysMultiline if values_count > 0 => true,
+        ArrayLayout::OnePerLine if values_count > 1 => true,
+        ArrayLayout::Compact => false,
+        _ => return Vec::new(),
+    };
+
+    vec![Change::TrailingComma {
+        target: array.syntax().clone(),
+        trailing_comma,
+    }]
+}
@@ -0,0 +1,81 @@
+use std::cmp::Ordering;
+
+use tombi_schema_store::XTombiArrayOfTablesOrder;
+use tombi_x_keyword::ArrayValuesOrder;
+
+/// Sort the entries of an array of tables, such as `[[package]]`, by the sub-keys of `x-tombi-array-of-tables-order`.
+///
+/// The sort is stable, so entries with equal sub-key values keep their relative order.
+/// Entries missing a sub-key are placed after the ones that have it.
+pub fn sort_array_of_tables<T>(
+    entries: &mut [(usize, &tombi_document_tree::Value, T)],
+    tables_order: &XTombiArrayOfTablesOrder,
+) {
+    entries.sort_by(|(_, a, _), (_, b, _)| {
+        for order_key in &tables_order.0 {
+            let ordering = match (
+                get_sort_value(a, &order_key.key),
+                get_sort_value(b, &order_key.key),
+            ) {
+                (Some(a), Some(b)) => compare_values(a, b, order_key.order),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        Ordering::Equal
+    });
+}
+
+fn get_sort_value<'a>(
+    entry: &'a tombi_document_tree::Value,
+    key: &str,
+) -> Option<&'a tombi_document_tree::Value> {
+    match entry {
+        tombi_document_tree::Value::Table(table) => table.get(key),
+        _ => None,
+    }
+}
+
+fn compare_values(
+    a: &tombi_document_tree::Value,
+    b: &tombi_document_tree::Value,
+    order: ArrayValuesOrder,
+) -> Ordering {
+    use tombi_document_tree::Value;
+
+    let ordering = match (a, b) {
+        (Value::Boolean(a), Value::Boolean(b)) => a.value().cmp(&b.value()),
+        (Value::Integer(a), Value::Integer(b)) => a.value().cmp(&b.value()),
+        (Value::Float(a), Value::Float(b)) => {
+            a.value().partial_cmp(&b.value()).unwrap_or(Ordering::Equal)
+        }
+        (Value::String(a), Value::String(b)) => match order {
+            ArrayValuesOrder::VersionSort => {
+                return tombi_version_sort::version_sort(a.value(), b.value());
+            }
+            _ => a.value().cmp(b.value()),
+        },
+        (Value::OffsetDateTime(a), Value::OffsetDateTime(b)) => {
+            a.value().to_string().cmp(&b.value().to_string())
+        }
+        (Value::LocalDateTime(a), Value::LocalDateTime(b)) => {
+            a.value().to_string().cmp(&b.value().to_string())
+        }
+        (Value::LocalDate(a), Value::LocalDate(b)) => {
+            a.value().to_string().cmp(&b.value().to_string())
+        }
+        (Value::LocalTime(a), Value::LocalTime(b)) => {
+            a.value().to_string().cmp(&b.value().to_string())
+        }
+        _ => Ordering::Equal,
+    };
+
+    match order {
+        ArrayValuesOrder::Descending => ordering.reverse(),
+        ArrayValuesOrder::Ascending | ArrayValuesOrder::VersionSort => ordering,
+    }
+}
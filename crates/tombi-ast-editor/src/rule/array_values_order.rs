@@ -14,11 +14,13 @@ use tombi_comment_directive::value::{
 use tombi_future::{BoxFuture, Boxable};
 use tombi_schema_store::{
     Accessor, AllOfSchema, AnyOfSchema, CurrentSchema, OneOfSchema, SchemaContext, SchemaView,
-    TableSchema, XTombiArrayValuesOrder,
+    StringSchema, TableSchema, XTombiArrayValuesOrder,
 };
 use tombi_syntax::SyntaxElement;
 use tombi_validator::Validate;
-use tombi_x_keyword::{ArrayValuesOrder, ArrayValuesOrderBy, ArrayValuesOrderGroup};
+use tombi_x_keyword::{
+    ArrayValuesOrder, ArrayValuesOrderBy, ArrayValuesOrderGroup, StringValuesOrderBy,
+};
 
 use boolean::create_boolean_sortable_values;
 use integer::create_integer_sortable_values;
@@ -364,6 +366,57 @@ fn try_array_values_order_by_from_item_schema<'a: 'b, 'b>(
     .boxed()
 }
 
+/// Get how the sort key is extracted from a string value, if the item schema specifies it.
+fn try_string_values_order_by_from_item_schema<'a: 'b, 'b>(
+    string_node: &'a tombi_document_tree::String,
+    accessors: &'a [Accessor],
+    current_schema: Option<&'a CurrentSchema<'a>>,
+    schema_context: &'a SchemaContext<'a>,
+) -> BoxFuture<'b, Option<StringValuesOrderBy>> {
+    async move {
+        let current_schema = current_schema?;
+        match current_schema.schema_view.as_ref() {
+            SchemaView::String(StringSchema {
+                array_values_order_by,
+                ..
+            }) => array_values_order_by.clone(),
+            SchemaView::AllOf(AllOfSchema { schemas, .. })
+            | SchemaView::AnyOf(AnyOfSchema { schemas, .. })
+            | SchemaView::OneOf(OneOfSchema { schemas, .. }) => {
+                let resolved_schemas = tombi_schema_store::resolve_and_collect_schemas(
+                    schemas,
+                    current_schema.schema_uri.clone(),
+                    current_schema.definitions.clone(),
+                    current_schema.strict,
+                    schema_context.store,
+                    &schema_context.schema_visits,
+                    accessors,
+                )
+                .await?;
+
+                for current_schema in &resolved_schemas {
+                    if string_node
+                        .validate(accessors, Some(current_schema), schema_context)
+                        .await
+                        .is_ok()
+                    {
+                        return try_string_values_order_by_from_item_schema(
+                            string_node,
+                            accessors,
+                            Some(current_schema),
+                            schema_context,
+                        )
+                        .await;
+                    }
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+    .boxed()
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum SortableType {
     Boolean,
@@ -506,6 +559,11 @@ enum SortFailReason {
 
     #[error("Cannot sort array values because the values have dotted keys inline table.")]
     DottedKeysInlineTableNotSupported,
+
+    #[error(
+        "Cannot sort array values because the pattern defined in `x-tombi-array-values-order-by` is invalid: {0}"
+    )]
+    InvalidArrayValuesOrderByPattern(String),
 }
 
 impl SortableValues {
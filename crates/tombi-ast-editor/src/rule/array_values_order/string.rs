@@ -1,11 +1,13 @@
 use itertools::Itertools;
 use tombi_ast::{AstNode, DanglingCommentGroupOr};
 use tombi_schema_store::{Accessor, CurrentSchema, SchemaContext};
+use tombi_x_keyword::StringValuesOrderBy;
 
 use crate::{
     node::make_comma,
     rule::array_values_order::{
         SortFailReason, SortableValues, try_array_values_order_by_from_item_schema,
+        try_string_values_order_by_from_item_schema,
     },
 };
 
@@ -16,6 +18,30 @@ pub async fn create_string_sortable_values<'a>(
     current_schema: Option<&'a CurrentSchema<'a>>,
     schema_context: &'a SchemaContext<'a>,
 ) -> Result<SortableValues, SortFailReason> {
+    // The items share the item schema, so it is resolved once with the first string.
+    let order_by = match value_nodes
+        .iter()
+        .find_map(|(index, value_node)| match value_node {
+            tombi_document_tree::Value::String(string) => Some((*index, string)),
+            _ => None,
+        }) {
+        Some((value_node_index, string)) => {
+            try_string_values_order_by_from_item_schema(
+                string,
+                &accessors
+                    .iter()
+                    .cloned()
+                    .chain(std::iter::once(Accessor::Index(value_node_index)))
+                    .collect_vec(),
+                current_schema,
+                schema_context,
+            )
+            .await
+        }
+        None => None,
+    };
+    let sort_key = SortKey::try_new(order_by.as_ref())?;
+
     let mut sortable_values = Vec::with_capacity(values_with_comma.len());
     for ((value, comma), (value_node_index, value_node)) in
         values_with_comma.into_iter().zip(value_nodes.iter())
@@ -29,7 +55,7 @@ pub async fn create_string_sortable_values<'a>(
                 | tombi_ast::Value::MultiLineLiteralString(_),
                 tombi_document_tree::Value::String(string),
             ) => {
-                sortable_values.push((string.value().to_owned(), value, comma));
+                sortable_values.push((sort_key.extract(string.value()), value, comma));
             }
             (
                 tombi_ast::Value::InlineTable(inline_table),
@@ -85,3 +111,70 @@ pub async fn create_string_sortable_values<'a>(
     }
     Ok(SortableValues::String(sortable_values))
 }
+
+/// The part of a string value that it is sorted by.
+enum SortKey {
+    Value,
+    Pep503Name,
+    Basename,
+    Pattern {
+        regex: tombi_regex::Regex,
+        group: usize,
+    },
+}
+
+impl SortKey {
+    fn try_new(order_by: Option<&StringValuesOrderBy>) -> Result<Self, SortFailReason> {
+        Ok(match order_by {
+            None => Self::Value,
+            Some(StringValuesOrderBy::Pep503Name) => Self::Pep503Name,
+            Some(StringValuesOrderBy::Basename) => Self::Basename,
+            Some(StringValuesOrderBy::Pattern(pattern)) => {
+                let regex = tombi_regex::Regex::new(pattern).map_err(|err| {
+                    SortFailReason::InvalidArrayValuesOrderByPattern(err.to_string())
+                })?;
+                let group = if regex.captures_len() > 1 { 1 } else { 0 };
+                Self::Pattern { regex, group }
+            }
+        })
+    }
+
+    fn extract(&self, value: &str) -> String {
+        match self {
+            Self::Value => value.to_owned(),
+            Self::Pep503Name => pep503_normalized_name(value),
+            Self::Basename => basename(value).to_owned(),
+            Self::Pattern { regex, group } => match regex.find_capture(value, *group) {
+                Some(matched) => value[matched.start()..matched.end()].to_owned(),
+                None => value.to_owned(),
+            },
+        }
+    }
+}
+
+/// The package name of a PEP 508 requirement such as `Foo.Bar[extra]>=1.0`, normalized to `foo-bar`.
+fn pep503_normalized_name(requirement: &str) -> String {
+    let name = requirement
+        .trim_start()
+        .split(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+        .next()
+        .unwrap_or_default();
+
+    let mut normalized = String::with_capacity(name.len());
+    for c in name.chars() {
+        if matches!(c, '-' | '_' | '.') {
+            if !normalized.ends_with('-') {
+                normalized.push('-');
+            }
+        } else {
+            normalized.push(c.to_ascii_lowercase());
+        }
+    }
+    normalized
+}
+
+/// The last component of a path such as `src/bin/main.rs`.
+fn basename(path: &str) -> &str {
+    let path = path.trim_end_matches(['/', '\\']);
+    path.rsplit(['/', '\\']).next().unwrap_or(path)
+}
@@ -0,0 +1,35 @@
+use tombi_ast::AstNode;
+use tombi_toml_version::TomlVersion;
+use tombi_x_keyword::InlineTableLayout;
+
+use crate::change::Change;
+
+/// Request the trailing comma that makes the formatter use the `x-tombi-inline-table-layout`.
+///
+/// Trailing commas and multi-line inline tables require TOML v1.1.0,
+/// so nothing changes for older versions.
+pub fn inline_table_layout(
+    inline_table: &tombi_ast::InlineTable,
+    layout: Option<InlineTableLayout>,
+    toml_version: TomlVersion,
+) -> Vec<Change> {
+    let Some(layout) = layout else {
+        return Vec::new();
+    };
+    if toml_version == TomlVersion::V1_0_0 {
+        return Vec::new();
+    }
+
+    let key_values_count = inline_table.key_values().count();
+    let trailing_comma = match layout {
+        InlineTableLayout::AlwaysMultiline if key_values_count > 0 => true,
+        InlineTableLayout::OnePerLine if key_values_count > 1 => true,
+        InlineTableLayout::Compact => false,
+        _ => return Vec::new(),
+    };
+
+    vec![Change::TrailingComma {
+        target: inline_table.syntax().clone(),
+        trailing_comma,
+    }]
+}
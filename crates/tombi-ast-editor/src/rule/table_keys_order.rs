@@ -15,6 +15,7 @@ use tombi_validator::Validate;
 use tombi_x_keyword::{TableKeysOrder, TableKeysOrderGroupKind};
 
 use crate::rule::TableOrderOverrides;
+use crate::rule::array_of_tables_order::sort_array_of_tables;
 
 pub async fn table_keys_order<'a>(
     value: &'a tombi_document_tree::Value,
@@ -342,9 +343,19 @@ where
                     .await
                     .inspect_err(|err| log::warn!("{err}"))
                 {
-                    for (index, (value, (_, targets))) in
-                        array.iter().zip(sort_targets_map).enumerate()
+                    let mut entries = array
+                        .iter()
+                        .zip(sort_targets_map)
+                        .enumerate()
+                        .map(|(index, (value, (_, targets)))| (index, value, targets))
+                        .collect_vec();
+                    if let Some(tables_order) = &array_schema.tables_order
+                        && schema_context.schema_array_values_order_enabled(Some(&current_schema))
                     {
+                        sort_array_of_tables(&mut entries, tables_order);
+                    }
+
+                    for (index, value, targets) in entries {
                         results.extend(
                             get_sorted_accessors(
                                 value,
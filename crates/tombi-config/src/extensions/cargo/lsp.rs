@@ -2,6 +2,7 @@ use crate::extensions::EnabledOnly;
 
 mod code_action;
 mod completion;
+mod diagnostic;
 mod document_link;
 mod goto_declaration;
 mod goto_definition;
@@ -11,6 +12,7 @@ mod references;
 
 pub use code_action::*;
 pub use completion::*;
+pub use diagnostic::*;
 pub use document_link::*;
 pub use goto_declaration::*;
 pub use goto_definition::*;
@@ -48,6 +50,9 @@ extension_features! {
         /// # Completion feature options
         pub completion: Option<CargoCompletionFeatures>,
 
+        /// # Diagnostic feature options
+        pub diagnostic: Option<CargoDiagnosticFeatures>,
+
         /// # Document link feature options
         pub document_link: Option<CargoDocumentLinkFeatures>,
 
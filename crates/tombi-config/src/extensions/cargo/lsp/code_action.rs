@@ -48,5 +48,10 @@ toggle_features! {
         ///
         /// Whether code actions can rewrite dependency versions to the latest published version.
         pub update_dependency_to_latest_version: Option<ToggleFeatureDefaultTrue>,
+
+        /// # Update-all-dependencies source action feature
+        ///
+        /// Whether source actions can bump every dependency requirement in the file to a newer published version.
+        pub update_all_dependencies: Option<ToggleFeatureDefaultTrue>,
     }
 }
@@ -0,0 +1,62 @@
+use tombi_severity_level::{SeverityLevel, SeverityLevelDefaultOff, SeverityLevelDefaultWarn};
+
+use crate::extensions::EnabledOnly;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum CargoDiagnosticFeatures {
+    Enabled(EnabledOnly),
+    Features(CargoDiagnosticFeatureTree),
+}
+
+toggle_features! {
+    CargoDiagnosticFeatures,
+
+    #[derive(Debug, Default, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+    #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+    #[cfg_attr(
+        feature = "jsonschema",
+        schemars(extend(
+            "x-tombi-table-keys-order" = tombi_x_keyword::TableKeysOrder::Ascending
+        ))
+    )]
+    pub struct CargoDiagnosticFeatureTree {
+        /// # Outdated dependency diagnostic
+        ///
+        /// Report dependencies whose version requirement excludes
+        /// the latest semver-compatible release or the latest release on crates.io.
+        ///
+        /// ```toml
+        /// [dependencies]
+        /// serde = "=1.0.100" # latest compatible version is 1.0.228
+        /// ```
+        pub outdated_dependency: Option<SeverityLevelDefaultOff>,
+
+        /// # Stale lock file diagnostic
+        ///
+        /// Report dependencies that are missing from `Cargo.lock`,
+        /// or whose version requirement is not satisfied by the locked version.
+        ///
+        /// ```toml
+        /// [dependencies]
+        /// serde = "2" # Cargo.lock still locks serde 1.0.228
+        /// ```
+        pub stale_lock_file: Option<SeverityLevelDefaultWarn>,
+
+        /// # Yanked dependency diagnostic
+        ///
+        /// Report dependencies whose `Cargo.lock` resolved version has been yanked from crates.io.
+        pub yanked_dependency: Option<SeverityLevelDefaultOff>,
+    }
+}
+
+impl From<&EnabledOnly> for Option<SeverityLevelDefaultOff> {
+    fn from(enabled_only: &EnabledOnly) -> Self {
+        (!enabled_only.enabled.value()).then(|| SeverityLevel::Off.into())
+    }
+}
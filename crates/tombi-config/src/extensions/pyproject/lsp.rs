@@ -2,6 +2,7 @@ use crate::extensions::EnabledOnly;
 
 mod code_action;
 mod completion;
+mod diagnostic;
 mod document_link;
 mod goto_declaration;
 mod goto_definition;
@@ -11,6 +12,7 @@ mod references;
 
 pub use code_action::*;
 pub use completion::*;
+pub use diagnostic::*;
 pub use document_link::*;
 pub use goto_declaration::*;
 pub use goto_definition::*;
@@ -52,6 +54,11 @@ extension_features! {
         /// Configure pyproject completion features.
         pub completion: Option<PyprojectCompletionFeatures>,
 
+        /// # Diagnostic feature options
+        ///
+        /// Configure pyproject diagnostic features.
+        pub diagnostic: Option<PyprojectDiagnosticFeatures>,
+
         /// # Document link feature options
         ///
         /// Configure pyproject document link features.
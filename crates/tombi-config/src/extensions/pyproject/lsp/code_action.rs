@@ -24,6 +24,12 @@ toggle_features! {
         ))
     )]
     pub struct PyprojectCodeActionFeatureTree {
+        /// # Convert-poetry-dependencies-to-pep621 code action feature
+        ///
+        /// Whether code actions can convert `[tool.poetry]` dependency tables
+        /// to PEP 621 `[project]` dependencies and PEP 735 `[dependency-groups]`.
+        pub convert_poetry_dependencies_to_pep621: Option<ToggleFeatureDefaultTrue>,
+
         /// # Use-workspace-dependency code action feature
         ///
         /// Whether code actions can reuse a dependency declared in the workspace.
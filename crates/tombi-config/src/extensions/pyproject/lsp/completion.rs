@@ -24,6 +24,22 @@ toggle_features! {
         ))
     )]
     pub struct PyprojectCompletionFeatureTree {
+        /// # Dependency name completion feature
+        ///
+        /// Whether completion suggests package names already cached from PyPI.
+        pub dependency_name: Option<ToggleFeatureDefaultTrue>,
+
+        /// # Dependency version completion feature
+        ///
+        /// Whether completion suggests package versions published on PyPI.
+        pub dependency_version: Option<ToggleFeatureDefaultTrue>,
+
+        /// # Entry point completion feature
+        ///
+        /// Whether completion suggests Python module paths and top-level objects
+        /// for `[project.scripts]`, `[project.gui-scripts]` and `[project.entry-points]`.
+        pub entry_point: Option<ToggleFeatureDefaultTrue>,
+
         /// # Path completion feature
         ///
         /// Whether completion suggests filesystem paths.
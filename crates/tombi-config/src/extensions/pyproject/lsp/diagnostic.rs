@@ -0,0 +1,57 @@
+use tombi_severity_level::{SeverityLevel, SeverityLevelDefaultWarn};
+
+use crate::extensions::EnabledOnly;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum PyprojectDiagnosticFeatures {
+    Enabled(EnabledOnly),
+    Features(PyprojectDiagnosticFeatureTree),
+}
+
+toggle_features! {
+    PyprojectDiagnosticFeatures,
+
+    #[derive(Debug, Default, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+    #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+    #[cfg_attr(
+        feature = "jsonschema",
+        schemars(extend(
+            "x-tombi-table-keys-order" = tombi_x_keyword::TableKeysOrder::Ascending
+        ))
+    )]
+    pub struct PyprojectDiagnosticFeatureTree {
+        /// # Entry point diagnostic
+        ///
+        /// Report entry points whose module does not exist in the project,
+        /// or whose module does not define the referenced top-level object.
+        ///
+        /// ```toml
+        /// [project.scripts]
+        /// mycli = "mypkg.cli:main" # `mypkg/cli.py` has no top-level `main`
+        /// ```
+        pub entry_point: Option<SeverityLevelDefaultWarn>,
+
+        /// # Stale lock file diagnostic
+        ///
+        /// Report dependencies that are missing from `uv.lock`, `poetry.lock` or `pdm.lock`,
+        /// or whose requirement is not satisfied by the locked version.
+        ///
+        /// ```toml
+        /// [project]
+        /// dependencies = ["requests>=3.0"] # uv.lock still locks requests 2.31.0
+        /// ```
+        pub stale_lock_file: Option<SeverityLevelDefaultWarn>,
+    }
+}
+
+impl From<&EnabledOnly> for Option<SeverityLevelDefaultWarn> {
+    fn from(enabled_only: &EnabledOnly) -> Self {
+        (!enabled_only.enabled.value()).then(|| SeverityLevel::Off.into())
+    }
+}
@@ -29,6 +29,12 @@ toggle_features! {
         /// Whether definition navigation resolves dependency targets.
         pub dependency: Option<ToggleFeatureDefaultTrue>,
 
+        /// # Entry point definition navigation feature
+        ///
+        /// Whether definition navigation resolves `[project.scripts]`, `[project.gui-scripts]`
+        /// and `[project.entry-points]` object references into Python modules.
+        pub entry_point: Option<ToggleFeatureDefaultTrue>,
+
         /// # Member definition navigation feature
         ///
         /// Whether definition navigation resolves workspace member targets.
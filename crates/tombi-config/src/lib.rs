@@ -18,7 +18,7 @@ pub use lint::*;
 pub use overrides::*;
 pub use schema::SchemaOverviewOptions;
 pub use schema::{
-    PatternAccessor, RootSchema, SchemaArrayValuesOrderRule, SchemaFormatOptions,
+    PatternAccessor, RootSchema, SchemaArrayValuesOrderRule, SchemaCatalog, SchemaFormatOptions,
     SchemaFormatRules, SchemaItem, SchemaLintOptions, SchemaLintRules,
     SchemaOverrideArrayValuesOrderRule, SchemaOverrideFormatOptions, SchemaOverrideFormatRules,
     SchemaOverrideItem, SchemaOverrideLintOptions, SchemaOverrideLintRules,
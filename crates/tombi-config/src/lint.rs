@@ -1,4 +1,5 @@
 use tombi_severity_level::SeverityLevelDefaultWarn;
+use tombi_x_keyword::TableStyle;
 
 /// # Linter options
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -70,6 +71,48 @@ pub struct LintRules {
     /// [animal]
     /// ```
     pub tables_out_of_order: Option<SeverityLevelDefaultWarn>,
+
+    /// # Table style
+    ///
+    /// Check if tables are written in the style preferred by the schema's `x-tombi-table-style`.
+    ///
+    /// ```toml
+    /// # table-style = { style = "inline" }
+    /// [dependencies]
+    /// serde = { version = "1", features = ["derive"] }
+    ///
+    /// # table-style = { style = "table" }
+    /// [dependencies.serde]
+    /// version = "1"
+    /// features = ["derive"]
+    /// ```
+    pub table_style: Option<TableStyleRule>,
+}
+
+/// # Table style rule
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "jsonschema", schemars(extend("x-tombi-table-keys-order" = tombi_x_keyword::TableKeysOrder::Schema)))]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct TableStyleRule {
+    /// # Severity level
+    pub level: Option<SeverityLevelDefaultWarn>,
+
+    /// # Table style
+    ///
+    /// Override the schema's `x-tombi-table-style`. `"any"` disables the check.
+    pub style: Option<TableStyle>,
+}
+
+impl TableStyleRule {
+    pub fn merge(self, override_rule: &Self) -> Self {
+        Self {
+            level: self.level.or(override_rule.level),
+            style: self.style.or(override_rule.style),
+        }
+    }
 }
 
 impl LintRules {
@@ -82,6 +125,10 @@ impl LintRules {
             tables_out_of_order: self
                 .tables_out_of_order
                 .or(override_rules.tables_out_of_order),
+            table_style: match (self.table_style, override_rules.table_style) {
+                (Some(rule), Some(override_rule)) => Some(rule.merge(&override_rule)),
+                (rule, override_rule) => rule.or(override_rule),
+            },
         }
     }
 }
@@ -0,0 +1,30 @@
+/// A secondary location attached to a [`crate::Diagnostic`].
+///
+/// The primary range of the diagnostic points at the offending code,
+/// and labels point at related code such as the original definition.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "wasm", derive(serde::Serialize))]
+pub struct Label {
+    range: tombi_text::Range,
+    message: String,
+}
+
+impl Label {
+    #[inline]
+    pub fn new(range: impl Into<tombi_text::Range>, message: impl Into<String>) -> Self {
+        Self {
+            range: range.into(),
+            message: message.into(),
+        }
+    }
+
+    #[inline]
+    pub fn range(&self) -> tombi_text::Range {
+        self.range
+    }
+
+    #[inline]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
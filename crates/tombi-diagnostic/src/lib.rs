@@ -1,6 +1,8 @@
+mod label;
 mod level;
 pub mod printer;
 
+pub use label::Label;
 pub use level::Level;
 pub use printer::Print;
 
@@ -13,6 +15,9 @@ pub struct Diagnostic {
     message: String,
     range: tombi_text::Range,
     source_file: Option<std::path::PathBuf>,
+    labels: Vec<Label>,
+    notes: Vec<String>,
+    helps: Vec<String>,
 }
 
 impl Diagnostic {
@@ -28,6 +33,9 @@ impl Diagnostic {
             message: message.into(),
             range: range.into(),
             source_file: None,
+            labels: Vec::new(),
+            notes: Vec::new(),
+            helps: Vec::new(),
         }
     }
 
@@ -43,6 +51,9 @@ impl Diagnostic {
             message: message.into(),
             range: range.into(),
             source_file: None,
+            labels: Vec::new(),
+            notes: Vec::new(),
+            helps: Vec::new(),
         }
     }
 
@@ -51,6 +62,41 @@ impl Diagnostic {
         self
     }
 
+    /// Attach a secondary location, such as the original definition of a duplicate key.
+    pub fn with_label(
+        mut self,
+        range: impl Into<tombi_text::Range>,
+        message: impl Into<String>,
+    ) -> Self {
+        self.labels.push(Label::new(range, message));
+        self
+    }
+
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.helps.push(help.into());
+        self
+    }
+
+    /// Replace the message, such as by the custom `errorMessage` of a schema.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    /// Move the diagnostic and its labels, such as from embedded content into its host document.
+    pub fn map_ranges(mut self, f: impl Fn(tombi_text::Range) -> tombi_text::Range) -> Self {
+        self.range = f(self.range);
+        for label in &mut self.labels {
+            *label = Label::new(f(label.range()), label.message());
+        }
+        self
+    }
+
     #[inline]
     pub fn level(&self) -> level::Level {
         self.level
@@ -90,6 +136,21 @@ impl Diagnostic {
     pub fn source_file(&self) -> Option<&std::path::Path> {
         self.source_file.as_deref()
     }
+
+    #[inline]
+    pub fn labels(&self) -> &[Label] {
+        &self.labels
+    }
+
+    #[inline]
+    pub fn notes(&self) -> &[String] {
+        &self.notes
+    }
+
+    #[inline]
+    pub fn helps(&self) -> &[String] {
+        &self.helps
+    }
 }
 
 impl PartialEq for Diagnostic {
@@ -131,16 +192,97 @@ impl tombi_text::FromLsp<Diagnostic> for tower_lsp::lsp_types::Diagnostic {
     ) -> tower_lsp::lsp_types::Diagnostic {
         use tombi_text::IntoLsp;
 
+        // LSP has no dedicated fields for notes and help, so append them to the message
+        // in the same order as the `pretty` printer.
+        let mut message = source.message().to_string();
+        for note in source.notes() {
+            message.push_str(&format!("\nnote: {note}"));
+        }
+        for help in source.helps() {
+            message.push_str(&format!("\nhelp: {help}"));
+        }
+
         tower_lsp::lsp_types::Diagnostic {
             range: source.range().into_lsp(line_index),
             severity: Some(match source.level() {
                 level::Level::WARNING => tower_lsp::lsp_types::DiagnosticSeverity::WARNING,
                 level::Level::ERROR => tower_lsp::lsp_types::DiagnosticSeverity::ERROR,
             }),
-            message: source.message().to_string(),
+            message,
             source: Some("Tombi".to_owned()),
             code: Some(tower_lsp::lsp_types::NumberOrString::String(source.code)),
             ..Default::default()
         }
     }
 }
+
+#[cfg(feature = "lsp")]
+impl Diagnostic {
+    /// Convert to an LSP diagnostic, mapping labels to `relatedInformation` in the given document.
+    pub fn into_lsp_with_uri(
+        self,
+        uri: &tower_lsp::lsp_types::Url,
+        line_index: &tombi_text::LineIndex,
+    ) -> tower_lsp::lsp_types::Diagnostic {
+        use tombi_text::IntoLsp;
+
+        let related_information = (!self.labels.is_empty()).then(|| {
+            self.labels
+                .iter()
+                .map(|label| tower_lsp::lsp_types::DiagnosticRelatedInformation {
+                    location: tower_lsp::lsp_types::Location {
+                        uri: uri.clone(),
+                        range: label.range().into_lsp(line_index),
+                    },
+                    message: label.message().to_string(),
+                })
+                .collect()
+        });
+
+        tower_lsp::lsp_types::Diagnostic {
+            related_information,
+            ..self.into_lsp(line_index)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "lsp"))]
+mod tests {
+    use tombi_text::{EncodingKind, IntoLsp, LineIndex};
+
+    use super::*;
+
+    #[test]
+    fn into_lsp_with_uri_maps_labels_to_related_information() {
+        let line_index = LineIndex::new("a = 1\na = 2\n", EncodingKind::Utf16);
+        let uri = tower_lsp::lsp_types::Url::parse("file:///test.toml").unwrap();
+
+        let diagnostic =
+            Diagnostic::new_error("duplicate key: a", "duplicate-key", ((1, 0), (1, 1)))
+                .with_label(((0, 0), (0, 1)), "first defined here")
+                .into_lsp_with_uri(&uri, &line_index);
+
+        let related_information = diagnostic.related_information.unwrap();
+        assert_eq!(related_information.len(), 1);
+        assert_eq!(related_information[0].location.uri, uri);
+        assert_eq!(related_information[0].location.range.start.line, 0);
+        assert_eq!(related_information[0].message, "first defined here");
+    }
+
+    #[test]
+    fn into_lsp_appends_notes_and_helps_to_message() {
+        let line_index = LineIndex::new("a = 1\n", EncodingKind::Utf16);
+
+        let diagnostic: tower_lsp::lsp_types::Diagnostic =
+            Diagnostic::new_warning("message", "code", ((0, 0), (0, 1)))
+                .with_note("some note")
+                .with_help("some help")
+                .into_lsp(&line_index);
+
+        assert_eq!(
+            diagnostic.message,
+            "message\nnote: some note\nhelp: some help"
+        );
+        assert_eq!(diagnostic.related_information, None);
+    }
+}
@@ -27,38 +27,63 @@ impl Print<Pretty> for Diagnostic {
     fn print(&self, printer: &mut Pretty) {
         self.level().print(printer);
 
-        let (message_style, at_style, link_style) = if printer.use_ansi_color {
-            (
-                Style::new().bold(),
-                Style::new().fg(Color::DarkGray),
-                Style::new().fg(Color::Cyan),
-            )
-        } else {
-            (Style::new(), Style::new(), Style::new())
-        };
+        let (message_style, at_style, link_style, note_style, help_style) =
+            if printer.use_ansi_color {
+                (
+                    Style::new().bold(),
+                    Style::new().fg(Color::DarkGray),
+                    Style::new().fg(Color::Cyan),
+                    Style::new().bold(),
+                    Style::new().bold().fg(Color::Cyan),
+                )
+            } else {
+                (
+                    Style::new(),
+                    Style::new(),
+                    Style::new(),
+                    Style::new(),
+                    Style::new(),
+                )
+            };
 
         eprintln!(": {}", message_style.paint(self.message()));
 
-        if let Some(source_file) = self.source_file() {
-            eprintln!(
-                "    {} {}",
-                at_style.paint("at"),
-                link_style.paint(format!(
-                    "{}:{}:{}",
-                    source_file.display(),
-                    self.position().line + 1,
-                    self.position().column + 1
-                )),
-            );
-        } else {
-            eprintln!(
-                "    {}",
-                at_style.paint(format!(
-                    "at line {} column {}",
-                    self.position().line + 1,
-                    self.position().column + 1
-                )),
-            );
+        let location = |position: tombi_text::Position| {
+            if let Some(source_file) = self.source_file() {
+                format!(
+                    "{} {}",
+                    at_style.paint("at"),
+                    link_style.paint(format!(
+                        "{}:{}:{}",
+                        source_file.display(),
+                        position.line + 1,
+                        position.column + 1
+                    )),
+                )
+            } else {
+                format!(
+                    "{}",
+                    at_style.paint(format!(
+                        "at line {} column {}",
+                        position.line + 1,
+                        position.column + 1
+                    )),
+                )
+            }
+        };
+
+        eprintln!("    {}", location(self.position()));
+
+        for label in self.labels() {
+            eprintln!("    {}: {}", location(label.range().start), label.message());
+        }
+
+        for note in self.notes() {
+            eprintln!("    = {}: {}", note_style.paint("note"), note);
+        }
+
+        for help in self.helps() {
+            eprintln!("    = {}: {}", help_style.paint("help"), help);
         }
     }
 }
@@ -5,6 +5,7 @@ pub enum Error {
     DuplicateKey {
         key: String,
         range: tombi_text::Range,
+        first_range: tombi_text::Range,
     },
 
     #[error("conflicting table")]
@@ -123,26 +124,20 @@ impl Error {
 #[cfg(feature = "diagnostic")]
 impl tombi_diagnostic::SetDiagnostics for Error {
     fn set_diagnostics(self, diagnostics: &mut Vec<tombi_diagnostic::Diagnostic>) {
-        match self {
-            Self::ConflictArray { range1, range2 } => {
-                let diagnostic1 =
-                    tombi_diagnostic::Diagnostic::new_error(self.to_message(), self.code(), range1);
-                if !diagnostics.contains(&diagnostic1) {
-                    diagnostics.push(diagnostic1);
-                }
-                diagnostics.push(tombi_diagnostic::Diagnostic::new_error(
-                    self.to_message(),
-                    self.code(),
-                    range2,
-                ));
-            }
-            _ => {
-                diagnostics.push(tombi_diagnostic::Diagnostic::new_error(
-                    self.to_message(),
-                    self.code(),
-                    self.range(),
-                ));
+        let diagnostic =
+            tombi_diagnostic::Diagnostic::new_error(self.to_message(), self.code(), self.range());
+
+        diagnostics.push(match self {
+            Self::DuplicateKey { first_range, .. }
+            | Self::ConflictTable {
+                range1: first_range,
+                ..
             }
-        }
+            | Self::ConflictArray {
+                range1: first_range,
+                ..
+            } => diagnostic.with_label(first_range, "first defined here"),
+            _ => diagnostic,
+        });
     }
 }
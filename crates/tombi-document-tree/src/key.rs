@@ -190,3 +190,15 @@ impl LikeString for Key {
         self.comment_directives.as_deref().map(|d| d.iter())
     }
 }
+
+#[cfg(feature = "json")]
+impl From<tombi_json::StringNode> for Key {
+    fn from(node: tombi_json::StringNode) -> Self {
+        Self {
+            kind: KeyKind::BasicString,
+            value: node.value,
+            range: node.range,
+            comment_directives: None,
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{Array, ArrayKind, DocumentTree, Table, TableKind, Value};
+
+/// The keys of the document trees of a streamed source, without their values.
+///
+/// Merging the document tree of each chunk into it reports the keys that are duplicated
+/// or conflict with those of the earlier chunks, as [`DocumentTree::merge`] does for a whole document.
+///
+/// Only the last table of each array of tables is kept, since a later header can not reach the others.
+#[derive(Debug, Clone, Default)]
+pub struct DocumentTreeKeys(Option<Table>);
+
+impl DocumentTreeKeys {
+    /// Move the tables of `tree` under the arrays of tables of the earlier chunks they belong to.
+    ///
+    /// The tree of a chunk is built without the headers before it,
+    /// so `[fruit.info]` after a `[[fruit]]` of an earlier chunk makes `fruit` a table.
+    pub fn resolve_arrays_of_tables(&self, tree: &mut DocumentTree) {
+        if let Some(keys) = &self.0 {
+            resolve_arrays_of_tables(keys, &mut tree.0);
+        }
+    }
+
+    pub fn merge(&mut self, tree: DocumentTree) -> Result<(), Vec<crate::Error>> {
+        let table = keys_table(tree.0);
+
+        let Some(keys) = self.0.as_mut() else {
+            self.0 = Some(table);
+            return Ok(());
+        };
+
+        let result = keys.merge(table.clone());
+        drop_previous_array_tables(keys, &table);
+
+        result
+    }
+}
+
+pub(crate) fn resolve_arrays_of_tables(keys: &Table, table: &mut Table) {
+    for (key, value) in table.key_values.iter_mut() {
+        let Some(keys_value) = keys.get(key) else {
+            continue;
+        };
+
+        match (keys_value, &mut *value) {
+            (Value::Array(array), Value::Table(child))
+                if array.kind() != ArrayKind::Array && child.kind() == TableKind::ParentTable =>
+            {
+                if let Some(Value::Table(last)) = array.last() {
+                    resolve_arrays_of_tables(last, child);
+                }
+
+                let child = std::mem::replace(child, Table::new_empty());
+                let mut parent_array = Array::new_parent_array_of_tables(&child);
+                parent_array.push(Value::Table(child));
+                *value = Value::Array(parent_array);
+            }
+            (Value::Table(keys_table), Value::Table(child)) => {
+                resolve_arrays_of_tables(keys_table, child);
+            }
+            (Value::Array(array), Value::Array(child_array))
+                if array.kind() != ArrayKind::Array && child_array.kind() != ArrayKind::Array =>
+            {
+                if let (Some(Value::Table(last)), Some(Value::Table(child))) =
+                    (array.last(), child_array.values_mut().last_mut())
+                {
+                    resolve_arrays_of_tables(last, child);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn keys_table(mut table: Table) -> Table {
+    table.header_comment_directives = None;
+    table.body_comment_directives = None;
+    table.group_boundary_comment_directives = None;
+
+    if matches!(table.kind(), TableKind::InlineTable { .. }) {
+        // Inline tables can not be extended, so only their kind is needed.
+        table.key_values.clear();
+    } else {
+        table.key_values = std::mem::take(&mut table.key_values)
+            .into_iter()
+            .map(|(key, value)| (key, keys_value(value)))
+            .collect();
+    }
+
+    table
+}
+
+fn keys_value(value: Value) -> Value {
+    match value {
+        Value::Table(table) => Value::Table(keys_table(table)),
+        Value::Array(array) if array.kind() != ArrayKind::Array => {
+            Value::Array(keys_array_of_table(array))
+        }
+        value => Value::Incomplete {
+            range: value.range(),
+        },
+    }
+}
+
+fn keys_array_of_table(mut array: Array) -> Array {
+    array.header_comment_directives = None;
+    array.body_comment_directives = None;
+    array.group_boundary_comment_directives = None;
+
+    let last = array.values_mut().pop().map(keys_value);
+    array.values_mut().clear();
+    array.values_mut().extend(last);
+
+    array
+}
+
+/// Drop all but the last table of the arrays of tables reached by the keys of `chunk`.
+fn drop_previous_array_tables(keys: &mut Table, chunk: &Table) {
+    for (key, chunk_value) in chunk.key_values() {
+        match (keys.get_mut(key), chunk_value) {
+            (Some(Value::Table(table)), Value::Table(chunk_table)) => {
+                drop_previous_array_tables(table, chunk_table);
+            }
+            (Some(Value::Array(array)), Value::Array(chunk_array))
+                if array.kind() != ArrayKind::Array =>
+            {
+                let values = array.values_mut();
+                values.drain(..values.len().saturating_sub(1));
+
+                if let (Some(Value::Table(table)), Some(Value::Table(chunk_table))) =
+                    (values.last_mut(), chunk_array.last())
+                {
+                    drop_previous_array_tables(table, chunk_table);
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -1,5 +1,6 @@
 mod error;
 mod key;
+mod keys;
 mod literal_value;
 mod root;
 pub mod support;
@@ -8,8 +9,9 @@ mod value_type;
 
 pub use error::Error;
 pub use key::{Key, KeyKind};
+pub use keys::DocumentTreeKeys;
 pub use literal_value::LiteralValueRef;
-pub use root::DocumentTree;
+pub use root::{DocumentTree, DocumentTreeItems};
 use tombi_ast::TombiValueCommentDirective;
 use tombi_toml_version::TomlVersion;
 pub use value::{
@@ -7,6 +7,18 @@ use crate::{DocumentTreeAndErrors, IntoDocumentTreeAndErrors, Table};
 #[derive(Debug, Clone, PartialEq)]
 pub struct DocumentTree(pub(crate) Table);
 
+impl DocumentTree {
+    /// Merge a document tree built from another part of the same document,
+    /// e.g. a chunk of a streamed source.
+    ///
+    /// The tables of `other` under the arrays of tables of `self` are moved into their last tables,
+    /// since a chunk is built without the headers before it.
+    pub fn merge(&mut self, mut other: DocumentTree) -> Result<(), Vec<crate::Error>> {
+        crate::keys::resolve_arrays_of_tables(&self.0, &mut other.0);
+        self.0.merge(other.0)
+    }
+}
+
 impl From<DocumentTree> for Table {
     fn from(tree: DocumentTree) -> Self {
         tree.0
@@ -38,80 +50,196 @@ impl IntoDocumentTreeAndErrors<crate::DocumentTree> for tombi_ast::Root {
         self,
         toml_version: TomlVersion,
     ) -> crate::DocumentTreeAndErrors<crate::DocumentTree> {
-        let mut errors = vec![];
+        build_document_tree(&self, |_, item| {
+            item.into_document_tree_and_errors(toml_version)
+        })
+    }
+}
 
-        let mut tree = {
-            let mut table = crate::Table::new_root(&self);
+/// Document tree fragments of the key-values, tables and arrays of tables of a root.
+///
+/// After an incremental reparse, most items of the new root share their green nodes with the previous root.
+/// [`DocumentTreeItems::build`] converts only the items that changed or moved, and reuses the fragments of the others.
+#[derive(Debug, Clone, Default)]
+pub struct DocumentTreeItems {
+    toml_version: Option<TomlVersion>,
+    fragments: tombi_hashmap::HashMap<ItemKey, ItemFragment>,
+}
 
-            let mut body_comment_directives = vec![];
-            for comment_group in self.dangling_comment_groups() {
-                for comment in comment_group.comments() {
-                    if let Err(error) = crate::support::comment::try_new_comment(&comment) {
-                        errors.push(error);
-                    }
-                    if let Some(comment_directive) = comment.get_tombi_value_directive() {
-                        body_comment_directives.push(comment_directive);
+/// The address of the green node and the start position of an item.
+///
+/// The conversion of an item only depends on its text and where it starts.
+type ItemKey = (usize, tombi_text::Line, tombi_text::Column);
+
+#[derive(Debug, Clone)]
+struct ItemFragment {
+    /// Keeps the green node alive, so that its address is not reused by another node.
+    _green: tombi_rg_tree::GreenNode,
+    table: Table,
+    errors: Vec<crate::Error>,
+}
+
+impl DocumentTreeItems {
+    /// Build the document tree of `root`, reusing the fragments of `self` for unchanged items.
+    ///
+    /// Returns the tree and the fragments of `root`, to be passed to the next build.
+    pub fn build(
+        &self,
+        root: &tombi_ast::Root,
+        toml_version: TomlVersion,
+    ) -> (DocumentTreeAndErrors<DocumentTree>, DocumentTreeItems) {
+        let reusable = self.toml_version == Some(toml_version);
+        let mut fragments = tombi_hashmap::HashMap::default();
+
+        let tree = build_document_tree(root, |syntax, item| {
+            let green = syntax.green().into_owned();
+            let start = syntax.range().start;
+            let key = (
+                &*green as *const tombi_rg_tree::GreenNodeData as usize,
+                start.line,
+                start.column,
+            );
+
+            let fragment = match self.fragments.get(&key).filter(|_| reusable) {
+                Some(fragment) => fragment.clone(),
+                None => {
+                    let (table, errors) = item.into_document_tree_and_errors(toml_version).into();
+                    ItemFragment {
+                        _green: green,
+                        table,
+                        errors,
                     }
                 }
+            };
+            let tree = DocumentTreeAndErrors {
+                tree: fragment.table.clone(),
+                errors: fragment.errors.clone(),
+            };
+            fragments.insert(key, fragment);
+            tree
+        });
+
+        (
+            tree,
+            DocumentTreeItems {
+                toml_version: Some(toml_version),
+                fragments,
+            },
+        )
+    }
+}
+
+enum RootItem {
+    KeyValue(tombi_ast::KeyValue),
+    Table(tombi_ast::Table),
+    ArrayOfTable(tombi_ast::ArrayOfTable),
+}
+
+impl IntoDocumentTreeAndErrors<Table> for RootItem {
+    fn into_document_tree_and_errors(
+        self,
+        toml_version: TomlVersion,
+    ) -> DocumentTreeAndErrors<Table> {
+        match self {
+            RootItem::KeyValue(key_value) => key_value.into_document_tree_and_errors(toml_version),
+            RootItem::Table(table) => table.into_document_tree_and_errors(toml_version),
+            RootItem::ArrayOfTable(array_of_table) => {
+                array_of_table.into_document_tree_and_errors(toml_version)
             }
+        }
+    }
+}
+
+fn build_document_tree(
+    root: &tombi_ast::Root,
+    mut convert_item: impl FnMut(&tombi_syntax::SyntaxNode, RootItem) -> DocumentTreeAndErrors<Table>,
+) -> DocumentTreeAndErrors<DocumentTree> {
+    use tombi_ast::AstNode;
 
-            if !body_comment_directives.is_empty() {
-                table.body_comment_directives = Some(body_comment_directives);
+    let mut errors = vec![];
+
+    let mut tree = {
+        let mut table = crate::Table::new_root(root);
+
+        let mut body_comment_directives = vec![];
+        for comment_group in root.dangling_comment_groups() {
+            for comment in comment_group.comments() {
+                if let Err(error) = crate::support::comment::try_new_comment(&comment) {
+                    errors.push(error);
+                }
+                if let Some(comment_directive) = comment.get_tombi_value_directive() {
+                    body_comment_directives.push(comment_directive);
+                }
             }
+        }
 
-            crate::DocumentTree(table)
-        };
-
-        {
-            let mut group_boundary_comment_directives = Vec::new();
-            for group in self.key_value_groups() {
-                match group {
-                    tombi_ast::DanglingCommentGroupOr::ItemGroup(key_value_group) => {
-                        for key_value in key_value_group.into_key_values() {
-                            let (table, errs) =
-                                key_value.into_document_tree_and_errors(toml_version).into();
-                            if !errs.is_empty() {
-                                errors.extend(errs);
-                            }
-                            if let Err(errs) = tree.0.merge(table) {
-                                errors.extend(errs);
-                            }
+        if !body_comment_directives.is_empty() {
+            table.body_comment_directives = Some(body_comment_directives);
+        }
+
+        crate::DocumentTree(table)
+    };
+
+    {
+        let mut group_boundary_comment_directives = Vec::new();
+        for group in root.key_value_groups() {
+            match group {
+                tombi_ast::DanglingCommentGroupOr::ItemGroup(key_value_group) => {
+                    for key_value in key_value_group.into_key_values() {
+                        let (table, errs) = convert_item(
+                            &key_value.syntax().clone(),
+                            RootItem::KeyValue(key_value),
+                        )
+                        .into();
+                        if !errs.is_empty() {
+                            errors.extend(errs);
+                        }
+                        if let Err(errs) = tree.0.merge(table) {
+                            errors.extend(errs);
                         }
                     }
-                    tombi_ast::DanglingCommentGroupOr::DanglingCommentGroup(comment_group) => {
-                        for comment in comment_group.comments() {
-                            if let Some(comment_directive) = comment.get_tombi_value_directive() {
-                                group_boundary_comment_directives.push(comment_directive);
-                            }
+                }
+                tombi_ast::DanglingCommentGroupOr::DanglingCommentGroup(comment_group) => {
+                    for comment in comment_group.comments() {
+                        if let Some(comment_directive) = comment.get_tombi_value_directive() {
+                            group_boundary_comment_directives.push(comment_directive);
                         }
                     }
                 }
             }
-            if !group_boundary_comment_directives.is_empty() {
-                tree.0.group_boundary_comment_directives = Some(group_boundary_comment_directives);
-            }
         }
+        if !group_boundary_comment_directives.is_empty() {
+            tree.0.group_boundary_comment_directives = Some(group_boundary_comment_directives);
+        }
+    }
 
-        for table_or_array_of_table in self.table_or_array_of_tables() {
-            let (table, errs) = match table_or_array_of_table {
-                tombi_ast::TableOrArrayOfTable::Table(table) => {
-                    table.into_document_tree_and_errors(toml_version)
-                }
-                tombi_ast::TableOrArrayOfTable::ArrayOfTable(array_of_table) => {
-                    array_of_table.into_document_tree_and_errors(toml_version)
-                }
+    for table_or_array_of_table in root.table_or_array_of_tables() {
+        let (table, errs) = match table_or_array_of_table {
+            tombi_ast::TableOrArrayOfTable::Table(table) => {
+                convert_item(&table.syntax().clone(), RootItem::Table(table))
             }
-            .into();
+            tombi_ast::TableOrArrayOfTable::ArrayOfTable(array_of_table) => convert_item(
+                &array_of_table.syntax().clone(),
+                RootItem::ArrayOfTable(array_of_table),
+            ),
+        }
+        .into();
 
-            if !errs.is_empty() {
-                errors.extend(errs);
-            }
+        if !errs.is_empty() {
+            errors.extend(errs);
+        }
 
-            if let Err(errs) = tree.0.merge(table) {
-                errors.extend(errs);
-            }
+        if let Err(errs) = tree.0.merge(table) {
+            errors.extend(errs);
         }
+    }
+
+    DocumentTreeAndErrors { tree, errors }
+}
 
-        DocumentTreeAndErrors { tree, errors }
+#[cfg(feature = "json")]
+impl From<tombi_json::ValueNode> for DocumentTree {
+    fn from(node: tombi_json::ValueNode) -> Self {
+        DocumentTree(Table::new_json_root(node))
     }
 }
@@ -322,3 +322,24 @@ fn collect_comment_directives_and_errors(
         (None, errors)
     }
 }
+
+/// Converts a JSON value, so that JSON documents such as JSON Schemas can be validated.
+///
+/// JSON `null` has no TOML counterpart and becomes [`Value::Incomplete`],
+/// which the validator checks as `null` when `SchemaContext::incomplete_as_null` is set.
+#[cfg(feature = "json")]
+impl From<tombi_json::ValueNode> for Value {
+    fn from(node: tombi_json::ValueNode) -> Self {
+        match node {
+            tombi_json::ValueNode::Null(null) => Value::Incomplete { range: null.range },
+            tombi_json::ValueNode::Bool(boolean) => Value::Boolean(boolean.into()),
+            tombi_json::ValueNode::Number(number) => match Integer::try_from(&number) {
+                Ok(integer) => Value::Integer(integer),
+                Err(()) => Value::Float(Float::from(&number)),
+            },
+            tombi_json::ValueNode::String(string) => Value::String(string.into()),
+            tombi_json::ValueNode::Array(array) => Value::Array(array.into()),
+            tombi_json::ValueNode::Object(object) => Value::Table(object.into()),
+        }
+    }
+}
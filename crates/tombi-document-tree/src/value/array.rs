@@ -259,6 +259,12 @@ impl std::fmt::Display for Array {
     }
 }
 
+impl From<Array> for Vec<Value> {
+    fn from(array: Array) -> Self {
+        array.values
+    }
+}
+
 impl ValueImpl for Array {
     fn value_type(&self) -> ValueType {
         ValueType::Array
@@ -440,3 +446,18 @@ impl IntoIterator for Array {
         self.values.into_iter()
     }
 }
+
+#[cfg(feature = "json")]
+impl From<tombi_json::ArrayNode> for crate::Array {
+    fn from(node: tombi_json::ArrayNode) -> Self {
+        Self {
+            kind: ArrayKind::Array,
+            range: node.range,
+            symbol_range: node.range,
+            values: node.items.into_iter().map(crate::Value::from).collect(),
+            header_comment_directives: None,
+            body_comment_directives: None,
+            group_boundary_comment_directives: None,
+        }
+    }
+}
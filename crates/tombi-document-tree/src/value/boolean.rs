@@ -85,3 +85,14 @@ impl IntoDocumentTreeAndErrors<crate::Value> for tombi_ast::Boolean {
         }
     }
 }
+
+#[cfg(feature = "json")]
+impl From<tombi_json::BoolNode> for crate::Boolean {
+    fn from(node: tombi_json::BoolNode) -> Self {
+        Self {
+            value: node.value,
+            range: node.range,
+            comment_directives: None,
+        }
+    }
+}
@@ -90,3 +90,14 @@ impl IntoDocumentTreeAndErrors<crate::Value> for tombi_ast::Float {
         }
     }
 }
+
+#[cfg(feature = "json")]
+impl From<&tombi_json::NumberNode> for crate::Float {
+    fn from(node: &tombi_json::NumberNode) -> Self {
+        Self {
+            value: node.value.as_f64().unwrap_or(f64::NAN),
+            range: node.range,
+            comment_directives: None,
+        }
+    }
+}
@@ -223,3 +223,17 @@ impl IntoDocumentTreeAndErrors<crate::Value> for tombi_ast::IntegerHex {
         }
     }
 }
+
+#[cfg(feature = "json")]
+impl TryFrom<&tombi_json::NumberNode> for crate::Integer {
+    type Error = ();
+
+    fn try_from(node: &tombi_json::NumberNode) -> Result<Self, Self::Error> {
+        Ok(Self {
+            kind: IntegerKind::Decimal,
+            value: node.value.as_i64().ok_or(())?,
+            range: node.range,
+            comment_directives: None,
+        })
+    }
+}
@@ -235,3 +235,15 @@ fn into_string_and_errors<T: AstNode>(
         errors,
     }
 }
+
+#[cfg(feature = "json")]
+impl From<tombi_json::StringNode> for crate::String {
+    fn from(node: tombi_json::StringNode) -> Self {
+        Self {
+            kind: StringKind::BasicString,
+            value: node.value,
+            range: node.range,
+            comment_directives: None,
+        }
+    }
+}
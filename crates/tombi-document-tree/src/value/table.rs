@@ -59,7 +59,7 @@ pub struct Table {
     kind: TableKind,
     range: tombi_text::Range,
     symbol_range: tombi_text::Range,
-    key_values: tombi_hashmap::IndexMap<Key, Value>,
+    pub(crate) key_values: tombi_hashmap::IndexMap<Key, Value>,
     pub(crate) header_comment_directives: Option<Vec<TombiValueCommentDirective>>,
     pub(crate) body_comment_directives: Option<Vec<TombiValueCommentDirective>>,
     pub(crate) group_boundary_comment_directives: Option<Vec<TombiValueCommentDirective>>,
@@ -311,9 +311,11 @@ impl Table {
                         }
                         _ => {
                             let range = key.range();
+                            let first_range = entry.key().range();
                             errors.push(crate::Error::DuplicateKey {
                                 key: key.value,
                                 range,
+                                first_range,
                             });
                         }
                     }
@@ -333,6 +335,7 @@ impl Table {
 
     pub(crate) fn insert(mut self, key: Key, value: Value) -> Result<Self, Vec<crate::Error>> {
         let mut errors = Vec::new();
+        let key_range = key.range();
 
         match self.key_values.entry(key) {
             tombi_hashmap::map::Entry::Occupied(mut entry) => {
@@ -353,7 +356,8 @@ impl Table {
                     _ => {
                         errors.push(crate::Error::DuplicateKey {
                             key: entry.key().value.to_string(),
-                            range: entry.key().range(),
+                            range: key_range,
+                            first_range: entry.key().range(),
                         });
                     }
                 }
@@ -1180,3 +1184,38 @@ fn append_header_comment_directives(
         table.key_values.insert(key, value);
     }
 }
+
+#[cfg(feature = "json")]
+impl From<tombi_json::ObjectNode> for crate::Table {
+    fn from(node: tombi_json::ObjectNode) -> Self {
+        Self {
+            kind: TableKind::InlineTable { has_comment: false },
+            range: node.range,
+            symbol_range: node.range,
+            key_values: node
+                .properties
+                .into_iter()
+                .map(|(key, value)| (Key::from(key), crate::Value::from(value)))
+                .collect(),
+            header_comment_directives: None,
+            body_comment_directives: None,
+            group_boundary_comment_directives: None,
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl Table {
+    pub(crate) fn new_json_root(node: tombi_json::ValueNode) -> Self {
+        let range = node.range();
+        let mut table = match node {
+            tombi_json::ValueNode::Object(object) => Table::from(object),
+            _ => Table::new_empty(),
+        };
+        table.kind = TableKind::Root;
+        table.range = range;
+        table.symbol_range = range;
+
+        table
+    }
+}
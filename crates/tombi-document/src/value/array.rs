@@ -80,7 +80,7 @@ impl IntoDocument<Array> for tombi_document_tree::Array {
     fn into_document(self, toml_version: tombi_toml_version::TomlVersion) -> Array {
         Array {
             kind: self.kind().into(),
-            values: Vec::<tombi_document_tree::Value>::from(self.values())
+            values: Vec::<tombi_document_tree::Value>::from(self)
                 .into_iter()
                 .map(|value| value.into_document(toml_version))
                 .collect(),
@@ -18,6 +18,7 @@ use crate::get_tombi_github_uri;
 pub enum CompletionContentPriority {
     Custom(String),
     Default,
+    Snippet,
     Const,
     Enum,
     Example,
@@ -37,6 +38,8 @@ impl CompletionContentPriority {
             // NOTE: Completion candidates provided by extensions are assigned priority numbers starting from 50,
             //       allowing them to be placed above basic features.
             CompletionContentPriority::Default => "50".to_string(),
+            // NOTE: `defaultSnippets` are shown next to the default value.
+            CompletionContentPriority::Snippet => "50".to_string(),
             CompletionContentPriority::Const => "51".to_string(),
             CompletionContentPriority::Enum => "52".to_string(),
             CompletionContentPriority::Example => "53".to_string(),
@@ -171,6 +174,51 @@ impl CompletionContent {
         }
     }
 
+    pub fn new_default_snippet(
+        label: String,
+        documentation: Option<String>,
+        edit: Option<CompletionEdit>,
+        schema_uri: Option<&SchemaUri>,
+    ) -> Self {
+        Self {
+            label,
+            kind: CompletionKind::Snippet,
+            emoji_icon: None,
+            priority: CompletionContentPriority::Snippet,
+            detail: None,
+            documentation,
+            filter_text: None,
+            schema_uri: schema_uri.cloned(),
+            edit,
+            deprecated: None,
+            preselect: None,
+            in_comment: false,
+        }
+    }
+
+    pub fn new_table_snippet(
+        label: String,
+        key_name: &str,
+        documentation: Option<String>,
+        edit: CompletionEdit,
+        schema_uri: Option<&SchemaUri>,
+    ) -> Self {
+        Self {
+            label,
+            kind: CompletionKind::Snippet,
+            emoji_icon: None,
+            priority: CompletionContentPriority::Snippet,
+            detail: None,
+            documentation,
+            filter_text: Some(key_name.to_string()),
+            schema_uri: schema_uri.cloned(),
+            edit: Some(edit),
+            deprecated: None,
+            preselect: None,
+            in_comment: false,
+        }
+    }
+
     pub fn new_type_hint_value(
         kind: CompletionKind,
         label: impl Into<String>,
@@ -566,6 +614,15 @@ impl FromLsp<CompletionContent> for tower_lsp::lsp_types::CompletionItem {
                     }),
                 })
             }
+            CompletionContentPriority::Snippet => {
+                Some(tower_lsp::lsp_types::CompletionItemLabelDetails {
+                    detail: None,
+                    description: Some(match &source.detail {
+                        Some(detail) => detail.to_string(),
+                        None => "Snippet".to_string(),
+                    }),
+                })
+            }
             CompletionContentPriority::Const => {
                 Some(tower_lsp::lsp_types::CompletionItemLabelDetails {
                     detail: None,
@@ -96,6 +96,71 @@ impl CompletionEdit {
         }
     }
 
+    /// An edit inserting a snippet (`defaultSnippets`) as the value.
+    pub fn new_snippet(
+        snippet: &str,
+        position: tombi_text::Position,
+        completion_hint: Option<CompletionHint>,
+    ) -> Option<Self> {
+        match completion_hint {
+            Some(
+                CompletionHint::DotTrigger { cleanup_range, .. }
+                | CompletionHint::EqualTrigger { cleanup_range, .. },
+            ) => Some(Self {
+                text_edit: CompletionTextEdit::Edit(TextEdit {
+                    new_text: format!(" = {snippet}"),
+                    range: tombi_text::Range::at(position),
+                }),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
+                additional_text_edits: Some(vec![TextEdit {
+                    range: cleanup_range,
+                    new_text: "".to_string(),
+                }]),
+            }),
+            Some(CompletionHint::InArray {
+                add_leading_comma,
+                add_trailing_comma,
+            }) => {
+                let new_text = match add_trailing_comma {
+                    Some(_) => format!("{snippet},"),
+                    None => snippet.to_string(),
+                };
+                let additional_text_edits =
+                    head_comma_text_edits(add_leading_comma, add_trailing_comma, position);
+
+                Some(Self {
+                    text_edit: CompletionTextEdit::Edit(TextEdit {
+                        new_text,
+                        range: tombi_text::Range::at(position),
+                    }),
+                    insert_text_format: Some(InsertTextFormat::SNIPPET),
+                    additional_text_edits,
+                })
+            }
+            Some(CompletionHint::InTableHeader) => None,
+            Some(CompletionHint::Comma { .. }) | None => Some(Self {
+                text_edit: CompletionTextEdit::Edit(TextEdit {
+                    new_text: snippet.to_string(),
+                    range: tombi_text::Range::at(position),
+                }),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
+                additional_text_edits: None,
+            }),
+        }
+    }
+
+    /// An edit replacing the editing key with a table header and its body.
+    pub fn new_table_snippet(snippet: &str, key_range: tombi_text::Range) -> Self {
+        Self {
+            text_edit: CompletionTextEdit::Edit(TextEdit {
+                new_text: snippet.to_string(),
+                range: key_range,
+            }),
+            insert_text_format: Some(InsertTextFormat::SNIPPET),
+            additional_text_edits: None,
+        }
+    }
+
     pub fn new_string_literal(
         quote: &str,
         position: tombi_text::Position,
@@ -16,6 +16,7 @@ pub enum CompletionKind {
     MagicTrigger,
     CommentDirective,
     File,
+    Snippet,
 }
 
 impl CompletionKind {
@@ -57,6 +58,7 @@ impl From<CompletionKind> for tower_lsp::lsp_types::CompletionItemKind {
             CompletionKind::MagicTrigger => tower_lsp::lsp_types::CompletionItemKind::METHOD,
             CompletionKind::CommentDirective => tower_lsp::lsp_types::CompletionItemKind::KEYWORD,
             CompletionKind::File => tower_lsp::lsp_types::CompletionItemKind::FILE,
+            CompletionKind::Snippet => tower_lsp::lsp_types::CompletionItemKind::SNIPPET,
         }
     }
 }
@@ -0,0 +1,22 @@
+use tombi_severity_level::SeverityLevel;
+
+/// Push an extension diagnostic at the configured severity level.
+///
+/// Nothing is pushed when the level is [`SeverityLevel::Off`].
+pub fn push_diagnostic(
+    level: impl Into<SeverityLevel>,
+    message: String,
+    code: &str,
+    range: tombi_text::Range,
+    diagnostics: &mut Vec<tombi_diagnostic::Diagnostic>,
+) {
+    match level.into() {
+        SeverityLevel::Error => diagnostics.push(tombi_diagnostic::Diagnostic::new_error(
+            message, code, range,
+        )),
+        SeverityLevel::Warn => diagnostics.push(tombi_diagnostic::Diagnostic::new_warning(
+            message, code, range,
+        )),
+        SeverityLevel::Off => {}
+    }
+}
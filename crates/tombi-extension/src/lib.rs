@@ -1,4 +1,5 @@
 mod completion;
+mod diagnostic;
 mod document_link;
 mod hover;
 mod inlay_hint;
@@ -9,6 +10,7 @@ pub mod remote_cache;
 mod text_edit;
 
 pub use completion::*;
+pub use diagnostic::push_diagnostic;
 pub use document_link::*;
 pub use hover::*;
 pub use inlay_hint::*;
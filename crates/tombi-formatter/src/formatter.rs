@@ -151,6 +151,10 @@ impl<'a> Formatter<'a> {
                     .and_then(|directive| {
                         directive.schema.as_ref().and_then(|schema| schema.strict)
                     }),
+                table_style: None,
+                validation_cache: None,
+                incomplete_as_null: false,
+                partial_root: false,
             },
         )
         .edit()
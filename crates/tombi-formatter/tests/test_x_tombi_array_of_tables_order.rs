@@ -0,0 +1,180 @@
+mod array_of_tables_order {
+    use tombi_formatter::{Formatter, test_format};
+    use tombi_test_lib::x_tombi_array_of_tables_order_test_schema_path;
+
+    test_format! {
+        #[tokio::test]
+        async fn test_sort_array_of_tables_by_key(
+            r#"
+            [[bin]]
+            name = "zeta"
+            path = "src/zeta.rs"
+
+            [[bin]]
+            name = "alpha"
+            path = "src/alpha.rs"
+            "#,
+            SchemaPath(x_tombi_array_of_tables_order_test_schema_path()),
+        ) -> Ok(
+            r#"
+            [[bin]]
+            name = "alpha"
+            path = "src/alpha.rs"
+
+            [[bin]]
+            name = "zeta"
+            path = "src/zeta.rs"
+            "#
+        )
+    }
+
+    test_format! {
+        #[tokio::test]
+        async fn test_sort_array_of_tables_keeps_comments(
+            r#"
+            # zeta binary
+            [[bin]]
+            name = "zeta"  # trailing
+
+            # alpha binary
+            [[bin]]
+            name = "alpha"
+            "#,
+            SchemaPath(x_tombi_array_of_tables_order_test_schema_path()),
+        ) -> Ok(
+            r#"
+            # alpha binary
+            [[bin]]
+            name = "alpha"
+
+            # zeta binary
+            [[bin]]
+            name = "zeta"  # trailing
+            "#
+        )
+    }
+
+    test_format! {
+        #[tokio::test]
+        async fn test_sort_array_of_tables_by_multiple_keys_with_version_sort(
+            r#"
+            [[package]]
+            name = "serde"
+            version = "1.0.10"
+
+            [[package]]
+            name = "anyhow"
+            version = "1.0.0"
+
+            [[package]]
+            name = "serde"
+            version = "1.0.9"
+            "#,
+            SchemaPath(x_tombi_array_of_tables_order_test_schema_path()),
+        ) -> Ok(
+            r#"
+            [[package]]
+            name = "anyhow"
+            version = "1.0.0"
+
+            [[package]]
+            name = "serde"
+            version = "1.0.9"
+
+            [[package]]
+            name = "serde"
+            version = "1.0.10"
+            "#
+        )
+    }
+
+    test_format! {
+        #[tokio::test]
+        async fn test_sort_array_of_tables_moves_sub_tables_with_entry(
+            r#"
+            [[package]]
+            name = "serde"
+
+            [package.metadata]
+            msrv = "1.60"
+
+            [[package]]
+            name = "anyhow"
+            "#,
+            SchemaPath(x_tombi_array_of_tables_order_test_schema_path()),
+        ) -> Ok(
+            r#"
+            [[package]]
+            name = "anyhow"
+
+            [[package]]
+            name = "serde"
+
+            [package.metadata]
+            msrv = "1.60"
+            "#
+        )
+    }
+
+    test_format! {
+        #[tokio::test]
+        async fn test_sort_array_of_tables_missing_key_last(
+            r#"
+            [[bin]]
+            path = "src/main.rs"
+
+            [[bin]]
+            name = "alpha"
+            "#,
+            SchemaPath(x_tombi_array_of_tables_order_test_schema_path()),
+        ) -> Ok(
+            r#"
+            [[bin]]
+            name = "alpha"
+
+            [[bin]]
+            path = "src/main.rs"
+            "#
+        )
+    }
+
+    test_format! {
+        #[tokio::test]
+        async fn test_sort_nested_array_of_tables_descending(
+            r#"
+            [[tool.overrides]]
+            module = "low"
+            priority = 1
+
+            [[tool.overrides]]
+            module = "high"
+            priority = 10
+            "#,
+            SchemaPath(x_tombi_array_of_tables_order_test_schema_path()),
+        ) -> Ok(
+            r#"
+            [[tool.overrides]]
+            module = "high"
+            priority = 10
+
+            [[tool.overrides]]
+            module = "low"
+            priority = 1
+            "#
+        )
+    }
+
+    test_format! {
+        #[tokio::test]
+        async fn test_sorted_array_of_tables_is_unchanged(
+            r#"
+            [[bin]]
+            name = "alpha"
+
+            [[bin]]
+            name = "zeta"
+            "#,
+            SchemaPath(x_tombi_array_of_tables_order_test_schema_path()),
+        ) -> Ok(source)
+    }
+}
@@ -0,0 +1,90 @@
+mod string_values_order_by {
+    use tombi_formatter::{Formatter, test_format};
+    use tombi_test_lib::x_tombi_array_values_order_by_test_schema_path;
+
+    test_format! {
+        #[tokio::test]
+        async fn test_pep503_name_ignores_case_and_specifiers(
+            r#"
+            dependencies = ["requests>=2", "Django[argon2]==5.0", "attrs"]
+            "#,
+            SchemaPath(x_tombi_array_values_order_by_test_schema_path()),
+        ) -> Ok(
+            r#"
+            dependencies = ["attrs", "Django[argon2]==5.0", "requests>=2"]
+            "#
+        )
+    }
+
+    test_format! {
+        #[tokio::test]
+        async fn test_pep503_name_normalizes_separators(
+            r#"
+            dependencies = ["foo-baz", "foo.bay", "foo_bar"]
+            "#,
+            SchemaPath(x_tombi_array_values_order_by_test_schema_path()),
+        ) -> Ok(
+            r#"
+            dependencies = ["foo_bar", "foo.bay", "foo-baz"]
+            "#
+        )
+    }
+
+    test_format! {
+        #[tokio::test]
+        async fn test_pep503_name_keeps_comments(
+            r#"
+            dependencies = [
+              "ruff",  # linter
+              "Black>=24",  # formatter
+            ]
+            "#,
+            SchemaPath(x_tombi_array_values_order_by_test_schema_path()),
+        ) -> Ok(
+            r#"
+            dependencies = [
+              "Black>=24",  # formatter
+              "ruff",  # linter
+            ]
+            "#
+        )
+    }
+
+    test_format! {
+        #[tokio::test]
+        async fn test_basename_descending(
+            r#"
+            files = ["z/a.toml", "a/c.toml", "m/b.toml"]
+            "#,
+            SchemaPath(x_tombi_array_values_order_by_test_schema_path()),
+        ) -> Ok(
+            r#"
+            files = ["a/c.toml", "m/b.toml", "z/a.toml"]
+            "#
+        )
+    }
+
+    test_format! {
+        #[tokio::test]
+        async fn test_pattern_capture_group_with_version_sort(
+            r#"
+            packages = ["foo@1.10.0", "bar@1.9.0", "baz@1.2.0"]
+            "#,
+            SchemaPath(x_tombi_array_values_order_by_test_schema_path()),
+        ) -> Ok(
+            r#"
+            packages = ["baz@1.2.0", "bar@1.9.0", "foo@1.10.0"]
+            "#
+        )
+    }
+
+    test_format! {
+        #[tokio::test]
+        async fn test_invalid_pattern_keeps_order(
+            r#"
+            invalid-pattern = ["b", "a"]
+            "#,
+            SchemaPath(x_tombi_array_values_order_by_test_schema_path()),
+        ) -> Ok(source)
+    }
+}
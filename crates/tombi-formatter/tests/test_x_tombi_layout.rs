@@ -0,0 +1,121 @@
+mod array_layout {
+    use tombi_formatter::{Formatter, test_format};
+    use tombi_test_lib::x_tombi_layout_test_schema_path;
+
+    test_format! {
+        #[tokio::test]
+        async fn test_one_per_line_expands_short_array(
+            r#"
+            one-per-line = ["a", "b"]
+            "#,
+            SchemaPath(x_tombi_layout_test_schema_path()),
+        ) -> Ok(
+            r#"
+            one-per-line = [
+              "a",
+              "b",
+            ]
+            "#
+        )
+    }
+
+    test_format! {
+        #[tokio::test]
+        async fn test_one_per_line_keeps_single_value_inline(
+            r#"
+            one-per-line = ["a"]
+            "#,
+            SchemaPath(x_tombi_layout_test_schema_path()),
+        ) -> Ok(source)
+    }
+
+    test_format! {
+        #[tokio::test]
+        async fn test_always_multiline_expands_single_value(
+            r#"
+            always-multiline = ["a"]
+            "#,
+            SchemaPath(x_tombi_layout_test_schema_path()),
+        ) -> Ok(
+            r#"
+            always-multiline = [
+              "a",
+            ]
+            "#
+        )
+    }
+
+    test_format! {
+        #[tokio::test]
+        async fn test_always_multiline_keeps_empty_array(
+            r#"
+            always-multiline = []
+            "#,
+            SchemaPath(x_tombi_layout_test_schema_path()),
+        ) -> Ok(source)
+    }
+
+    test_format! {
+        #[tokio::test]
+        async fn test_compact_collapses_trailing_comma(
+            r#"
+            compact = [
+              "a",
+              "b",
+            ]
+            "#,
+            SchemaPath(x_tombi_layout_test_schema_path()),
+        ) -> Ok(
+            r#"
+            compact = ["a", "b"]
+            "#
+        )
+    }
+
+    test_format! {
+        #[tokio::test]
+        async fn test_compact_keeps_comment(
+            r#"
+            compact = [
+              "a",
+              "b",  # comment
+            ]
+            "#,
+            SchemaPath(x_tombi_layout_test_schema_path()),
+        ) -> Ok(source)
+    }
+}
+
+mod inline_table_layout {
+    use tombi_formatter::{Formatter, test_format};
+    use tombi_test_lib::x_tombi_layout_test_schema_path;
+
+    test_format! {
+        #[tokio::test]
+        async fn test_one_per_line_expands_inline_table(
+            r#"
+            inline-one-per-line = { a = "1", b = "2" }
+            "#,
+            TomlVersion::V1_1_0,
+            SchemaPath(x_tombi_layout_test_schema_path()),
+        ) -> Ok(
+            r#"
+            inline-one-per-line = {
+              a = "1",
+              b = "2",
+            }
+            "#
+        )
+    }
+
+    test_format! {
+        #[tokio::test]
+        async fn test_one_per_line_ignored_in_v1_0_0(
+            r#"
+            inline-one-per-line = { a = "1", b = "2" }
+            "#,
+            TomlVersion::V1_0_0,
+            SchemaPath(x_tombi_layout_test_schema_path()),
+        ) -> Ok(source)
+    }
+}
@@ -178,6 +178,7 @@ pub fn canonicalize(path: &Path) -> std::io::Result<PathBuf> {
         .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, "virtual path not found"))
 }
 
+#[allow(clippy::result_unit_err)]
 pub async fn run_blocking<F, R>(function: F) -> Result<R, ()>
 where
     F: FnOnce() -> R + Send + 'static,
@@ -6,12 +6,13 @@ pub struct Cursor<'a> {
     current_position: tombi_text::Position,
     token_start_offset: tombi_text::Offset,
     token_start_position: tombi_text::Position,
+    options: crate::LexOptions,
 }
 
 pub(crate) const EOF_CHAR: char = '\0';
 
 impl<'a> Cursor<'a> {
-    pub fn new(input: &'a str) -> Cursor<'a> {
+    pub fn new(input: &'a str, options: crate::LexOptions) -> Cursor<'a> {
         let chars = input.chars();
         let current = chars.clone().next().unwrap_or(EOF_CHAR);
 
@@ -22,9 +23,15 @@ impl<'a> Cursor<'a> {
             current_position: Default::default(),
             token_start_offset: Default::default(),
             token_start_position: Default::default(),
+            options,
         }
     }
 
+    #[inline]
+    pub(crate) fn options(&self) -> crate::LexOptions {
+        self.options
+    }
+
     #[inline]
     pub(crate) fn current(&self) -> char {
         self.current_char
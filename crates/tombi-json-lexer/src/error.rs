@@ -17,6 +17,7 @@ pub enum ErrorKind {
     UnexpectedEscapeSequence,
     InvalidUnicodeEscapeSequence,
     InvalidLineBreak,
+    UnterminatedComment,
 }
 
 impl Error {
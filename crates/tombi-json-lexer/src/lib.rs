@@ -24,12 +24,23 @@ regex!(
     REGEX_FLOAT = r"^-?[0-9]+(:?(:?\.[0-9]+)?[eE][+-]?[0-9]+|\.[0-9]+)$";
 );
 
+/// Options of the lexer for the JSON dialects that extend the syntax.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LexOptions {
+    /// Accept `//` and `/* */` comments, as JSONC and JSON5 do.
+    pub allow_comments: bool,
+}
+
 pub fn lex(source: &str) -> Lexed {
+    lex_with_options(source, LexOptions::default())
+}
+
+pub fn lex_with_options(source: &str, options: LexOptions) -> Lexed {
     let mut lexed = Lexed::default();
     let mut last_offset = tombi_text::Offset::default();
     let mut last_position = tombi_text::Position::default();
 
-    for result in tokenize(source) {
+    for result in tokenize_with_options(source, options) {
         let (last_span, last_range) = lexed.push_result_token(result);
         last_offset = last_span.end;
         last_position = last_range.end;
@@ -50,7 +61,14 @@ pub fn lex(source: &str) -> Lexed {
 }
 
 pub fn tokenize(source: &str) -> impl Iterator<Item = Result<Token, crate::Error>> + '_ {
-    let mut cursor = Cursor::new(source);
+    tokenize_with_options(source, LexOptions::default())
+}
+
+pub fn tokenize_with_options(
+    source: &str,
+    options: LexOptions,
+) -> impl Iterator<Item = Result<Token, crate::Error>> + '_ {
+    let mut cursor = Cursor::new(source, options);
 
     std::iter::from_fn(move || {
         let token = cursor.advance_token();
@@ -84,6 +102,8 @@ impl Cursor<'_> {
             ',' => Ok(Token::new(T![,], self.pop_span_range())),
             ':' => Ok(Token::new(T![:], self.pop_span_range())),
             '"' => self.string(),
+            // JSONC / JSON5 comments
+            '/' if self.options().allow_comments => self.comment(),
             // JSON number
             '0'..='9' | '-' => self.number(),
             // JSON keywords
@@ -156,6 +176,34 @@ impl Cursor<'_> {
         Ok(Token::new(SyntaxKind::LINE_BREAK, self.pop_span_range()))
     }
 
+    fn comment(&mut self) -> Result<Token, crate::Error> {
+        debug_assert!(self.current() == '/');
+
+        match self.peek(1) {
+            '/' => {
+                self.eat_while(|c| !is_line_break(c));
+                Ok(Token::new(SyntaxKind::COMMENT, self.pop_span_range()))
+            }
+            '*' => {
+                self.bump();
+                while let Some(c) = self.bump() {
+                    if c == '*' && self.peek(1) == '/' {
+                        self.bump();
+                        return Ok(Token::new(SyntaxKind::COMMENT, self.pop_span_range()));
+                    }
+                }
+                Err(crate::Error::new(
+                    UnterminatedComment,
+                    self.pop_span_range(),
+                ))
+            }
+            _ => {
+                self.eat_while(|c| !is_token_separator(c));
+                Err(crate::Error::new(InvalidToken, self.pop_span_range()))
+            }
+        }
+    }
+
     fn number(&mut self) -> Result<Token, crate::Error> {
         let line = self.peek_with_current_while(|c| !is_token_separator(c));
 
@@ -3,18 +3,33 @@
 // using macros to define test cases in a declarative way.
 
 use itertools::Itertools;
-use tombi_json_lexer::{ErrorKind, Token, tokenize};
+use tombi_json_lexer::{ErrorKind, LexOptions, Token, tokenize_with_options};
 use tombi_json_syntax::SyntaxKind::*;
 
+const COMMENTS: LexOptions = LexOptions {
+    allow_comments: true,
+};
+
 macro_rules! test_tokens {
     {#[test]fn $name:ident($source:expr) -> [
         $(Token($kind:expr, $text:literal),)*
+    ];} => {
+        test_tokens! {
+            #[test]
+            fn $name($source, LexOptions::default()) -> [
+                $(Token($kind, $text),)*
+            ];
+        }
+    };
+
+    {#[test]fn $name:ident($source:expr, $options:expr) -> [
+        $(Token($kind:expr, $text:literal),)*
     ];} => {
         #[test]
         fn $name() {
             tombi_test_lib::init_log();
 
-            let tokens = tokenize($source).collect_vec();
+            let tokens = tokenize_with_options($source, $options).collect_vec();
             let (expected, _) = [
                 $(
                     ($kind, $text),
@@ -49,7 +64,7 @@ macro_rules! test_token {
         fn $name() {
             let source = textwrap::dedent($source);
             let source = source.trim();
-            let tokens = tokenize(&source).collect_vec();
+            let tokens = tokenize_with_options(&source, LexOptions::default()).collect_vec();
             let start_position = tombi_text::Position::MIN;
             let end_position = start_position + tombi_text::RelativePosition::of(source);
 
@@ -71,11 +86,18 @@ macro_rules! test_token {
     };
 
     {#[test]fn $name:ident($source:expr) -> Err(Token($kind:expr, ($start_offset:expr, $end_offset:expr)));} => {
+        test_token! {
+            #[test]
+            fn $name($source, LexOptions::default()) -> Err(Token($kind, ($start_offset, $end_offset)));
+        }
+    };
+
+    {#[test]fn $name:ident($source:expr, $options:expr) -> Err(Token($kind:expr, ($start_offset:expr, $end_offset:expr)));} => {
         #[test]
         fn $name() {
             let source = textwrap::dedent($source);
             let source = source.trim();
-            let tokens = tokenize(&source).collect_vec();
+            let tokens = tokenize_with_options(&source, $options).collect_vec();
             let start_position = tombi_text::Position::MIN;
             let end_position = start_position + tombi_text::RelativePosition::of(source);
 
@@ -459,3 +481,32 @@ test_tokens! {
         Token(BRACE_END, "}"),
     ];
 }
+
+test_tokens! {
+    #[test]
+    fn line_comment("// comment\n1", COMMENTS) -> [
+        Token(COMMENT, "// comment"),
+        Token(LINE_BREAK, "\n"),
+        Token(NUMBER, "1"),
+    ];
+}
+
+test_tokens! {
+    #[test]
+    fn block_comment("[/* a\n * b */1]", COMMENTS) -> [
+        Token(BRACKET_START, "["),
+        Token(COMMENT, "/* a\n * b */"),
+        Token(NUMBER, "1"),
+        Token(BRACKET_END, "]"),
+    ];
+}
+
+test_token! {
+    #[test]
+    fn error_unterminated_block_comment("/* comment", COMMENTS) -> Err(Token(ErrorKind::UnterminatedComment, (0, 10)));
+}
+
+test_token! {
+    #[test]
+    fn error_comment_without_option("//comment") -> Err(Token(ErrorKind::InvalidToken, (0, 9)));
+}
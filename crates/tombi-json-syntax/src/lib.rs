@@ -25,6 +25,7 @@ pub enum SyntaxKind {
     // Trivia
     WHITESPACE,
     LINE_BREAK,
+    COMMENT,
     // Nodes
     ROOT,
     ARRAY,
@@ -40,7 +41,10 @@ pub enum SyntaxKind {
 impl SyntaxKind {
     #[inline]
     pub fn is_trivia(self) -> bool {
-        matches!(self, SyntaxKind::WHITESPACE | SyntaxKind::LINE_BREAK)
+        matches!(
+            self,
+            SyntaxKind::WHITESPACE | SyntaxKind::LINE_BREAK | SyntaxKind::COMMENT
+        )
     }
 }
 
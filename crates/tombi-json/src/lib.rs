@@ -2,7 +2,8 @@ mod node;
 mod parser;
 
 pub use node::{ArrayNode, BoolNode, NullNode, NumberNode, ObjectNode, StringNode, ValueNode};
-pub use parser::{Error as ParserError, parse};
+pub use parser::{Error as ParserError, parse, parse_with_error_range, parse_with_options};
+pub use tombi_json_lexer::LexOptions;
 pub use tombi_json_value::{Number, Object, Value};
 pub use tombi_text::Range;
 
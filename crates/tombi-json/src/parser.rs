@@ -2,7 +2,7 @@ mod error;
 
 use crate::{ArrayNode, BoolNode, NullNode, NumberNode, ObjectNode, StringNode, ValueNode};
 pub use error::Error;
-use tombi_json_lexer::{Lexed, Token, lex};
+use tombi_json_lexer::{LexOptions, Lexed, Token, lex_with_options};
 use tombi_json_syntax::{SyntaxKind, T};
 use tombi_json_value::Number;
 use tombi_text::Range;
@@ -23,7 +23,11 @@ pub struct Parser<'a> {
 
 impl<'a> Parser<'a> {
     pub fn new(source: &'a str) -> Self {
-        let lexed = lex(source);
+        Self::new_with_options(source, LexOptions::default())
+    }
+
+    pub fn new_with_options(source: &'a str, options: LexOptions) -> Self {
+        let lexed = lex_with_options(source, options);
         Self {
             source,
             lexed,
@@ -65,6 +69,25 @@ impl<'a> Parser<'a> {
         Ok(root)
     }
 
+    /// Range of the token where parsing stopped, used to locate a parse error.
+    fn error_range(&self) -> Range {
+        if let Some(error) = self.lexed.errors.first() {
+            return error.range();
+        }
+        self.lexed
+            .tokens
+            .get(self.position)
+            .filter(|token| token.kind() != SyntaxKind::EOF)
+            .or_else(|| {
+                self.lexed.tokens[..self.position.min(self.lexed.tokens.len())]
+                    .iter()
+                    .rev()
+                    .find(|token| !token.kind().is_trivia() && token.kind() != SyntaxKind::EOF)
+            })
+            .map(|token| token.range())
+            .unwrap_or_default()
+    }
+
     fn parse_string(&mut self) -> Result<StringNode, crate::parser::Error> {
         // Get the current token (without advancing the position)
         match self.peek() {
@@ -438,6 +461,24 @@ pub fn parse(source: &str) -> Result<ValueNode, crate::parser::Error> {
     parser.parse()
 }
 
+/// Parse a JSON string with the options of a JSON dialect, such as the comments of JSONC
+pub fn parse_with_options(
+    source: &str,
+    options: LexOptions,
+) -> Result<ValueNode, crate::parser::Error> {
+    let mut parser = Parser::new_with_options(source, options);
+    parser.parse()
+}
+
+/// Parse a JSON string into a Tree, returning the range where parsing failed on error
+pub fn parse_with_error_range(source: &str) -> Result<ValueNode, (crate::parser::Error, Range)> {
+    let mut parser = Parser::new(source);
+    parser.parse().map_err(|error| {
+        let range = parser.error_range();
+        (error, range)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -535,6 +576,44 @@ mod tests {
         assert!(value_node.is_object());
     }
 
+    #[test]
+    fn test_parse_with_error_range() {
+        let (_, range) = parse_with_error_range("{\n  \"a\": 1,\n  \"b\" 2\n}").unwrap_err();
+        assert_eq!(range, Range::new((2, 6).into(), (2, 7).into()));
+    }
+
+    #[test]
+    fn test_parse_comments_and_trailing_commas() {
+        let source = r#"
+        // Hand-maintained schema
+        {
+            /* The name */
+            "name": "John", // trailing comment
+            "courses": ["Math", "Physics",],
+        }
+        "#;
+
+        assert!(parse(source).is_err());
+
+        let value_node = parse_with_options(
+            source,
+            LexOptions {
+                allow_comments: true,
+            },
+        )
+        .unwrap();
+        let object = value_node.as_object().unwrap();
+        pretty_assertions::assert_eq!(object.properties.len(), 2);
+        pretty_assertions::assert_eq!(
+            object
+                .properties
+                .get("courses")
+                .and_then(|courses| courses.as_array())
+                .map(|courses| courses.items.len()),
+            Some(2)
+        );
+    }
+
     test_json_parser!(
         nesting_at_limit_is_accepted,
         format!("{}1{}", "[".repeat(128), "]".repeat(128)),
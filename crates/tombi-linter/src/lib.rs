@@ -7,7 +7,7 @@ mod rule;
 pub use diagnostic::{Diagnostic, DiagnosticKind};
 pub use error::{Error, ErrorKind};
 use lint::Lint;
-pub use linter::Linter;
+pub use linter::{Linter, ParsedDocumentTree};
 use rule::Rule;
 pub use tombi_config::LintOptions;
 
@@ -1,6 +1,9 @@
 use std::borrow::Cow;
 
+use futures::StreamExt;
 use itertools::Either;
+use tombi_ast::AstNode;
+use tombi_comment_directive::document::TombiDocumentDirectiveContent;
 use tombi_config::TomlVersion;
 use tombi_diagnostic::{Diagnostic, SetDiagnostics};
 use tombi_document_tree::IntoDocumentTreeAndErrors;
@@ -8,12 +11,35 @@ use tombi_severity_level::SeverityLevelDefaultWarn;
 
 use crate::lint::Lint;
 
+/// Diagnostic codes that can only be decided with the whole document.
+///
+/// When linting a stream, they are not reported for the root table
+/// and the tables and arrays of tables that continue into another chunk.
+/// The codes of arrays start with `array-`.
+const STREAM_UNCHECKED_CODES: &[&str] = &[
+    "table-key-required",
+    "table-min-keys",
+    "table-dependency-required",
+    "array-min-values",
+    "array-contains",
+    "array-min-contains",
+];
+
+/// A document tree built by the caller, with the errors of building it.
+pub struct ParsedDocumentTree<'a> {
+    pub tree: &'a tombi_document_tree::DocumentTree,
+    pub errors: &'a [Diagnostic],
+    /// The TOML version the tree was built with.
+    pub toml_version: TomlVersion,
+}
+
 pub struct Linter<'a> {
     toml_version: TomlVersion,
     options: Cow<'a, crate::LintOptions>,
     source_text: Cow<'a, str>,
     source_uri_or_path: Option<Either<&'a tombi_uri::Uri, &'a std::path::Path>>,
     schema_store: &'a tombi_schema_store::SchemaStore,
+    validation_cache: Option<tombi_schema_store::ValidationCache>,
     pub(crate) diagnostics: Vec<tombi_diagnostic::Diagnostic>,
 }
 
@@ -30,10 +56,21 @@ impl<'a> Linter<'a> {
             source_text: Cow::Borrowed(""),
             source_uri_or_path,
             schema_store,
+            validation_cache: None,
             diagnostics: Vec::new(),
         }
     }
 
+    /// Reuse the validation results of root table values kept in `validation_cache`,
+    /// and keep the results of this lint there for the next one.
+    pub fn with_validation_cache(
+        mut self,
+        validation_cache: tombi_schema_store::ValidationCache,
+    ) -> Self {
+        self.validation_cache = Some(validation_cache);
+        self
+    }
+
     pub async fn lint(mut self, source: &str) -> Result<(), Vec<Diagnostic>> {
         self.source_text = Cow::Borrowed(source);
 
@@ -42,26 +79,204 @@ impl<'a> Linter<'a> {
             error.set_diagnostics(&mut self.diagnostics);
         }
 
-        let (source_schema, tombi_document_comment_directive) = {
-            let (source_schema, error_with_range) =
-                tombi_schema_store::lint_source_schema_from_ast(
-                    &root,
-                    self.source_uri_or_path,
-                    self.schema_store,
+        self.lint_root(root, None).await
+    }
+
+    /// Lint a source that has already been parsed, such as a document open in the language server.
+    ///
+    /// `document_tree` is validated instead of building the tree from `root` again,
+    /// as long as it was built with the TOML version the linter resolves for the source.
+    pub async fn lint_parsed(
+        mut self,
+        source: &str,
+        root: tombi_ast::Root,
+        parse_errors: &[Diagnostic],
+        document_tree: ParsedDocumentTree<'_>,
+    ) -> Result<(), Vec<Diagnostic>> {
+        self.source_text = Cow::Borrowed(source);
+        self.diagnostics.extend_from_slice(parse_errors);
+
+        self.lint_root(root, Some(document_tree)).await
+    }
+
+    async fn lint_root(
+        mut self,
+        root: tombi_ast::Root,
+        document_tree: Option<ParsedDocumentTree<'_>>,
+    ) -> Result<(), Vec<Diagnostic>> {
+        let (source_schema, tombi_document_comment_directive) =
+            self.resolve_source_schema(&root).await;
+
+        if self.is_lint_disabled(tombi_document_comment_directive.as_ref()) {
+            return Ok(());
+        }
+
+        root.lint(&mut self).await;
+
+        if !self.has_errors(0) {
+            let document_tree = match document_tree
+                .filter(|document_tree| document_tree.toml_version == self.toml_version)
+            {
+                Some(ParsedDocumentTree { tree, errors, .. }) => {
+                    self.diagnostics.extend_from_slice(errors);
+                    Cow::Borrowed(tree)
+                }
+                None => Cow::Owned(self.build_document_tree(root)),
+            };
+            let diagnostics = self
+                .validate(
+                    &document_tree,
+                    source_schema.as_ref(),
+                    tombi_document_comment_directive.as_ref(),
+                    false,
                 )
                 .await;
-            if let Some((err, range)) = error_with_range {
-                self.diagnostics.push(err.to_warning_diagnostic(range));
-            };
-
-            let (tombi_document_comment_directive, diagnostics) =
-                tombi_validator::comment_directive::get_tombi_document_comment_directive_and_diagnostics(&root).await;
             self.diagnostics.extend(diagnostics);
+        }
+
+        if self.diagnostics.is_empty() {
+            Ok(())
+        } else {
+            Err(self.diagnostics)
+        }
+    }
+
+    /// Lint TOML one table or array of tables at a time, from the chunks of [`tombi_parser::parse_stream`].
+    ///
+    /// Memory stays proportional to the largest table, which suits very large files.
+    /// Since each table is validated on its own, rules that need the whole document
+    /// (e.g. table order) are not checked, and required keys and minimum counts are
+    /// only checked for the tables that do not continue into another chunk.
+    ///
+    /// The chunks are taken as a stream, so that a blocking reader can be parsed off the async runtime.
+    pub async fn lint_stream<S>(mut self, chunks: S) -> std::io::Result<Result<(), Vec<Diagnostic>>>
+    where
+        S: futures::Stream<Item = std::io::Result<tombi_parser::Chunk>> + Send,
+    {
+        let mut chunks = std::pin::pin!(chunks);
+        let Some(first_chunk) = chunks.next().await else {
+            return Ok(Ok(()));
+        };
+        let first_chunk = first_chunk?;
+
+        // The document comment directives are in the first chunk.
+        let (source_schema, tombi_document_comment_directive) = self
+            .resolve_source_schema(&first_chunk.clone().into_root_and_errors().0)
+            .await;
 
-            (source_schema, tombi_document_comment_directive)
+        // Syntax errors are still reported when linting is disabled, as `lint` does not skip a document with them.
+        let is_lint_disabled = self.is_lint_disabled(tombi_document_comment_directive.as_ref());
+
+        let mut document_tree_keys = tombi_document_tree::DocumentTreeKeys::default();
+        let mut stream_tables = StreamTables::default();
+        let mut deferred_diagnostics = Vec::new();
+        let mut chunks = futures::stream::iter([Ok(first_chunk)])
+            .chain(chunks)
+            .enumerate();
+        while let Some((chunk_index, chunk)) = chunks.next().await {
+            let (root, errors) = chunk?.into_root_and_errors();
+
+            let error_count = self.error_count();
+            for error in errors {
+                error.set_diagnostics(&mut self.diagnostics);
+            }
+
+            if is_lint_disabled {
+                continue;
+            }
+
+            self.source_text = Cow::Owned(root.syntax().to_string());
+            root.lint(&mut self).await;
+
+            if !self.has_errors(error_count) {
+                let mut document_tree = self.build_document_tree(root);
+                document_tree_keys.resolve_arrays_of_tables(&mut document_tree);
+
+                let chunk_tables = stream_tables.insert(chunk_index, &document_tree);
+                for diagnostic in self
+                    .validate(
+                        &document_tree,
+                        source_schema.as_ref(),
+                        tombi_document_comment_directive.as_ref(),
+                        true,
+                    )
+                    .await
+                {
+                    let is_array = diagnostic.code().starts_with("array-");
+                    match chunk_tables.get(&(diagnostic.range(), is_array)) {
+                        Some(accessors) if STREAM_UNCHECKED_CODES.contains(&diagnostic.code()) => {
+                            deferred_diagnostics.push((accessors.clone(), diagnostic));
+                        }
+                        _ => self.diagnostics.push(diagnostic),
+                    }
+                }
+
+                // Report the keys that conflict with the tables of the earlier chunks.
+                if let Err(errors) = document_tree_keys.merge(document_tree) {
+                    errors.set_diagnostics(&mut self.diagnostics);
+                }
+            }
+        }
+
+        // A table is only complete once no later chunk has continued it.
+        // When its range is shared with its parent tables, they must not be continued either.
+        self.diagnostics.extend(
+            deferred_diagnostics
+                .into_iter()
+                .filter(|(accessors, _)| {
+                    !accessors
+                        .iter()
+                        .any(|accessors| stream_tables.continues(accessors))
+                })
+                .map(|(_, diagnostic)| diagnostic),
+        );
+
+        if self.diagnostics.is_empty() {
+            Ok(Ok(()))
+        } else {
+            Ok(Err(self.diagnostics))
+        }
+    }
+
+    async fn resolve_source_schema(
+        &mut self,
+        root: &tombi_ast::Root,
+    ) -> (
+        Option<tombi_schema_store::SourceSchema>,
+        Option<TombiDocumentDirectiveContent>,
+    ) {
+        let (source_schema, error_with_range) = tombi_schema_store::lint_source_schema_from_ast(
+            root,
+            self.source_uri_or_path,
+            self.schema_store,
+        )
+        .await;
+        if let Some((err, range)) = error_with_range {
+            self.diagnostics.push(err.to_warning_diagnostic(range));
         };
 
-        if let Some(tombi_document_comment_directive) = &tombi_document_comment_directive
+        let (tombi_document_comment_directive, diagnostics) =
+            tombi_validator::comment_directive::get_tombi_document_comment_directive_and_diagnostics(root).await;
+        self.diagnostics.extend(diagnostics);
+
+        self.toml_version = tombi_document_comment_directive
+            .as_ref()
+            .and_then(|directive| directive.toml_version)
+            .unwrap_or_else(|| {
+                source_schema
+                    .as_ref()
+                    .and_then(|schema| schema.toml_version())
+                    .unwrap_or(self.toml_version)
+            });
+
+        (source_schema, tombi_document_comment_directive)
+    }
+
+    fn is_lint_disabled(
+        &self,
+        tombi_document_comment_directive: Option<&TombiDocumentDirectiveContent>,
+    ) -> bool {
+        if let Some(tombi_document_comment_directive) = tombi_document_comment_directive
             && let Some(lint) = &tombi_document_comment_directive.lint
             && lint.disabled.unwrap_or_default()
         {
@@ -80,80 +295,71 @@ impl<'a> Linter<'a> {
                         log::info!("skip linting for stdin due to `lint.disable`");
                     }
                 }
-                return Ok(());
+                return true;
             }
         }
 
-        self.toml_version = tombi_document_comment_directive
-            .as_ref()
-            .and_then(|directive| directive.toml_version)
-            .unwrap_or_else(|| {
-                source_schema
-                    .as_ref()
-                    .and_then(|schema| schema.toml_version())
-                    .unwrap_or(self.toml_version)
-            });
-
-        {
-            root.lint(&mut self).await;
-        }
+        false
+    }
 
-        if self
-            .diagnostics
+    fn error_count(&self) -> usize {
+        self.diagnostics
             .iter()
             .filter(|diagnostic| diagnostic.level() == tombi_diagnostic::Level::ERROR)
             .count()
-            == 0
-        {
-            let (document_tree, errors) =
-                root.into_document_tree_and_errors(self.toml_version).into();
+    }
 
-            errors.set_diagnostics(&mut self.diagnostics);
+    /// Whether errors have been reported since there were `error_count` of them.
+    fn has_errors(&self, error_count: usize) -> bool {
+        self.error_count() > error_count
+    }
 
-            log::trace!("document_tree: {:#?}", document_tree);
+    fn build_document_tree(&mut self, root: tombi_ast::Root) -> tombi_document_tree::DocumentTree {
+        let (document_tree, errors) = root.into_document_tree_and_errors(self.toml_version).into();
 
-            let schema_context = tombi_schema_store::SchemaContext {
-                toml_version: self.toml_version,
-                root_schema: source_schema
-                    .as_ref()
-                    .and_then(|source_schema| source_schema.root_schema.as_deref()),
-                sub_schema_link_map: source_schema
-                    .as_ref()
-                    .map(|source_schema| &source_schema.sub_schema_link_map),
-                deprecated_lint_level: source_schema
-                    .as_ref()
-                    .and_then(|source_schema| source_schema.deprecated_lint_level),
-                schema_format_rules: source_schema
-                    .as_ref()
-                    .map(|source_schema| &source_schema.schema_format_rules),
-                schema_lint_rules: source_schema
-                    .as_ref()
-                    .map(|source_schema| &source_schema.schema_lint_rules),
-                schema_overrides: source_schema
-                    .as_ref()
-                    .map(|source_schema| &source_schema.schema_overrides),
-                schema_visits: Default::default(),
-                store: self.schema_store,
-                strict: tombi_document_comment_directive
-                    .as_ref()
-                    .and_then(|directive| {
-                        directive.schema.as_ref().and_then(|schema| schema.strict)
-                    }),
-            };
+        errors.set_diagnostics(&mut self.diagnostics);
 
-            if let Err(diagnostics) =
-                tombi_validator::validate(document_tree, source_schema.as_ref(), &schema_context)
-                    .await
-            {
-                let diagnostics = self.apply_lint_rules_to_diagnostics(diagnostics);
-                self.diagnostics.extend(diagnostics);
-            }
-        }
+        document_tree
+    }
 
-        if self.diagnostics.is_empty() {
-            Ok(())
-        } else {
-            Err(self.diagnostics)
+    async fn validate(
+        &mut self,
+        document_tree: &tombi_document_tree::DocumentTree,
+        source_schema: Option<&tombi_schema_store::SourceSchema>,
+        tombi_document_comment_directive: Option<&TombiDocumentDirectiveContent>,
+        partial_root: bool,
+    ) -> Vec<Diagnostic> {
+        log::trace!("document_tree: {:#?}", document_tree);
+
+        let schema_context = tombi_schema_store::SchemaContext {
+            toml_version: self.toml_version,
+            root_schema: source_schema
+                .and_then(|source_schema| source_schema.root_schema.as_deref()),
+            sub_schema_link_map: source_schema
+                .map(|source_schema| &source_schema.sub_schema_link_map),
+            deprecated_lint_level: source_schema
+                .and_then(|source_schema| source_schema.deprecated_lint_level),
+            schema_format_rules: source_schema
+                .map(|source_schema| &source_schema.schema_format_rules),
+            schema_lint_rules: source_schema.map(|source_schema| &source_schema.schema_lint_rules),
+            schema_overrides: source_schema.map(|source_schema| &source_schema.schema_overrides),
+            schema_visits: Default::default(),
+            store: self.schema_store,
+            strict: tombi_document_comment_directive
+                .and_then(|directive| directive.schema.as_ref().and_then(|schema| schema.strict)),
+            table_style: self
+                .options
+                .rules
+                .as_ref()
+                .and_then(|rules| rules.table_style),
+            validation_cache: self.validation_cache.clone(),
+            incomplete_as_null: false,
+            partial_root,
+        };
+
+        match tombi_validator::validate(document_tree, source_schema, &schema_context).await {
+            Ok(()) => Vec::new(),
+            Err(diagnostics) => self.apply_lint_rules_to_diagnostics(diagnostics),
         }
     }
 
@@ -214,3 +420,101 @@ impl<'a> Linter<'a> {
             .collect()
     }
 }
+
+/// The tables and arrays of tables of the chunks of a stream, by their accessors.
+#[derive(Default)]
+struct StreamTables {
+    /// The chunk that defines each table or array of tables, or `None` once another chunk continues it.
+    chunks: tombi_hashmap::HashMap<Vec<tombi_schema_store::Accessor>, Option<usize>>,
+    /// The number of `[[...]]` headers of each array of tables.
+    lengths: tombi_hashmap::HashMap<Vec<tombi_schema_store::Accessor>, usize>,
+}
+
+/// The accessors of the tables (`false`) and arrays of tables (`true`) of a chunk, by range.
+///
+/// A range can be shared, e.g. by `[a.b]` and its parent table `a`.
+type ChunkTables =
+    tombi_hashmap::HashMap<(tombi_text::Range, bool), Vec<Vec<tombi_schema_store::Accessor>>>;
+
+impl StreamTables {
+    /// Record the tables and arrays of tables of a chunk, except for the root table.
+    fn insert(
+        &mut self,
+        chunk_index: usize,
+        document_tree: &tombi_document_tree::DocumentTree,
+    ) -> ChunkTables {
+        let mut chunk_tables = ChunkTables::default();
+        for (key, value) in document_tree.key_values() {
+            self.insert_value(
+                chunk_index,
+                value,
+                &mut vec![tombi_schema_store::Accessor::Key(key.value.clone())],
+                &mut chunk_tables,
+            );
+        }
+        chunk_tables
+    }
+
+    fn insert_value(
+        &mut self,
+        chunk_index: usize,
+        value: &tombi_document_tree::Value,
+        accessors: &mut Vec<tombi_schema_store::Accessor>,
+        chunk_tables: &mut ChunkTables,
+    ) {
+        use tombi_document_tree::{ArrayKind, TableKind, Value};
+
+        match value {
+            Value::Table(table) if !matches!(table.kind(), TableKind::InlineTable { .. }) => {
+                self.insert_accessors(chunk_index, (table.range(), false), accessors, chunk_tables);
+
+                for (key, value) in table.key_values() {
+                    accessors.push(tombi_schema_store::Accessor::Key(key.value.clone()));
+                    self.insert_value(chunk_index, value, accessors, chunk_tables);
+                    accessors.pop();
+                }
+            }
+            Value::Array(array) if array.kind() != ArrayKind::Array => {
+                self.insert_accessors(chunk_index, (array.range(), true), accessors, chunk_tables);
+
+                for value in array.values() {
+                    // `[[...]]` adds an element, while the other headers continue the last one.
+                    let length = self.lengths.entry(accessors.clone()).or_default();
+                    if array.kind() == ArrayKind::ArrayOfTable {
+                        *length += 1;
+                    }
+                    accessors.push(tombi_schema_store::Accessor::Index(
+                        length.saturating_sub(1),
+                    ));
+                    self.insert_value(chunk_index, value, accessors, chunk_tables);
+                    accessors.pop();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn insert_accessors(
+        &mut self,
+        chunk_index: usize,
+        key: (tombi_text::Range, bool),
+        accessors: &[tombi_schema_store::Accessor],
+        chunk_tables: &mut ChunkTables,
+    ) {
+        let chunk = self
+            .chunks
+            .entry(accessors.to_vec())
+            .or_insert(Some(chunk_index));
+        if *chunk != Some(chunk_index) {
+            *chunk = None;
+        }
+        chunk_tables
+            .entry(key)
+            .or_default()
+            .push(accessors.to_vec());
+    }
+
+    fn continues(&self, accessors: &[tombi_schema_store::Accessor]) -> bool {
+        matches!(self.chunks.get(accessors), Some(None))
+    }
+}
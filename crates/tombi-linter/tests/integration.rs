@@ -10,6 +10,8 @@ mod array_const_enum_test_schema;
 mod cargo_schema;
 #[path = "integration/contains_test_schema.rs"]
 mod contains_test_schema;
+#[path = "integration/content_test_schema.rs"]
+mod content_test_schema;
 #[path = "integration/dependencies_strict_mode_test_schema.rs"]
 mod dependencies_strict_mode_test_schema;
 #[path = "integration/dependencies_test_schema.rs"]
@@ -24,6 +26,8 @@ mod format_annotation_test_schema;
 mod issue_1895_rustfmt_like_schema;
 #[path = "integration/json_schema_test_suite.rs"]
 mod json_schema_test_suite;
+#[path = "integration/json_schema_test_suite_conformance.rs"]
+mod json_schema_test_suite_conformance;
 #[path = "integration/min_max_contains_test_schema.rs"]
 mod min_max_contains_test_schema;
 #[path = "integration/non_schema.rs"]
@@ -42,12 +46,18 @@ mod recursive_anchor_ref_test_schema;
 mod recursive_defs_any_of_test_schema;
 #[path = "integration/schema_resolution_error.rs"]
 mod schema_resolution_error;
+#[path = "integration/stream.rs"]
+mod stream;
 #[path = "integration/string_format_test_schema.rs"]
 mod string_format_test_schema;
 #[path = "integration/table_const_enum_test_schema.rs"]
 mod table_const_enum_test_schema;
+#[path = "integration/table_style_test_schema.rs"]
+mod table_style_test_schema;
 #[path = "integration/tombi_schema.rs"]
 mod tombi_schema;
+#[path = "integration/toml_schema.rs"]
+mod toml_schema;
 #[path = "integration/tuple_items_test_schema.rs"]
 mod tuple_items_test_schema;
 #[path = "integration/unevaluated_items_test_schema.rs"]
@@ -60,3 +70,7 @@ mod unevaluated_properties_test_schema;
 mod union_best_match;
 #[path = "integration/untagged_union_schema.rs"]
 mod untagged_union_schema;
+#[path = "integration/validation_cache.rs"]
+mod validation_cache;
+#[path = "integration/vscode_extension_test_schema.rs"]
+mod vscode_extension_test_schema;
@@ -0,0 +1,207 @@
+use tombi_linter::test_lint;
+use tombi_test_lib::{content_annotation_test_schema_path, content_test_schema_path};
+
+// --- contentMediaType: application/json ---
+
+test_lint! {
+    #[test]
+    fn test_json_content_valid(
+        r#"
+        json = '{"name": "tombi", "tags": [1, 2]}'
+        "#,
+        SchemaPath(content_test_schema_path()),
+    ) -> Ok(_)
+}
+
+test_lint! {
+    #[test]
+    fn test_json_content_invalid_syntax(
+        r#"
+        json = '{"name": }'
+        "#,
+        SchemaPath(content_test_schema_path()),
+    ) -> Err([
+        tombi_validator::DiagnosticKind::StringContentMediaType {
+            media_type: "application/json".to_string(),
+            reason: "Invalid value".to_string(),
+        },
+    ])
+}
+
+// --- contentSchema ---
+
+test_lint! {
+    #[test]
+    fn test_json_content_schema_valid(
+        r#"
+        json_with_schema = '{"port": 8080}'
+        "#,
+        SchemaPath(content_test_schema_path()),
+    ) -> Ok(_)
+}
+
+test_lint! {
+    #[test]
+    fn test_json_content_schema_type_mismatch(
+        r#"
+        json_with_schema = '{"port": "8080"}'
+        "#,
+        SchemaPath(content_test_schema_path()),
+    ) -> Err([tombi_validator::DiagnosticKind::TypeMismatch {
+        expected: tombi_schema_store::ValueType::Integer,
+        actual: tombi_document_tree::ValueType::String,
+    }])
+}
+
+test_lint! {
+    #[test]
+    fn test_json_content_schema_key_required(
+        r#"
+        json_with_schema = '{"port": null}'
+        "#,
+        SchemaPath(content_test_schema_path()),
+    ) -> Err([tombi_validator::DiagnosticKind::TableKeyRequired {
+        key: "port".to_string(),
+    }])
+}
+
+// --- contentMediaType: application/toml ---
+
+test_lint! {
+    #[test]
+    fn test_toml_content_valid(
+        r#"
+        toml = """
+        name = "tombi"
+        """
+        "#,
+        SchemaPath(content_test_schema_path()),
+    ) -> Ok(_)
+}
+
+test_lint! {
+    #[test]
+    fn test_toml_content_invalid_syntax(
+        r#"
+        toml = "name = "
+        "#,
+        SchemaPath(content_test_schema_path()),
+    ) -> Err([
+        tombi_validator::DiagnosticKind::StringContentMediaType {
+            media_type: "application/toml".to_string(),
+            reason: "expected value".to_string(),
+        },
+    ])
+}
+
+test_lint! {
+    #[test]
+    fn test_toml_content_schema_type_mismatch(
+        r#"
+        toml = "name = 1"
+        "#,
+        SchemaPath(content_test_schema_path()),
+    ) -> Err([tombi_validator::DiagnosticKind::TypeMismatch {
+        expected: tombi_schema_store::ValueType::String,
+        actual: tombi_document_tree::ValueType::Integer,
+    }])
+}
+
+// --- contentEncoding: base64 ---
+
+test_lint! {
+    #[test]
+    fn test_base64_content_valid(
+        r#"
+        base64 = "dG9tYmk="
+        "#,
+        SchemaPath(content_test_schema_path()),
+    ) -> Ok(_)
+}
+
+test_lint! {
+    #[test]
+    fn test_base64_content_invalid(
+        r#"
+        base64 = "dG9tYmk!"
+        "#,
+        SchemaPath(content_test_schema_path()),
+    ) -> Err([tombi_validator::DiagnosticKind::StringContentEncoding {
+        encoding: "base64".to_string(),
+    }])
+}
+
+test_lint! {
+    #[test]
+    fn test_base64_json_content_valid(
+        r#"
+        base64_json = "eyJhIjogMX0="
+        "#,
+        SchemaPath(content_test_schema_path()),
+    ) -> Ok(_)
+}
+
+test_lint! {
+    #[test]
+    fn test_base64_json_content_invalid(
+        r#"
+        base64_json = "eyJhIjo="
+        "#,
+        SchemaPath(content_test_schema_path()),
+    ) -> Err([
+        tombi_validator::DiagnosticKind::StringContentMediaType {
+            media_type: "application/json".to_string(),
+            reason: "Invalid value".to_string(),
+        },
+    ])
+}
+
+test_lint! {
+    #[test]
+    fn test_content_errors_are_warnings(
+        r#"
+        base64 = "dG9tYmk!"
+        "#,
+        SchemaPath(content_test_schema_path()),
+    ) -> Diagnostics([
+        { code: "string-content-encoding", level: tombi_diagnostic::Level::WARNING }
+    ])
+}
+
+// --- 2019-09 and later: content keywords are annotations ---
+
+test_lint! {
+    #[test]
+    fn test_content_not_asserted_in_2020_12(
+        r#"
+        json = '{"name": }'
+        base64 = "dG9tYmk!"
+        "#,
+        SchemaPath(content_annotation_test_schema_path()),
+    ) -> Ok(_)
+}
+
+test_lint! {
+    #[test]
+    fn test_content_asserted_in_2020_12_with_x_tombi_content_assertion(
+        r#"
+        asserted_json = '{"port": "8080"}'
+        asserted_base64 = "dG9tYmk!"
+        "#,
+        SchemaPath(content_annotation_test_schema_path()),
+    ) -> Diagnostics([
+        { code: "type-mismatch", level: tombi_diagnostic::Level::ERROR },
+        { code: "string-content-encoding", level: tombi_diagnostic::Level::WARNING }
+    ])
+}
+
+test_lint! {
+    #[test]
+    fn test_content_valid_in_2020_12_with_x_tombi_content_assertion(
+        r#"
+        asserted_json = '{"port": 8080}'
+        asserted_base64 = "dG9tYmk="
+        "#,
+        SchemaPath(content_annotation_test_schema_path()),
+    ) -> Ok(_)
+}
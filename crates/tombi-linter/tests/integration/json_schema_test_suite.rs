@@ -18,7 +18,7 @@ fn normalize_toml_text(input: &str) -> String {
     toml_text
 }
 
-async fn validate_test_suite(
+pub(crate) async fn validate_test_suite(
     schema: JsonValue,
     toml_text: &str,
 ) -> Result<(), Vec<tombi_diagnostic::Diagnostic>> {
@@ -0,0 +1,575 @@
+//! Runs the vendored [JSON-Schema-Test-Suite](https://github.com/json-schema-org/JSON-Schema-Test-Suite)
+//! against the validator.
+//!
+//! Each JSON instance is converted into a TOML document where representable,
+//! and the results are reported per dialect and keyword
+//! in `json-schema-test-suite-conformance.txt` under `CARGO_TARGET_TMPDIR`.
+
+use std::{collections::BTreeMap, fmt::Write, path::Path};
+
+use serde_json::{Value as JsonValue, json};
+
+use super::json_schema_test_suite::validate_test_suite;
+
+/// `(dialect directory, `$schema` URI)`. Draft 7 tests omit `$schema`, so the URI is injected.
+const DIALECTS: &[(&str, &str)] = &[
+    ("draft7", "http://json-schema.org/draft-07/schema#"),
+    (
+        "draft2019-09",
+        "https://json-schema.org/draft/2019-09/schema",
+    ),
+    (
+        "draft2020-12",
+        "https://json-schema.org/draft/2020-12/schema",
+    ),
+];
+
+/// Cases known to disagree with the suite, as `(dialect, keyword, group, test)`.
+///
+/// A case listed here that starts passing is reported too, so that the list stays up to date.
+const KNOWN_FAILURES: &[(&str, &str, &str, &str)] = &[
+    (
+        "draft7",
+        "allOf",
+        "allOf combined with anyOf, oneOf",
+        "allOf: false, anyOf: false, oneOf: true",
+    ),
+    (
+        "draft7",
+        "allOf",
+        "allOf combined with anyOf, oneOf",
+        "allOf: false, anyOf: true, oneOf: true",
+    ),
+    (
+        "draft7",
+        "allOf",
+        "allOf combined with anyOf, oneOf",
+        "allOf: true, anyOf: false, oneOf: true",
+    ),
+    (
+        "draft7",
+        "anyOf",
+        "nested anyOf, to check validation semantics",
+        "anything non-null is invalid",
+    ),
+    (
+        "draft7",
+        "if-then-else",
+        "if and then without else",
+        "invalid through then",
+    ),
+    (
+        "draft7",
+        "if-then-else",
+        "if and else without then",
+        "invalid through else",
+    ),
+    (
+        "draft7",
+        "if-then-else",
+        "validate against correct branch, then vs else",
+        "invalid through then",
+    ),
+    (
+        "draft7",
+        "if-then-else",
+        "validate against correct branch, then vs else",
+        "invalid through else",
+    ),
+    (
+        "draft7",
+        "if-then-else",
+        "if with boolean schema true",
+        "boolean schema true in if always chooses the then path (invalid)",
+    ),
+    (
+        "draft7",
+        "if-then-else",
+        "if with boolean schema false",
+        "boolean schema false in if always chooses the else path (invalid)",
+    ),
+    (
+        "draft7",
+        "if-then-else",
+        "if appears at the end when serialized (keyword processing sequence)",
+        "no redirects to then and fails",
+    ),
+    (
+        "draft7",
+        "if-then-else",
+        "if appears at the end when serialized (keyword processing sequence)",
+        "invalid redirects to else and fails",
+    ),
+    (
+        "draft7",
+        "multipleOf",
+        "by number",
+        "35 is not multiple of 1.5",
+    ),
+    (
+        "draft7",
+        "oneOf",
+        "nested oneOf, to check validation semantics",
+        "anything non-null is invalid",
+    ),
+    (
+        "draft7",
+        "type",
+        "integer type matches integers",
+        "a float with zero fractional part is an integer",
+    ),
+    (
+        "draft2019-09",
+        "allOf",
+        "allOf combined with anyOf, oneOf",
+        "allOf: false, anyOf: false, oneOf: true",
+    ),
+    (
+        "draft2019-09",
+        "allOf",
+        "allOf combined with anyOf, oneOf",
+        "allOf: false, anyOf: true, oneOf: true",
+    ),
+    (
+        "draft2019-09",
+        "allOf",
+        "allOf combined with anyOf, oneOf",
+        "allOf: true, anyOf: false, oneOf: true",
+    ),
+    (
+        "draft2019-09",
+        "anyOf",
+        "nested anyOf, to check validation semantics",
+        "anything non-null is invalid",
+    ),
+    (
+        "draft2019-09",
+        "if-then-else",
+        "if and then without else",
+        "invalid through then",
+    ),
+    (
+        "draft2019-09",
+        "if-then-else",
+        "if and else without then",
+        "invalid through else",
+    ),
+    (
+        "draft2019-09",
+        "if-then-else",
+        "validate against correct branch, then vs else",
+        "invalid through then",
+    ),
+    (
+        "draft2019-09",
+        "if-then-else",
+        "validate against correct branch, then vs else",
+        "invalid through else",
+    ),
+    (
+        "draft2019-09",
+        "if-then-else",
+        "if with boolean schema true",
+        "boolean schema true in if always chooses the then path (invalid)",
+    ),
+    (
+        "draft2019-09",
+        "if-then-else",
+        "if with boolean schema false",
+        "boolean schema false in if always chooses the else path (invalid)",
+    ),
+    (
+        "draft2019-09",
+        "if-then-else",
+        "if appears at the end when serialized (keyword processing sequence)",
+        "no redirects to then and fails",
+    ),
+    (
+        "draft2019-09",
+        "if-then-else",
+        "if appears at the end when serialized (keyword processing sequence)",
+        "invalid redirects to else and fails",
+    ),
+    (
+        "draft2019-09",
+        "multipleOf",
+        "by number",
+        "35 is not multiple of 1.5",
+    ),
+    (
+        "draft2019-09",
+        "oneOf",
+        "nested oneOf, to check validation semantics",
+        "anything non-null is invalid",
+    ),
+    (
+        "draft2019-09",
+        "ref",
+        "ref applies alongside sibling keywords",
+        "ref valid, maxItems invalid",
+    ),
+    (
+        "draft2019-09",
+        "type",
+        "integer type matches integers",
+        "a float with zero fractional part is an integer",
+    ),
+    (
+        "draft2020-12",
+        "allOf",
+        "allOf combined with anyOf, oneOf",
+        "allOf: false, anyOf: false, oneOf: true",
+    ),
+    (
+        "draft2020-12",
+        "allOf",
+        "allOf combined with anyOf, oneOf",
+        "allOf: false, anyOf: true, oneOf: true",
+    ),
+    (
+        "draft2020-12",
+        "allOf",
+        "allOf combined with anyOf, oneOf",
+        "allOf: true, anyOf: false, oneOf: true",
+    ),
+    (
+        "draft2020-12",
+        "anyOf",
+        "nested anyOf, to check validation semantics",
+        "anything non-null is invalid",
+    ),
+    (
+        "draft2020-12",
+        "if-then-else",
+        "if and then without else",
+        "invalid through then",
+    ),
+    (
+        "draft2020-12",
+        "if-then-else",
+        "if and else without then",
+        "invalid through else",
+    ),
+    (
+        "draft2020-12",
+        "if-then-else",
+        "validate against correct branch, then vs else",
+        "invalid through then",
+    ),
+    (
+        "draft2020-12",
+        "if-then-else",
+        "validate against correct branch, then vs else",
+        "invalid through else",
+    ),
+    (
+        "draft2020-12",
+        "if-then-else",
+        "if with boolean schema true",
+        "boolean schema true in if always chooses the then path (invalid)",
+    ),
+    (
+        "draft2020-12",
+        "if-then-else",
+        "if with boolean schema false",
+        "boolean schema false in if always chooses the else path (invalid)",
+    ),
+    (
+        "draft2020-12",
+        "if-then-else",
+        "if appears at the end when serialized (keyword processing sequence)",
+        "no redirects to then and fails",
+    ),
+    (
+        "draft2020-12",
+        "if-then-else",
+        "if appears at the end when serialized (keyword processing sequence)",
+        "invalid redirects to else and fails",
+    ),
+    (
+        "draft2020-12",
+        "multipleOf",
+        "by number",
+        "35 is not multiple of 1.5",
+    ),
+    (
+        "draft2020-12",
+        "oneOf",
+        "nested oneOf, to check validation semantics",
+        "anything non-null is invalid",
+    ),
+    (
+        "draft2020-12",
+        "ref",
+        "ref applies alongside sibling keywords",
+        "ref valid, maxItems invalid",
+    ),
+    (
+        "draft2020-12",
+        "type",
+        "integer type matches integers",
+        "a float with zero fractional part is an integer",
+    ),
+];
+
+#[derive(Debug, Default)]
+struct KeywordReport {
+    passed: usize,
+    failed: usize,
+    skipped: usize,
+}
+
+#[tokio::test]
+async fn json_schema_test_suite_conformance() {
+    tombi_test_lib::init_log();
+
+    let suite_dir = tombi_test_lib::project_root_path()
+        .join("crates/tombi-linter/tests/fixtures/json-schema-test-suite/tests");
+
+    let mut reports = BTreeMap::<(&str, String), KeywordReport>::new();
+    let mut unexpected = Vec::new();
+
+    for (dialect, meta_schema) in DIALECTS {
+        for (keyword, groups) in load_keyword_files(&suite_dir.join(dialect)) {
+            let report = reports.entry((dialect, keyword.clone())).or_default();
+
+            for group in groups.as_array().expect("test groups must be an array") {
+                let group_description = group["description"].as_str().unwrap();
+                let schema = &group["schema"];
+
+                for test in group["tests"].as_array().unwrap() {
+                    let test_description = test["description"].as_str().unwrap();
+                    let Some((schema, toml_text)) =
+                        to_toml_case(schema, &test["data"], meta_schema)
+                    else {
+                        report.skipped += 1;
+                        continue;
+                    };
+
+                    let expected_valid = test["valid"].as_bool().unwrap();
+                    let actual_valid = match validate_test_suite(schema, &toml_text).await {
+                        Ok(()) => true,
+                        Err(diagnostics) => !diagnostics.iter().any(|d| d.is_error()),
+                    };
+
+                    let passed = expected_valid == actual_valid;
+                    if passed {
+                        report.passed += 1;
+                    } else {
+                        report.failed += 1;
+                    }
+
+                    let known_failure = KNOWN_FAILURES.contains(&(
+                        dialect,
+                        keyword.as_str(),
+                        group_description,
+                        test_description,
+                    ));
+                    if passed == known_failure {
+                        unexpected.push(format!(
+                            "{}: {dialect}/{keyword}: {group_description:?} / {test_description:?}",
+                            if passed { "now passes" } else { "fails" },
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    let mut summary = String::new();
+    for ((dialect, keyword), report) in &reports {
+        writeln!(
+            summary,
+            "{dialect:<14} {keyword:<20} passed: {:>3}, failed: {:>3}, skipped: {:>3}",
+            report.passed, report.failed, report.skipped
+        )
+        .unwrap();
+    }
+    std::fs::write(
+        Path::new(env!("CARGO_TARGET_TMPDIR")).join("json-schema-test-suite-conformance.txt"),
+        &summary,
+    )
+    .unwrap();
+
+    assert!(
+        unexpected.is_empty(),
+        "unexpected JSON Schema Test Suite results (update KNOWN_FAILURES if intended):\n{}\n\n{summary}",
+        unexpected.join("\n")
+    );
+}
+
+fn load_keyword_files(dialect_dir: &Path) -> Vec<(String, JsonValue)> {
+    let mut paths = std::fs::read_dir(dialect_dir)
+        .unwrap_or_else(|_| panic!("missing suite directory: {}", dialect_dir.display()))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "json")
+        })
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let keyword = path.file_stem().unwrap().to_string_lossy().into_owned();
+            let content = std::fs::read_to_string(&path).unwrap();
+            (keyword, serde_json::from_str(&content).unwrap())
+        })
+        .collect()
+}
+
+/// Convert a test case into a schema and a TOML document.
+///
+/// An object instance is the root table of the document.
+/// Any other instance is written as `value = <instance>`, and the schema is moved under `properties.value`.
+///
+/// Returns None if the instance contains `null`, which is not representable in TOML.
+fn to_toml_case(
+    schema: &JsonValue,
+    data: &JsonValue,
+    meta_schema: &str,
+) -> Option<(JsonValue, String)> {
+    let JsonValue::Object(object) = data else {
+        let toml_text = format!("value = {}\n", to_toml_value(data)?);
+        return Some((wrap_schema(schema, meta_schema), toml_text));
+    };
+
+    let mut schema = schema.clone();
+    if let JsonValue::Object(object) = &mut schema {
+        object
+            .entry("$schema")
+            .or_insert_with(|| JsonValue::String(meta_schema.to_string()));
+    }
+
+    let mut toml_text = String::new();
+    for (key, value) in object {
+        writeln!(
+            toml_text,
+            "{} = {}",
+            to_toml_string(key),
+            to_toml_value(value)?
+        )
+        .unwrap();
+    }
+    Some((schema, toml_text))
+}
+
+/// Move `schema` under `properties.value` of a new root schema.
+///
+/// The keywords that belong to the root of a schema document are kept at the root,
+/// and the JSON Pointers into the moved schema are rebased onto `#/properties/value`.
+fn wrap_schema(schema: &JsonValue, meta_schema: &str) -> JsonValue {
+    let mut schema = schema.clone();
+    rebase_refs(&mut schema);
+
+    let mut root = serde_json::Map::new();
+    root.insert(
+        "$schema".to_string(),
+        JsonValue::String(meta_schema.to_string()),
+    );
+    if let JsonValue::Object(object) = &mut schema {
+        for keyword in ["$schema", "$id", "definitions", "$defs"] {
+            if let Some(value) = object.remove(keyword) {
+                root.insert(keyword.to_string(), value);
+            }
+        }
+    }
+    root.insert("properties".to_string(), json!({ "value": schema }));
+
+    JsonValue::Object(root)
+}
+
+fn rebase_refs(schema: &mut JsonValue) {
+    match schema {
+        JsonValue::Object(object) => {
+            for (key, value) in object.iter_mut() {
+                match (key.as_str(), value) {
+                    // Instances, not schemas.
+                    ("enum" | "const", _) => {}
+                    ("$ref", JsonValue::String(reference)) => {
+                        // The definitions stay at the root.
+                        if let Some(pointer) = reference.strip_prefix('#')
+                            && (pointer.is_empty() || pointer.starts_with('/'))
+                            && !["/definitions/", "/$defs/"]
+                                .iter()
+                                .any(|prefix| pointer.starts_with(prefix))
+                        {
+                            *reference = format!("#/properties/value{pointer}");
+                        }
+                    }
+                    (_, value) => rebase_refs(value),
+                }
+            }
+        }
+        JsonValue::Array(items) => items.iter_mut().for_each(rebase_refs),
+        _ => {}
+    }
+}
+
+fn to_toml_value(value: &JsonValue) -> Option<String> {
+    Some(match value {
+        JsonValue::Null => return None,
+        JsonValue::Bool(value) => value.to_string(),
+        JsonValue::Number(number) => {
+            if number.is_f64() {
+                let value = number.as_f64()?;
+                if value.fract() == 0.0 {
+                    format!("{value:.1}")
+                } else {
+                    value.to_string()
+                }
+            } else {
+                number.to_string()
+            }
+        }
+        JsonValue::String(value) => to_toml_string(value),
+        JsonValue::Array(items) => format!(
+            "[{}]",
+            items
+                .iter()
+                .map(to_toml_value)
+                .collect::<Option<Vec<_>>>()?
+                .join(", ")
+        ),
+        JsonValue::Object(object) => {
+            if object.is_empty() {
+                "{}".to_string()
+            } else {
+                format!(
+                    "{{ {} }}",
+                    object
+                        .iter()
+                        .map(|(key, value)| Some(format!(
+                            "{} = {}",
+                            to_toml_string(key),
+                            to_toml_value(value)?
+                        )))
+                        .collect::<Option<Vec<_>>>()?
+                        .join(", ")
+                )
+            }
+        }
+    })
+}
+
+/// Quote a string as a TOML basic string.
+fn to_toml_string(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\u{8}' => quoted.push_str("\\b"),
+            '\t' => quoted.push_str("\\t"),
+            '\n' => quoted.push_str("\\n"),
+            '\u{c}' => quoted.push_str("\\f"),
+            '\r' => quoted.push_str("\\r"),
+            c if c.is_control() => write!(quoted, "\\u{:04X}", c as u32).unwrap(),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
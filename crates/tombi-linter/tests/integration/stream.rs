@@ -0,0 +1,294 @@
+use itertools::Itertools;
+use tombi_test_lib::{table_snippet_test_schema_path, type_test_schema_path};
+
+async fn lint_both(
+    source: &str,
+) -> (
+    Vec<(String, tombi_text::Range)>,
+    Vec<(String, tombi_text::Range)>,
+) {
+    lint_both_with_schema(source, type_test_schema_path()).await
+}
+
+async fn lint_both_with_schema(
+    source: &str,
+    schema_path: std::path::PathBuf,
+) -> (
+    Vec<(String, tombi_text::Range)>,
+    Vec<(String, tombi_text::Range)>,
+) {
+    let schema_store = tombi_schema_store::SchemaStore::new();
+    schema_store
+        .associate_schema(
+            tombi_schema_store::SchemaUri::from_file_path(schema_path).unwrap(),
+            vec!["*.toml".to_string()],
+            &tombi_schema_store::AssociateSchemaOptions::default(),
+        )
+        .await;
+
+    let source_path = tombi_test_lib::project_root_path().join("test.toml");
+    let options = tombi_linter::LintOptions::default();
+    let linter = || {
+        tombi_linter::Linter::new(
+            tombi_config::TomlVersion::default(),
+            &options,
+            Some(itertools::Either::Right(source_path.as_path())),
+            &schema_store,
+        )
+    };
+    let codes_and_ranges = |result: Result<(), Vec<tombi_diagnostic::Diagnostic>>| {
+        result
+            .err()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|diagnostic| (diagnostic.code().to_string(), diagnostic.range()))
+            .sorted()
+            .collect_vec()
+    };
+
+    let whole = codes_and_ranges(linter().lint(source).await);
+    let stream = codes_and_ranges(
+        linter()
+            .lint_stream(futures::stream::iter(tombi_parser::parse_stream(
+                source.as_bytes(),
+            )))
+            .await
+            .unwrap(),
+    );
+
+    (whole, stream)
+}
+
+/// Keep the diagnostics of the keys that are duplicated or conflict with another table.
+fn key_conflicts(
+    diagnostics: Vec<(String, tombi_text::Range)>,
+) -> Vec<(String, tombi_text::Range)> {
+    diagnostics
+        .into_iter()
+        .filter(|(code, _)| {
+            ["duplicate-key", "conflict-table", "conflict-array"].contains(&code.as_str())
+        })
+        .collect_vec()
+}
+
+#[tokio::test]
+async fn test_lint_stream_reports_same_diagnostics_as_whole_document() {
+    tombi_test_lib::init_log();
+
+    let source = textwrap::dedent(
+        r#"
+        integer = 5
+
+        [table]
+        integer = 0
+        string = ""
+
+        # leading comment
+        [literal]
+        key1 = 1
+        key2 = 2
+        "#,
+    );
+
+    let (whole, stream) = lint_both(&source).await;
+
+    assert!(!whole.is_empty());
+    pretty_assertions::assert_eq!(stream, whole);
+}
+
+#[tokio::test]
+async fn test_lint_stream_reports_syntax_errors_in_later_tables() {
+    tombi_test_lib::init_log();
+
+    let source = textwrap::dedent(
+        r#"
+        integer = 5
+
+        [table]
+        integer = 5
+        string = "value"
+
+        [table2]
+        key =
+        "#,
+    );
+
+    let (whole, stream) = lint_both(&source).await;
+
+    assert!(!whole.is_empty());
+    pretty_assertions::assert_eq!(stream, whole);
+}
+
+#[tokio::test]
+async fn test_lint_stream_reports_min_keys_of_tables_in_one_chunk() {
+    tombi_test_lib::init_log();
+
+    let source = textwrap::dedent(
+        r#"
+        [table]
+        integer = 5
+        "#,
+    );
+
+    let (whole, stream) = lint_both(&source).await;
+
+    pretty_assertions::assert_eq!(
+        whole.iter().map(|(code, _)| code.as_str()).collect_vec(),
+        vec!["table-min-keys"]
+    );
+    pretty_assertions::assert_eq!(stream, whole);
+}
+
+#[tokio::test]
+async fn test_lint_stream_reports_required_keys_of_tables_in_one_chunk() {
+    tombi_test_lib::init_log();
+
+    let source = textwrap::dedent(
+        r#"
+        name = "name"
+
+        [tool.linter]
+        enabled = true
+        "#,
+    );
+
+    let (whole, stream) = lint_both_with_schema(&source, table_snippet_test_schema_path()).await;
+
+    assert!(whole.iter().any(|(code, _)| code == "table-key-required"));
+    pretty_assertions::assert_eq!(stream, whole);
+}
+
+#[tokio::test]
+async fn test_lint_stream_reports_required_keys_of_nested_tables_in_one_chunk() {
+    tombi_test_lib::init_log();
+
+    let source = textwrap::dedent(
+        r#"
+        [tool]
+        linter.enabled = true
+        "#,
+    );
+
+    let (whole, stream) = lint_both_with_schema(&source, table_snippet_test_schema_path()).await;
+
+    assert!(whole.iter().any(|(code, _)| code == "table-key-required"));
+    pretty_assertions::assert_eq!(stream, whole);
+}
+
+#[tokio::test]
+async fn test_lint_stream_reports_required_keys_of_arrays_of_tables_in_one_chunk() {
+    tombi_test_lib::init_log();
+
+    let source = textwrap::dedent(
+        r#"
+        [[bin]]
+        name = "a"
+        path = "a.rs"
+
+        [[bin]]
+        name = "b"
+        "#,
+    );
+
+    let (whole, stream) = lint_both_with_schema(&source, table_snippet_test_schema_path()).await;
+
+    assert!(whole.iter().any(|(code, _)| code == "table-key-required"));
+    pretty_assertions::assert_eq!(stream, whole);
+}
+
+#[tokio::test]
+async fn test_lint_stream_skips_required_keys_of_tables_continued_in_another_chunk() {
+    tombi_test_lib::init_log();
+
+    let source = textwrap::dedent(
+        r#"
+        [tool.linter]
+        enabled = true
+
+        [tool.other]
+        key = 1
+
+        [tool.linter.select]
+        "#,
+    );
+
+    let (whole, stream) = lint_both_with_schema(&source, table_snippet_test_schema_path()).await;
+
+    assert!(whole.iter().any(|(code, _)| code == "table-key-required"));
+    assert!(!stream.iter().any(|(code, _)| code == "table-key-required"));
+}
+
+#[tokio::test]
+async fn test_lint_stream_reports_duplicate_tables_across_chunks() {
+    tombi_test_lib::init_log();
+
+    let source = textwrap::dedent(
+        r#"
+        [table]
+        integer = 5
+
+        [literal]
+        key1 = 1
+
+        [table]
+        string = "value"
+        "#,
+    );
+
+    let (whole, stream) = lint_both(&source).await;
+
+    let whole = key_conflicts(whole);
+    assert!(!whole.is_empty());
+    pretty_assertions::assert_eq!(key_conflicts(stream), whole);
+}
+
+#[tokio::test]
+async fn test_lint_stream_reports_table_and_array_of_tables_conflicts_across_chunks() {
+    tombi_test_lib::init_log();
+
+    let source = textwrap::dedent(
+        r#"
+        [[fruit]]
+        key = 1
+
+        [[fruit]]
+        key = 2
+
+        [fruit.info]
+        key = 3
+
+        [[fruit.info]]
+        key = 4
+        "#,
+    );
+
+    let (whole, stream) = lint_both(&source).await;
+
+    let whole = key_conflicts(whole);
+    assert!(!whole.is_empty());
+    pretty_assertions::assert_eq!(key_conflicts(stream), whole);
+}
+
+#[tokio::test]
+async fn test_lint_stream_reports_only_syntax_errors_when_lint_is_disabled() {
+    tombi_test_lib::init_log();
+
+    let source = textwrap::dedent(
+        r#"
+        #:tombi lint.disabled = true
+        integer = "string"
+        key =
+
+        [table]
+        integer = "string"
+        "#,
+    );
+
+    let (whole, stream) = lint_both(&source).await;
+
+    pretty_assertions::assert_eq!(
+        stream.iter().map(|(code, _)| code.as_str()).collect_vec(),
+        vec!["expected-value"]
+    );
+    pretty_assertions::assert_eq!(stream, whole);
+}
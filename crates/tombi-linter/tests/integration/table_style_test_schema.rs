@@ -0,0 +1,191 @@
+use tombi_config::{LintOptions, LintRules, SeverityLevel, TableStyleRule};
+use tombi_linter::test_lint;
+use tombi_test_lib::x_tombi_table_style_test_schema_path;
+use tombi_x_keyword::TableStyle;
+
+fn table_style_rule(rule: TableStyleRule) -> LintOptions {
+    LintOptions {
+        rules: Some(LintRules {
+            table_style: Some(rule),
+            ..Default::default()
+        }),
+    }
+}
+
+fn table_style(table_style: TableStyle) -> LintOptions {
+    table_style_rule(TableStyleRule {
+        style: Some(table_style),
+        ..Default::default()
+    })
+}
+
+fn table_style_level(level: SeverityLevel) -> LintOptions {
+    table_style_rule(TableStyleRule {
+        level: Some(level.into()),
+        ..Default::default()
+    })
+}
+
+test_lint! {
+    #[test]
+    fn test_table_style_inline(
+        r#"
+        [dependencies]
+        serde = { version = "1", features = ["derive"] }
+        "#,
+        SchemaPath(x_tombi_table_style_test_schema_path()),
+    ) -> Ok(_)
+}
+
+test_lint! {
+    #[test]
+    fn test_table_style_inline_but_table(
+        r#"
+        [dependencies.serde]
+        version = "1"
+        features = ["derive"]
+        "#,
+        SchemaPath(x_tombi_table_style_test_schema_path()),
+    ) -> Err([tombi_validator::DiagnosticKind::TableStyle {
+        expected: TableStyle::Inline,
+    }])
+}
+
+test_lint! {
+    #[test]
+    fn test_table_style_inline_but_dotted(
+        r#"
+        [dependencies]
+        serde.version = "1"
+        "#,
+        SchemaPath(x_tombi_table_style_test_schema_path()),
+    ) -> Err([tombi_validator::DiagnosticKind::TableStyle {
+        expected: TableStyle::Inline,
+    }])
+}
+
+test_lint! {
+    #[test]
+    fn test_table_style_table(
+        r#"
+        [profile.release]
+        opt-level = 3
+        "#,
+        SchemaPath(x_tombi_table_style_test_schema_path()),
+    ) -> Ok(_)
+}
+
+test_lint! {
+    #[test]
+    fn test_table_style_table_but_inline(
+        r#"
+        [profile]
+        release = { opt-level = 3 }
+        "#,
+        SchemaPath(x_tombi_table_style_test_schema_path()),
+    ) -> Err([tombi_validator::DiagnosticKind::TableStyle {
+        expected: TableStyle::Table,
+    }])
+}
+
+test_lint! {
+    #[test]
+    fn test_table_style_dotted(
+        r#"
+        lints.rust.unsafe_code = "forbid"
+        "#,
+        SchemaPath(x_tombi_table_style_test_schema_path()),
+    ) -> Ok(_)
+}
+
+test_lint! {
+    #[test]
+    fn test_table_style_dotted_but_table(
+        r#"
+        [lints]
+        rust.unsafe_code = "forbid"
+        "#,
+        SchemaPath(x_tombi_table_style_test_schema_path()),
+    ) -> Err([tombi_validator::DiagnosticKind::TableStyle {
+        expected: TableStyle::Dotted,
+    }])
+}
+
+test_lint! {
+    #[test]
+    fn test_table_style_any(
+        r#"
+        [free]
+        a = { key = 1 }
+        b.key = 1
+
+        [free.c]
+        key = 1
+        "#,
+        SchemaPath(x_tombi_table_style_test_schema_path()),
+    ) -> Ok(_)
+}
+
+test_lint! {
+    #[test]
+    fn test_table_style_overridden_by_lint_rules(
+        r#"
+        [dependencies.serde]
+        version = "1"
+        "#,
+        SchemaPath(x_tombi_table_style_test_schema_path()),
+        table_style(TableStyle::Table),
+    ) -> Ok(_)
+}
+
+test_lint! {
+    #[test]
+    fn test_table_style_overridden_by_lint_rules_violated(
+        r#"
+        [dependencies]
+        serde = { version = "1" }
+        "#,
+        SchemaPath(x_tombi_table_style_test_schema_path()),
+        table_style(TableStyle::Table),
+    ) -> Err([tombi_validator::DiagnosticKind::TableStyle {
+        expected: TableStyle::Table,
+    }])
+}
+
+test_lint! {
+    #[test]
+    fn test_table_style_disabled_by_lint_rules(
+        r#"
+        [dependencies.serde]
+        version = "1"
+        "#,
+        SchemaPath(x_tombi_table_style_test_schema_path()),
+        table_style(TableStyle::Any),
+    ) -> Ok(_)
+}
+
+test_lint! {
+    #[test]
+    fn test_table_style_level_error(
+        r#"
+        [dependencies.serde]
+        version = "1"
+        "#,
+        SchemaPath(x_tombi_table_style_test_schema_path()),
+        table_style_level(SeverityLevel::Error),
+    ) -> Diagnostics([
+        { code: "table-style", level: tombi_diagnostic::Level::ERROR }
+    ])
+}
+
+test_lint! {
+    #[test]
+    fn test_table_style_level_off(
+        r#"
+        [dependencies.serde]
+        version = "1"
+        "#,
+        SchemaPath(x_tombi_table_style_test_schema_path()),
+        table_style_level(SeverityLevel::Off),
+    ) -> Ok(_)
+}
@@ -0,0 +1,62 @@
+use tombi_linter::test_lint;
+use tombi_test_lib::{project_root_path, toml_schema_test_schema_path};
+
+fn embedded_catalog_config() -> tombi_config::Config {
+    let mut config = tombi_config::Config::default();
+    config.schema = Some(tombi_config::SchemaOverviewOptions {
+        enabled: None,
+        strict: None,
+        catalog: Some(tombi_config::SchemaCatalog {
+            paths: Some(vec![tombi_config::TOMBI_SCHEMASTORE_CATALOG_URL.into()]),
+        }),
+    });
+    config
+}
+
+test_lint! {
+    #[test]
+    fn test_toml_schema(
+        r#"
+        name = "tombi"
+        port = 8080
+        tags = ["toml"]
+        "#,
+        SchemaPath(toml_schema_test_schema_path()),
+    ) -> Ok(_)
+}
+
+test_lint! {
+    #[test]
+    fn test_toml_schema_type_mismatch(
+        r#"
+        port = "8080"
+        "#,
+        SchemaPath(toml_schema_test_schema_path()),
+    ) -> Err([tombi_validator::DiagnosticKind::TypeMismatch {
+        expected: tombi_schema_store::ValueType::Integer,
+        actual: tombi_document_tree::ValueType::String,
+    }])
+}
+
+test_lint! {
+    #[test]
+    fn test_toml_schema_file_with_meta_schema(
+        include_str!("../../../../schemas/toml-schema-test.schema.toml"),
+        Config(embedded_catalog_config()),
+        SourcePath(project_root_path().join("test.schema.toml")),
+    ) -> Ok(_)
+}
+
+test_lint! {
+    #[test]
+    fn test_toml_schema_file_with_meta_schema_type_mismatch(
+        r#"
+        title = 1
+        "#,
+        Config(embedded_catalog_config()),
+        SourcePath(project_root_path().join("test.schema.toml")),
+    ) -> Err([tombi_validator::DiagnosticKind::TypeMismatch {
+        expected: tombi_schema_store::ValueType::String,
+        actual: tombi_document_tree::ValueType::Integer,
+    }])
+}
@@ -0,0 +1,65 @@
+use itertools::Itertools;
+use tombi_test_lib::type_test_schema_path;
+
+#[tokio::test]
+async fn test_lint_with_validation_cache_reports_same_diagnostics_after_edit() {
+    tombi_test_lib::init_log();
+
+    let schema_store = tombi_schema_store::SchemaStore::new();
+    schema_store
+        .associate_schema(
+            tombi_schema_store::SchemaUri::from_file_path(type_test_schema_path()).unwrap(),
+            vec!["*.toml".to_string()],
+            &tombi_schema_store::AssociateSchemaOptions::default(),
+        )
+        .await;
+
+    let source_path = tombi_test_lib::project_root_path().join("test.toml");
+    let options = tombi_linter::LintOptions::default();
+    let linter = || {
+        tombi_linter::Linter::new(
+            tombi_config::TomlVersion::default(),
+            &options,
+            Some(itertools::Either::Right(source_path.as_path())),
+            &schema_store,
+        )
+    };
+    let codes_and_ranges = |result: Result<(), Vec<tombi_diagnostic::Diagnostic>>| {
+        result
+            .err()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|diagnostic| (diagnostic.code().to_string(), diagnostic.range()))
+            .sorted()
+            .collect_vec()
+    };
+
+    let before = textwrap::dedent(
+        r#"
+        integer = 5
+
+        [table]
+        integer = 5
+        string = "value"
+        "#,
+    );
+    let after = before.replace("[table]\ninteger = 5", "[table]\ninteger = 0");
+
+    assert_ne!(
+        codes_and_ranges(linter().lint(&before).await),
+        codes_and_ranges(linter().lint(&after).await)
+    );
+
+    let validation_cache = tombi_schema_store::ValidationCache::default();
+    for source in [&before, &before, &after, &before] {
+        let cached = codes_and_ranges(
+            linter()
+                .with_validation_cache(validation_cache.clone())
+                .lint(source)
+                .await,
+        );
+        let uncached = codes_and_ranges(linter().lint(source).await);
+
+        pretty_assertions::assert_eq!(cached, uncached);
+    }
+}
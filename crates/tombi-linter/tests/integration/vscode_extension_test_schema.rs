@@ -0,0 +1,101 @@
+use tombi_linter::test_lint;
+use tombi_test_lib::vscode_extension_test_schema_path;
+
+// --- errorMessage: string ---
+
+test_lint! {
+    #[test]
+    fn test_error_message_replaces_type_mismatch(
+        r#"
+        name = 1
+        "#,
+        SchemaPath(vscode_extension_test_schema_path()),
+    ) -> Err(["name must be a string"])
+}
+
+// --- errorMessage: keywords ---
+
+test_lint! {
+    #[test]
+    fn test_error_message_by_keyword(
+        r#"
+        port = 0
+        "#,
+        SchemaPath(vscode_extension_test_schema_path()),
+    ) -> Err(["port must be at least 1"])
+}
+
+test_lint! {
+    #[test]
+    fn test_error_message_fallback_keyword(
+        r#"
+        port = 70000
+        "#,
+        SchemaPath(vscode_extension_test_schema_path()),
+    ) -> Err(["port must be a TCP port number"])
+}
+
+test_lint! {
+    #[test]
+    fn test_error_message_array_keyword(
+        r#"
+        tags = ["a", "a"]
+        "#,
+        SchemaPath(vscode_extension_test_schema_path()),
+    ) -> Err(["tags must not repeat", "tags must not repeat"])
+}
+
+test_lint! {
+    #[test]
+    fn test_error_message_table_required(
+        r#"
+        [server]
+        port = 8080
+        "#,
+        SchemaPath(vscode_extension_test_schema_path()),
+    ) -> Err(["server needs a host"])
+}
+
+test_lint! {
+    #[test]
+    fn test_error_message_table_additional_properties(
+        r#"
+        [server]
+        host = "localhost"
+        user = "admin"
+        "#,
+        SchemaPath(vscode_extension_test_schema_path()),
+    ) -> Err(["server only accepts host and port"])
+}
+
+test_lint! {
+    #[test]
+    fn test_error_message_does_not_replace_nested_errors(
+        r#"
+        [server]
+        host = 1
+        "#,
+        SchemaPath(vscode_extension_test_schema_path()),
+    ) -> Err(["host must be a string"])
+}
+
+// --- keyword without errorMessage ---
+
+test_lint! {
+    #[test]
+    fn test_enum_keeps_generic_message(
+        r#"
+        log-level = "trace"
+        "#,
+        SchemaPath(vscode_extension_test_schema_path()),
+    ) -> Err([
+        tombi_validator::DiagnosticKind::Enum {
+            expected: vec![
+                "\"debug\"".to_string(),
+                "\"info\"".to_string(),
+                "\"error\"".to_string(),
+            ],
+            actual: "\"trace\"".to_string(),
+        },
+    ])
+}
@@ -12,8 +12,9 @@ use tower_lsp::lsp_types::{
     DocumentSymbolParams, DocumentSymbolResponse, FoldingRange, FoldingRangeParams,
     GotoDefinitionParams, GotoDefinitionResponse, Hover, HoverParams, InitializeParams,
     InitializeResult, InitializedParams, InlayHint, InlayHintParams, ReferenceParams,
-    SemanticTokensParams, SemanticTokensResult, TextDocumentIdentifier, WorkspaceDiagnosticParams,
-    WorkspaceDiagnosticReportResult,
+    SemanticTokensDeltaParams, SemanticTokensFullDeltaResult, SemanticTokensParams,
+    SemanticTokensRangeParams, SemanticTokensRangeResult, SemanticTokensResult,
+    TextDocumentIdentifier, WorkspaceDiagnosticParams, WorkspaceDiagnosticReportResult,
     request::{
         GotoDeclarationParams, GotoDeclarationResponse, GotoTypeDefinitionParams,
         GotoTypeDefinitionResponse,
@@ -26,19 +27,23 @@ use crate::{
     goto_definition::try_get_goto_definition_response,
     goto_type_definition::try_get_type_definition_response,
     handler::{
-        AssociateSchemaParams, GetBuiltInSchemaParams, GetStatusResponse, GetTomlVersionResponse,
-        ListSchemasParams, ListSchemasResponse, RefreshCacheParams, TomlVersionSource,
-        handle_associate_schema, handle_code_action, handle_completion, handle_diagnostic,
-        handle_did_change, handle_did_change_configuration, handle_did_change_watched_files,
-        handle_did_close, handle_did_open, handle_did_save, handle_document_link,
-        handle_document_symbol, handle_folding_range, handle_formatting,
+        AssociateSchemaParams, EmbeddedLanguagesResponse, GetBuiltInSchemaParams,
+        GetStatusResponse, GetTomlVersionResponse, ListAssociatedFilesResponse, ListSchemasParams,
+        ListSchemasResponse, RefreshCacheParams, TomlVersionSource, handle_associate_schema,
+        handle_code_action, handle_completion, handle_diagnostic, handle_did_change,
+        handle_did_change_configuration, handle_did_change_watched_files, handle_did_close,
+        handle_did_open, handle_did_save, handle_document_link, handle_document_symbol,
+        handle_embedded_languages, handle_folding_range, handle_formatting,
         handle_get_built_in_schema, handle_get_status, handle_get_toml_version,
         handle_goto_declaration, handle_goto_definition, handle_goto_type_definition, handle_hover,
-        handle_initialize, handle_initialized, handle_inlay_hint, handle_list_schemas,
-        handle_references, handle_refresh_cache, handle_semantic_tokens_full, handle_shutdown,
+        handle_initialize, handle_initialized, handle_inlay_hint, handle_list_associated_files,
+        handle_list_schemas, handle_references, handle_refresh_cache, handle_semantic_tokens_full,
+        handle_semantic_tokens_full_delta, handle_semantic_tokens_range, handle_shutdown,
         handle_update_config, handle_update_schema, handle_workspace_diagnostic, push_diagnostics,
     },
     references::try_get_reference_locations,
+    schema_document::SchemaDocumentSource,
+    semantic_tokens::SemanticTokensCache,
     workspace_diagnostic::WorkspaceDiagnosticsCache,
 };
 
@@ -52,11 +57,14 @@ pub struct Backend {
     pub background_tasks: Arc<std::sync::Mutex<Vec<tombi_future::TaskHandle>>>,
     pub document_sources:
         Arc<tokio::sync::RwLock<tombi_hashmap::HashMap<tombi_uri::Uri, DocumentSource>>>,
+    pub schema_document_sources:
+        Arc<tokio::sync::RwLock<tombi_hashmap::HashMap<tombi_uri::Uri, SchemaDocumentSource>>>,
     opening_documents: Arc<
         std::sync::Mutex<tombi_hashmap::HashMap<tombi_uri::Uri, tokio::sync::watch::Sender<bool>>>,
     >,
     pub config_manager: Arc<ConfigManager>,
     pub workspace_diagnostics_cache: Arc<tokio::sync::RwLock<WorkspaceDiagnosticsCache>>,
+    pub semantic_tokens_cache: Arc<tokio::sync::RwLock<SemanticTokensCache>>,
 }
 
 #[derive(Debug)]
@@ -101,9 +109,11 @@ impl Backend {
             })),
             background_tasks: Default::default(),
             document_sources: Default::default(),
+            schema_document_sources: Default::default(),
             opening_documents: Default::default(),
             config_manager: Arc::new(ConfigManager::new(options)),
             workspace_diagnostics_cache: Default::default(),
+            semantic_tokens_cache: Default::default(),
         }
     }
 
@@ -216,6 +226,30 @@ impl Backend {
         &self,
         text_document_uri: &tombi_uri::Uri,
         text: &str,
+    ) -> (TomlVersion, TomlVersionSource) {
+        let root = tombi_parser::parse(text)
+            .cast::<tombi_ast::Root>()
+            .map(|parsed| parsed.tree());
+
+        self.root_toml_version_and_source(text_document_uri, root.as_ref())
+            .await
+    }
+
+    /// Like [`Self::text_document_toml_version`], for a document that has already been parsed.
+    pub async fn root_toml_version(
+        &self,
+        text_document_uri: &tombi_uri::Uri,
+        root: &tombi_ast::Root,
+    ) -> TomlVersion {
+        self.root_toml_version_and_source(text_document_uri, Some(root))
+            .await
+            .0
+    }
+
+    async fn root_toml_version_and_source(
+        &self,
+        text_document_uri: &tombi_uri::Uri,
+        root: Option<&tombi_ast::Root>,
     ) -> (TomlVersion, TomlVersionSource) {
         let ConfigSchemaStore {
             config,
@@ -226,21 +260,18 @@ impl Backend {
             .config_schema_store_for_uri(text_document_uri)
             .await;
 
-        let source_schema = if let Some(parsed) =
-            tombi_parser::parse(text).cast::<tombi_ast::Root>()
-        {
-            let root = parsed.tree();
+        let source_schema = if let Some(root) = root {
             if let Some(TombiDocumentDirectiveContent {
                 toml_version: Some(toml_version),
                 ..
-            }) = tombi_validator::comment_directive::get_tombi_document_comment_directive(&root)
-                .await
+            }) =
+                tombi_validator::comment_directive::get_tombi_document_comment_directive(root).await
             {
                 return (toml_version, TomlVersionSource::Comment);
             }
 
             match schema_store
-                .resolve_source_schema_from_ast(&root, Some(Either::Left(text_document_uri)))
+                .resolve_source_schema_from_ast(root, Some(Either::Left(text_document_uri)))
                 .await
             {
                 Ok(Some(schema)) => Some(schema),
@@ -335,10 +366,33 @@ impl Backend {
         handle_list_schemas(self, params).await
     }
 
+    #[inline]
+    pub async fn embedded_languages(
+        &self,
+        params: TextDocumentIdentifier,
+    ) -> Result<EmbeddedLanguagesResponse, tower_lsp::jsonrpc::Error> {
+        handle_embedded_languages(self, params).await
+    }
+
+    #[inline]
+    pub async fn list_associated_files(
+        &self,
+        params: TextDocumentIdentifier,
+    ) -> Result<ListAssociatedFilesResponse, tower_lsp::jsonrpc::Error> {
+        handle_list_associated_files(self, params).await
+    }
+
     #[inline]
     pub async fn push_diagnostics(&self, text_document_uri: tombi_uri::Uri) {
         push_diagnostics(self, text_document_uri).await
     }
+
+    /// Drop the validation results kept for open documents, after the config or the schemas changed.
+    pub async fn clear_validation_caches(&self) {
+        for document_source in self.document_sources.read().await.values() {
+            document_source.validation_cache().clear();
+        }
+    }
 }
 
 impl Drop for Backend {
@@ -424,6 +478,20 @@ impl tower_lsp::LanguageServer for Backend {
         handle_semantic_tokens_full(self, params).await
     }
 
+    async fn semantic_tokens_full_delta(
+        &self,
+        params: SemanticTokensDeltaParams,
+    ) -> Result<Option<SemanticTokensFullDeltaResult>, tower_lsp::jsonrpc::Error> {
+        handle_semantic_tokens_full_delta(self, params).await
+    }
+
+    async fn semantic_tokens_range(
+        &self,
+        params: SemanticTokensRangeParams,
+    ) -> Result<Option<SemanticTokensRangeResult>, tower_lsp::jsonrpc::Error> {
+        handle_semantic_tokens_range(self, params).await
+    }
+
     async fn document_symbol(
         &self,
         params: DocumentSymbolParams,
@@ -2,9 +2,10 @@ use tombi_ast::AstNode;
 use tombi_document_tree::{TableKind, dig_accessors};
 use tombi_schema_store::{Accessor, AccessorContext, AccessorKeyKind};
 use tombi_text::IntoLsp;
+use tombi_x_keyword::TableStyle;
 use tower_lsp::lsp_types::{
-    CodeAction, CodeActionKind, DocumentChanges, OneOf, OptionalVersionedTextDocumentIdentifier,
-    TextDocumentEdit, TextEdit, WorkspaceEdit,
+    CodeAction, CodeActionKind, Diagnostic, DocumentChanges, NumberOrString, OneOf,
+    OptionalVersionedTextDocumentIdentifier, TextDocumentEdit, TextEdit, WorkspaceEdit,
 };
 
 pub enum CodeActionRefactorRewriteName {
@@ -165,6 +166,41 @@ pub fn inline_table_to_dot_keys_code_action(
     }
 }
 
+/// Turn a rewrite into the quick fix of a `table-style` diagnostic that expects its result.
+///
+/// The rewrite stays a refactoring when no such diagnostic is reported at the cursor.
+pub fn into_table_style_quick_fix(
+    mut code_action: CodeAction,
+    expected: TableStyle,
+    diagnostics: &[Diagnostic],
+    line_index: &tombi_text::LineIndex,
+    position: tombi_text::Position,
+) -> CodeAction {
+    let message = tombi_validator::DiagnosticKind::TableStyle { expected }.to_string();
+
+    let diagnostics = diagnostics
+        .iter()
+        .filter(|diagnostic| {
+            diagnostic.code == Some(NumberOrString::String("table-style".to_string()))
+                && diagnostic.message == message
+                && tombi_text::Range::new(
+                    diagnostic.range.start.into_lsp(line_index),
+                    diagnostic.range.end.into_lsp(line_index),
+                )
+                .contains(position)
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+
+    if !diagnostics.is_empty() {
+        code_action.kind = Some(CodeActionKind::QUICKFIX);
+        code_action.diagnostics = Some(diagnostics);
+        code_action.is_preferred = Some(true);
+    }
+
+    code_action
+}
+
 fn get_ast_inline_table_node(
     root: &tombi_ast::Root,
     table: &tombi_document_tree::Table,
@@ -757,9 +757,23 @@ fn tombi_json_value_to_completion_example_item(
     ))
 }
 
+/// The documentation of an `enum` item: its `enumDescriptions` entry, or else the schema description.
+pub(crate) fn enum_item_documentation(
+    enum_descriptions: Option<&[Option<String>]>,
+    index: usize,
+    description: Option<&String>,
+) -> Option<String> {
+    enum_descriptions
+        .and_then(|enum_descriptions| enum_descriptions.get(index))
+        .cloned()
+        .flatten()
+        .or_else(|| description.cloned())
+}
+
 fn tombi_json_value_to_completion_enum_item(
     value: &tombi_json::Value,
     position: tombi_text::Position,
+    documentation: Option<String>,
     schema_uri: Option<&SchemaUri>,
     completion_hint: Option<CompletionHint>,
 ) -> Option<CompletionContent> {
@@ -773,7 +787,12 @@ fn tombi_json_value_to_completion_enum_item(
     let label = value.to_string();
     let edit = CompletionEdit::new_literal(&label, position, completion_hint);
     Some(CompletionContent::new_enum_value(
-        label, None, None, edit, schema_uri, None,
+        label,
+        None,
+        documentation,
+        edit,
+        schema_uri,
+        None,
     ))
 }
 
@@ -3,7 +3,7 @@ use tombi_schema_store::{Accessor, CurrentSchema, SchemaView};
 use tombi_x_keyword::StringFormat;
 
 use super::{
-    CompletionContent, CompletionHint, FindCompletionContents,
+    CompletionContent, CompletionEdit, CompletionHint, FindCompletionContents,
     merge_adjacent_schema_completion_items, tombi_json_value_to_completion_enum_item,
     value::{
         find_all_of_completion_items, find_any_of_completion_items, find_one_of_completion_items,
@@ -76,10 +76,8 @@ impl FindCompletionContents for SchemaCompletion {
                 .and_then(|format| format.parse::<StringFormat>().ok())
                 .is_some_and(|format| format.toml_date_time_type().is_some());
             if !has_toml_datetime_format
-                && let Some(candidates) = current_schema
-                    .semantic_schema
-                    .as_deref()
-                    .and_then(|schema| schema.finite_literal_candidates())
+                && let Some(semantic_schema) = current_schema.semantic_schema.as_deref()
+                && let Some(candidates) = semantic_schema.finite_literal_candidates()
             {
                 let completion_items = candidates
                     .iter()
@@ -87,18 +85,31 @@ impl FindCompletionContents for SchemaCompletion {
                         tombi_json_value_to_completion_enum_item(
                             value,
                             position,
+                            semantic_schema
+                                .enum_description(value)
+                                .map(ToString::to_string),
                             Some(current_schema.schema_uri.as_ref()),
                             completion_hint,
                         )
                     })
                     .collect::<Vec<_>>();
-                return completion_items;
+                return completion_items
+                    .into_iter()
+                    .chain(default_snippet_completion_items(
+                        current_schema,
+                        position,
+                        completion_hint,
+                    ))
+                    .collect();
             }
 
+            let default_snippet_items =
+                default_snippet_completion_items(current_schema, position, completion_hint);
+
             let projected_schema = current_schema.for_completion(schema_context.string_formats());
             let current_schema = projected_schema.as_ref().unwrap_or(current_schema);
 
-            match current_schema.schema_view.as_ref() {
+            let mut completion_items = match current_schema.schema_view.as_ref() {
                 SchemaView::Boolean(boolean_schema) => {
                     boolean_schema
                         .find_completion_contents(
@@ -272,12 +283,41 @@ impl FindCompletionContents for SchemaCompletion {
                     .await
                 }
                 SchemaView::Anything(_) | SchemaView::Nothing(_) | SchemaView::Null => Vec::new(),
-            }
+            };
+            completion_items.extend(default_snippet_items);
+
+            completion_items
         }
         .boxed()
     }
 }
 
+/// Completion items for the VS Code `defaultSnippets` of the schema.
+fn default_snippet_completion_items(
+    current_schema: &CurrentSchema<'_>,
+    position: tombi_text::Position,
+    completion_hint: Option<CompletionHint>,
+) -> Vec<CompletionContent> {
+    let Some(semantic_schema) = current_schema.semantic_schema.as_deref() else {
+        return Vec::new();
+    };
+
+    semantic_schema
+        .default_snippets()
+        .iter()
+        .filter_map(|default_snippet| {
+            let insert_text = default_snippet.insert_text();
+            let edit = CompletionEdit::new_snippet(&insert_text, position, completion_hint)?;
+            Some(CompletionContent::new_default_snippet(
+                default_snippet.label.clone().unwrap_or(insert_text),
+                default_snippet.description.clone(),
+                Some(edit),
+                Some(current_schema.schema_uri.as_ref()),
+            ))
+        })
+        .collect()
+}
+
 impl tombi_validator::Validate for SchemaCompletion {
     fn validate<'a: 'b, 'b>(
         &'a self,
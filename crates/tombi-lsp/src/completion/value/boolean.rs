@@ -6,7 +6,7 @@ use crate::{
     comment_directive::get_key_table_value_comment_directive_content_and_schema_uri,
     completion::{
         CompletionContent, CompletionEdit, CompletionHint, FindCompletionContents,
-        comment::get_tombi_comment_directive_content_completion_contents,
+        comment::get_tombi_comment_directive_content_completion_contents, enum_item_documentation,
         merge_adjacent_schema_completion_items,
     },
 };
@@ -98,13 +98,17 @@ impl FindCompletionContents for BooleanSchema {
             }
 
             if let Some(r#enum) = &self.r#enum {
-                completion_items.extend(r#enum.iter().map(|value| {
+                completion_items.extend(r#enum.iter().enumerate().map(|(index, value)| {
                     let label = value.to_string();
                     let edit = CompletionEdit::new_literal(&label, position, completion_hint);
                     CompletionContent::new_enum_value(
                         value.to_string(),
                         self.title.clone(),
-                        self.description.clone(),
+                        enum_item_documentation(
+                            self.enum_descriptions.as_deref(),
+                            index,
+                            self.description.as_ref(),
+                        ),
                         edit,
                         schema_uri,
                         self.deprecated(),
@@ -7,7 +7,7 @@ use crate::{
     comment_directive::get_key_table_value_comment_directive_content_and_schema_uri,
     completion::{
         CompletionContent, CompletionEdit, CompletionHint, FindCompletionContents,
-        comment::get_tombi_comment_directive_content_completion_contents,
+        comment::get_tombi_comment_directive_content_completion_contents, enum_item_documentation,
         merge_adjacent_schema_completion_items,
     },
 };
@@ -99,13 +99,17 @@ impl FindCompletionContents for IntegerSchema {
             }
 
             if let Some(r#enum) = &self.r#enum {
-                for item in r#enum {
+                for (index, item) in r#enum.iter().enumerate() {
                     let label = item.to_string();
                     let edit = CompletionEdit::new_literal(&label, position, completion_hint);
                     completion_items.push(CompletionContent::new_enum_value(
                         label,
                         self.title.clone(),
-                        self.description.clone(),
+                        enum_item_documentation(
+                            self.enum_descriptions.as_deref(),
+                            index,
+                            self.description.as_ref(),
+                        ),
                         edit,
                         schema_uri,
                         self.deprecated(),
@@ -5,13 +5,15 @@ use itertools::Itertools;
 use tombi_future::{BoxFuture, Boxable};
 use tombi_schema_store::{
     Accessor, CurrentSchema, FindSchemaCandidates, PatternAccessor, Referable, SchemaAccessor,
-    SchemaStore, SchemaView, TableSchema, is_online_url,
+    SchemaStore, SchemaView, TableSchema, XTombiTableKeysOrder, is_online_url,
 };
+use tombi_x_keyword::{TableKeysOrder, TableKeysOrderGroupKind};
 
 use crate::{
     comment_directive::get_table_comment_directive_content_with_schema_uri,
     completion::{
-        CompletionCandidate, CompletionContent, CompletionHint, FindCompletionContents,
+        CompletionCandidate, CompletionContent, CompletionEdit, CompletionHint,
+        FindCompletionContents,
         comment::get_tombi_comment_directive_content_completion_contents,
         value::{
             all_of::find_all_of_completion_items, any_of::find_any_of_completion_items,
@@ -1388,6 +1390,22 @@ fn collect_table_key_completion_contents<'a: 'b, 'b>(
                 completion_hint,
                 singleton_value_label.clone(),
             ));
+
+            if let Some(table_snippet) = table_snippet_completion_content(
+                table,
+                key_name,
+                position,
+                replace_range,
+                accessors,
+                &schema_candidate,
+                current_schema,
+                schema_context.store,
+                completion_hint,
+            )
+            .await
+            {
+                completion_contents.push(table_snippet);
+            }
         }
 
         Some(completion_contents)
@@ -1395,6 +1413,371 @@ fn collect_table_key_completion_contents<'a: 'b, 'b>(
     .boxed()
 }
 
+/// A snippet inserting a sub-table (or an array of tables) header
+/// together with the `required` keys of its schema.
+///
+/// ```toml
+/// [[bin]]
+/// name = "$1"
+/// path = "$2"
+/// ```
+async fn table_snippet_completion_content(
+    table: &tombi_document_tree::Table,
+    key_name: &str,
+    position: tombi_text::Position,
+    replace_range: Option<tombi_text::Range>,
+    accessors: &[Accessor],
+    schema_candidate: &SchemaView,
+    current_schema: &CurrentSchema<'_>,
+    schema_store: &SchemaStore,
+    completion_hint: Option<CompletionHint>,
+) -> Option<CompletionContent> {
+    if completion_hint.is_some()
+        || !matches!(
+            table.kind(),
+            tombi_document_tree::TableKind::Root | tombi_document_tree::TableKind::Table
+        )
+        || !accessors.iter().all(Accessor::is_key)
+        || has_key_values_after(table, position)
+    {
+        return None;
+    }
+
+    let header_keys = accessors
+        .iter()
+        .filter_map(Accessor::as_key)
+        .chain(std::iter::once(key_name))
+        .map(tombi_toml_text::to_key_string)
+        .join(".");
+
+    let (header, documentation, body) = match schema_candidate {
+        SchemaView::Table(table_schema) => {
+            if table.contains_key(key_name) {
+                return None;
+            }
+            let body = required_keys_snippet(
+                table_schema,
+                &current_schema.schema_uri,
+                &current_schema.definitions,
+                current_schema.strict,
+                schema_store,
+            )
+            .await?;
+            (
+                format!("[{header_keys}]"),
+                table_schema.description.clone(),
+                body,
+            )
+        }
+        SchemaView::Array(array_schema) => {
+            if let Some(tombi_document_tree::Value::Array(array)) = table.get(key_name)
+                && array.kind() != tombi_document_tree::ArrayKind::ArrayOfTable
+            {
+                return None;
+            }
+            let item_schema = tombi_schema_store::resolve_schema_item(
+                array_schema.items.as_ref()?,
+                Cow::Borrowed(&current_schema.schema_uri),
+                Cow::Borrowed(&current_schema.definitions),
+                current_schema.strict,
+                schema_store,
+            )
+            .await
+            .ok()??;
+            let SchemaView::Table(table_schema) = item_schema.schema_view.as_ref() else {
+                return None;
+            };
+            let body = required_keys_snippet(
+                table_schema,
+                &item_schema.schema_uri,
+                &item_schema.definitions,
+                item_schema.strict,
+                schema_store,
+            )
+            .await?;
+            (
+                format!("[[{header_keys}]]"),
+                table_schema
+                    .description
+                    .clone()
+                    .or_else(|| array_schema.description.clone()),
+                body,
+            )
+        }
+        _ => return None,
+    };
+
+    let key_range = replace_range.unwrap_or_else(|| tombi_text::Range::at(position));
+
+    Some(CompletionContent::new_table_snippet(
+        header.clone(),
+        key_name,
+        documentation,
+        CompletionEdit::new_table_snippet(
+            &format!("{}\n{body}", escape_snippet_text(&header)),
+            key_range,
+        ),
+        Some(&current_schema.schema_uri),
+    ))
+}
+
+/// Whether key-values of the table are written after the position.
+///
+/// A table header inserted there would take them over.
+fn has_key_values_after(
+    table: &tombi_document_tree::Table,
+    position: tombi_text::Position,
+) -> bool {
+    table.key_values().iter().any(|(key, value)| {
+        key.range().start > position
+            && !matches!(
+                value,
+                tombi_document_tree::Value::Table(table)
+                    if matches!(
+                        table.kind(),
+                        tombi_document_tree::TableKind::Table
+                            | tombi_document_tree::TableKind::ParentTable
+                    )
+            )
+            && !matches!(
+                value,
+                tombi_document_tree::Value::Array(array)
+                    if matches!(
+                        array.kind(),
+                        tombi_document_tree::ArrayKind::ArrayOfTable
+                            | tombi_document_tree::ArrayKind::ParentArrayOfTable
+                    )
+            )
+    })
+}
+
+/// The `required` keys of the table schema, one `key = value` line each,
+/// ordered by `x-tombi-table-keys-order`.
+async fn required_keys_snippet(
+    table_schema: &TableSchema,
+    schema_uri: &tombi_schema_store::SchemaUri,
+    definitions: &tombi_schema_store::SchemaDefinitions,
+    strict: Option<tombi_schema_type::BoolDefaultTrue>,
+    schema_store: &SchemaStore,
+) -> Option<String> {
+    let mut required_keys = table_schema
+        .required
+        .clone()
+        .filter(|required_keys| !required_keys.is_empty())?;
+    sort_required_keys(&mut required_keys, table_schema).await;
+
+    let mut lines = Vec::with_capacity(required_keys.len());
+    for (index, key) in required_keys.iter().enumerate() {
+        let tab_stop = index + 1;
+        let value = match table_schema
+            .resolve_property_schema(
+                &SchemaAccessor::Key(key.clone()),
+                Cow::Borrowed(schema_uri),
+                Cow::Borrowed(definitions),
+                strict,
+                schema_store,
+            )
+            .await
+        {
+            Ok(Some(property_schema)) => {
+                let schema_candidates = property_schema
+                    .schema_view
+                    .match_flattened_schemas(
+                        &|schema| !matches!(schema, SchemaView::Null | SchemaView::Nothing(_)),
+                        &property_schema.schema_uri,
+                        &property_schema.definitions,
+                        property_schema.strict,
+                        schema_store,
+                    )
+                    .await;
+                match schema_candidates.first() {
+                    Some(schema_candidate) => placeholder_value(schema_candidate, tab_stop),
+                    None => format!("${tab_stop}"),
+                }
+            }
+            _ => format!("${tab_stop}"),
+        };
+        lines.push(format!(
+            "{} = {value}",
+            escape_snippet_text(&tombi_toml_text::to_key_string(key))
+        ));
+    }
+
+    Some(lines.join("\n"))
+}
+
+async fn sort_required_keys(required_keys: &mut [String], table_schema: &TableSchema) {
+    let order = match &table_schema.keys_order {
+        Some(XTombiTableKeysOrder::All(order)) => *order,
+        Some(XTombiTableKeysOrder::Groups(groups)) => {
+            let Some(group) = groups
+                .iter()
+                .find(|group| group.target == TableKeysOrderGroupKind::Keys)
+            else {
+                return;
+            };
+            group.order
+        }
+        None => return,
+    };
+
+    match order {
+        TableKeysOrder::Ascending => required_keys.sort(),
+        TableKeysOrder::Descending => required_keys.sort_by(|a, b| b.cmp(a)),
+        TableKeysOrder::VersionSort => {
+            required_keys.sort_by(|a, b| tombi_version_sort::version_sort(a, b))
+        }
+        TableKeysOrder::Schema => {
+            let accessors = table_schema.accessors().await;
+            required_keys.sort_by_key(|key| {
+                accessors
+                    .iter()
+                    .position(|accessor| accessor.as_key() == Some(key.as_str()))
+                    .unwrap_or(usize::MAX)
+            });
+        }
+    }
+}
+
+/// The tab stop of a value, populated from `const`, `default`, `examples` or the first `enum` value.
+fn placeholder_value(schema: &SchemaView, tab_stop: usize) -> String {
+    fn first_value<T: Clone>(
+        const_value: &Option<T>,
+        default: &Option<T>,
+        examples: &Option<Vec<T>>,
+        enum_values: &Option<Vec<T>>,
+    ) -> Option<T> {
+        const_value
+            .clone()
+            .or_else(|| default.clone())
+            .or_else(|| examples.as_ref().and_then(|values| values.first().cloned()))
+            .or_else(|| {
+                enum_values
+                    .as_ref()
+                    .and_then(|values| values.first().cloned())
+            })
+    }
+
+    fn literal(tab_stop: usize, value: Option<String>, type_default: &str) -> String {
+        let value = value.unwrap_or_else(|| type_default.to_string());
+        if value.is_empty() {
+            format!("${tab_stop}")
+        } else {
+            format!("${{{tab_stop}:{}}}", escape_snippet_text(&value))
+        }
+    }
+
+    match schema {
+        SchemaView::String(schema) => {
+            let value = first_value(
+                &schema.const_value,
+                &schema.default,
+                &schema.examples,
+                &schema.r#enum,
+            )
+            .map(|value| {
+                let value = tombi_toml_text::to_basic_string(&value);
+                value[1..value.len() - 1].to_string()
+            });
+            format!("\"{}\"", literal(tab_stop, value, ""))
+        }
+        SchemaView::Integer(schema) => literal(
+            tab_stop,
+            first_value(
+                &schema.const_value,
+                &schema.default,
+                &schema.examples,
+                &schema.r#enum,
+            )
+            .map(|value| value.to_string()),
+            "0",
+        ),
+        SchemaView::Float(schema) => literal(
+            tab_stop,
+            first_value(
+                &schema.const_value,
+                &schema.default,
+                &schema.examples,
+                &schema.r#enum,
+            )
+            .map(|value| {
+                if value.is_finite() && value.fract() == 0.0 {
+                    format!("{value:.1}")
+                } else {
+                    value.to_string()
+                }
+            }),
+            "0.0",
+        ),
+        SchemaView::Boolean(schema) => literal(
+            tab_stop,
+            first_value(
+                &schema.const_value,
+                &schema.default,
+                &schema.examples,
+                &schema.r#enum,
+            )
+            .map(|value| value.to_string()),
+            "false",
+        ),
+        SchemaView::OffsetDateTime(schema) => literal(
+            tab_stop,
+            first_value(
+                &schema.const_value,
+                &schema.default,
+                &schema.examples,
+                &schema.r#enum,
+            ),
+            "",
+        ),
+        SchemaView::LocalDateTime(schema) => literal(
+            tab_stop,
+            first_value(
+                &schema.const_value,
+                &schema.default,
+                &schema.examples,
+                &schema.r#enum,
+            ),
+            "",
+        ),
+        SchemaView::LocalDate(schema) => literal(
+            tab_stop,
+            first_value(
+                &schema.const_value,
+                &schema.default,
+                &schema.examples,
+                &schema.r#enum,
+            ),
+            "",
+        ),
+        SchemaView::LocalTime(schema) => literal(
+            tab_stop,
+            first_value(
+                &schema.const_value,
+                &schema.default,
+                &schema.examples,
+                &schema.r#enum,
+            ),
+            "",
+        ),
+        SchemaView::Array(_) => format!("[${tab_stop}]"),
+        SchemaView::Table(_) => format!("{{ ${tab_stop} }}"),
+        SchemaView::Anything(_)
+        | SchemaView::Nothing(_)
+        | SchemaView::Null
+        | SchemaView::OneOf(_)
+        | SchemaView::AnyOf(_)
+        | SchemaView::AllOf(_) => format!("${tab_stop}"),
+    }
+}
+
+/// Escapes the characters having a meaning in the LSP snippet syntax.
+fn escape_snippet_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('$', "\\$")
+        .replace('}', "\\}")
+}
+
 fn key_singleton_literal_label(schema_candidates: &[SchemaView]) -> Option<String> {
     let labels = schema_candidates
         .iter()
@@ -1,8 +1,11 @@
 use itertools::{Either, Itertools};
 use tombi_glob::{MatchResult, matches_file_patterns};
-use tombi_text::{IntoLsp, LineIndex};
 
-use crate::{backend::Backend, config_manager::ConfigSchemaStore};
+use crate::{
+    backend::Backend,
+    config_manager::ConfigSchemaStore,
+    schema_document::{get_schema_document_diagnostics_result, is_schema_document_uri},
+};
 
 #[derive(Debug, Clone)]
 pub struct DiagnosticsResult {
@@ -62,6 +65,10 @@ pub async fn get_diagnostics_result(
         return None;
     }
 
+    if is_schema_document_uri(text_document_uri) {
+        return get_schema_document_diagnostics_result(backend, text_document_uri).await;
+    }
+
     if let Ok(text_document_path) = tombi_uri::Uri::to_file_path(text_document_uri) {
         match matches_file_patterns(&text_document_path, config_path.as_deref(), &config) {
             MatchResult::Matched => {}
@@ -76,16 +83,31 @@ pub async fn get_diagnostics_result(
         }
     }
 
-    let (text, version, toml_version, encoding_kind) = {
+    let (
+        text,
+        ast,
+        ast_errors,
+        document_tree,
+        document_tree_errors,
+        validation_cache,
+        version,
+        toml_version,
+        line_index,
+    ) = {
         let Ok(document_sources) = backend.document_sources.try_read() else {
             return None;
         };
         let document_source = document_sources.get(text_document_uri)?;
         (
             document_source.text_arc(),
+            document_source.ast(),
+            document_source.ast_errors().to_vec(),
+            document_source.document_tree(),
+            document_source.document_tree_errors().to_vec(),
+            document_source.validation_cache(),
             document_source.version,
             document_source.toml_version,
-            document_source.line_index().encoding_kind,
+            document_source.line_index_arc(),
         )
     };
 
@@ -100,24 +122,63 @@ pub async fn get_diagnostics_result(
         return None;
     };
 
-    let diagnostics = match tombi_linter::Linter::new(
+    let mut diagnostics = match tombi_linter::Linter::new(
         toml_version,
         &lint_options,
         Some(Either::Left(text_document_uri)),
         &schema_store,
     )
-    .lint(text.as_ref())
+    .with_validation_cache(validation_cache)
+    .lint_parsed(
+        text.as_ref(),
+        ast.as_ref().clone(),
+        &ast_errors,
+        tombi_linter::ParsedDocumentTree {
+            tree: &document_tree,
+            errors: &document_tree_errors,
+            toml_version,
+        },
+    )
     .await
     {
         Ok(_) => Vec::new(),
-        Err(diagnostics) => {
-            let line_index = LineIndex::new(text.as_ref(), encoding_kind);
-            diagnostics
-                .into_iter()
-                .unique()
-                .map(|diagnostic| diagnostic.into_lsp(&line_index))
-                .collect_vec()
-        }
+        Err(diagnostics) => diagnostics,
+    };
+
+    if config.cargo_extension_enabled()
+        && let Ok(Some(extension_diagnostics)) = tombi_extension_cargo::diagnostic(
+            text_document_uri,
+            &document_tree,
+            toml_version,
+            schema_store.offline(),
+            schema_store.cache_options(),
+            config.cargo_extension_features(),
+        )
+        .await
+    {
+        diagnostics.extend(extension_diagnostics);
+    }
+
+    if config.pyproject_extension_enabled()
+        && let Ok(Some(extension_diagnostics)) = tombi_extension_pyproject::diagnostic(
+            text_document_uri,
+            &document_tree,
+            toml_version,
+            config.pyproject_extension_features(),
+        )
+        .await
+    {
+        diagnostics.extend(extension_diagnostics);
+    }
+
+    let diagnostics = if diagnostics.is_empty() {
+        Vec::new()
+    } else {
+        diagnostics
+            .into_iter()
+            .unique()
+            .map(|diagnostic| diagnostic.into_lsp_with_uri(text_document_uri, &line_index))
+            .collect_vec()
     };
 
     let diagnostics_result = DiagnosticsResult {
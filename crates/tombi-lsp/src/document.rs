@@ -1,9 +1,8 @@
 use std::sync::Arc;
 
 use tombi_diagnostic::SetDiagnostics;
-use tombi_text::{EncodingKind, LineIndex};
-
-use tombi_document_tree::IntoDocumentTreeAndErrors;
+use tombi_syntax::SyntaxNode;
+use tombi_text::{EncodingKind, FromLsp, LineIndex};
 
 #[derive(Debug, Clone)]
 pub struct DocumentSource {
@@ -19,6 +18,9 @@ pub struct DocumentSource {
 
     pub toml_version: tombi_config::TomlVersion,
 
+    /// Parse result kept for incremental reparsing
+    parsed: tombi_parser::Parsed<SyntaxNode>,
+
     /// Parsed AST (always exists, even with errors)
     ast: Arc<tombi_ast::Root>,
 
@@ -30,6 +32,12 @@ pub struct DocumentSource {
 
     /// DocumentTree generation errors (empty if no errors)
     document_tree_errors: Vec<tombi_diagnostic::Diagnostic>,
+
+    /// DocumentTree fragments of the root items, reused for the items an edit does not touch
+    document_tree_items: tombi_document_tree::DocumentTreeItems,
+
+    /// Validation results of the root table values, reused while they are unchanged
+    validation_cache: tombi_schema_store::ValidationCache,
 }
 
 impl DocumentSource {
@@ -40,35 +48,27 @@ impl DocumentSource {
         encoding_kind: EncodingKind,
     ) -> Self {
         let text: Arc<str> = Arc::<str>::from(text.into());
-
-        let (ast, errors) = tombi_parser::parse(text.as_ref()).into_root_and_errors();
-
-        // Convert parser errors to diagnostics
-        let mut ast_errors = Vec::with_capacity(errors.len());
-        for error in errors {
-            error.set_diagnostics(&mut ast_errors);
-        }
-
-        // Create DocumentTree from AST and collect DocumentTree errors
-        let (document_tree, errors) = ast
-            .clone()
-            .into_document_tree_and_errors(toml_version)
-            .into();
-
-        let mut document_tree_errors = Vec::with_capacity(errors.len());
-        for error in errors {
-            error.set_diagnostics(&mut document_tree_errors);
-        }
+        let parsed = tombi_parser::parse(text.as_ref());
+        let Document {
+            ast,
+            ast_errors,
+            document_tree,
+            document_tree_errors,
+            document_tree_items,
+        } = build_document(&parsed, toml_version, &Default::default());
 
         Self {
             line_index: Arc::new(LineIndex::from_arc(Arc::clone(&text), encoding_kind)),
             text,
             version,
             toml_version,
+            parsed,
             ast: Arc::new(ast),
             ast_errors,
             document_tree: Arc::new(document_tree),
             document_tree_errors,
+            document_tree_items,
+            validation_cache: Default::default(),
         }
     }
 
@@ -82,33 +82,69 @@ impl DocumentSource {
 
     pub fn set_text(&mut self, text: impl Into<String>, toml_version: tombi_config::TomlVersion) {
         self.text = Arc::<str>::from(text.into());
-        self.toml_version = toml_version;
         self.line_index = Arc::new(LineIndex::from_arc(
             Arc::clone(&self.text),
             self.line_index.encoding_kind,
         ));
+        let parsed = tombi_parser::parse(self.text.as_ref());
+        self.set_parsed(parsed, toml_version);
+    }
+
+    /// Rebuild the document tree with another TOML version, keeping the parse result.
+    pub fn set_toml_version(&mut self, toml_version: tombi_config::TomlVersion) {
+        let parsed = self.parsed.clone();
+        self.set_parsed(parsed, toml_version);
+    }
+
+    /// Apply an incremental change from the editor.
+    ///
+    /// Only the table or key-value group containing the change is reparsed when possible,
+    /// and only the root items of the reparsed block that changed or moved are converted
+    /// into the document tree again.
+    pub fn edit_text(
+        &mut self,
+        range: tower_lsp::lsp_types::Range,
+        new_text: &str,
+        toml_version: tombi_config::TomlVersion,
+    ) {
+        let span = tombi_text::Span::from_lsp(range, &self.line_index);
+        let edit = std::ops::Range::<usize>::from(span);
+
+        let mut text = String::with_capacity(self.text.len() - edit.len() + new_text.len());
+        text.push_str(&self.text[..edit.start]);
+        text.push_str(new_text);
+        text.push_str(&self.text[edit.end..]);
+        self.text = Arc::<str>::from(text);
+        self.line_index = Arc::new(self.line_index.edit(
+            Arc::clone(&self.text),
+            span,
+            new_text.len(),
+        ));
 
-        // Re-parse the text and collect errors
-        let (ast, errors) = tombi_parser::parse(self.text.as_ref()).into_root_and_errors();
-        self.ast = Arc::new(ast);
+        let parsed = self.parsed.reparse(span, new_text);
+        self.set_parsed(parsed, toml_version);
+    }
 
-        // Convert parser errors to diagnostics
-        self.ast_errors = Vec::with_capacity(errors.len());
-        for error in errors {
-            error.set_diagnostics(&mut self.ast_errors);
-        }
+    fn set_parsed(
+        &mut self,
+        parsed: tombi_parser::Parsed<SyntaxNode>,
+        toml_version: tombi_config::TomlVersion,
+    ) {
+        self.toml_version = toml_version;
 
-        let (document_tree, errors) = self
-            .ast
-            .as_ref()
-            .clone()
-            .into_document_tree_and_errors(toml_version)
-            .into();
+        let Document {
+            ast,
+            ast_errors,
+            document_tree,
+            document_tree_errors,
+            document_tree_items,
+        } = build_document(&parsed, toml_version, &self.document_tree_items);
+        self.parsed = parsed;
+        self.ast = Arc::new(ast);
+        self.ast_errors = ast_errors;
         self.document_tree = Arc::new(document_tree);
-        self.document_tree_errors = Vec::with_capacity(errors.len());
-        for error in errors {
-            error.set_diagnostics(&mut self.document_tree_errors);
-        }
+        self.document_tree_errors = document_tree_errors;
+        self.document_tree_items = document_tree_items;
     }
 
     pub fn line_index(&self) -> &LineIndex {
@@ -138,6 +174,51 @@ impl DocumentSource {
     pub fn document_tree_errors(&self) -> &[tombi_diagnostic::Diagnostic] {
         &self.document_tree_errors
     }
+
+    /// Get the validation results kept for this document
+    pub fn validation_cache(&self) -> tombi_schema_store::ValidationCache {
+        self.validation_cache.clone()
+    }
+}
+
+struct Document {
+    ast: tombi_ast::Root,
+    ast_errors: Vec<tombi_diagnostic::Diagnostic>,
+    document_tree: tombi_document_tree::DocumentTree,
+    document_tree_errors: Vec<tombi_diagnostic::Diagnostic>,
+    document_tree_items: tombi_document_tree::DocumentTreeItems,
+}
+
+fn build_document(
+    parsed: &tombi_parser::Parsed<SyntaxNode>,
+    toml_version: tombi_config::TomlVersion,
+    document_tree_items: &tombi_document_tree::DocumentTreeItems,
+) -> Document {
+    let (ast, errors) = parsed.clone().into_root_and_errors();
+
+    // Convert parser errors to diagnostics
+    let mut ast_errors = Vec::with_capacity(errors.len());
+    for error in errors {
+        error.set_diagnostics(&mut ast_errors);
+    }
+
+    // Create DocumentTree from AST, reusing the fragments of unchanged items
+    let (document_tree_and_errors, document_tree_items) =
+        document_tree_items.build(&ast, toml_version);
+    let (document_tree, errors) = document_tree_and_errors.into();
+
+    let mut document_tree_errors = Vec::with_capacity(errors.len());
+    for error in errors {
+        error.set_diagnostics(&mut document_tree_errors);
+    }
+
+    Document {
+        ast,
+        ast_errors,
+        document_tree,
+        document_tree_errors,
+        document_tree_items,
+    }
 }
 
 #[cfg(test)]
@@ -161,4 +242,77 @@ mod tests {
 
         assert_eq!(line_index.line_text(1), Some("version = \"1.0.0\""));
     }
+
+    #[test]
+    fn edit_text_matches_full_text_update() {
+        let text = "[package]\nname = \"before\"\nversion = \"1.0.0\"\n";
+        let mut edited =
+            DocumentSource::new(text, Some(1), TomlVersion::default(), EncodingKind::Utf16);
+        let mut replaced = edited.clone();
+
+        edited.edit_text(
+            tower_lsp::lsp_types::Range::new(
+                tower_lsp::lsp_types::Position::new(1, 8),
+                tower_lsp::lsp_types::Position::new(1, 14),
+            ),
+            "after",
+            TomlVersion::default(),
+        );
+        replaced.set_text(
+            "[package]\nname = \"after\"\nversion = \"1.0.0\"\n",
+            TomlVersion::default(),
+        );
+
+        assert_eq!(edited.text(), replaced.text());
+        assert_eq!(edited.ast().to_string(), replaced.ast().to_string());
+        assert_eq!(edited.ast_errors(), replaced.ast_errors());
+        assert_eq!(edited.line_index().line_text(1), Some("name = \"after\""));
+    }
+
+    #[test]
+    fn edit_text_rebuilds_same_document_tree() {
+        let text = "key = 1\n\n[a]\nb = 1\nc = \"x\"\n\n[[d]]\ne = 2\n\n[a.f]\ng = 3\n";
+        let edits = [
+            ((3, 4), (3, 5), "10"),
+            ((4, 4), (4, 7), "[1, 2]"),
+            ((7, 4), (7, 5), "2\nh = 4"),
+            ((4, 0), (4, 0), "b = 2\n"),
+            ((0, 6), (0, 7), ""),
+        ];
+
+        for ((start_line, start_column), (end_line, end_column), new_text) in edits {
+            let mut edited =
+                DocumentSource::new(text, Some(1), TomlVersion::default(), EncodingKind::Utf16);
+            edited.edit_text(
+                tower_lsp::lsp_types::Range::new(
+                    tower_lsp::lsp_types::Position::new(start_line, start_column),
+                    tower_lsp::lsp_types::Position::new(end_line, end_column),
+                ),
+                new_text,
+                TomlVersion::default(),
+            );
+            let replaced = DocumentSource::new(
+                edited.text(),
+                Some(1),
+                TomlVersion::default(),
+                EncodingKind::Utf16,
+            );
+
+            assert_eq!(
+                edited.document_tree(),
+                replaced.document_tree(),
+                "{new_text:?}"
+            );
+            assert_eq!(
+                edited.document_tree_errors(),
+                replaced.document_tree_errors(),
+                "{new_text:?}"
+            );
+            assert_eq!(
+                edited.line_index().iter().collect::<Vec<_>>(),
+                replaced.line_index().iter().collect::<Vec<_>>(),
+                "{new_text:?}"
+            );
+        }
+    }
 }
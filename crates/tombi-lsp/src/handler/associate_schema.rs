@@ -69,6 +69,7 @@ pub async fn handle_associate_schema(backend: &Backend, params: AssociateSchemaP
         .write()
         .await
         .clear_all();
+    backend.clear_validation_caches().await;
 
     if let Err(err) = push_workspace_diagnostics(
         backend,
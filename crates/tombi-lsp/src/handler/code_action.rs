@@ -1,12 +1,16 @@
 use crate::{
     Backend,
-    code_action::{dot_keys_to_inline_table_code_action, inline_table_to_dot_keys_code_action},
+    code_action::{
+        dot_keys_to_inline_table_code_action, inline_table_to_dot_keys_code_action,
+        into_table_style_quick_fix,
+    },
     completion::get_completion_keys_with_context,
     config_manager::ConfigSchemaStore,
 };
 use tombi_document_tree::get_accessors;
 use tombi_schema_store::build_accessor_contexts;
 use tombi_text::IntoLsp;
+use tombi_x_keyword::TableStyle;
 use tower_lsp::lsp_types::{CodeActionOrCommand, CodeActionParams};
 
 pub async fn handle_code_action(
@@ -19,6 +23,7 @@ pub async fn handle_code_action(
     let CodeActionParams {
         text_document,
         range,
+        context,
         ..
     } = params;
 
@@ -79,9 +84,16 @@ pub async fn handle_code_action(
         &accessors,
         &accessor_contexts,
     ) {
-        code_actions.push(CodeActionOrCommand::CodeAction(code_action));
+        code_actions.push(CodeActionOrCommand::CodeAction(into_table_style_quick_fix(
+            code_action,
+            TableStyle::Inline,
+            &context.diagnostics,
+            line_index,
+            position,
+        )));
     }
 
+    // No rewrite moves keys under a new table header, so `TableStyle::Table` has no quick fix.
     if let Some(code_action) = inline_table_to_dot_keys_code_action(
         &text_document_uri,
         line_index,
@@ -90,7 +102,13 @@ pub async fn handle_code_action(
         &accessors,
         &accessor_contexts,
     ) {
-        code_actions.push(CodeActionOrCommand::CodeAction(code_action));
+        code_actions.push(CodeActionOrCommand::CodeAction(into_table_style_quick_fix(
+            code_action,
+            TableStyle::Dotted,
+            &context.diagnostics,
+            line_index,
+            position,
+        )));
     }
 
     if config.cargo_extension_enabled()
@@ -105,6 +123,7 @@ pub async fn handle_code_action(
             config.cargo_extension_features(),
             schema_store.offline(),
             schema_store.cache_options(),
+            context.only.as_deref(),
         )
         .await?
     {
@@ -227,6 +227,8 @@ pub async fn handle_completion(
             toml_version,
             completion_hint,
             comment_context.as_ref(),
+            offline,
+            cache_options,
             config.pyproject_extension_features(),
         )
         .await?
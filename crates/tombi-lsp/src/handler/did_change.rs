@@ -1,6 +1,6 @@
 use tower_lsp::lsp_types::DidChangeTextDocumentParams;
 
-use crate::backend::Backend;
+use crate::{backend::Backend, schema_document::is_schema_document_uri};
 
 pub async fn handle_did_change(backend: &Backend, params: DidChangeTextDocumentParams) {
     log::info!("handle_did_change");
@@ -12,14 +12,33 @@ pub async fn handle_did_change(backend: &Backend, params: DidChangeTextDocumentP
     } = params;
 
     let text_document_uri = text_document.uri.into();
-    let mut latest_text = None;
 
-    for content_change in content_changes {
-        if let Some(range) = content_change.range {
-            log::warn!("range change is not supported: {:?}", range);
-        } else {
-            latest_text = Some(content_change.text);
+    if is_schema_document_uri(&text_document_uri) {
+        let need_publish_diagnostics = {
+            let mut schema_document_sources = backend.schema_document_sources.write().await;
+            let Some(document) = schema_document_sources.get_mut(&text_document_uri) else {
+                return;
+            };
+
+            let need_publish_diagnostics = document
+                .version
+                .is_none_or(|version| version < text_document.version);
+
+            for content_change in &content_changes {
+                match content_change.range {
+                    Some(range) => document.edit_text(range, &content_change.text),
+                    None => document.set_text(&content_change.text),
+                }
+            }
+            document.version = Some(text_document.version);
+
+            need_publish_diagnostics
+        };
+
+        if need_publish_diagnostics {
+            backend.push_diagnostics(text_document_uri).await;
         }
+        return;
     }
 
     // Apply the edit and bump the document version up front, without awaiting in
@@ -30,7 +49,7 @@ pub async fn handle_did_change(backend: &Backend, params: DidChangeTextDocumentP
     // diagnostics against the previous version. The TOML version is reused from the
     // previous parse here and refined below only if the edit actually changed it
     // (e.g. an edited `#:schema` directive).
-    let (need_publish_diagnostics, previous_toml_version) = {
+    let (need_publish_diagnostics, previous_toml_version, latest_ast) = {
         let mut document_sources = backend.document_sources.write().await;
         let Some(document) = document_sources.get_mut(&text_document_uri) else {
             return;
@@ -41,12 +60,20 @@ pub async fn handle_did_change(backend: &Backend, params: DidChangeTextDocumentP
             .is_none_or(|version| version < text_document.version);
         let previous_toml_version = document.toml_version;
 
-        if let Some(text) = latest_text.as_ref() {
-            document.set_text(text, previous_toml_version);
+        // Range changes are applied in order, each relative to the result of the previous one.
+        for content_change in &content_changes {
+            match content_change.range {
+                Some(range) => {
+                    document.edit_text(range, &content_change.text, previous_toml_version)
+                }
+                None => document.set_text(&content_change.text, previous_toml_version),
+            }
         }
         document.version = Some(text_document.version);
 
-        (need_publish_diagnostics, previous_toml_version)
+        let latest_ast = (!content_changes.is_empty()).then(|| document.ast());
+
+        (need_publish_diagnostics, previous_toml_version, latest_ast)
     };
 
     backend
@@ -57,10 +84,8 @@ pub async fn handle_did_change(backend: &Backend, params: DidChangeTextDocumentP
 
     // Refine the TOML version if this edit changed it, and re-apply only when the
     // document has not been superseded by a newer change in the meantime.
-    if let Some(text) = latest_text.as_ref() {
-        let toml_version = backend
-            .text_document_toml_version(&text_document_uri, text)
-            .await;
+    if let Some(ast) = latest_ast.as_ref() {
+        let toml_version = backend.root_toml_version(&text_document_uri, ast).await;
 
         if toml_version != previous_toml_version {
             {
@@ -71,7 +96,7 @@ pub async fn handle_did_change(backend: &Backend, params: DidChangeTextDocumentP
                 if document.version != Some(text_document.version) {
                     return;
                 }
-                document.set_text(text, toml_version);
+                document.set_toml_version(toml_version);
             }
 
             backend
@@ -1,6 +1,6 @@
 use tower_lsp::lsp_types::DidCloseTextDocumentParams;
 
-use crate::{Backend, config_manager::ConfigSchemaStore};
+use crate::{Backend, config_manager::ConfigSchemaStore, schema_document::is_schema_document_uri};
 
 pub async fn handle_did_close(backend: &Backend, params: DidCloseTextDocumentParams) {
     log::info!("handle_did_close");
@@ -10,6 +10,19 @@ pub async fn handle_did_close(backend: &Backend, params: DidCloseTextDocumentPar
 
     let text_document_uri = text_document.uri.as_ref();
 
+    if is_schema_document_uri(text_document_uri) {
+        backend
+            .schema_document_sources
+            .write()
+            .await
+            .remove(text_document_uri);
+        backend
+            .client
+            .publish_diagnostics(text_document.uri, Vec::new(), None)
+            .await;
+        return;
+    }
+
     {
         let mut document_sources = backend.document_sources.write().await;
 
@@ -24,6 +37,12 @@ pub async fn handle_did_close(backend: &Backend, params: DidCloseTextDocumentPar
         .await
         .close(text_document_uri);
 
+    backend
+        .semantic_tokens_cache
+        .write()
+        .await
+        .close(text_document_uri);
+
     let ConfigSchemaStore { config, .. } = backend
         .config_manager
         .config_schema_store_for_uri(text_document_uri)
@@ -1,6 +1,10 @@
 use tower_lsp::lsp_types::DidOpenTextDocumentParams;
 
-use crate::{backend::Backend, document::DocumentSource};
+use crate::{
+    backend::Backend,
+    document::DocumentSource,
+    schema_document::{SchemaDocumentSource, is_schema_document_uri},
+};
 
 fn select_cache_warming<T>(
     cargo_enabled: bool,
@@ -21,6 +25,21 @@ pub async fn handle_did_open(backend: &Backend, params: DidOpenTextDocumentParam
     let DidOpenTextDocumentParams { text_document, .. } = params;
 
     let text_document_uri: tombi_uri::Uri = text_document.uri.into();
+
+    if is_schema_document_uri(&text_document_uri) {
+        let encoding_kind = backend.capabilities.read().await.encoding_kind;
+        backend.schema_document_sources.write().await.insert(
+            text_document_uri.clone(),
+            SchemaDocumentSource::new(
+                text_document.text,
+                Some(text_document.version),
+                encoding_kind,
+            ),
+        );
+        backend.push_diagnostics(text_document_uri).await;
+        return;
+    }
+
     backend.begin_document_open(text_document_uri.clone());
     let toml_version = backend
         .text_document_toml_version(&text_document_uri, &text_document.text)
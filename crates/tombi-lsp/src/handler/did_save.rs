@@ -1,6 +1,6 @@
 use tower_lsp::lsp_types::DidSaveTextDocumentParams;
 
-use crate::backend::Backend;
+use crate::{backend::Backend, schema_document::is_schema_document_uri};
 
 pub async fn handle_did_save(backend: &Backend, params: DidSaveTextDocumentParams) {
     log::info!("handle_did_save");
@@ -15,6 +15,26 @@ pub async fn handle_did_save(backend: &Backend, params: DidSaveTextDocumentParam
 
     let mut need_publish_diagnostics = { backend.is_diagnostic_mode_push().await };
 
+    if is_schema_document_uri(&text_document_uri) {
+        if let Some(text) = text
+            && let Some(document) = backend
+                .schema_document_sources
+                .write()
+                .await
+                .get_mut(&text_document_uri)
+        {
+            if need_publish_diagnostics && document.text() == text {
+                need_publish_diagnostics = false;
+            }
+            document.set_text(text);
+        }
+
+        if need_publish_diagnostics {
+            backend.push_diagnostics(text_document_uri).await
+        }
+        return;
+    }
+
     if let Some(text) = text {
         let mut document_sources = backend.document_sources.write().await;
 
@@ -0,0 +1,52 @@
+use tombi_text::IntoLsp;
+use tower_lsp::lsp_types::TextDocumentIdentifier;
+
+use crate::{backend::Backend, schema_annotation::get_document_schema_annotations};
+
+pub async fn handle_embedded_languages(
+    backend: &Backend,
+    params: TextDocumentIdentifier,
+) -> Result<EmbeddedLanguagesResponse, tower_lsp::jsonrpc::Error> {
+    log::info!("handle_embedded_languages");
+    log::trace!("{:?}", params);
+
+    let TextDocumentIdentifier { uri } = params;
+    let text_document_uri = uri.into();
+    backend.wait_for_document_open(&text_document_uri).await;
+
+    let document_sources = backend.document_sources.read().await;
+    let Some(document_source) = document_sources.get(&text_document_uri) else {
+        return Ok(EmbeddedLanguagesResponse::default());
+    };
+
+    let embedded_languages =
+        get_document_schema_annotations(backend, &text_document_uri, document_source, None)
+            .await
+            .embedded_languages
+            .into_iter()
+            .map(|embedded_language| EmbeddedLanguageRange {
+                range: embedded_language
+                    .range
+                    .into_lsp(document_source.line_index()),
+                language: embedded_language.language,
+            })
+            .collect();
+
+    Ok(EmbeddedLanguagesResponse { embedded_languages })
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmbeddedLanguagesResponse {
+    pub embedded_languages: Vec<EmbeddedLanguageRange>,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmbeddedLanguageRange {
+    /// The range of the string value, including its quotes.
+    pub range: tower_lsp::lsp_types::Range,
+
+    /// The language identifier, such as `shellscript`, `python` or `sql`.
+    pub language: String,
+}
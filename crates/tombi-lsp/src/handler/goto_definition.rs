@@ -4,6 +4,7 @@ use tower_lsp::lsp_types::{GotoDefinitionParams, TextDocumentPositionParams};
 use crate::Backend;
 use crate::config_manager::ConfigSchemaStore;
 use crate::handler::hover::get_hover_keys_with_range;
+use crate::schema_document::{get_schema_document_definitions, is_schema_document_uri};
 
 pub async fn handle_goto_definition(
     backend: &Backend,
@@ -39,6 +40,10 @@ pub async fn handle_goto_definition(
         return Ok(Default::default());
     }
 
+    if is_schema_document_uri(&text_document_uri) {
+        return Ok(get_schema_document_definitions(backend, &text_document_uri, position).await);
+    }
+
     let Ok(document_sources) = backend.document_sources.try_read() else {
         return Ok(Default::default());
     };
@@ -16,7 +16,7 @@ use tower_lsp::lsp_types::{
 use crate::{
     Backend,
     backend::{BackendCapabilities, DiagnosticMode},
-    semantic_tokens::SUPPORTED_TOKEN_TYPES,
+    semantic_tokens::{SUPPORTED_TOKEN_MODIFIERS, SUPPORTED_TOKEN_TYPES},
 };
 
 pub async fn handle_initialize(
@@ -107,7 +107,7 @@ pub fn server_capabilities(
         text_document_sync: Some(TextDocumentSyncCapability::Options(
             TextDocumentSyncOptions {
                 open_close: Some(true),
-                change: Some(TextDocumentSyncKind::FULL),
+                change: Some(TextDocumentSyncKind::INCREMENTAL),
                 save: Some(TextDocumentSyncSaveOptions::Supported(true)),
                 ..Default::default()
             },
@@ -162,9 +162,10 @@ pub fn server_capabilities(
             SemanticTokensOptions {
                 legend: SemanticTokensLegend {
                     token_types: SUPPORTED_TOKEN_TYPES.to_vec(),
-                    token_modifiers: vec![],
+                    token_modifiers: SUPPORTED_TOKEN_MODIFIERS.to_vec(),
                 },
-                full: Some(SemanticTokensFullOptions::Bool(true)),
+                full: Some(SemanticTokensFullOptions::Delta { delta: Some(true) }),
+                range: Some(true),
                 ..Default::default()
             }
             .into(),
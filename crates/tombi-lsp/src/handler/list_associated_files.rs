@@ -0,0 +1,131 @@
+use itertools::{Either, Itertools};
+use tombi_glob::search_pattern_matched_paths;
+use tower_lsp::lsp_types::{TextDocumentIdentifier, Url};
+
+use crate::{backend::Backend, workspace_config::get_workspace_configs};
+
+/// List the TOML files in the workspace that are associated with a schema document.
+pub async fn handle_list_associated_files(
+    backend: &Backend,
+    params: TextDocumentIdentifier,
+) -> Result<ListAssociatedFilesResponse, tower_lsp::jsonrpc::Error> {
+    log::info!("handle_list_associated_files");
+    log::trace!("{:?}", params);
+
+    let TextDocumentIdentifier { uri } = params;
+    let schema_uri: tombi_uri::Uri = uri.into();
+
+    let mut candidates = tombi_hashmap::HashSet::new();
+
+    if let Some(configs) = get_workspace_configs(backend).await {
+        for workspace_config in configs {
+            let files_options = workspace_config.config.files.clone().unwrap_or_default();
+
+            for matched_path in
+                search_pattern_matched_paths(workspace_config.workspace_folder_path, files_options)
+                    .await
+            {
+                let tombi_glob::FileSearchEntry::Found(path) = matched_path else {
+                    continue;
+                };
+
+                if let Ok(uri) = tombi_uri::Uri::from_file_path(path) {
+                    candidates.insert(uri);
+                }
+            }
+        }
+    }
+
+    // Opened documents are included even if they are outside the workspace folders.
+    candidates.extend(backend.document_sources.read().await.keys().cloned());
+
+    let mut files = Vec::new();
+
+    for text_document_uri in candidates.into_iter().sorted_by_key(|uri| uri.to_string()) {
+        let Some(root) = get_ast(backend, &text_document_uri).await else {
+            continue;
+        };
+
+        let schema_store = backend
+            .config_manager
+            .config_schema_store_for_uri(&text_document_uri)
+            .await
+            .schema_store;
+
+        let Ok(Some(source_schema)) = schema_store
+            .resolve_source_schema_from_ast(&root, Some(Either::Left(&text_document_uri)))
+            .await
+        else {
+            continue;
+        };
+
+        if source_schema
+            .root_schema
+            .as_ref()
+            .is_some_and(|root_schema| is_same_document(&root_schema.schema_uri, &schema_uri))
+        {
+            files.push(AssociatedFile {
+                uri: text_document_uri.clone().into(),
+                accessor: None,
+            });
+        }
+
+        for (accessors, sub_schema_link) in &source_schema.sub_schema_link_map {
+            if is_same_document(&sub_schema_link.schema_uri, &schema_uri) {
+                files.push(AssociatedFile {
+                    uri: text_document_uri.clone().into(),
+                    accessor: Some(
+                        tombi_schema_store::PatternAccessors::from(accessors.as_slice())
+                            .to_string(),
+                    ),
+                });
+            }
+        }
+    }
+
+    Ok(ListAssociatedFilesResponse { files })
+}
+
+async fn get_ast(
+    backend: &Backend,
+    text_document_uri: &tombi_uri::Uri,
+) -> Option<std::sync::Arc<tombi_ast::Root>> {
+    if let Some(document_source) = backend.document_sources.read().await.get(text_document_uri) {
+        return Some(document_source.ast());
+    }
+
+    let text_document_path = text_document_uri.to_file_path().ok()?;
+    let content = tombi_fs::read_to_string_async(&text_document_path)
+        .await
+        .ok()?;
+
+    tombi_parser::parse(&content)
+        .cast::<tombi_ast::Root>()
+        .map(|parsed| std::sync::Arc::new(parsed.tree()))
+}
+
+/// Compare schema URIs ignoring the fragment, which selects a sub-schema of the same document.
+fn is_same_document(schema_uri: &tombi_uri::Uri, document_uri: &tombi_uri::Uri) -> bool {
+    let mut schema_uri = schema_uri.clone();
+    schema_uri.set_fragment(None);
+    &schema_uri == document_uri
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListAssociatedFilesResponse {
+    pub files: Vec<AssociatedFile>,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssociatedFile {
+    /// The URI of the TOML file.
+    pub uri: Url,
+
+    /// The keys the schema is associated with as a sub-schema, such as `tool.tombi`.
+    ///
+    /// None if the schema is the root schema of the file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accessor: Option<String>,
+}
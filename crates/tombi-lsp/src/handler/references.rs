@@ -5,6 +5,7 @@ use tower_lsp::lsp_types::{ReferenceParams, TextDocumentPositionParams};
 use crate::Backend;
 use crate::config_manager::ConfigSchemaStore;
 use crate::handler::hover::get_hover_keys_with_range;
+use crate::schema_document::{get_schema_document_references, is_schema_document_uri};
 
 pub async fn handle_references(
     backend: &Backend,
@@ -41,6 +42,16 @@ pub async fn handle_references(
         return Ok(None);
     }
 
+    if is_schema_document_uri(&text_document_uri) {
+        return Ok(get_schema_document_references(
+            backend,
+            &text_document_uri,
+            position,
+            context.include_declaration,
+        )
+        .await);
+    }
+
     let Ok(document_sources) = backend.document_sources.try_read() else {
         return Ok(None);
     };
@@ -1,9 +1,6 @@
 use tower_lsp::lsp_types::{SemanticTokens, SemanticTokensParams, SemanticTokensResult};
 
-use crate::{
-    backend::Backend,
-    semantic_tokens::{AppendSemanticTokens, SemanticTokensBuilder},
-};
+use crate::{backend::Backend, semantic_tokens::get_semantic_tokens};
 
 pub async fn handle_semantic_tokens_full(
     backend: &Backend,
@@ -19,18 +16,16 @@ pub async fn handle_semantic_tokens_full(
     let Some(document_source) = document_sources.get(&text_document_uri) else {
         return Ok(None);
     };
-    let line_index = document_source.line_index();
 
-    let mut tokens_builder = SemanticTokensBuilder::new(text_document_uri, line_index);
-
-    document_source
-        .ast()
-        .append_semantic_tokens(&mut tokens_builder);
-
-    let tokens = tokens_builder.build();
+    let tokens = get_semantic_tokens(backend, &text_document_uri, document_source).await;
+    let result_id = backend
+        .semantic_tokens_cache
+        .write()
+        .await
+        .set(text_document_uri, tokens.clone());
 
     Ok(Some(SemanticTokensResult::Tokens(SemanticTokens {
-        result_id: None,
+        result_id: Some(result_id),
         data: tokens,
     })))
 }
@@ -0,0 +1,48 @@
+use tower_lsp::lsp_types::{
+    SemanticTokens, SemanticTokensDelta, SemanticTokensDeltaParams, SemanticTokensFullDeltaResult,
+};
+
+use crate::{
+    backend::Backend,
+    semantic_tokens::{diff_tokens, get_semantic_tokens},
+};
+
+pub async fn handle_semantic_tokens_full_delta(
+    backend: &Backend,
+    params: SemanticTokensDeltaParams,
+) -> Result<Option<SemanticTokensFullDeltaResult>, tower_lsp::jsonrpc::Error> {
+    log::info!("handle_semantic_tokens_full_delta");
+    log::trace!("{:?}", params);
+
+    let SemanticTokensDeltaParams {
+        text_document,
+        previous_result_id,
+        ..
+    } = params;
+    let text_document_uri: tombi_uri::Uri = text_document.uri.into();
+
+    let document_sources = backend.document_sources.read().await;
+    let Some(document_source) = document_sources.get(&text_document_uri) else {
+        return Ok(None);
+    };
+
+    let tokens = get_semantic_tokens(backend, &text_document_uri, document_source).await;
+
+    let mut semantic_tokens_cache = backend.semantic_tokens_cache.write().await;
+    let edits = semantic_tokens_cache
+        .get(&text_document_uri, &previous_result_id)
+        .map(|previous_tokens| diff_tokens(previous_tokens, &tokens));
+    let result_id = semantic_tokens_cache.set(text_document_uri, tokens.clone());
+
+    Ok(Some(match edits {
+        Some(edits) => SemanticTokensFullDeltaResult::TokensDelta(SemanticTokensDelta {
+            result_id: Some(result_id),
+            edits,
+        }),
+        // The previous tokens are unknown, so all the tokens are sent.
+        None => SemanticTokensFullDeltaResult::Tokens(SemanticTokens {
+            result_id: Some(result_id),
+            data: tokens,
+        }),
+    }))
+}
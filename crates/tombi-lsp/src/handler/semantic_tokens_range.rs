@@ -0,0 +1,41 @@
+use tombi_text::FromLsp;
+use tower_lsp::lsp_types::{SemanticTokens, SemanticTokensRangeParams, SemanticTokensRangeResult};
+
+use crate::{
+    backend::Backend,
+    semantic_tokens::{get_semantic_tokens_in_range, tokens_in_range},
+};
+
+pub async fn handle_semantic_tokens_range(
+    backend: &Backend,
+    params: SemanticTokensRangeParams,
+) -> Result<Option<SemanticTokensRangeResult>, tower_lsp::jsonrpc::Error> {
+    log::info!("handle_semantic_tokens_range");
+    log::trace!("{:?}", params);
+
+    let SemanticTokensRangeParams {
+        text_document,
+        range,
+        ..
+    } = params;
+    let text_document_uri: tombi_uri::Uri = text_document.uri.into();
+
+    let document_sources = backend.document_sources.read().await;
+    let Some(document_source) = document_sources.get(&text_document_uri) else {
+        return Ok(None);
+    };
+
+    // Only the nodes intersecting the range are walked; tokens_in_range trims those partially out of it.
+    let tokens = get_semantic_tokens_in_range(
+        backend,
+        &text_document_uri,
+        document_source,
+        tombi_text::Range::from_lsp(range, document_source.line_index()),
+    )
+    .await;
+
+    Ok(Some(SemanticTokensRangeResult::Tokens(SemanticTokens {
+        result_id: None,
+        data: tokens_in_range(&tokens, range),
+    })))
+}
@@ -21,6 +21,7 @@ pub async fn handle_update_config(
                 {
                     Ok(_) => {
                         backend.workspace_diagnostics_cache.write().await.reset();
+                        backend.clear_validation_caches().await;
                         log::info!("updated config: {}", text_document_uri);
                         return Ok(true);
                     }
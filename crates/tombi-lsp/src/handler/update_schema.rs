@@ -29,6 +29,7 @@ pub async fn handle_update_schema(
                     .write()
                     .await
                     .clear_all();
+                backend.clear_validation_caches().await;
 
                 // Refresh workspace diagnostics after schema update
                 // Include open files to ensure diagnostics are updated for all files, including those open in the editor
@@ -11,6 +11,8 @@ mod hover;
 mod location;
 mod references;
 mod remote_file;
+mod schema_annotation;
+mod schema_document;
 mod schema_resolver;
 mod semantic_tokens;
 mod workspace_config;
@@ -29,6 +31,7 @@ pub mod handler {
     mod did_save;
     mod document_link;
     mod document_symbol;
+    mod embedded_languages;
     mod folding_range;
     mod formatting;
     mod get_built_in_schema;
@@ -41,10 +44,13 @@ pub mod handler {
     mod initialize;
     mod initialized;
     mod inlay_hint;
+    mod list_associated_files;
     mod list_schemas;
     mod references;
     mod refresh_cache;
     mod semantic_tokens_full;
+    mod semantic_tokens_full_delta;
+    mod semantic_tokens_range;
     mod shutdown;
     mod update_config;
     mod update_schema;
@@ -62,6 +68,9 @@ pub mod handler {
     pub use did_save::handle_did_save;
     pub use document_link::handle_document_link;
     pub use document_symbol::handle_document_symbol;
+    pub use embedded_languages::{
+        EmbeddedLanguageRange, EmbeddedLanguagesResponse, handle_embedded_languages,
+    };
     pub use folding_range::handle_folding_range;
     pub use formatting::handle_formatting;
     pub use get_built_in_schema::{GetBuiltInSchemaParams, handle_get_built_in_schema};
@@ -76,10 +85,15 @@ pub mod handler {
     pub use initialize::handle_initialize;
     pub use initialized::handle_initialized;
     pub use inlay_hint::handle_inlay_hint;
+    pub use list_associated_files::{
+        AssociatedFile, ListAssociatedFilesResponse, handle_list_associated_files,
+    };
     pub use list_schemas::{ListSchemasParams, ListSchemasResponse, handle_list_schemas};
     pub use references::handle_references;
     pub use refresh_cache::{RefreshCacheParams, handle_refresh_cache};
     pub use semantic_tokens_full::handle_semantic_tokens_full;
+    pub use semantic_tokens_full_delta::handle_semantic_tokens_full_delta;
+    pub use semantic_tokens_range::handle_semantic_tokens_range;
     pub use shutdown::handle_shutdown;
     pub use update_config::handle_update_config;
     pub use update_schema::handle_update_schema;
@@ -114,5 +128,7 @@ pub fn lsp_service(
     .custom_method("tombi/updateConfig", Backend::update_config)
     .custom_method("tombi/associateSchema", Backend::associate_schema)
     .custom_method("tombi/refreshCache", Backend::refresh_cache)
+    .custom_method("tombi/embeddedLanguages", Backend::embedded_languages)
+    .custom_method("tombi/listAssociatedFiles", Backend::list_associated_files)
     .finish()
 }
@@ -19,6 +19,7 @@ pub async fn into_lsp_locations(
     }
 
     let document_sources = backend.document_sources.try_read().ok();
+    let schema_document_sources = backend.schema_document_sources.try_read().ok();
 
     let locations = locations
         .into_iter()
@@ -31,7 +32,15 @@ pub async fn into_lsp_locations(
                 .and_then(|ds| ds.get(&location.uri))
             {
                 Some(document_source) => location.range.into_lsp(document_source.line_index()),
-                None => tombi_text::convert_range_to_lsp(location.range),
+                None => match schema_document_sources
+                    .as_ref()
+                    .and_then(|ds| ds.get(&location.uri))
+                {
+                    Some(schema_document_source) => {
+                        location.range.into_lsp(schema_document_source.line_index())
+                    }
+                    None => tombi_text::convert_range_to_lsp(location.range),
+                },
             };
             tower_lsp::lsp_types::Location {
                 uri: location.uri.into(),
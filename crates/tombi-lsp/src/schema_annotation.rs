@@ -0,0 +1,381 @@
+use std::borrow::Cow;
+
+use itertools::Either;
+use tombi_future::Boxable;
+use tombi_schema_store::{
+    CurrentSchema, SchemaAccessor, SchemaContext, SchemaView, SemanticSchema, TableSchema,
+};
+
+use crate::{Backend, config_manager::ConfigSchemaStore, document::DocumentSource};
+
+/// The ranges of a document annotated by its schema.
+#[derive(Debug, Default)]
+pub struct SchemaAnnotations {
+    /// Strings holding code written in another language.
+    pub embedded_languages: Vec<EmbeddedLanguage>,
+
+    /// Keys whose schema is `deprecated`.
+    pub deprecated_keys: Vec<tombi_text::Range>,
+
+    /// Keys whose schema is `readOnly`.
+    pub read_only_keys: Vec<tombi_text::Range>,
+
+    /// Values equal to the `default` of their schema.
+    pub default_values: Vec<tombi_text::Range>,
+
+    /// Keys not allowed by the table schema.
+    pub not_allowed_keys: Vec<tombi_text::Range>,
+}
+
+/// A string value holding code written in another language.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmbeddedLanguage {
+    pub range: tombi_text::Range,
+    pub language: String,
+}
+
+/// Collect the schema annotations of a document, using the schema associated with it.
+///
+/// With `range`, only the values intersecting it are annotated.
+pub async fn get_document_schema_annotations(
+    backend: &Backend,
+    text_document_uri: &tombi_uri::Uri,
+    document_source: &DocumentSource,
+    range: Option<tombi_text::Range>,
+) -> SchemaAnnotations {
+    let ConfigSchemaStore { schema_store, .. } = backend
+        .config_manager
+        .config_schema_store_for_uri(text_document_uri)
+        .await;
+
+    let root = document_source.ast();
+    let Some(source_schema) = schema_store
+        .resolve_source_schema_from_ast(&root, Some(Either::Left(text_document_uri)))
+        .await
+        .ok()
+        .flatten()
+    else {
+        return SchemaAnnotations::default();
+    };
+
+    let strict = tombi_validator::comment_directive::get_tombi_document_comment_directive(&root)
+        .await
+        .and_then(|directive| directive.schema.and_then(|schema| schema.strict));
+    let schema_context = SchemaContext::from_source_schema(
+        document_source.toml_version,
+        Some(&source_schema),
+        &schema_store,
+        strict,
+    );
+
+    get_schema_annotations(&document_source.document_tree(), &schema_context, range).await
+}
+
+/// Collect the ranges of a document annotated by `deprecated`, `readOnly`, `default`,
+/// `additionalProperties` and embedded languages (`x-tombi-language` or `contentMediaType`).
+pub async fn get_schema_annotations(
+    document_tree: &tombi_document_tree::DocumentTree,
+    schema_context: &SchemaContext<'_>,
+    range: Option<tombi_text::Range>,
+) -> SchemaAnnotations {
+    let mut annotations = SchemaAnnotations::default();
+
+    let Some(document_schema) = schema_context.root_schema else {
+        return annotations;
+    };
+    let Some(schema_view) = document_schema.schema_view.as_ref() else {
+        return annotations;
+    };
+    let current_schema = CurrentSchema {
+        schema_view: schema_view.clone(),
+        semantic_schema: document_schema.semantic_schema.clone(),
+        schema_uri: Cow::Borrowed(&document_schema.schema_uri),
+        definitions: Cow::Borrowed(&document_schema.definitions),
+        strict: document_schema.strict,
+    }
+    .into_owned();
+
+    collect_table(
+        document_tree,
+        &current_schema,
+        schema_context,
+        range,
+        &mut annotations,
+    )
+    .await;
+
+    for ranges in [
+        &mut annotations.deprecated_keys,
+        &mut annotations.read_only_keys,
+        &mut annotations.default_values,
+        &mut annotations.not_allowed_keys,
+    ] {
+        ranges.sort();
+        ranges.dedup();
+    }
+    annotations
+        .embedded_languages
+        .sort_by_key(|embedded_language| embedded_language.range);
+    annotations
+        .embedded_languages
+        .dedup_by_key(|embedded_language| embedded_language.range);
+
+    annotations
+}
+
+fn collect_table<'a: 'b, 'b>(
+    table: &'a tombi_document_tree::Table,
+    table_schema: &'a CurrentSchema<'static>,
+    schema_context: &'a SchemaContext<'a>,
+    range: Option<tombi_text::Range>,
+    annotations: &'a mut SchemaAnnotations,
+) -> tombi_future::BoxFuture<'b, ()> {
+    async move {
+        let table_schemas = flatten_schemas(table_schema, schema_context).await;
+
+        for (key, value) in table.key_values() {
+            if !is_in_range(
+                tombi_text::Range::new(key.range().start, value.range().end),
+                range,
+            ) {
+                continue;
+            }
+
+            let mut is_table_schema = false;
+            let mut is_allowed = false;
+            for table_schema in &table_schemas {
+                let SchemaView::Table(table_schema_view) = table_schema.schema_view.as_ref() else {
+                    continue;
+                };
+                is_table_schema = true;
+
+                let Some(property_schema) =
+                    resolve_property_schema(key, table_schema_view, table_schema, schema_context)
+                        .await
+                else {
+                    if table_schema_view
+                        .allows_additional_properties(schema_context.strict(Some(table_schema)))
+                    {
+                        is_allowed = true;
+                    }
+                    continue;
+                };
+                is_allowed = true;
+
+                if property_schema.schema_view.deprecation().await.is_some() {
+                    annotations.deprecated_keys.push(key.range());
+                }
+                if is_read_only(&property_schema) {
+                    annotations.read_only_keys.push(key.range());
+                }
+                collect_value(value, &property_schema, schema_context, range, annotations).await;
+            }
+
+            if is_table_schema && !is_allowed {
+                annotations.not_allowed_keys.push(key.range());
+            }
+        }
+    }
+    .boxed()
+}
+
+fn collect_value<'a: 'b, 'b>(
+    value: &'a tombi_document_tree::Value,
+    current_schema: &'a CurrentSchema<'static>,
+    schema_context: &'a SchemaContext<'a>,
+    range: Option<tombi_text::Range>,
+    annotations: &'a mut SchemaAnnotations,
+) -> tombi_future::BoxFuture<'b, ()> {
+    async move {
+        for current_schema in flatten_schemas(current_schema, schema_context).await {
+            if is_default_value(value, current_schema.schema_view.as_ref()) {
+                annotations.default_values.push(value.range());
+            }
+
+            match (value, current_schema.schema_view.as_ref()) {
+                (tombi_document_tree::Value::String(string), SchemaView::String(string_schema)) => {
+                    if let Some(language) = string_schema.embedded_language() {
+                        annotations.embedded_languages.push(EmbeddedLanguage {
+                            range: string.range(),
+                            language: language.to_string(),
+                        });
+                    }
+                }
+                (tombi_document_tree::Value::Table(table), SchemaView::Table(_)) => {
+                    collect_table(table, &current_schema, schema_context, range, annotations).await;
+                }
+                (tombi_document_tree::Value::Array(array), SchemaView::Array(array_schema)) => {
+                    for (index, item) in array.values().iter().enumerate() {
+                        if !is_in_range(item.range(), range) {
+                            continue;
+                        }
+                        let item_schema = match array_schema
+                            .prefix_items
+                            .as_ref()
+                            .and_then(|prefix_items| prefix_items.get(index))
+                            .or(array_schema.items.as_ref())
+                        {
+                            Some(item_schema) => {
+                                resolve_schema_item(item_schema, &current_schema, schema_context)
+                                    .await
+                            }
+                            None => None,
+                        };
+                        if let Some(item_schema) = item_schema {
+                            collect_value(item, &item_schema, schema_context, range, annotations)
+                                .await;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    .boxed()
+}
+
+/// Resolve the schema of `key` by `properties`, `patternProperties` and `additionalProperties`, in that order.
+async fn resolve_property_schema(
+    key: &tombi_document_tree::Key,
+    table_schema_view: &TableSchema,
+    table_schema: &CurrentSchema<'static>,
+    schema_context: &SchemaContext<'_>,
+) -> Option<CurrentSchema<'static>> {
+    if let Ok(Some(property_schema)) = table_schema_view
+        .resolve_property_schema(
+            &SchemaAccessor::Key(key.value.clone()),
+            table_schema.schema_uri.clone(),
+            table_schema.definitions.clone(),
+            table_schema.strict,
+            schema_context.store,
+        )
+        .await
+    {
+        return Some(property_schema);
+    }
+
+    if let Some(pattern_properties) = &table_schema_view.pattern_properties {
+        let pattern_keys = pattern_properties
+            .read()
+            .await
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>();
+        for pattern_key in pattern_keys {
+            let Ok(pattern) = tombi_regex::Regex::new(&pattern_key) else {
+                continue;
+            };
+            if pattern.is_match(&key.value)
+                && let Ok(Some(pattern_property_schema)) = table_schema_view
+                    .resolve_pattern_property_schema(
+                        &pattern_key,
+                        table_schema.schema_uri.clone(),
+                        table_schema.definitions.clone(),
+                        table_schema.strict,
+                        schema_context.store,
+                    )
+                    .await
+            {
+                return Some(pattern_property_schema);
+            }
+        }
+    }
+
+    let (_, additional_property_schema) = table_schema_view.additional_property_schema.as_ref()?;
+    resolve_schema_item(additional_property_schema, table_schema, schema_context).await
+}
+
+fn is_in_range(value_range: tombi_text::Range, range: Option<tombi_text::Range>) -> bool {
+    range.is_none_or(|range| range.intersects(value_range))
+}
+
+fn is_read_only(current_schema: &CurrentSchema<'_>) -> bool {
+    match current_schema.semantic_schema.as_deref() {
+        Some(SemanticSchema::Object(object)) => object
+            .annotations
+            .read_only
+            .as_ref()
+            .is_some_and(|read_only| read_only.value),
+        _ => false,
+    }
+}
+
+fn is_default_value(value: &tombi_document_tree::Value, schema_view: &SchemaView) -> bool {
+    match (value, schema_view) {
+        (tombi_document_tree::Value::Boolean(boolean), SchemaView::Boolean(schema)) => {
+            schema.default == Some(boolean.value())
+        }
+        (tombi_document_tree::Value::Integer(integer), SchemaView::Integer(schema)) => {
+            schema.default == Some(integer.value())
+        }
+        (tombi_document_tree::Value::Float(float), SchemaView::Float(schema)) => {
+            schema.default == Some(float.value())
+        }
+        (tombi_document_tree::Value::String(string), SchemaView::String(schema)) => {
+            schema.default.as_deref() == Some(string.value())
+        }
+        (
+            tombi_document_tree::Value::OffsetDateTime(date_time),
+            SchemaView::OffsetDateTime(schema),
+        ) => schema.default.as_deref() == Some(date_time.value().to_string().as_str()),
+        (
+            tombi_document_tree::Value::LocalDateTime(date_time),
+            SchemaView::LocalDateTime(schema),
+        ) => schema.default.as_deref() == Some(date_time.value().to_string().as_str()),
+        (tombi_document_tree::Value::LocalDate(date), SchemaView::LocalDate(schema)) => {
+            schema.default.as_deref() == Some(date.value().to_string().as_str())
+        }
+        (tombi_document_tree::Value::LocalTime(time), SchemaView::LocalTime(schema)) => {
+            schema.default.as_deref() == Some(time.value().to_string().as_str())
+        }
+        _ => false,
+    }
+}
+
+/// Expand `oneOf`/`anyOf`/`allOf` schemas into the schemas they are composed of.
+async fn flatten_schemas(
+    current_schema: &CurrentSchema<'static>,
+    schema_context: &SchemaContext<'_>,
+) -> Vec<CurrentSchema<'static>> {
+    let schemas = match current_schema.schema_view.as_ref() {
+        SchemaView::OneOf(one_of_schema) => &one_of_schema.schemas,
+        SchemaView::AnyOf(any_of_schema) => &any_of_schema.schemas,
+        SchemaView::AllOf(all_of_schema) => &all_of_schema.schemas,
+        _ => return vec![current_schema.clone()],
+    };
+
+    let mut flattened = Vec::new();
+    for schema in tombi_schema_store::resolve_and_collect_schemas(
+        schemas,
+        current_schema.schema_uri.clone(),
+        current_schema.definitions.clone(),
+        current_schema.strict,
+        schema_context.store,
+        &schema_context.schema_visits,
+        &[],
+    )
+    .await
+    .unwrap_or_default()
+    {
+        flattened.extend(Box::pin(flatten_schemas(&schema, schema_context)).await);
+    }
+
+    flattened
+}
+
+async fn resolve_schema_item(
+    schema_item: &tombi_schema_store::SchemaItem,
+    current_schema: &CurrentSchema<'static>,
+    schema_context: &SchemaContext<'_>,
+) -> Option<CurrentSchema<'static>> {
+    tombi_schema_store::resolve_schema_item(
+        schema_item,
+        current_schema.schema_uri.clone(),
+        current_schema.definitions.clone(),
+        current_schema.strict,
+        schema_context.store,
+    )
+    .await
+    .ok()
+    .flatten()
+}
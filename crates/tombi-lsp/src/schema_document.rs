@@ -0,0 +1,106 @@
+mod diagnostic;
+mod reference;
+
+use std::sync::Arc;
+
+use tombi_text::{EncodingKind, FromLsp, LineIndex};
+
+pub use diagnostic::get_schema_document_diagnostics_result;
+pub use reference::{get_schema_document_definitions, get_schema_document_references};
+
+/// Whether the document is a JSON Schema authored in the workspace, such as `foo.schema.json`.
+///
+/// Schema documents are kept apart from TOML documents and only get the schema authoring features:
+/// meta-schema validation, goto-definition on `$ref` and references to `$defs` entries.
+pub fn is_schema_document_uri(text_document_uri: &tombi_uri::Uri) -> bool {
+    text_document_uri.path().ends_with(".schema.json")
+}
+
+#[derive(Debug, Clone)]
+pub struct SchemaDocumentSource {
+    /// The text of the document.
+    text: Arc<str>,
+
+    line_index: Arc<LineIndex>,
+
+    /// The version of the document.
+    pub version: Option<i32>,
+
+    /// Parsed JSON value (None if the document has a syntax error)
+    value_node: Option<Arc<tombi_json::ValueNode>>,
+
+    /// JSON syntax error message and range
+    parse_error: Option<(String, tombi_text::Range)>,
+}
+
+impl SchemaDocumentSource {
+    pub fn new(text: impl Into<String>, version: Option<i32>, encoding_kind: EncodingKind) -> Self {
+        let text: Arc<str> = Arc::<str>::from(text.into());
+        let (value_node, parse_error) = parse(text.as_ref());
+
+        Self {
+            line_index: Arc::new(LineIndex::from_arc(Arc::clone(&text), encoding_kind)),
+            text,
+            version,
+            value_node,
+            parse_error,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        self.text.as_ref()
+    }
+
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        self.text = Arc::<str>::from(text.into());
+        self.reparse();
+    }
+
+    /// Apply an incremental change from the editor.
+    pub fn edit_text(&mut self, range: tower_lsp::lsp_types::Range, new_text: &str) {
+        let span = tombi_text::Span::from_lsp(range, &self.line_index);
+
+        let mut text = self.text.to_string();
+        text.replace_range(std::ops::Range::<usize>::from(span), new_text);
+        self.text = Arc::<str>::from(text);
+        self.reparse();
+    }
+
+    fn reparse(&mut self) {
+        self.line_index = Arc::new(LineIndex::from_arc(
+            Arc::clone(&self.text),
+            self.line_index.encoding_kind,
+        ));
+        (self.value_node, self.parse_error) = parse(self.text.as_ref());
+    }
+
+    pub fn line_index(&self) -> &LineIndex {
+        self.line_index.as_ref()
+    }
+
+    pub fn line_index_arc(&self) -> Arc<LineIndex> {
+        Arc::clone(&self.line_index)
+    }
+
+    /// Get the parsed JSON value
+    pub fn value_node(&self) -> Option<Arc<tombi_json::ValueNode>> {
+        self.value_node.clone()
+    }
+
+    /// Get the JSON syntax error
+    pub fn parse_error(&self) -> Option<&(String, tombi_text::Range)> {
+        self.parse_error.as_ref()
+    }
+}
+
+fn parse(
+    text: &str,
+) -> (
+    Option<Arc<tombi_json::ValueNode>>,
+    Option<(String, tombi_text::Range)>,
+) {
+    match tombi_json::parse_with_error_range(text) {
+        Ok(value_node) => (Some(Arc::new(value_node)), None),
+        Err((error, range)) => (None, Some((error.to_string(), range))),
+    }
+}
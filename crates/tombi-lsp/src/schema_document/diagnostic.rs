@@ -0,0 +1,136 @@
+use itertools::Itertools;
+use tombi_json::ValueNode;
+
+use super::reference::{collect_refs, resolve_json_pointer};
+use crate::{backend::Backend, config_manager::ConfigSchemaStore, diagnostic::DiagnosticsResult};
+
+pub async fn get_schema_document_diagnostics_result(
+    backend: &Backend,
+    text_document_uri: &tombi_uri::Uri,
+) -> Option<DiagnosticsResult> {
+    let (value_node, parse_error, version, line_index) = {
+        let schema_document_sources = backend.schema_document_sources.read().await;
+        let schema_document_source = schema_document_sources.get(text_document_uri)?;
+        (
+            schema_document_source.value_node(),
+            schema_document_source.parse_error().cloned(),
+            schema_document_source.version,
+            schema_document_source.line_index_arc(),
+        )
+    };
+
+    let mut diagnostics = Vec::new();
+
+    if let Some((message, range)) = parse_error {
+        diagnostics.push(tombi_diagnostic::Diagnostic::new_error(
+            message,
+            "invalid-json",
+            range,
+        ));
+    }
+
+    if let Some(value_node) = value_node {
+        diagnostics.extend(validate_local_refs(&value_node));
+        diagnostics.extend(validate_meta_schema(backend, text_document_uri, &value_node).await);
+    }
+
+    Some(DiagnosticsResult {
+        diagnostics: diagnostics
+            .into_iter()
+            .unique()
+            .map(|diagnostic| diagnostic.into_lsp_with_uri(text_document_uri, &line_index))
+            .collect_vec(),
+        version,
+    })
+}
+
+/// Report `$ref`s pointing into the document itself whose target does not exist.
+fn validate_local_refs(value_node: &ValueNode) -> Vec<tombi_diagnostic::Diagnostic> {
+    let mut refs = Vec::new();
+    collect_refs(value_node, &mut refs);
+
+    refs.into_iter()
+        .filter_map(|reference| {
+            let pointer = reference.value.strip_prefix('#')?;
+            resolve_json_pointer(value_node, pointer)
+                .is_none()
+                .then(|| {
+                    tombi_diagnostic::Diagnostic::new_warning(
+                        format!("\"{}\" does not resolve to a schema", reference.value),
+                        "unresolved-ref",
+                        reference.range,
+                    )
+                })
+        })
+        .collect_vec()
+}
+
+/// Validate the document against the meta-schema declared by its `$schema` keyword.
+async fn validate_meta_schema(
+    backend: &Backend,
+    text_document_uri: &tombi_uri::Uri,
+    value_node: &ValueNode,
+) -> Vec<tombi_diagnostic::Diagnostic> {
+    let Some(ValueNode::String(meta_schema)) = value_node
+        .as_object()
+        .and_then(|object| object.get("$schema"))
+    else {
+        return Vec::new();
+    };
+
+    let Ok(meta_schema_uri) = text_document_uri.join(&meta_schema.value) else {
+        return vec![tombi_diagnostic::Diagnostic::new_warning(
+            format!("\"{}\" is not a valid schema URI", meta_schema.value),
+            tombi_schema_store::SCHEMA_RESOLUTION_DIAGNOSTIC_CODE,
+            meta_schema.range,
+        )];
+    };
+    let meta_schema_uri = tombi_schema_store::SchemaUri::from(meta_schema_uri);
+
+    let ConfigSchemaStore { schema_store, .. } = backend
+        .config_manager
+        .config_schema_store_for_uri(text_document_uri)
+        .await;
+
+    let document_schema = match schema_store.try_get_document_schema(&meta_schema_uri).await {
+        Ok(Some(document_schema)) => document_schema,
+        Ok(None) => return Vec::new(),
+        Err(error) => {
+            return vec![tombi_diagnostic::Diagnostic::new_warning(
+                error.to_string(),
+                tombi_schema_store::SCHEMA_RESOLUTION_DIAGNOSTIC_CODE,
+                meta_schema.range,
+            )];
+        }
+    };
+
+    let source_schema = tombi_schema_store::SourceSchema::new(
+        Some(document_schema),
+        Default::default(),
+        None,
+        None,
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    );
+    // Meta-schemas allow unknown keywords, so strict mode would reject every vendor extension.
+    let mut schema_context = tombi_schema_store::SchemaContext::from_source_schema(
+        Default::default(),
+        Some(&source_schema),
+        &schema_store,
+        Some(false.into()),
+    );
+    // JSON `null` is converted to an incomplete value, which is otherwise accepted by any schema.
+    schema_context.incomplete_as_null = true;
+
+    match tombi_validator::validate(
+        &tombi_document_tree::DocumentTree::from(value_node.clone()),
+        Some(&source_schema),
+        &schema_context,
+    )
+    .await
+    {
+        Ok(()) => Vec::new(),
+        Err(diagnostics) => diagnostics,
+    }
+}
@@ -0,0 +1,258 @@
+use std::sync::Arc;
+
+use itertools::Itertools;
+use tombi_json::{StringNode, ValueNode};
+use tombi_text::IntoLsp;
+
+use super::SchemaDocumentSource;
+use crate::backend::Backend;
+
+/// Keywords whose entries are reusable schemas that `$ref`s point to.
+const DEFINITIONS_KEYWORDS: &[&str] = &["$defs", "definitions"];
+
+/// Go to the schema a `$ref` under the cursor points to.
+pub async fn get_schema_document_definitions(
+    backend: &Backend,
+    text_document_uri: &tombi_uri::Uri,
+    position: tower_lsp::lsp_types::Position,
+) -> Option<Vec<tombi_extension::Location>> {
+    let (value_node, position) = {
+        let schema_document_sources = backend.schema_document_sources.read().await;
+        let schema_document_source = schema_document_sources.get(text_document_uri)?;
+        (
+            schema_document_source.value_node()?,
+            position.into_lsp(schema_document_source.line_index()),
+        )
+    };
+
+    let reference = find_ref_at(&value_node, position)?;
+    let (target_uri, pointer) = resolve_ref_target(text_document_uri, &reference.value)?;
+
+    let target_value_node = if &target_uri == text_document_uri {
+        value_node.clone()
+    } else {
+        load_schema_value_node(backend, &target_uri).await?
+    };
+
+    let range = resolve_json_pointer(&target_value_node, &pointer)?;
+
+    Some(vec![tombi_extension::Location {
+        uri: target_uri,
+        range,
+    }])
+}
+
+/// Find the `$ref`s pointing to the `$defs` entry under the cursor.
+///
+/// The cursor may also be on a `$ref` itself, in which case the references of its target are returned.
+pub async fn get_schema_document_references(
+    backend: &Backend,
+    text_document_uri: &tombi_uri::Uri,
+    position: tower_lsp::lsp_types::Position,
+    include_declaration: bool,
+) -> Option<Vec<tombi_extension::Location>> {
+    let schema_document_sources = backend.schema_document_sources.read().await;
+    let schema_document_source = schema_document_sources.get(text_document_uri)?;
+    let value_node = schema_document_source.value_node()?;
+    let position = position.into_lsp(schema_document_source.line_index());
+
+    let (target_uri, pointer) =
+        if let Some((pointer, _)) = find_definition_at(&value_node, position) {
+            (text_document_uri.clone(), pointer)
+        } else {
+            let reference = find_ref_at(&value_node, position)?;
+            resolve_ref_target(text_document_uri, &reference.value)?
+        };
+
+    let mut locations = Vec::new();
+
+    if include_declaration
+        && let Some(target_value_node) = schema_document_sources
+            .get(&target_uri)
+            .and_then(SchemaDocumentSource::value_node)
+        && let Some(range) = resolve_json_pointer(&target_value_node, &pointer)
+    {
+        locations.push(tombi_extension::Location {
+            uri: target_uri.clone(),
+            range,
+        });
+    }
+
+    for (uri, schema_document_source) in schema_document_sources
+        .iter()
+        .sorted_by_key(|(uri, _)| (*uri != text_document_uri, uri.to_string()))
+    {
+        let Some(value_node) = schema_document_source.value_node() else {
+            continue;
+        };
+
+        let mut refs = Vec::new();
+        collect_refs(&value_node, &mut refs);
+
+        for reference in refs {
+            if resolve_ref_target(uri, &reference.value).as_ref()
+                == Some(&(target_uri.clone(), pointer.clone()))
+            {
+                locations.push(tombi_extension::Location {
+                    uri: uri.clone(),
+                    range: reference.range,
+                });
+            }
+        }
+    }
+
+    Some(locations)
+}
+
+/// Collect all `$ref` string values in document order.
+pub(super) fn collect_refs<'a>(value_node: &'a ValueNode, refs: &mut Vec<&'a StringNode>) {
+    match value_node {
+        ValueNode::Object(object) => {
+            for (key, value) in object.properties.iter() {
+                if key.value == "$ref"
+                    && let ValueNode::String(reference) = value
+                {
+                    refs.push(reference);
+                }
+                collect_refs(value, refs);
+            }
+        }
+        ValueNode::Array(array) => {
+            for item in &array.items {
+                collect_refs(item, refs);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn find_ref_at(value_node: &ValueNode, position: tombi_text::Position) -> Option<&StringNode> {
+    let mut refs = Vec::new();
+    collect_refs(value_node, &mut refs);
+
+    refs.into_iter()
+        .find(|reference| reference.range.contains(position))
+}
+
+/// Find the `$defs` entry whose key is under the cursor, returning its JSON Pointer and key range.
+fn find_definition_at(
+    value_node: &ValueNode,
+    position: tombi_text::Position,
+) -> Option<(String, tombi_text::Range)> {
+    fn walk(
+        value_node: &ValueNode,
+        position: tombi_text::Position,
+        pointer: &mut Vec<String>,
+    ) -> Option<(String, tombi_text::Range)> {
+        if !value_node.range().contains(position) {
+            return None;
+        }
+
+        match value_node {
+            ValueNode::Object(object) => {
+                let in_definitions = pointer
+                    .last()
+                    .is_some_and(|key| DEFINITIONS_KEYWORDS.contains(&key.as_str()));
+
+                for (key, value) in object.properties.iter() {
+                    pointer.push(key.value.clone());
+                    if in_definitions && key.range.contains(position) {
+                        return Some((to_json_pointer(pointer), key.range));
+                    }
+                    if let Some(definition) = walk(value, position, pointer) {
+                        return Some(definition);
+                    }
+                    pointer.pop();
+                }
+                None
+            }
+            ValueNode::Array(array) => {
+                for (index, item) in array.items.iter().enumerate() {
+                    pointer.push(index.to_string());
+                    if let Some(definition) = walk(item, position, pointer) {
+                        return Some(definition);
+                    }
+                    pointer.pop();
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+
+    walk(value_node, position, &mut Vec::new())
+}
+
+/// Split a `$ref` into the document it points to and the JSON Pointer inside that document.
+fn resolve_ref_target(
+    text_document_uri: &tombi_uri::Uri,
+    reference: &str,
+) -> Option<(tombi_uri::Uri, String)> {
+    let (document, pointer) = reference.split_once('#').unwrap_or((reference, ""));
+
+    let target_uri = if document.is_empty() {
+        text_document_uri.clone()
+    } else {
+        text_document_uri.join(document).ok()?.into()
+    };
+
+    Some((target_uri, pointer.to_string()))
+}
+
+/// Resolve a JSON Pointer such as `/$defs/name`, returning the range of the key it points to.
+pub(super) fn resolve_json_pointer(
+    value_node: &ValueNode,
+    pointer: &str,
+) -> Option<tombi_text::Range> {
+    if pointer.is_empty() {
+        return Some(tombi_text::Range::at(value_node.range().start));
+    }
+
+    let mut current = value_node;
+    let mut range = current.range();
+
+    for token in pointer.strip_prefix('/')?.split('/') {
+        let token = token.replace("~1", "/").replace("~0", "~");
+        match current {
+            ValueNode::Object(object) => {
+                let (key, value) = object
+                    .properties
+                    .iter()
+                    .find(|(key, _)| key.value == token)?;
+                range = key.range;
+                current = value;
+            }
+            ValueNode::Array(array) => {
+                current = array.items.get(token.parse::<usize>().ok()?)?;
+                range = current.range();
+            }
+            _ => return None,
+        }
+    }
+
+    Some(range)
+}
+
+fn to_json_pointer(tokens: &[String]) -> String {
+    tokens
+        .iter()
+        .map(|token| format!("/{}", token.replace('~', "~0").replace('/', "~1")))
+        .collect()
+}
+
+/// Get the parsed JSON of another schema document, preferring the editor's copy if it is open.
+async fn load_schema_value_node(
+    backend: &Backend,
+    schema_uri: &tombi_uri::Uri,
+) -> Option<Arc<ValueNode>> {
+    if let Some(schema_document_source) =
+        backend.schema_document_sources.read().await.get(schema_uri)
+    {
+        return schema_document_source.value_node();
+    }
+
+    let schema_path = schema_uri.to_file_path().ok()?;
+    let content = tombi_fs::read_to_string_async(&schema_path).await.ok()?;
+
+    tombi_json::parse(&content).ok().map(Arc::new)
+}
@@ -1,18 +1,74 @@
 mod array;
 mod array_or_table;
 mod builder;
+mod cache;
 mod comment;
+mod edit;
 mod inline_table;
 mod key;
 mod key_value;
 mod root;
 mod table;
+mod token_modifier;
 mod token_type;
 mod value;
 
 pub use builder::SemanticTokensBuilder;
+pub use cache::SemanticTokensCache;
+pub use edit::{diff_tokens, tokens_in_range};
+pub use token_modifier::SUPPORTED_TOKEN_MODIFIERS;
 pub use token_type::{SUPPORTED_TOKEN_TYPES, TokenType};
+use tower_lsp::lsp_types::SemanticToken;
+
+use crate::{
+    Backend, document::DocumentSource, schema_annotation::get_document_schema_annotations,
+};
 
 pub trait AppendSemanticTokens {
     fn append_semantic_tokens(&self, builder: &mut SemanticTokensBuilder);
 }
+
+/// Build the semantic tokens of the document, with the modifiers derived from its schema.
+pub async fn get_semantic_tokens(
+    backend: &Backend,
+    text_document_uri: &tombi_uri::Uri,
+    document_source: &DocumentSource,
+) -> Vec<SemanticToken> {
+    build_semantic_tokens(backend, text_document_uri, document_source, None).await
+}
+
+/// Build the semantic tokens of the nodes intersecting `range`.
+///
+/// The tokens are encoded from the start of the document, like those of [`get_semantic_tokens`].
+pub async fn get_semantic_tokens_in_range(
+    backend: &Backend,
+    text_document_uri: &tombi_uri::Uri,
+    document_source: &DocumentSource,
+    range: tombi_text::Range,
+) -> Vec<SemanticToken> {
+    build_semantic_tokens(backend, text_document_uri, document_source, Some(range)).await
+}
+
+async fn build_semantic_tokens(
+    backend: &Backend,
+    text_document_uri: &tombi_uri::Uri,
+    document_source: &DocumentSource,
+    range: Option<tombi_text::Range>,
+) -> Vec<SemanticToken> {
+    let token_modifiers = token_modifier::token_modifiers_by_range(
+        get_document_schema_annotations(backend, text_document_uri, document_source, range).await,
+    );
+
+    let mut tokens_builder =
+        SemanticTokensBuilder::new(text_document_uri.clone(), document_source.line_index())
+            .with_token_modifiers(token_modifiers);
+    if let Some(range) = range {
+        tokens_builder = tokens_builder.with_range(range);
+    }
+
+    document_source
+        .ast()
+        .append_semantic_tokens(&mut tokens_builder);
+
+    tokens_builder.build()
+}
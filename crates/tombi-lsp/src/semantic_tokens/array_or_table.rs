@@ -14,7 +14,7 @@ impl AppendSemanticTokens for tombi_ast::ArrayOfTable {
 
         if let Some(header) = self.header() {
             for key in header.keys() {
-                builder.add_token(TokenType::TABLE, key.syntax().clone().into());
+                builder.add_token(TokenType::ARRAY_OF_TABLE, key.syntax().clone().into());
             }
         }
 
@@ -41,7 +41,9 @@ impl AppendSemanticTokens for tombi_ast::ArrayOfTable {
                 }
                 DanglingCommentGroupOr::ItemGroup(key_value_group) => {
                     for key_value in key_value_group.key_values() {
-                        key_value.append_semantic_tokens(builder);
+                        if builder.intersects(key_value.syntax().range()) {
+                            key_value.append_semantic_tokens(builder);
+                        }
                     }
                 }
             }
@@ -9,6 +9,8 @@ pub struct SemanticTokensBuilder<'a> {
     tokens: Vec<SemanticToken>,
     last_range: tombi_text::Range,
     line_index: &'a tombi_text::LineIndex,
+    token_modifiers: tombi_hashmap::HashMap<tombi_text::Range, u32>,
+    range: Option<tombi_text::Range>,
     pub text_document_uri: tombi_uri::Uri,
 }
 
@@ -18,14 +20,38 @@ impl<'a> SemanticTokensBuilder<'a> {
             tokens: Vec::new(),
             last_range: tombi_text::Range::default(),
             line_index,
+            token_modifiers: Default::default(),
+            range: None,
             text_document_uri,
         }
     }
 
+    /// Set the modifiers bitset of the tokens by their range.
+    pub fn with_token_modifiers(
+        mut self,
+        token_modifiers: tombi_hashmap::HashMap<tombi_text::Range, u32>,
+    ) -> Self {
+        self.token_modifiers = token_modifiers;
+        self
+    }
+
+    /// Limit the nodes walked to those intersecting `range`.
+    pub fn with_range(mut self, range: tombi_text::Range) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Whether the tokens of a node in `range` are to be added.
+    pub fn intersects(&self, range: tombi_text::Range) -> bool {
+        self.range
+            .is_none_or(|builder_range| builder_range.intersects(range))
+    }
+
     pub fn add_token(&mut self, token_type: TokenType, elem: tombi_syntax::SyntaxElement) {
         let range: tombi_text::Range = elem.range();
         let (delta_line, delta_start) =
             delta_line_and_start(self.last_range.start, range.start, self.line_index);
+        let token_modifiers_bitset = self.token_modifiers.get(&range).copied().unwrap_or(0);
 
         #[allow(clippy::cast_possible_truncation)]
         self.tokens.push(SemanticToken {
@@ -33,7 +59,7 @@ impl<'a> SemanticTokensBuilder<'a> {
             delta_start,
             length: token_length(range, self.line_index),
             token_type: token_type as u32,
-            token_modifiers_bitset: 0,
+            token_modifiers_bitset,
         });
 
         self.last_range = range;
@@ -0,0 +1,36 @@
+use tower_lsp::lsp_types::SemanticToken;
+
+/// The last semantic tokens sent for each document, used to answer `semanticTokens/full/delta`.
+#[derive(Debug, Default)]
+pub struct SemanticTokensCache {
+    next_result_id: u64,
+    cache: tombi_hashmap::HashMap<tombi_uri::Uri, (String, Vec<SemanticToken>)>,
+}
+
+impl SemanticTokensCache {
+    /// Store the tokens of the document, and return their new result id.
+    pub fn set(&mut self, text_document_uri: tombi_uri::Uri, tokens: Vec<SemanticToken>) -> String {
+        self.next_result_id += 1;
+        let result_id = self.next_result_id.to_string();
+        self.cache
+            .insert(text_document_uri, (result_id.clone(), tokens));
+
+        result_id
+    }
+
+    /// The tokens of the document, if they were sent with `result_id`.
+    pub fn get(
+        &self,
+        text_document_uri: &tombi_uri::Uri,
+        result_id: &str,
+    ) -> Option<&[SemanticToken]> {
+        self.cache
+            .get(text_document_uri)
+            .filter(|(cached_result_id, _)| cached_result_id == result_id)
+            .map(|(_, tokens)| tokens.as_slice())
+    }
+
+    pub fn close(&mut self, text_document_uri: &tombi_uri::Uri) {
+        self.cache.remove(text_document_uri);
+    }
+}
@@ -0,0 +1,142 @@
+use tower_lsp::lsp_types::{SemanticToken, SemanticTokensEdit};
+
+/// The number of `u32` values each token is encoded into.
+const TOKEN_SIZE: u32 = 5;
+
+/// The edit turning `old` into `new`, replacing everything between their common prefix and suffix.
+pub fn diff_tokens(old: &[SemanticToken], new: &[SemanticToken]) -> Vec<SemanticTokensEdit> {
+    let prefix_len = old
+        .iter()
+        .zip(new)
+        .take_while(|(old, new)| old == new)
+        .count();
+    let (old, new) = (&old[prefix_len..], &new[prefix_len..]);
+
+    let suffix_len = old
+        .iter()
+        .rev()
+        .zip(new.iter().rev())
+        .take_while(|(old, new)| old == new)
+        .count();
+    let (old, new) = (
+        &old[..old.len() - suffix_len],
+        &new[..new.len() - suffix_len],
+    );
+
+    if old.is_empty() && new.is_empty() {
+        return Vec::with_capacity(0);
+    }
+
+    vec![SemanticTokensEdit {
+        start: prefix_len as u32 * TOKEN_SIZE,
+        delete_count: old.len() as u32 * TOKEN_SIZE,
+        data: (!new.is_empty()).then(|| new.to_vec()),
+    }]
+}
+
+/// The tokens overlapping `range`, re-encoded relative to the first of them.
+pub fn tokens_in_range(
+    tokens: &[SemanticToken],
+    range: tower_lsp::lsp_types::Range,
+) -> Vec<SemanticToken> {
+    let mut tokens_in_range = Vec::new();
+    let (mut line, mut character) = (0, 0);
+    let mut last_position = None;
+
+    for token in tokens {
+        if token.delta_line == 0 {
+            character += token.delta_start;
+        } else {
+            line += token.delta_line;
+            character = token.delta_start;
+        }
+
+        let start = tower_lsp::lsp_types::Position::new(line, character);
+        if start >= range.end {
+            break;
+        }
+        // Multi-line tokens are encoded with the total length of their lines,
+        // so their end is an approximation on the start line.
+        let end = tower_lsp::lsp_types::Position::new(line, character + token.length);
+        if end <= range.start {
+            continue;
+        }
+
+        let (delta_line, delta_start) = match last_position {
+            Some((last_line, last_character)) if last_line == line => {
+                (0, character - last_character)
+            }
+            Some((last_line, _)) => (line - last_line, character),
+            None => (line, character),
+        };
+        tokens_in_range.push(SemanticToken {
+            delta_line,
+            delta_start,
+            ..*token
+        });
+        last_position = Some((line, character));
+    }
+
+    tokens_in_range
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(delta_line: u32, delta_start: u32, length: u32) -> SemanticToken {
+        SemanticToken {
+            delta_line,
+            delta_start,
+            length,
+            token_type: 0,
+            token_modifiers_bitset: 0,
+        }
+    }
+
+    #[test]
+    fn diff_tokens_replaces_changed_middle() {
+        let old = [token(0, 0, 1), token(0, 2, 1), token(1, 0, 1)];
+        let new = [
+            token(0, 0, 1),
+            token(0, 2, 3),
+            token(0, 4, 1),
+            token(1, 0, 1),
+        ];
+
+        pretty_assertions::assert_eq!(
+            diff_tokens(&old, &new),
+            vec![SemanticTokensEdit {
+                start: 5,
+                delete_count: 5,
+                data: Some(vec![token(0, 2, 3), token(0, 4, 1)]),
+            }]
+        );
+    }
+
+    #[test]
+    fn diff_tokens_of_same_tokens_is_empty() {
+        let tokens = [token(0, 0, 1), token(1, 0, 1)];
+
+        pretty_assertions::assert_eq!(diff_tokens(&tokens, &tokens), vec![]);
+    }
+
+    #[test]
+    fn tokens_in_range_are_relative_to_first_token() {
+        let tokens = [
+            token(0, 0, 1),
+            token(1, 0, 3),
+            token(0, 4, 1),
+            token(2, 2, 1),
+        ];
+        let range = tower_lsp::lsp_types::Range::new(
+            tower_lsp::lsp_types::Position::new(1, 0),
+            tower_lsp::lsp_types::Position::new(2, 0),
+        );
+
+        pretty_assertions::assert_eq!(
+            tokens_in_range(&tokens, range),
+            vec![token(1, 0, 3), token(0, 4, 1)]
+        );
+    }
+}
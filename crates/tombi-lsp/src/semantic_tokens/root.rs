@@ -1,4 +1,4 @@
-use tombi_ast::DanglingCommentGroupOr;
+use tombi_ast::{AstNode, DanglingCommentGroupOr};
 
 use super::{AppendSemanticTokens, SemanticTokensBuilder};
 
@@ -9,6 +9,9 @@ impl AppendSemanticTokens for tombi_ast::Root {
             self.schema_document_comment_directive(source_path.as_deref());
         for comment_group in self.dangling_comment_groups() {
             for comment in comment_group.comments() {
+                if !builder.intersects(comment.syntax().range()) {
+                    continue;
+                }
                 if let Some(schema_document_directive) = &schema_document_directive
                     && comment
                         .syntax()
@@ -32,19 +35,25 @@ impl AppendSemanticTokens for tombi_ast::Root {
             match group {
                 DanglingCommentGroupOr::DanglingCommentGroup(comment_group) => {
                     for comment in comment_group.comments() {
-                        comment.append_semantic_tokens(builder);
+                        if builder.intersects(comment.syntax().range()) {
+                            comment.append_semantic_tokens(builder);
+                        }
                     }
                 }
                 DanglingCommentGroupOr::ItemGroup(key_value_group) => {
                     for key_value in key_value_group.key_values() {
-                        key_value.append_semantic_tokens(builder);
+                        if builder.intersects(key_value.syntax().range()) {
+                            key_value.append_semantic_tokens(builder);
+                        }
                     }
                 }
             }
         }
 
         for table_or_array_of_table in self.table_or_array_of_tables() {
-            table_or_array_of_table.append_semantic_tokens(builder)
+            if builder.intersects(table_or_array_of_table.syntax().range()) {
+                table_or_array_of_table.append_semantic_tokens(builder)
+            }
         }
     }
 }
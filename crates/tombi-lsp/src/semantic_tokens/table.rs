@@ -36,7 +36,9 @@ impl AppendSemanticTokens for tombi_ast::Table {
             match group {
                 DanglingCommentGroupOr::ItemGroup(key_value_group) => {
                     for key_value in key_value_group.key_values() {
-                        key_value.append_semantic_tokens(builder);
+                        if builder.intersects(key_value.syntax().range()) {
+                            key_value.append_semantic_tokens(builder);
+                        }
                     }
                 }
                 DanglingCommentGroupOr::DanglingCommentGroup(comment_group) => {
@@ -0,0 +1,89 @@
+use tower_lsp::lsp_types::SemanticTokenModifier;
+
+use crate::schema_annotation::SchemaAnnotations;
+
+macro_rules! token_modifiers {
+    (
+        standard {
+            $($standard:ident),*$(,)?
+        }
+        custom {
+            $(($custom:ident, $string:literal)),*$(,)?
+        }
+    ) => {
+        pub mod token_modifier {
+            use super::SemanticTokenModifier;
+
+            $(pub(crate) const $custom: SemanticTokenModifier = SemanticTokenModifier::new($string);)*
+        }
+
+        #[allow(clippy::upper_case_acronyms)]
+        #[allow(non_camel_case_types)]
+        #[derive(Debug, Clone, Copy)]
+        pub enum TokenModifier {
+            $($standard,)*
+            $($custom),*
+        }
+
+        pub const SUPPORTED_TOKEN_MODIFIERS: &[SemanticTokenModifier] = &[
+            $(SemanticTokenModifier::$standard,)*
+            $(self::token_modifier::$custom),*
+        ];
+    }
+}
+
+token_modifiers! {
+    standard {
+        DEPRECATED,
+        READONLY,
+    }
+    custom {
+        // Strings holding code in another language, reported by `tombi/embeddedLanguages`.
+        (EMBEDDED, "embedded"),
+        // Values equal to the `default` of their schema.
+        (DEFAULT_VALUE, "defaultValue"),
+        // Keys not allowed by the table schema.
+        (NOT_ALLOWED, "notAllowed"),
+    }
+}
+
+impl TokenModifier {
+    #[inline]
+    pub const fn bitset(self) -> u32 {
+        1 << self as u32
+    }
+}
+
+/// The modifiers bitset of each token range, derived from the schema annotations.
+pub fn token_modifiers_by_range(
+    annotations: SchemaAnnotations,
+) -> tombi_hashmap::HashMap<tombi_text::Range, u32> {
+    let mut token_modifiers = tombi_hashmap::HashMap::default();
+    let SchemaAnnotations {
+        embedded_languages,
+        deprecated_keys,
+        read_only_keys,
+        default_values,
+        not_allowed_keys,
+    } = annotations;
+
+    for (ranges, token_modifier) in [
+        (
+            embedded_languages
+                .into_iter()
+                .map(|embedded_language| embedded_language.range)
+                .collect(),
+            TokenModifier::EMBEDDED,
+        ),
+        (deprecated_keys, TokenModifier::DEPRECATED),
+        (read_only_keys, TokenModifier::READONLY),
+        (default_values, TokenModifier::DEFAULT_VALUE),
+        (not_allowed_keys, TokenModifier::NOT_ALLOWED),
+    ] {
+        for range in ranges {
+            *token_modifiers.entry(range).or_default() |= token_modifier.bitset();
+        }
+    }
+
+    token_modifiers
+}
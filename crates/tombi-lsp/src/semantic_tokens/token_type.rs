@@ -40,6 +40,7 @@ token_types! {
     }
     custom {
         (TABLE, "table"),
+        (ARRAY_OF_TABLE, "arrayOfTables"),
         (KEY, "key"),
         (BOOLEAN, "boolean"),
         // NOTE: "datetime" does not exist, so we will use "regexp" instead.
@@ -86,6 +86,12 @@ impl CachedResponseSpec {
 
 pub struct UseCacheResponses(pub Vec<CachedResponseSpec>);
 
+/// A `table-style` diagnostic reported by the linter, sent as the code action context.
+pub struct TableStyleDiagnostic(pub tombi_x_keyword::TableStyle);
+
+/// The code action kinds requested by the client, such as `source` for source actions.
+pub struct Only(pub Vec<tower_lsp::lsp_types::CodeActionKind>);
+
 mod refactor_rewrite {
     mod common {
         use tombi_lsp::code_action::CodeActionRefactorRewriteName;
@@ -231,11 +237,50 @@ mod refactor_rewrite {
         }
     }
 
+    mod table_style {
+        use tombi_lsp::code_action::CodeActionRefactorRewriteName;
+        use tombi_x_keyword::TableStyle;
+
+        use crate::{TableStyleDiagnostic, test_code_action_refactor_rewrite};
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn dotted_keys_to_inline_table_quick_fix(
+                r#"
+            foo.bar█ = 1
+            "#,
+                Select(CodeActionRefactorRewriteName::DottedKeysToInlineTable),
+                TableStyleDiagnostic(TableStyle::Inline),
+            ) -> Ok(Some(
+                r#"
+            foo = { bar = 1 }
+            "#
+            ));
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn inline_table_to_dotted_keys_quick_fix(
+                r#"
+            foo = { bar = █1 }
+            "#,
+                Select(CodeActionRefactorRewriteName::InlineTableToDottedKeys),
+                TableStyleDiagnostic(TableStyle::Dotted),
+            ) -> Ok(Some(
+                r#"
+            foo.bar = 1
+            "#
+            ));
+        }
+    }
+
     mod cargo_toml {
-        use tombi_extension_cargo::CodeActionRefactorRewriteName;
+        use tombi_extension_cargo::{CodeActionRefactorRewriteName, CodeActionSourceName};
         use tombi_test_lib::project_root_path;
 
-        use crate::{CachedResponseSpec, UseCacheResponses, test_code_action_refactor_rewrite};
+        use crate::{
+            CachedResponseSpec, Only, UseCacheResponses, test_code_action_refactor_rewrite,
+        };
 
         test_code_action_refactor_rewrite! {
             #[tokio::test]
@@ -622,6 +667,206 @@ mod refactor_rewrite {
                 "#
             ));
         }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn cargo_toml_update_all_dependencies(
+                r#"
+                [dependencies]
+                serde█ = "1.0"
+                tokio = { version = "1.0.0", features = ["full"] }
+                rand = "0.8"
+                bounded = ">=1, <2"
+
+                [dev-dependencies]
+                local = { path = "../local" }
+                "#,
+                Select(CodeActionSourceName::UpdateAllDependencies),
+                Only(vec![tower_lsp::lsp_types::CodeActionKind::SOURCE]),
+                project_root_path().join("crates/subcrate/Cargo.toml"),
+                tombi_lsp::backend::Options {
+                    offline: Some(true),
+                    no_cache: Some(false),
+                },
+                UseCacheResponses(update_all_dependencies_cached_responses()),
+            ) -> Ok(Some(
+                r#"
+                [dependencies]
+                serde = "1.0"
+                tokio = { version = "1.47.1", features = ["full"] }
+                rand = "0.8"
+                bounded = ">=1, <2"
+
+                [dev-dependencies]
+                local = { path = "../local" }
+                "#
+            ));
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn cargo_toml_update_all_dependencies_including_breaking(
+                r#"
+                [dependencies]
+                serde█ = "1.0"
+                tokio = { version = "1.0.0", features = ["full"] }
+                rand = "0.8"
+                bounded = ">=1, <2"
+                "#,
+                Select(CodeActionSourceName::UpdateAllDependenciesIncludingBreaking),
+                Only(vec![tower_lsp::lsp_types::CodeActionKind::SOURCE]),
+                project_root_path().join("crates/subcrate/Cargo.toml"),
+                tombi_lsp::backend::Options {
+                    offline: Some(true),
+                    no_cache: Some(false),
+                },
+                UseCacheResponses(update_all_dependencies_cached_responses()),
+            ) -> Ok(Some(
+                r#"
+                [dependencies]
+                serde = "1.0"
+                tokio = { version = "1.47.1", features = ["full"] }
+                rand = "0.9"
+                bounded = ">=1, <2"
+                "#
+            ));
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn cargo_toml_update_all_dependencies_with_cargo_lock(
+                r#"
+                [package]
+                name = "update-all-dependencies"
+                version = "0.1.0"
+                edition = "2024"
+
+                [dependencies]
+                serde█ = "1.0.0"
+                tokio = { version = "1.0.0", features = ["full"] }
+                rand = "0.8"
+                "#,
+                Select(CodeActionSourceName::UpdateAllDependencies),
+                Only(vec![tower_lsp::lsp_types::CodeActionKind::SOURCE]),
+                project_root_path().join(
+                    "crates/tombi-lsp/tests/fixtures/cargo/update-all-dependencies/Cargo.toml"
+                ),
+                tombi_lsp::backend::Options {
+                    offline: Some(true),
+                    no_cache: Some(false),
+                },
+                UseCacheResponses(update_all_dependencies_with_cargo_lock_cached_responses()),
+            ) -> Ok(Some(
+                r#"
+                [package]
+                name = "update-all-dependencies"
+                version = "0.1.0"
+                edition = "2024"
+
+                [dependencies]
+                serde = "1.0.228"
+                tokio = { version = "1.47.1", features = ["full"] }
+                rand = "0.8"
+                "#
+            ));
+        }
+
+        test_code_action_refactor_rewrite! {
+            #[tokio::test]
+            async fn cargo_toml_update_all_dependencies_including_breaking_with_cargo_lock(
+                r#"
+                [package]
+                name = "update-all-dependencies"
+                version = "0.1.0"
+                edition = "2024"
+
+                [dependencies]
+                serde█ = "1.0.0"
+                tokio = { version = "1.0.0", features = ["full"] }
+                rand = "0.8"
+                "#,
+                Select(CodeActionSourceName::UpdateAllDependenciesIncludingBreaking),
+                Only(vec![tower_lsp::lsp_types::CodeActionKind::SOURCE]),
+                project_root_path().join(
+                    "crates/tombi-lsp/tests/fixtures/cargo/update-all-dependencies/Cargo.toml"
+                ),
+                tombi_lsp::backend::Options {
+                    offline: Some(true),
+                    no_cache: Some(false),
+                },
+                UseCacheResponses(update_all_dependencies_with_cargo_lock_cached_responses()),
+            ) -> Ok(Some(
+                r#"
+                [package]
+                name = "update-all-dependencies"
+                version = "0.1.0"
+                edition = "2024"
+
+                [dependencies]
+                serde = "1.0.228"
+                tokio = { version = "1.47.1", features = ["full"] }
+                rand = "0.9"
+                "#
+            ));
+        }
+
+        /// `serde` has no cached response, so it is bumped to the version in the fixture `Cargo.lock`,
+        /// and the cached `tokio` versions are older than the locked one, which is kept.
+        fn update_all_dependencies_with_cargo_lock_cached_responses() -> Vec<CachedResponseSpec> {
+            vec![
+                CachedResponseSpec::new(
+                    "https://crates.io/api/v1/crates/tokio/versions",
+                    r#"{
+                        "versions": [
+                            { "num": "1.40.0" },
+                            { "num": "1.0.0" }
+                        ]
+                    }"#,
+                ),
+                CachedResponseSpec::new(
+                    "https://crates.io/api/v1/crates/rand/versions",
+                    r#"{
+                        "versions": [
+                            { "num": "0.9.2" },
+                            { "num": "0.8.5" }
+                        ]
+                    }"#,
+                ),
+            ]
+        }
+
+        /// `serde` has no cached response, so it falls back to its version in `Cargo.lock`, which `"1.0"` already accepts.
+        fn update_all_dependencies_cached_responses() -> Vec<CachedResponseSpec> {
+            vec![
+                CachedResponseSpec::new(
+                    "https://crates.io/api/v1/crates/tokio/versions",
+                    r#"{
+                        "versions": [
+                            { "num": "1.47.1" },
+                            { "num": "1.0.0" }
+                        ]
+                    }"#,
+                ),
+                CachedResponseSpec::new(
+                    "https://crates.io/api/v1/crates/rand/versions",
+                    r#"{
+                        "versions": [
+                            { "num": "0.9.2" },
+                            { "num": "0.8.5" }
+                        ]
+                    }"#,
+                ),
+                CachedResponseSpec::new(
+                    "https://crates.io/api/v1/crates/bounded/versions",
+                    r#"{
+                        "versions": [
+                            { "num": "2.0.0" },
+                            { "num": "1.5.0" }
+                        ]
+                    }"#,
+                ),
+            ]
+        }
     }
 
     mod pyproject_toml {
@@ -760,6 +1005,8 @@ mod refactor_rewrite {
                     toml_file_path: Option<std::path::PathBuf>,
                     backend_options: tombi_lsp::backend::Options,
                     cached_responses: Vec<$crate::CachedResponseSpec>,
+                    diagnostics: Vec<tower_lsp::lsp_types::Diagnostic>,
+                    only: Option<Vec<tower_lsp::lsp_types::CodeActionKind>>,
                 }
 
                 #[allow(unused)]
@@ -795,6 +1042,31 @@ mod refactor_rewrite {
                     }
                 }
 
+                impl ApplyTestArg for $crate::Only {
+                    fn apply(self, args: &mut TestArgs) {
+                        args.only = Some(self.0);
+                    }
+                }
+
+                impl ApplyTestArg for $crate::TableStyleDiagnostic {
+                    fn apply(self, args: &mut TestArgs) {
+                        args.diagnostics.push(tower_lsp::lsp_types::Diagnostic {
+                            range: tower_lsp::lsp_types::Range::new(
+                                tower_lsp::lsp_types::Position::new(0, 0),
+                                tower_lsp::lsp_types::Position::new(u32::MAX, 0),
+                            ),
+                            code: Some(tower_lsp::lsp_types::NumberOrString::String(
+                                "table-style".to_string(),
+                            )),
+                            message: tombi_validator::DiagnosticKind::TableStyle {
+                                expected: self.0,
+                            }
+                            .to_string(),
+                            ..Default::default()
+                        });
+                    }
+                }
+
                 #[allow(unused_mut)]
                 let mut args = TestArgs::default();
                 $(ApplyTestArg::apply($arg, &mut args);)*
@@ -857,7 +1129,11 @@ mod refactor_rewrite {
                             + tombi_text::RelativePosition::of(&toml_text[..index])),
                     )
                     .into_lsp(&line_index),
-                    context: Default::default(),
+                    context: tower_lsp::lsp_types::CodeActionContext {
+                        diagnostics: args.diagnostics.clone(),
+                        only: args.only.clone(),
+                        ..Default::default()
+                    },
                     work_done_progress_params: Default::default(),
                     partial_result_params: Default::default(),
                 };
@@ -887,6 +1163,12 @@ mod refactor_rewrite {
                             )
                             .into());
                         };
+                        if !args.diagnostics.is_empty() {
+                            pretty_assertions::assert_eq!(
+                                action.kind,
+                                Some(tower_lsp::lsp_types::CodeActionKind::QUICKFIX)
+                            );
+                        }
                         let Some(edit) = action.edit else {
                             return Err("selected code action has no edit".into());
                         };
@@ -960,6 +960,76 @@ mod completion_edit {
         }
     }
 
+    mod vscode_extension_schema {
+        use tombi_test_lib::vscode_extension_test_schema_path;
+
+        use super::*;
+
+        test_completion_edit! {
+            #[tokio::test]
+            async fn default_snippet(
+                r#"
+                server = █
+                "#,
+                Select("localhost"),
+                SchemaPath(vscode_extension_test_schema_path()),
+            ) -> Ok(
+                r#"
+                server = { host = "${1:localhost}", port = ${2:8080} }
+                "#
+            );
+        }
+    }
+
+    mod table_snippet_schema {
+        use tombi_test_lib::table_snippet_test_schema_path;
+
+        use super::*;
+
+        test_completion_edit! {
+            #[tokio::test]
+            async fn array_of_tables_snippet(
+                r#"
+                name = "tombi"
+
+                bi█
+                "#,
+                Select("[[bin]]"),
+                SchemaPath(table_snippet_test_schema_path()),
+            ) -> Ok(
+                r#"
+                name = "tombi"
+
+                [[bin]]
+                name = "$1"
+                path = "$2"
+                "#
+            );
+        }
+
+        test_completion_edit! {
+            #[tokio::test]
+            async fn table_snippet_ordered_by_keys_order(
+                r#"
+                [tool]
+                █
+                "#,
+                Select("[tool.linter]"),
+                SchemaPath(table_snippet_test_schema_path()),
+            ) -> Ok(
+                r#"
+                [tool]
+                [tool.linter]
+                cache-dir = "${1:.cache}"
+                enabled = ${2:false}
+                line-length = ${3:88}
+                select = [$4]
+                target-version = "${5:py38}"
+                "#
+            );
+        }
+    }
+
     mod without_schema {
         use super::*;
 
@@ -1320,6 +1320,38 @@ mod completion_labels {
             ]);
         }
 
+        test_completion_labels! {
+            #[tokio::test]
+            async fn pyproject_project_scripts_module_completion(
+                r#"
+                [project.scripts]
+                mypkg = "mypkg.█"
+                "#,
+                SourcePath(project_root_path().join(
+                    "crates/tombi-lsp/tests/fixtures/pyproject-entry-points/pyproject.toml"
+                )),
+                SchemaPath(pyproject_schema_path()),
+            ) -> Ok([
+                "mypkg.cli",
+            ]);
+        }
+
+        test_completion_labels! {
+            #[tokio::test]
+            async fn pyproject_project_scripts_object_completion(
+                r#"
+                [project.scripts]
+                mypkg = "mypkg.cli:█"
+                "#,
+                SourcePath(project_root_path().join(
+                    "crates/tombi-lsp/tests/fixtures/pyproject-entry-points/pyproject.toml"
+                )),
+                SchemaPath(pyproject_schema_path()),
+            ) -> Ok([
+                "mypkg.cli:main",
+            ]);
+        }
+
         test_completion_labels! {
             #[tokio::test]
             async fn pyproject_project_dynamic_array(
@@ -1661,6 +1693,107 @@ mod completion_labels {
         }
     }
 
+    mod vscode_extension_schema {
+        use tombi_test_lib::vscode_extension_test_schema_path;
+
+        use super::*;
+
+        test_completion_labels! {
+            #[tokio::test]
+            async fn enum_descriptions_as_documentation(
+                "log-level = █",
+                SchemaPath(vscode_extension_test_schema_path()),
+            ) -> Ok([
+                {
+                    "label": "\"debug\"",
+                    "documentation": "Show every message.",
+                },
+                {
+                    "label": "\"error\"",
+                    "documentation": "Show errors only.",
+                },
+            ]);
+        }
+
+        test_completion_labels! {
+            #[tokio::test]
+            async fn markdown_description_as_documentation(
+                "█",
+                SchemaPath(vscode_extension_test_schema_path()),
+            ) -> Ok([
+                {
+                    "label": "name",
+                    "documentation": "The **name** of the server.",
+                },
+            ]);
+        }
+
+        test_completion_labels! {
+            #[tokio::test]
+            async fn default_snippets(
+                "server = █",
+                SchemaPath(vscode_extension_test_schema_path()),
+            ) -> Ok([
+                {
+                    "label": "localhost",
+                    "documentation": "A server on localhost.",
+                },
+            ]);
+        }
+    }
+
+    mod table_snippet_schema {
+        use tombi_test_lib::table_snippet_test_schema_path;
+
+        use super::*;
+
+        test_completion_labels! {
+            #[tokio::test]
+            async fn array_of_tables_snippet(
+                "█",
+                SchemaPath(table_snippet_test_schema_path()),
+            ) -> Ok(["[[bin]]", "bin", "name", "tool"]);
+        }
+
+        test_completion_labels! {
+            #[tokio::test]
+            async fn table_snippet(
+                r#"
+                [tool]
+                █
+                "#,
+                SchemaPath(table_snippet_test_schema_path()),
+            ) -> Ok(["[tool.linter]", "linter"]);
+        }
+
+        test_completion_labels! {
+            #[tokio::test]
+            async fn table_snippet_documentation(
+                r#"
+                [tool]
+                █
+                "#,
+                SchemaPath(table_snippet_test_schema_path()),
+            ) -> Ok([
+                {
+                    "label": "[tool.linter]",
+                    "documentation": "The linter settings.",
+                },
+            ]);
+        }
+
+        test_completion_labels! {
+            #[tokio::test]
+            async fn no_table_snippet_before_key_values(
+                r#"
+                █
+                name = "tombi"
+                "#,
+                SchemaPath(table_snippet_test_schema_path()),
+            ) -> Ok(["bin", "tool"]);
+        }
+    }
+
     mod cargo_schema {
         use tombi_test_lib::cargo_schema_path;
 
@@ -249,12 +249,64 @@ mod diagnostic {
                 SourceChanges(vec![(1, invalid_text()), (2, valid_text()), (3, invalid_text())]),
             ) -> Ok([
                 Diagnostic {
-                    message: "\"idx\" is not allowed",
+                    message: "\"idx\" is not allowed\nhelp: expected one of \"id\"",
                     range: ((1, 0), (1, 13)),
                 }
             ]);
         );
     }
+
+    mod pyproject_entry_points {
+        use tombi_test_lib::project_root_path;
+
+        use super::*;
+        use std::path::PathBuf;
+
+        fn fixture_path() -> PathBuf {
+            project_root_path().join("crates/tombi-lsp/tests/fixtures/pyproject-entry-points")
+        }
+
+        test_diagnostic_file!(
+            #[tokio::test]
+            async fn pyproject_scripts_report_missing_module_and_object(
+                SourcePath(fixture_path().join("pyproject.toml")),
+            ) -> Ok([
+                Diagnostic {
+                    message: "`run` is not defined at the top level of `mypkg.cli`",
+                    range: ((6, 23), (6, 38)),
+                },
+                Diagnostic {
+                    message: "Python module `mypkg.missing` not found in the project",
+                    range: ((7, 23), (7, 43)),
+                }
+            ]);
+        );
+    }
+
+    mod pyproject_lock_file {
+        use tombi_test_lib::project_root_path;
+
+        use super::*;
+
+        test_diagnostic_file!(
+            #[tokio::test]
+            async fn pyproject_dependencies_report_stale_poetry_lock(
+                SourcePath(
+                    project_root_path()
+                        .join("crates/tombi-lsp/tests/fixtures/pyproject-poetry-lock/pyproject.toml")
+                ),
+            ) -> Ok([
+                Diagnostic {
+                    message: "`requests` requirement \">=3.0\" is not satisfied by the locked version 2.31.0 in poetry.lock",
+                    range: ((4, 2), (4, 17)),
+                },
+                Diagnostic {
+                    message: "`httpx` is missing from poetry.lock",
+                    range: ((6, 2), (6, 15)),
+                }
+            ]);
+        );
+    }
 }
 
 // Unified test macro
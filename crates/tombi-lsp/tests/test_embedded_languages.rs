@@ -0,0 +1,92 @@
+mod embedded_languages {
+    use tombi_lsp::{
+        Backend,
+        handler::{handle_did_open, handle_embedded_languages},
+    };
+    use tombi_test_lib::project_root_path;
+    use tower_lsp::{
+        LspService,
+        lsp_types::{
+            DidOpenTextDocumentParams, Position, Range, TextDocumentIdentifier, TextDocumentItem,
+            Url,
+        },
+    };
+
+    async fn open_document(
+        backend: &Backend,
+        source: &str,
+    ) -> Result<(tempfile::TempDir, Url), Box<dyn std::error::Error>> {
+        let schema_path = project_root_path()
+            .join("crates/tombi-lsp/tests/fixtures/embedded-languages.schema.json");
+        let text = format!("#:schema {}\n{source}", schema_path.display());
+
+        let temp_dir = tempfile::tempdir()?;
+        let source_path = temp_dir.path().join("embedded-languages.toml");
+        std::fs::write(&source_path, &text)?;
+
+        let uri = Url::from_file_path(&source_path)
+            .map_err(|_| format!("Failed to convert path to URL: {}", source_path.display()))?;
+
+        handle_did_open(
+            backend,
+            DidOpenTextDocumentParams {
+                text_document: TextDocumentItem {
+                    uri: uri.clone(),
+                    language_id: "toml".to_string(),
+                    version: 0,
+                    text,
+                },
+            },
+        )
+        .await;
+
+        Ok((temp_dir, uri))
+    }
+
+    fn range(start: (u32, u32), end: (u32, u32)) -> Range {
+        Range::new(Position::new(start.0, start.1), Position::new(end.0, end.1))
+    }
+
+    #[tokio::test]
+    async fn returns_strings_with_embedded_languages() -> Result<(), Box<dyn std::error::Error>> {
+        tombi_test_lib::init_log();
+
+        let (service, _) = LspService::new(|client| Backend::new(client, &Default::default()));
+        let backend = service.inner();
+
+        let (_temp_dir, uri) = open_document(
+            backend,
+            r#"name = "tombi"
+query = "SELECT 1"
+config = '{"debug": true}'
+
+[tasks]
+build = "cargo build"
+test = ["cargo test", "cargo clippy"]
+"#,
+        )
+        .await?;
+
+        let response = handle_embedded_languages(backend, TextDocumentIdentifier { uri }).await?;
+
+        pretty_assertions::assert_eq!(
+            response
+                .embedded_languages
+                .iter()
+                .map(|embedded_language| (
+                    embedded_language.range,
+                    embedded_language.language.as_str()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (range((2, 8), (2, 18)), "sql"),
+                (range((3, 9), (3, 26)), "json"),
+                (range((6, 8), (6, 21)), "shellscript"),
+                (range((7, 8), (7, 20)), "shellscript"),
+                (range((7, 22), (7, 36)), "shellscript"),
+            ]
+        );
+
+        Ok(())
+    }
+}
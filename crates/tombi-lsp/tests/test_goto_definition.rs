@@ -868,6 +868,28 @@ mod goto_definition_tests {
             ) -> Ok([project_root_path().join("crates/tombi-lsp/tests/fixtures/pyproject_workspace/members/app1/pyproject.toml")]);
         );
 
+        test_goto_definition!(
+            #[tokio::test]
+            async fn tool_poetry_dependencies_path_dependency(
+                r#"
+                [tool.poetry.dependencies]
+                app1 = { path = "members/app1█", develop = true }
+                "#,
+                SourcePath(project_root_path().join("crates/tombi-lsp/tests/fixtures/pyproject_workspace/pyproject.toml")),
+            ) -> Ok([project_root_path().join("crates/tombi-lsp/tests/fixtures/pyproject_workspace/members/app1/pyproject.toml")]);
+        );
+
+        test_goto_definition!(
+            #[tokio::test]
+            async fn tool_poetry_group_dependencies_path_dependency_key(
+                r#"
+                [tool.poetry.group.dev.dependencies]
+                app1█ = { path = "members/app1" }
+                "#,
+                SourcePath(project_root_path().join("crates/tombi-lsp/tests/fixtures/pyproject_workspace/pyproject.toml")),
+            ) -> Ok([project_root_path().join("crates/tombi-lsp/tests/fixtures/pyproject_workspace/members/app1/pyproject.toml")]);
+        );
+
         test_goto_definition!(
             #[tokio::test]
             async fn tool_pyproject_workspace_members(
@@ -946,6 +968,38 @@ mod goto_definition_tests {
         );
     }
 
+    mod pyproject_entry_points {
+        use super::*;
+
+        fn pyproject_entry_points_fixtures_path() -> std::path::PathBuf {
+            project_root_path().join("crates/tombi-lsp/tests/fixtures/pyproject-entry-points")
+        }
+
+        test_goto_definition!(
+            #[tokio::test]
+            async fn project_scripts_object_reference(
+                r#"
+                [project.scripts]
+                mypkg = "mypkg.cli:main█"
+                "#,
+                SourcePath(pyproject_entry_points_fixtures_path().join("pyproject.toml")),
+            ) -> Ok([
+                (pyproject_entry_points_fixtures_path().join("src/mypkg/cli.py"), ((3, 4), (3, 8))),
+            ]);
+        );
+
+        test_goto_definition!(
+            #[tokio::test]
+            async fn project_entry_points_module_reference(
+                r#"
+                [project.entry-points."mypkg.plugins"]
+                default = "mypkg█"
+                "#,
+                SourcePath(pyproject_entry_points_fixtures_path().join("pyproject.toml")),
+            ) -> Ok([pyproject_entry_points_fixtures_path().join("src/mypkg/__init__.py")]);
+        );
+    }
+
     mod pyproject_workspace_dependencies {
         use super::*;
 
@@ -1268,6 +1268,26 @@ mod hover_keys_value {
         );
     }
 
+    mod vscode_extension_schema {
+        use tombi_test_lib::vscode_extension_test_schema_path;
+
+        use super::*;
+
+        test_hover_keys_value!(
+            #[tokio::test]
+            async fn markdown_description(
+                r#"
+                na█me = "tombi"
+                "#,
+                SchemaPath(vscode_extension_test_schema_path()),
+            ) -> Ok({
+                "Keys": "name",
+                "Value": "String?",
+                "Description": Some("The **name** of the server.")
+            });
+        );
+    }
+
     mod pyproject_schema {
         use super::*;
 
@@ -0,0 +1,413 @@
+mod schema_document {
+    use tombi_lsp::{
+        Backend,
+        handler::{
+            handle_diagnostic, handle_did_open, handle_goto_definition,
+            handle_list_associated_files, handle_references,
+        },
+    };
+    use tombi_test_lib::project_root_path;
+    use tower_lsp::{
+        LspService,
+        lsp_types::{
+            DidOpenTextDocumentParams, DocumentDiagnosticParams, DocumentDiagnosticReport,
+            DocumentDiagnosticReportResult, GotoDefinitionParams, PartialResultParams, Position,
+            ReferenceContext, ReferenceParams, TextDocumentIdentifier, TextDocumentItem,
+            TextDocumentPositionParams, Url, WorkDoneProgressParams,
+        },
+    };
+
+    type Ranges<T> = Vec<(T, ((u32, u32), (u32, u32)))>;
+
+    fn fixture_path(file_name: &str) -> std::path::PathBuf {
+        project_root_path()
+            .join("crates/tombi-lsp/tests/fixtures/schema-document")
+            .join(file_name)
+    }
+
+    fn fixture_uri(file_name: &str) -> Url {
+        Url::from_file_path(fixture_path(file_name)).unwrap()
+    }
+
+    /// Open a document, returning the position of the cursor marker (█).
+    async fn open_document(
+        backend: &Backend,
+        uri: &Url,
+        language_id: &str,
+        source: &str,
+    ) -> Option<Position> {
+        let mut text = textwrap::dedent(source).trim().to_string();
+        let position = text.find("█").map(|index| {
+            text.remove(index);
+            let line = text[..index].matches('\n').count();
+            let character = text[..index]
+                .rsplit('\n')
+                .next()
+                .unwrap_or_default()
+                .encode_utf16()
+                .count();
+            Position::new(line as u32, character as u32)
+        });
+
+        handle_did_open(
+            backend,
+            DidOpenTextDocumentParams {
+                text_document: TextDocumentItem {
+                    uri: uri.clone(),
+                    language_id: language_id.to_string(),
+                    version: 0,
+                    text,
+                },
+            },
+        )
+        .await;
+
+        position
+    }
+
+    async fn get_diagnostics(backend: &Backend, uri: &Url) -> Ranges<String> {
+        let result = handle_diagnostic(
+            backend,
+            DocumentDiagnosticParams {
+                text_document: TextDocumentIdentifier { uri: uri.clone() },
+                identifier: None,
+                previous_result_id: None,
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+            },
+        )
+        .await
+        .unwrap();
+
+        let DocumentDiagnosticReportResult::Report(DocumentDiagnosticReport::Full(report)) = result
+        else {
+            panic!("Unexpected diagnostic result: {result:?}");
+        };
+
+        report
+            .full_document_diagnostic_report
+            .items
+            .into_iter()
+            .map(|diagnostic| {
+                (
+                    diagnostic.message,
+                    (
+                        (
+                            diagnostic.range.start.line,
+                            diagnostic.range.start.character,
+                        ),
+                        (diagnostic.range.end.line, diagnostic.range.end.character),
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    fn position_params(uri: &Url, position: Position) -> TextDocumentPositionParams {
+        TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            position,
+        }
+    }
+
+    fn to_locations(locations: Option<Vec<tombi_extension::Location>>) -> Ranges<Url> {
+        locations
+            .unwrap_or_default()
+            .into_iter()
+            .map(|location| {
+                (
+                    location.uri.into(),
+                    (
+                        (location.range.start.line, location.range.start.column),
+                        (location.range.end.line, location.range.end.column),
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn reports_json_syntax_error() -> Result<(), Box<dyn std::error::Error>> {
+        tombi_test_lib::init_log();
+
+        let (service, _) = LspService::new(|client| Backend::new(client, &Default::default()));
+        let backend = service.inner();
+
+        let uri = fixture_uri("example.schema.json");
+        open_document(
+            backend,
+            &uri,
+            "json",
+            r#"
+            {
+              "type": "object"
+              "title": "Example"
+            }
+            "#,
+        )
+        .await;
+
+        pretty_assertions::assert_eq!(
+            get_diagnostics(backend, &uri).await,
+            vec![(
+                "Unexpected token: expected BRACE_END, got STRING".to_string(),
+                ((2, 2), (2, 9))
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn validates_against_meta_schema() -> Result<(), Box<dyn std::error::Error>> {
+        tombi_test_lib::init_log();
+
+        let (service, _) = LspService::new(|client| Backend::new(client, &Default::default()));
+        let backend = service.inner();
+
+        let uri = fixture_uri("example.schema.json");
+        open_document(
+            backend,
+            &uri,
+            "json",
+            r##"
+            {
+              "$schema": "./meta.json",
+              "type": "object",
+              "properties": {
+                "name": { "type": "strin" },
+                "age": { "$ref": "#/$defs/age" }
+              },
+              "x-vendor-extension": true
+            }
+            "##,
+        )
+        .await;
+
+        pretty_assertions::assert_eq!(
+            get_diagnostics(backend, &uri).await,
+            vec![
+                (
+                    "\"#/$defs/age\" does not resolve to a schema".to_string(),
+                    ((5, 21), (5, 34))
+                ),
+                (
+                    "the value must be one of [\"array\", \"boolean\", \"integer\", \"null\", \"number\", \"object\", \"string\"], but found \"strin\"".to_string(),
+                    ((4, 22), (4, 29))
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reports_null_not_allowed_by_meta_schema() -> Result<(), Box<dyn std::error::Error>> {
+        tombi_test_lib::init_log();
+
+        let (service, _) = LspService::new(|client| Backend::new(client, &Default::default()));
+        let backend = service.inner();
+
+        let uri = fixture_uri("example.schema.json");
+        open_document(
+            backend,
+            &uri,
+            "json",
+            r##"
+            {
+              "$schema": "./meta.json",
+              "title": null,
+              "type": "object"
+            }
+            "##,
+        )
+        .await;
+
+        pretty_assertions::assert_eq!(
+            get_diagnostics(backend, &uri).await,
+            vec![(
+                "expected a value of type String, but found null".to_string(),
+                ((2, 11), (2, 15))
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn goto_definition_of_local_ref() -> Result<(), Box<dyn std::error::Error>> {
+        tombi_test_lib::init_log();
+
+        let (service, _) = LspService::new(|client| Backend::new(client, &Default::default()));
+        let backend = service.inner();
+
+        let uri = fixture_uri("example.schema.json");
+        let position = open_document(
+            backend,
+            &uri,
+            "json",
+            r##"
+            {
+              "properties": {
+                "name": { "$ref": "#/$defs/n█ame" }
+              },
+              "$defs": {
+                "name": { "type": "string" }
+              }
+            }
+            "##,
+        )
+        .await
+        .unwrap();
+
+        let locations = handle_goto_definition(
+            backend,
+            GotoDefinitionParams {
+                text_document_position_params: position_params(&uri, position),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+            },
+        )
+        .await?;
+
+        pretty_assertions::assert_eq!(to_locations(locations), vec![(uri, ((5, 4), (5, 10)))]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn goto_definition_of_relative_file_ref() -> Result<(), Box<dyn std::error::Error>> {
+        tombi_test_lib::init_log();
+
+        let (service, _) = LspService::new(|client| Backend::new(client, &Default::default()));
+        let backend = service.inner();
+
+        let uri = fixture_uri("example.schema.json");
+        let position = open_document(
+            backend,
+            &uri,
+            "json",
+            r##"
+            {
+              "properties": {
+                "name": { "$ref": "./shared.schema.json#/$defs/█name" }
+              }
+            }
+            "##,
+        )
+        .await
+        .unwrap();
+
+        let locations = handle_goto_definition(
+            backend,
+            GotoDefinitionParams {
+                text_document_position_params: position_params(&uri, position),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+            },
+        )
+        .await?;
+
+        pretty_assertions::assert_eq!(
+            to_locations(locations),
+            vec![(fixture_uri("shared.schema.json"), ((3, 4), (3, 10)))]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn references_of_defs_entry() -> Result<(), Box<dyn std::error::Error>> {
+        tombi_test_lib::init_log();
+
+        let (service, _) = LspService::new(|client| Backend::new(client, &Default::default()));
+        let backend = service.inner();
+
+        let uri = fixture_uri("example.schema.json");
+        let position = open_document(
+            backend,
+            &uri,
+            "json",
+            r##"
+            {
+              "properties": {
+                "first": { "$ref": "#/$defs/name" },
+                "last": { "$ref": "#/$defs/name" },
+                "age": { "$ref": "#/$defs/age" }
+              },
+              "$defs": {
+                "na█me": { "type": "string" },
+                "age": { "type": "integer" }
+              }
+            }
+            "##,
+        )
+        .await
+        .unwrap();
+
+        let locations = handle_references(
+            backend,
+            ReferenceParams {
+                text_document_position: position_params(&uri, position),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+                context: ReferenceContext {
+                    include_declaration: true,
+                },
+            },
+        )
+        .await?;
+
+        pretty_assertions::assert_eq!(
+            to_locations(locations),
+            vec![
+                (uri.clone(), ((7, 4), (7, 10))),
+                (uri.clone(), ((2, 23), (2, 37))),
+                (uri, ((3, 22), (3, 36))),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn lists_associated_toml_files() -> Result<(), Box<dyn std::error::Error>> {
+        tombi_test_lib::init_log();
+
+        let (service, _) = LspService::new(|client| Backend::new(client, &Default::default()));
+        let backend = service.inner();
+
+        let temp_dir = tempfile::tempdir()?;
+        let schema_path = fixture_path("shared.schema.json");
+        let associated_uri = Url::from_file_path(temp_dir.path().join("associated.toml")).unwrap();
+        let unrelated_uri = Url::from_file_path(temp_dir.path().join("unrelated.toml")).unwrap();
+
+        open_document(
+            backend,
+            &associated_uri,
+            "toml",
+            &format!("#:schema {}\n\nname = \"tombi\"\n", schema_path.display()),
+        )
+        .await;
+        open_document(backend, &unrelated_uri, "toml", "name = \"tombi\"\n").await;
+
+        let response = handle_list_associated_files(
+            backend,
+            TextDocumentIdentifier {
+                uri: fixture_uri("shared.schema.json"),
+            },
+        )
+        .await?;
+
+        pretty_assertions::assert_eq!(
+            response
+                .files
+                .into_iter()
+                .map(|file| (file.uri, file.accessor))
+                .collect::<Vec<_>>(),
+            vec![(associated_uri, None)]
+        );
+
+        Ok(())
+    }
+}
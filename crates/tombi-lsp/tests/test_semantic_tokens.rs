@@ -0,0 +1,282 @@
+mod semantic_tokens {
+    use tombi_lsp::{
+        Backend,
+        handler::{
+            handle_did_change, handle_did_open, handle_initialize, handle_semantic_tokens_full,
+            handle_semantic_tokens_full_delta, handle_semantic_tokens_range,
+        },
+    };
+    use tombi_test_lib::project_root_path;
+    use tower_lsp::{
+        LspService,
+        lsp_types::{
+            DidChangeTextDocumentParams, DidOpenTextDocumentParams, InitializeParams, Position,
+            Range, SemanticTokenModifier, SemanticTokens, SemanticTokensDeltaParams,
+            SemanticTokensFullDeltaResult, SemanticTokensParams, SemanticTokensRangeParams,
+            SemanticTokensRangeResult, SemanticTokensResult, SemanticTokensServerCapabilities,
+            TextDocumentContentChangeEvent, TextDocumentIdentifier, TextDocumentItem, Url,
+            VersionedTextDocumentIdentifier,
+        },
+    };
+
+    const SOURCE: &str = r#"name = "tombi"
+edition = "2021"
+legacy = true
+id = 1
+script = "cargo build"
+unknown = 1
+"#;
+
+    struct TestDocument {
+        _temp_dir: tempfile::TempDir,
+        uri: Url,
+        modifiers: Vec<SemanticTokenModifier>,
+    }
+
+    async fn open_document(
+        backend: &Backend,
+        source: &str,
+    ) -> Result<TestDocument, Box<dyn std::error::Error>> {
+        let initialize_result = handle_initialize(backend, InitializeParams::default()).await?;
+        let Some(SemanticTokensServerCapabilities::SemanticTokensOptions(options)) =
+            initialize_result.capabilities.semantic_tokens_provider
+        else {
+            panic!("expected semantic tokens options");
+        };
+
+        let schema_path =
+            project_root_path().join("crates/tombi-lsp/tests/fixtures/semantic-tokens.schema.json");
+        let text = format!("#:schema {}\n{source}", schema_path.display());
+
+        let temp_dir = tempfile::tempdir()?;
+        let source_path = temp_dir.path().join("semantic-tokens.toml");
+        std::fs::write(&source_path, &text)?;
+
+        let uri = Url::from_file_path(&source_path)
+            .map_err(|_| format!("Failed to convert path to URL: {}", source_path.display()))?;
+
+        handle_did_open(
+            backend,
+            DidOpenTextDocumentParams {
+                text_document: TextDocumentItem {
+                    uri: uri.clone(),
+                    language_id: "toml".to_string(),
+                    version: 0,
+                    text,
+                },
+            },
+        )
+        .await;
+
+        Ok(TestDocument {
+            _temp_dir: temp_dir,
+            uri,
+            modifiers: options.legend.token_modifiers,
+        })
+    }
+
+    /// The modifier names of each token that has modifiers, with its absolute line.
+    fn modified_tokens(
+        tokens: &SemanticTokens,
+        modifiers: &[SemanticTokenModifier],
+    ) -> Vec<(u32, Vec<String>)> {
+        let mut line = 0;
+        tokens
+            .data
+            .iter()
+            .filter_map(|token| {
+                line += token.delta_line;
+                (token.token_modifiers_bitset != 0).then(|| {
+                    (
+                        line,
+                        modifiers
+                            .iter()
+                            .enumerate()
+                            .filter(|(index, _)| token.token_modifiers_bitset & (1 << index) != 0)
+                            .map(|(_, modifier)| modifier.as_str().to_string())
+                            .collect(),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    async fn full_tokens(
+        backend: &Backend,
+        uri: &Url,
+    ) -> Result<SemanticTokens, Box<dyn std::error::Error>> {
+        let Some(SemanticTokensResult::Tokens(tokens)) = handle_semantic_tokens_full(
+            backend,
+            SemanticTokensParams {
+                text_document: TextDocumentIdentifier { uri: uri.clone() },
+                work_done_progress_params: Default::default(),
+                partial_result_params: Default::default(),
+            },
+        )
+        .await?
+        else {
+            panic!("expected semantic tokens");
+        };
+
+        Ok(tokens)
+    }
+
+    #[tokio::test]
+    async fn full_has_schema_modifiers() -> Result<(), Box<dyn std::error::Error>> {
+        tombi_test_lib::init_log();
+
+        let (service, _) = LspService::new(|client| Backend::new(client, &Default::default()));
+        let backend = service.inner();
+        let document = open_document(backend, SOURCE).await?;
+
+        let tokens = full_tokens(backend, &document.uri).await?;
+
+        pretty_assertions::assert_eq!(
+            modified_tokens(&tokens, &document.modifiers),
+            vec![
+                (2, vec!["defaultValue".to_string()]),
+                (3, vec!["deprecated".to_string()]),
+                (4, vec!["readonly".to_string()]),
+                (5, vec!["embedded".to_string()]),
+                (6, vec!["notAllowed".to_string()]),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn range_returns_tokens_in_range() -> Result<(), Box<dyn std::error::Error>> {
+        tombi_test_lib::init_log();
+
+        let (service, _) = LspService::new(|client| Backend::new(client, &Default::default()));
+        let backend = service.inner();
+        let document = open_document(backend, SOURCE).await?;
+
+        let Some(SemanticTokensRangeResult::Tokens(tokens)) = handle_semantic_tokens_range(
+            backend,
+            SemanticTokensRangeParams {
+                text_document: TextDocumentIdentifier {
+                    uri: document.uri.clone(),
+                },
+                range: Range::new(Position::new(3, 0), Position::new(5, 0)),
+                work_done_progress_params: Default::default(),
+                partial_result_params: Default::default(),
+            },
+        )
+        .await?
+        else {
+            panic!("expected semantic tokens");
+        };
+
+        // `legacy = true` and `id = 1`, each with a key, `=` and a value.
+        pretty_assertions::assert_eq!(tokens.data.len(), 6);
+        pretty_assertions::assert_eq!(
+            modified_tokens(&tokens, &document.modifiers),
+            vec![
+                (3, vec!["deprecated".to_string()]),
+                (4, vec!["readonly".to_string()]),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn range_skips_tables_out_of_range() -> Result<(), Box<dyn std::error::Error>> {
+        tombi_test_lib::init_log();
+
+        let (service, _) = LspService::new(|client| Backend::new(client, &Default::default()));
+        let backend = service.inner();
+        let document = open_document(backend, "[a]\nx = 1\n\n[b]\ny = 2\n").await?;
+
+        let Some(SemanticTokensRangeResult::Tokens(tokens)) = handle_semantic_tokens_range(
+            backend,
+            SemanticTokensRangeParams {
+                text_document: TextDocumentIdentifier {
+                    uri: document.uri.clone(),
+                },
+                range: Range::new(Position::new(5, 0), Position::new(6, 0)),
+                work_done_progress_params: Default::default(),
+                partial_result_params: Default::default(),
+            },
+        )
+        .await?
+        else {
+            panic!("expected semantic tokens");
+        };
+
+        // `y = 2`, encoded from the start of the document.
+        pretty_assertions::assert_eq!(
+            tokens
+                .data
+                .iter()
+                .map(|token| (token.delta_line, token.delta_start, token.length))
+                .collect::<Vec<_>>(),
+            vec![(5, 0, 1), (0, 2, 1), (0, 2, 1)]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn full_delta_returns_edits_from_previous_result()
+    -> Result<(), Box<dyn std::error::Error>> {
+        tombi_test_lib::init_log();
+
+        let (service, _) = LspService::new(|client| Backend::new(client, &Default::default()));
+        let backend = service.inner();
+        let document = open_document(backend, SOURCE).await?;
+
+        let previous_tokens = full_tokens(backend, &document.uri).await?;
+
+        let text = std::fs::read_to_string(document.uri.to_file_path().unwrap())?
+            .replace("edition = \"2021\"", "edition = \"2024\"");
+        handle_did_change(
+            backend,
+            DidChangeTextDocumentParams {
+                text_document: VersionedTextDocumentIdentifier {
+                    uri: document.uri.clone(),
+                    version: 1,
+                },
+                content_changes: vec![TextDocumentContentChangeEvent {
+                    range: None,
+                    range_length: None,
+                    text,
+                }],
+            },
+        )
+        .await;
+
+        let Some(SemanticTokensFullDeltaResult::TokensDelta(delta)) =
+            handle_semantic_tokens_full_delta(
+                backend,
+                SemanticTokensDeltaParams {
+                    text_document: TextDocumentIdentifier {
+                        uri: document.uri.clone(),
+                    },
+                    previous_result_id: previous_tokens.result_id.clone().unwrap(),
+                    work_done_progress_params: Default::default(),
+                    partial_result_params: Default::default(),
+                },
+            )
+            .await?
+        else {
+            panic!("expected semantic tokens delta");
+        };
+
+        // Only the `defaultValue` modifier of "2021" is removed.
+        pretty_assertions::assert_eq!(delta.edits.len(), 1);
+        pretty_assertions::assert_eq!(delta.edits[0].delete_count, 5);
+        pretty_assertions::assert_eq!(
+            delta.edits[0]
+                .data
+                .as_ref()
+                .map(|tokens| tokens[0].token_modifiers_bitset),
+            Some(0)
+        );
+        assert_ne!(delta.result_id, previous_tokens.result_id);
+
+        Ok(())
+    }
+}
@@ -6,6 +6,8 @@ mod output;
 mod parse;
 mod parsed;
 mod parser;
+mod reparse;
+mod stream;
 mod support;
 mod token_set;
 
@@ -15,6 +17,7 @@ use itertools::Itertools;
 use output::Output;
 use parse::Parse;
 pub use parsed::Parsed;
+pub use stream::{Chunk, ParseStream, parse_stream};
 pub use tombi_syntax::{SyntaxKind, SyntaxNode, SyntaxToken};
 
 pub fn parse(source: &str) -> Parsed<SyntaxNode> {
@@ -5,7 +5,7 @@ use tombi_syntax::SyntaxNode;
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Parsed<T> {
-    green_tree: tombi_rg_tree::GreenNode,
+    pub(crate) green_tree: tombi_rg_tree::GreenNode,
     pub errors: Vec<crate::Error>,
     pub line_ending: tombi_text::LineEnding,
     _ty: PhantomData<fn() -> T>,
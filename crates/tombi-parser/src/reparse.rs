@@ -0,0 +1,309 @@
+use itertools::Itertools;
+use tombi_rg_tree::NodeOrToken;
+use tombi_syntax::{SyntaxKind::*, SyntaxNode, T};
+use tombi_text::{LineEnding, Position, RelativePosition};
+
+use crate::{ErrorKind, Parsed};
+
+impl Parsed<SyntaxNode> {
+    /// Reparse the source after replacing `span` of it with `new_text`.
+    ///
+    /// When the edit is confined to the body of a table, an array of tables or a key-value group,
+    /// only that block is reparsed and the green nodes of the rest of the document are reused.
+    /// Otherwise, the whole source is parsed again.
+    pub fn reparse(&self, span: tombi_text::Span, new_text: &str) -> Parsed<SyntaxNode> {
+        if let Some(parsed) = reparse_block(self, span, new_text) {
+            return parsed;
+        }
+
+        let mut source = self.syntax_node().to_string();
+        source.replace_range(std::ops::Range::<usize>::from(span), new_text);
+
+        crate::parse(&source)
+    }
+}
+
+fn reparse_block(
+    parsed: &Parsed<SyntaxNode>,
+    span: tombi_text::Span,
+    new_text: &str,
+) -> Option<Parsed<SyntaxNode>> {
+    let root = parsed.syntax_node();
+    if !root.span().contains_span(span) {
+        return None;
+    }
+
+    let covering_node = match root.covering_element(span) {
+        NodeOrToken::Node(node) => node,
+        NodeOrToken::Token(token) => token.parent()?,
+    };
+    let node = covering_node
+        .ancestors()
+        .find(|node| is_reparsable_block(node, span))?;
+
+    let node_span = node.span();
+    let node_range = node.range();
+    let relative_span = std::ops::Range::<usize>::from(span - node_span.start);
+
+    let mut block_text = node.text().to_string();
+    // The lexer only records whether CRLF appears somewhere, so removing one needs a full parse.
+    if parsed.line_ending == LineEnding::Crlf && block_text[relative_span.clone()].contains('\r') {
+        return None;
+    }
+    block_text.replace_range(relative_span, new_text);
+
+    let block = match node.kind() {
+        KEY_VALUE_GROUP => crate::parse_as::<tombi_ast::KeyValueGroup>(&block_text),
+        TABLE => crate::parse_as::<tombi_ast::Table>(&block_text),
+        ARRAY_OF_TABLE => crate::parse_as::<tombi_ast::ArrayOfTable>(&block_text),
+        _ => return None,
+    };
+
+    let block_node = block.syntax_node();
+    if block_node.kind() != node.kind()
+        || usize::from(block_node.span().end) != block_text.len()
+        || !is_same_block_shape(&node, &block_node)
+    {
+        return None;
+    }
+
+    let new_end = node_range.start + RelativePosition::of(&block_text);
+    if block.errors.iter().any(|error| {
+        // An unterminated multi-line string would run past the block in the whole document,
+        // and errors at the end of the block depend on the token that follows it.
+        matches!(
+            error.kind(),
+            ErrorKind::InvalidMultilineBasicString | ErrorKind::InvalidMultilineLiteralString
+        ) || node_range.start + RelativePosition::from(error.range().end) >= new_end
+    }) {
+        return None;
+    }
+
+    let old_end = node_range.end;
+    let errors = parsed
+        .errors
+        .iter()
+        .filter(|error| error.range().start < node_range.start)
+        .cloned()
+        .chain(block.errors.iter().map(|error| {
+            crate::Error::new(
+                error.kind(),
+                tombi_text::Range::new(
+                    node_range.start + RelativePosition::from(error.range().start),
+                    node_range.start + RelativePosition::from(error.range().end),
+                ),
+            )
+        }))
+        .chain(
+            parsed
+                .errors
+                .iter()
+                .filter(|error| error.range().start >= old_end)
+                .map(|error| {
+                    crate::Error::new(
+                        error.kind(),
+                        tombi_text::Range::new(
+                            shift_position(error.range().start, old_end, new_end),
+                            shift_position(error.range().end, old_end, new_end),
+                        ),
+                    )
+                }),
+        )
+        .collect_vec();
+
+    let line_ending = if block.line_ending == LineEnding::Crlf {
+        LineEnding::Crlf
+    } else {
+        parsed.line_ending
+    };
+
+    Some(Parsed::new(
+        node.replace_with(block.green_tree),
+        errors,
+        line_ending,
+    ))
+}
+
+/// Whether `node` can be reparsed on its own for an edit of `span`.
+///
+/// The boundaries of a block depend on the surrounding text,
+/// so the edit must stay strictly inside the block, which must start and end at line boundaries.
+fn is_reparsable_block(node: &SyntaxNode, span: tombi_text::Span) -> bool {
+    let node_span = node.span();
+    if !(node_span.start < span.start && span.end < node_span.end) {
+        return false;
+    }
+
+    match node.kind() {
+        KEY_VALUE_GROUP => {}
+        // The header decides which kind of block this is, so only the body is reparsed.
+        TABLE | ARRAY_OF_TABLE => {
+            let Some(header_end) = node
+                .children_with_tokens()
+                .find(|child| matches!(child.kind(), T![']'] | T!("]]")))
+            else {
+                return false;
+            };
+            if span.start < header_end.span().end {
+                return false;
+            }
+        }
+        _ => return false,
+    }
+
+    let starts_line = match node.first_token().and_then(|token| token.prev_token()) {
+        None => true,
+        Some(token) if token.kind() == LINE_BREAK => true,
+        Some(token) if token.kind() == WHITESPACE => token
+            .prev_token()
+            .is_none_or(|token| token.kind() == LINE_BREAK),
+        Some(_) => false,
+    };
+    let ends_line = node
+        .last_token()
+        .and_then(|token| token.next_token())
+        .is_none_or(|token| token.kind() == LINE_BREAK);
+
+    starts_line && ends_line
+}
+
+/// Comments at the edges of a block may belong to the neighbouring block in the whole document,
+/// so the first and last tokens must keep their kinds.
+fn is_same_block_shape(old_node: &SyntaxNode, new_node: &SyntaxNode) -> bool {
+    let first_kinds = (
+        old_node.first_token().map(|token| token.kind()),
+        new_node.first_token().map(|token| token.kind()),
+    );
+    let last_kinds = (
+        old_node.last_token().map(|token| token.kind()),
+        new_node.last_token().map(|token| token.kind()),
+    );
+
+    first_kinds.0 == first_kinds.1 && last_kinds.0 == last_kinds.1 && last_kinds.1 != Some(COMMENT)
+}
+
+fn shift_position(position: Position, old_end: Position, new_end: Position) -> Position {
+    if position.line == old_end.line {
+        Position::new(
+            new_end.line,
+            new_end.column + position.column - old_end.column,
+        )
+    } else {
+        Position::new(position.line + new_end.line - old_end.line, position.column)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+    use tombi_text::{Offset, Span};
+
+    const SOURCE: &str = r#"# document comment
+
+key1 = "value1"
+key2 = [1, 2, 3]
+
+# table leading comment
+[table]
+key3 = { a = 1, b = "2" }
+key4 = """
+multi-line
+"""
+
+# dangling comment
+
+[[array]]
+key5 = 1979-05-27T07:32:00Z # trailing comment
+key6 = [
+  1,
+  2,
+]
+
+[[array]]
+key7 = 'literal'
+"#;
+
+    fn sources() -> [String; 2] {
+        [SOURCE.to_string(), SOURCE.replace('\n', "\r\n")]
+    }
+
+    fn assert_same_as_full_parse(source: &str, span: Span, new_text: &str) {
+        let mut new_source = source.to_string();
+        new_source.replace_range(std::ops::Range::<usize>::from(span), new_text);
+
+        let expected = crate::parse(&new_source);
+        let actual = crate::parse(source).reparse(span, new_text);
+
+        let sort_errors = |errors: &[crate::Error]| {
+            errors
+                .iter()
+                .map(|error| (error.range().start, error.range().end, error.code()))
+                .sorted()
+                .collect_vec()
+        };
+
+        pretty_assertions::assert_eq!(
+            format!("{:#?}", actual.syntax_node()),
+            format!("{:#?}", expected.syntax_node()),
+            "edit {span:?} with {new_text:?}"
+        );
+        pretty_assertions::assert_eq!(
+            sort_errors(&actual.errors),
+            sort_errors(&expected.errors),
+            "edit {span:?} with {new_text:?}"
+        );
+        assert_eq!(actual.line_ending, expected.line_ending);
+    }
+
+    #[test]
+    fn reparse_insertions_match_full_parse() {
+        for source in sources() {
+            for (offset, _) in source.char_indices() {
+                for new_text in [
+                    "x", " ", "\n", "\n\n", "#", "\"", "'", "\"\"\"", "'''", "=", ",", "[", "]",
+                    "[[", "{", "}", "1", "\r\n",
+                ] {
+                    let span = Span::empty(Offset::new(offset as u32));
+                    assert_same_as_full_parse(&source, span, new_text);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn reparse_deletions_match_full_parse() {
+        for source in sources() {
+            for (offset, c) in source.char_indices() {
+                for len in [c.len_utf8(), c.len_utf8() + 1] {
+                    if offset + len > source.len() {
+                        continue;
+                    }
+                    let span = Span::at(Offset::new(offset as u32), len as u32);
+                    assert_same_as_full_parse(&source, span, "");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn reparse_reuses_untouched_blocks() {
+        let parsed = crate::parse(SOURCE);
+        let offset = SOURCE.find("key5 = ").unwrap() + "key5 = ".len();
+        let reparsed = parsed.reparse(Span::at(Offset::new(offset as u32), 4), "2000");
+
+        let old_root = parsed.syntax_node();
+        let new_root = reparsed.syntax_node();
+        let old_table = old_root
+            .children()
+            .find(|node| node.kind() == tombi_syntax::SyntaxKind::TABLE);
+        let new_table = new_root
+            .children()
+            .find(|node| node.kind() == tombi_syntax::SyntaxKind::TABLE);
+
+        assert!(std::ptr::eq(
+            &*old_table.unwrap().green(),
+            &*new_table.unwrap().green()
+        ));
+        assert!(new_root.to_string().contains("key5 = 2000-05-27T07:32:00Z"));
+    }
+}
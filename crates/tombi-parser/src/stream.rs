@@ -0,0 +1,384 @@
+use std::io::BufRead;
+
+use itertools::Itertools;
+use tombi_syntax::SyntaxNode;
+use tombi_text::{Offset, Position, RelativePosition};
+
+use crate::Parsed;
+
+/// Parse TOML read from `reader` one table or array of tables at a time.
+///
+/// The source is split at the top-level headers, so only the text of one chunk is held in memory.
+/// The first chunk holds the key-values before the first header (and the first table
+/// when there are no such key-values), and every following chunk holds one table or array of tables
+/// together with its leading comments.
+pub fn parse_stream<R: BufRead>(reader: R) -> ParseStream<R> {
+    ParseStream {
+        reader,
+        scanner: LineScanner::default(),
+        buffer: String::new(),
+        leading_comments_start: None,
+        has_item: false,
+        position: Position::MIN,
+        offset: Offset::new(0),
+        finished: false,
+    }
+}
+
+pub struct ParseStream<R> {
+    reader: R,
+    scanner: LineScanner,
+    buffer: String,
+    /// Start of the comment lines directly before the next header,
+    /// which are moved to the next chunk as the leading comments of its table.
+    leading_comments_start: Option<usize>,
+    has_item: bool,
+    position: Position,
+    offset: Offset,
+    finished: bool,
+}
+
+impl<R: BufRead> Iterator for ParseStream<R> {
+    type Item = std::io::Result<Chunk>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let mut line = String::new();
+        loop {
+            line.clear();
+            match self.reader.read_line(&mut line) {
+                Ok(0) => {
+                    self.finished = true;
+                    return Some(Ok(self.take_chunk(self.buffer.len())));
+                }
+                Ok(_) => {}
+                Err(error) => {
+                    self.finished = true;
+                    return Some(Err(error));
+                }
+            }
+
+            let line_kind = self.scanner.scan_line(&line);
+            let chunk = if line_kind == LineKind::Header && self.has_item {
+                let chunk_end = self
+                    .leading_comments_start
+                    .take()
+                    .unwrap_or(self.buffer.len());
+                Some(self.take_chunk(chunk_end))
+            } else {
+                None
+            };
+
+            match line_kind {
+                LineKind::Comment => {
+                    self.leading_comments_start.get_or_insert(self.buffer.len());
+                }
+                LineKind::Blank => self.leading_comments_start = None,
+                LineKind::Header | LineKind::Other => {
+                    self.leading_comments_start = None;
+                    self.has_item = true;
+                }
+            }
+            self.buffer.push_str(&line);
+
+            if let Some(chunk) = chunk {
+                return Some(Ok(chunk));
+            }
+        }
+    }
+}
+
+impl<R> ParseStream<R> {
+    fn take_chunk(&mut self, end: usize) -> Chunk {
+        let rest = self.buffer.split_off(end);
+        let text = std::mem::replace(&mut self.buffer, rest);
+        if let Some(leading_comments_start) = self.leading_comments_start.as_mut() {
+            *leading_comments_start -= end;
+        }
+        self.has_item = false;
+
+        let position = self.position;
+        let offset = self.offset;
+        self.position = Position::new(
+            position.line + text.bytes().filter(|byte| *byte == b'\n').count() as u32,
+            0,
+        );
+        self.offset = offset + Offset::of(&text);
+
+        let mut parsed = crate::parse(&text);
+        parsed.errors = parsed
+            .errors
+            .iter()
+            .map(|error| {
+                crate::Error::new(
+                    error.kind(),
+                    tombi_text::Range::new(
+                        position + RelativePosition::from(error.range().start),
+                        position + RelativePosition::from(error.range().end),
+                    ),
+                )
+            })
+            .collect_vec();
+
+        Chunk {
+            parsed,
+            position,
+            offset,
+        }
+    }
+}
+
+/// A part of a streamed source, parsed as a document on its own.
+#[derive(Debug, Clone)]
+pub struct Chunk {
+    parsed: Parsed<SyntaxNode>,
+    position: Position,
+    offset: Offset,
+}
+
+impl Chunk {
+    /// The position of the chunk in the whole source.
+    #[inline]
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// The byte offset of the chunk in the whole source.
+    #[inline]
+    pub fn offset(&self) -> Offset {
+        self.offset
+    }
+
+    /// The syntax tree of the chunk.
+    ///
+    /// Its ranges are positions in the whole source, and its spans are offsets in the chunk.
+    pub fn syntax_node(&self) -> SyntaxNode {
+        SyntaxNode::new_root_at(self.parsed.green_tree.clone(), self.position)
+    }
+
+    /// The syntax errors of the chunk, with ranges in the whole source.
+    #[inline]
+    pub fn errors(&self) -> &[crate::Error] {
+        &self.parsed.errors
+    }
+
+    #[inline]
+    pub fn line_ending(&self) -> tombi_text::LineEnding {
+        self.parsed.line_ending
+    }
+
+    pub fn into_root_and_errors(self) -> (tombi_ast::Root, Vec<crate::Error>) {
+        use tombi_ast::AstNode;
+
+        let root = tombi_ast::Root::cast(self.syntax_node()).unwrap();
+        (root, self.parsed.errors)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LineKind {
+    Header,
+    Comment,
+    Blank,
+    Other,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum ScanState {
+    #[default]
+    Normal,
+    MultilineBasicString,
+    MultilineLiteralString,
+}
+
+/// Tracks just enough of the lexical state to tell whether a line starts at the top level.
+#[derive(Debug, Default)]
+struct LineScanner {
+    state: ScanState,
+    bracket_depth: usize,
+}
+
+impl LineScanner {
+    fn scan_line(&mut self, line: &str) -> LineKind {
+        let line_kind = if self.state == ScanState::Normal && self.bracket_depth == 0 {
+            match line.trim_start_matches([' ', '\t']).as_bytes().first() {
+                Some(b'[') => LineKind::Header,
+                Some(b'#') => LineKind::Comment,
+                None | Some(b'\r' | b'\n') => LineKind::Blank,
+                Some(_) => LineKind::Other,
+            }
+        } else {
+            LineKind::Other
+        };
+
+        let bytes = line.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            match self.state {
+                ScanState::Normal => match bytes[i] {
+                    b'#' => break,
+                    b'"' if bytes[i..].starts_with(b"\"\"\"") => {
+                        self.state = ScanState::MultilineBasicString;
+                        i += 3;
+                        continue;
+                    }
+                    b'\'' if bytes[i..].starts_with(b"'''") => {
+                        self.state = ScanState::MultilineLiteralString;
+                        i += 3;
+                        continue;
+                    }
+                    b'"' => {
+                        i += 1;
+                        while i < bytes.len() && bytes[i] != b'"' {
+                            i += if bytes[i] == b'\\' { 2 } else { 1 };
+                        }
+                    }
+                    b'\'' => {
+                        i += 1;
+                        while i < bytes.len() && bytes[i] != b'\'' {
+                            i += 1;
+                        }
+                    }
+                    b'[' | b'{' => self.bracket_depth += 1,
+                    b']' | b'}' => self.bracket_depth = self.bracket_depth.saturating_sub(1),
+                    _ => {}
+                },
+                ScanState::MultilineBasicString => {
+                    if bytes[i] == b'\\' {
+                        i += 2;
+                        continue;
+                    }
+                    if bytes[i..].starts_with(b"\"\"\"") {
+                        self.state = ScanState::Normal;
+                        i += 3;
+                        // A closing delimiter may be preceded by up to two quotes of the content.
+                        i += bytes[i..]
+                            .iter()
+                            .take(2)
+                            .take_while(|b| **b == b'"')
+                            .count();
+                        continue;
+                    }
+                }
+                ScanState::MultilineLiteralString => {
+                    if bytes[i..].starts_with(b"'''") {
+                        self.state = ScanState::Normal;
+                        i += 3;
+                        i += bytes[i..]
+                            .iter()
+                            .take(2)
+                            .take_while(|b| **b == b'\'')
+                            .count();
+                        continue;
+                    }
+                }
+            }
+            i += 1;
+        }
+
+        // A header never continues on the next line, even if it is broken.
+        if line_kind == LineKind::Header && self.state == ScanState::Normal {
+            self.bracket_depth = 0;
+        }
+
+        line_kind
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+    use tombi_ast::AstNode;
+    use tombi_text::Position;
+
+    use super::parse_stream;
+
+    const SOURCE: &str = r#"#:schema ./schema.json
+
+key1 = "value1"
+key2 = [
+  [1, 2],
+  { a = 1 },
+]
+
+# table leading comment
+[table]
+key3 = """
+[not-a-table]
+"""
+key4 = '''
+[[not-an-array-of-tables]]'''
+
+# dangling comment
+
+[[array]]
+key5 = "]"
+
+[[array]]
+key6 = ["[", '[']
+"#;
+
+    fn chunk_texts(source: &str) -> Vec<String> {
+        parse_stream(source.as_bytes())
+            .map(|chunk| chunk.unwrap().syntax_node().to_string())
+            .collect_vec()
+    }
+
+    #[test]
+    fn splits_source_at_top_level_headers() {
+        let chunks = chunk_texts(SOURCE);
+
+        pretty_assertions::assert_eq!(chunks.concat(), SOURCE);
+        pretty_assertions::assert_eq!(
+            chunks
+                .iter()
+                .map(|chunk| chunk.lines().find(|line| line.starts_with('[')))
+                .collect_vec(),
+            vec![None, Some("[table]"), Some("[[array]]"), Some("[[array]]")]
+        );
+        assert!(chunks[1].starts_with("# table leading comment\n[table]"));
+    }
+
+    #[test]
+    fn keeps_leading_tables_in_first_chunk() {
+        let source = "#:schema ./schema.json\n[table]\nkey = 1\n[other]\n";
+
+        pretty_assertions::assert_eq!(
+            chunk_texts(source),
+            vec!["#:schema ./schema.json\n[table]\nkey = 1\n", "[other]\n"]
+        );
+        pretty_assertions::assert_eq!(chunk_texts(""), vec![""]);
+    }
+
+    #[test]
+    fn chunks_have_positions_in_whole_source() {
+        let chunks = parse_stream(SOURCE.as_bytes())
+            .map(Result::unwrap)
+            .collect_vec();
+        let table = &chunks[1];
+
+        assert_eq!(table.position(), Position::new(8, 0));
+        assert_eq!(
+            usize::from(table.offset()),
+            SOURCE.find("# table leading comment").unwrap()
+        );
+
+        let (root, errors) = table.clone().into_root_and_errors();
+        assert!(errors.is_empty());
+        let header = root.table_or_array_of_tables().next().unwrap();
+        assert_eq!(header.syntax().range().start, Position::new(8, 0));
+    }
+
+    #[test]
+    fn chunk_errors_have_positions_in_whole_source() {
+        let source = "[table]\nkey = 1\n\n[other]\nkey = \n";
+        let errors = parse_stream(source.as_bytes())
+            .flat_map(|chunk| chunk.unwrap().errors().to_vec())
+            .collect_vec();
+
+        pretty_assertions::assert_eq!(errors, crate::parse(source).errors);
+    }
+}
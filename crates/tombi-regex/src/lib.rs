@@ -84,4 +84,30 @@ impl Regex {
             self.0.find(text).map(Match::from)
         }
     }
+
+    /// Find the first match and return the span of its capture group `group`.
+    #[inline]
+    pub fn find_capture(&self, text: &str, group: usize) -> Option<Match> {
+        #[cfg(all(not(feature = "regex"), feature = "fancy-regex"))]
+        {
+            self.0
+                .captures(text)
+                .ok()
+                .flatten()
+                .and_then(|captures| captures.get(group).map(Match::from))
+        }
+
+        #[cfg(feature = "regex")]
+        {
+            self.0
+                .captures(text)
+                .and_then(|captures| captures.get(group).map(Match::from))
+        }
+    }
+
+    /// The number of capture groups, including the implicit group 0 for the whole match.
+    #[inline]
+    pub fn captures_len(&self) -> usize {
+        self.0.captures_len()
+    }
 }
@@ -292,7 +292,7 @@ impl NodeData {
                     }
                 }
             }
-            ptr::NonNull::new_unchecked(Box::into_raw(Box::new(res)))
+            Box::into_non_null(Box::new(res))
         }
     }
 
@@ -622,6 +622,19 @@ impl SyntaxNode {
         }
     }
 
+    /// Creates an immutable root for a fragment of a larger source that starts at `position`.
+    ///
+    /// Only the positions are shifted; the offsets stay relative to the fragment.
+    pub fn new_root_at(green: GreenNode, position: tombi_text::Position) -> SyntaxNode {
+        let green = GreenNode::into_raw(green);
+        let green = Green::Node {
+            ptr: Cell::new(green),
+        };
+        SyntaxNode {
+            ptr: NodeData::new(None, 0, tombi_text::Offset::new(0), position, green, false),
+        }
+    }
+
     pub fn new_root_mut(green: GreenNode) -> SyntaxNode {
         let green = GreenNode::into_raw(green);
         let green = Green::Node {
@@ -52,6 +52,9 @@ impl<L: Language> RedNode<L> {
     pub fn new_root(green: GreenNode) -> RedNode<L> {
         RedNode::from(cursor::SyntaxNode::new_root(green))
     }
+    pub fn new_root_at(green: GreenNode, position: tombi_text::Position) -> RedNode<L> {
+        RedNode::from(cursor::SyntaxNode::new_root_at(green, position))
+    }
     pub fn new_root_mut(green: GreenNode) -> RedNode<L> {
         RedNode::from(cursor::SyntaxNode::new_root_mut(green))
     }
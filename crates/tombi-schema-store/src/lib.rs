@@ -7,6 +7,8 @@ pub mod macros;
 mod options;
 mod schema;
 mod store;
+#[cfg(feature = "toml-schema")]
+mod toml_schema;
 mod value_type;
 mod x_taplo;
 
@@ -209,6 +211,14 @@ pub fn get_tombi_schemastore_content(schema_uri: &tombi_uri::Uri) -> Option<&'st
             ))),
             _ => None,
         },
+        Some(tombi_uri::json_schema_hostname!()) => match schema_uri.path() {
+            "/draft-07/schema" => Some(include_str!(concat!(
+                "../../../",
+                tombi_uri::json_schema_hostname!(),
+                "/draft-07/schema.json"
+            ))),
+            _ => None,
+        },
 
         // TODO: Remove this deprecated uri after v1.0.0 release.
         None => match schema_uri.path() {
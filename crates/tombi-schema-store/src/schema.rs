@@ -3,8 +3,10 @@ mod any_of_schema;
 mod any_schema;
 mod array_schema;
 mod boolean_schema;
+mod default_snippet;
 mod deprecation;
 mod document_schema;
+mod error_message;
 mod float_schema;
 mod if_then_else_schema;
 mod integer_schema;
@@ -22,6 +24,7 @@ mod semantic_schema;
 mod source_schema;
 mod string_schema;
 mod table_schema;
+mod validation_cache;
 
 use std::sync::Arc;
 
@@ -29,10 +32,12 @@ use crate::{Accessor, SchemaStore};
 pub use all_of_schema::AllOfSchema;
 pub use any_of_schema::AnyOfSchema;
 pub use any_schema::AnythingSchema;
-pub use array_schema::{ArraySchema, XTombiArrayValuesOrder};
+pub use array_schema::{ArraySchema, XTombiArrayOfTablesOrder, XTombiArrayValuesOrder};
 pub use boolean_schema::BooleanSchema;
+pub use default_snippet::{DefaultSnippet, DefaultSnippetBody};
 pub use deprecation::Deprecation;
 pub use document_schema::DocumentSchema;
+pub use error_message::ErrorMessage;
 pub use float_schema::FloatSchema;
 pub use if_then_else_schema::IfThenElseSchema;
 pub use integer_schema::IntegerSchema;
@@ -58,6 +63,7 @@ pub use string_schema::StringSchema;
 pub use table_schema::{Dependency, TableKeysOrderGroup, TableSchema, XTombiTableKeysOrder};
 pub use tombi_accessor::{PatternAccessor, PatternAccessors, SchemaAccessor, SchemaAccessors};
 pub use tombi_uri::{CatalogUri, SchemaUri};
+pub use validation_cache::ValidationCache;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct OrderOverride<T: Copy> {
@@ -316,6 +322,41 @@ pub(crate) fn schema_item_from_schema_value_for_type(
     Some(Arc::new(tokio::sync::RwLock::new(referable)))
 }
 
+/// Reads the description of a schema object, preferring the VS Code `markdownDescription`.
+pub(crate) fn description(object: &tombi_json::ObjectNode) -> Option<String> {
+    object
+        .get("markdownDescription")
+        .or_else(|| object.get("description"))
+        .and_then(|value| value.as_str().map(ToString::to_string))
+}
+
+/// Reads the VS Code `markdownEnumDescriptions` (or `enumDescriptions`) of a schema object,
+/// aligned with the `enum` items accepted by `is_enum_item`.
+pub(crate) fn enum_descriptions(
+    object: &tombi_json::ObjectNode,
+    is_enum_item: impl Fn(&tombi_json::ValueNode) -> bool,
+) -> Option<Vec<Option<String>>> {
+    let r#enum = object.get("enum")?.as_array()?;
+    let descriptions = object
+        .get("markdownEnumDescriptions")
+        .or_else(|| object.get("enumDescriptions"))?
+        .as_array()?;
+
+    Some(
+        r#enum
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| is_enum_item(item))
+            .map(|(index, _)| {
+                descriptions
+                    .get(index)
+                    .and_then(|description| description.as_str().map(ToString::to_string))
+            })
+            .collect(),
+    )
+}
+
 pub(crate) fn bool_schema_view(allow: bool, range: tombi_text::Range) -> SchemaView {
     if allow {
         SchemaView::Anything(AnythingSchema {
@@ -35,10 +35,7 @@ impl AnyOfSchema {
             .get("title")
             .and_then(|v| v.as_str())
             .map(|s| s.to_string());
-        let description = object
-            .get("description")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
+        let description = crate::description(object);
         let schemas = object
             .get("anyOf")
             .and_then(|v| v.as_array())
@@ -4,7 +4,8 @@ use itertools::Itertools;
 use tombi_future::{BoxFuture, Boxable};
 use tombi_schema_type::BoolDefaultTrue;
 use tombi_x_keyword::{
-    ArrayValuesOrder, ArrayValuesOrderGroup, StringFormat, X_TOMBI_ARRAY_VALUES_ORDER,
+    ArrayLayout, ArrayOfTablesOrderKey, ArrayValuesOrder, ArrayValuesOrderGroup, StringFormat,
+    X_TOMBI_ARRAY_LAYOUT, X_TOMBI_ARRAY_OF_TABLES_ORDER, X_TOMBI_ARRAY_VALUES_ORDER,
 };
 
 use super::{
@@ -36,6 +37,8 @@ pub struct ArraySchema {
     pub const_value: Option<tombi_json::Value>,
     pub examples: Option<Vec<tombi_json::Value>>,
     pub values_order: Option<XTombiArrayValuesOrder>,
+    pub tables_order: Option<XTombiArrayOfTablesOrder>,
+    pub layout: Option<ArrayLayout>,
     pub deprecation: Option<crate::Deprecation>,
     pub one_of: Option<Box<OneOfSchema>>,
     pub any_of: Option<Box<AnyOfSchema>>,
@@ -71,9 +74,7 @@ impl ArraySchema {
             title: object
                 .get("title")
                 .and_then(|v| v.as_str().map(|s| s.to_string())),
-            description: object
-                .get("description")
-                .and_then(|v| v.as_str().map(|s| s.to_string())),
+            description: crate::description(object),
             items: object.get("items").and_then(|value| {
                 // draft 2020-12: when prefixItems is present, boolean `items`
                 // configures overflow allowance and should not be treated as a schema item.
@@ -201,6 +202,22 @@ impl ArraySchema {
             values_order: object
                 .get(X_TOMBI_ARRAY_VALUES_ORDER)
                 .and_then(XTombiArrayValuesOrder::new),
+            tables_order: object
+                .get(X_TOMBI_ARRAY_OF_TABLES_ORDER)
+                .and_then(XTombiArrayOfTablesOrder::new),
+            layout: object.get(X_TOMBI_ARRAY_LAYOUT).and_then(|v| {
+                if let Some(v) = v.as_str() {
+                    if let Ok(v) = ArrayLayout::try_from(v) {
+                        Some(v)
+                    } else {
+                        log::warn!("invalid {X_TOMBI_ARRAY_LAYOUT}: {v}");
+                        None
+                    }
+                } else {
+                    log::warn!("invalid {X_TOMBI_ARRAY_LAYOUT}: {}", v);
+                    None
+                }
+            }),
             deprecation: crate::Deprecation::new(object),
             one_of,
             any_of,
@@ -357,3 +374,58 @@ impl XTombiArrayValuesOrder {
         None
     }
 }
+
+/// The sub-keys `[[...]]` entries are sorted by, compared in turn until one differs.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct XTombiArrayOfTablesOrder(pub Vec<ArrayOfTablesOrderKey>);
+
+impl XTombiArrayOfTablesOrder {
+    pub fn new(value_node: &tombi_json::ValueNode) -> Option<Self> {
+        let Some(items) = value_node.as_array() else {
+            log::warn!("invalid {X_TOMBI_ARRAY_OF_TABLES_ORDER}: {}", value_node);
+            return None;
+        };
+
+        let mut keys = Vec::with_capacity(items.items.len());
+        for item in &items.items {
+            match item {
+                tombi_json::ValueNode::String(key) => keys.push(ArrayOfTablesOrderKey {
+                    key: key.value.clone(),
+                    order: ArrayValuesOrder::Ascending,
+                }),
+                tombi_json::ValueNode::Object(object) => {
+                    let Some(key) = object.get("key").and_then(|v| v.as_str()) else {
+                        log::warn!("invalid {X_TOMBI_ARRAY_OF_TABLES_ORDER} key: {}", item);
+                        return None;
+                    };
+                    let order = match object.get("order") {
+                        Some(order) => match order
+                            .as_str()
+                            .and_then(|v| ArrayValuesOrder::try_from(v).ok())
+                        {
+                            Some(order) => order,
+                            None => {
+                                log::warn!(
+                                    "invalid {X_TOMBI_ARRAY_OF_TABLES_ORDER} order: {}",
+                                    order
+                                );
+                                return None;
+                            }
+                        },
+                        None => ArrayValuesOrder::Ascending,
+                    };
+                    keys.push(ArrayOfTablesOrderKey {
+                        key: key.to_string(),
+                        order,
+                    });
+                }
+                _ => {
+                    log::warn!("invalid {X_TOMBI_ARRAY_OF_TABLES_ORDER} key: {}", item);
+                    return None;
+                }
+            }
+        }
+
+        (!keys.is_empty()).then_some(Self(keys))
+    }
+}
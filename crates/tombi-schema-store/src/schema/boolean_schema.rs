@@ -8,6 +8,7 @@ pub struct BooleanSchema {
     pub default: Option<bool>,
     pub const_value: Option<bool>,
     pub r#enum: Option<Vec<bool>>,
+    pub enum_descriptions: Option<Vec<Option<String>>>,
     pub examples: Option<Vec<bool>>,
     pub deprecation: Option<crate::Deprecation>,
     pub one_of: Option<Box<OneOfSchema>>,
@@ -35,11 +36,10 @@ impl BooleanSchema {
             title: object
                 .get("title")
                 .and_then(|value| value.as_str().map(|s| s.to_string())),
-            description: object
-                .get("description")
-                .and_then(|value| value.as_str().map(|s| s.to_string())),
+            description: crate::description(object),
             default: object.get("default").and_then(|v| v.as_bool()),
             const_value: object.get("const").and_then(|v| v.as_bool()),
+            enum_descriptions: crate::enum_descriptions(object, |item| item.is_bool()),
             r#enum: object
                 .get("enum")
                 .and_then(|value| value.as_array())
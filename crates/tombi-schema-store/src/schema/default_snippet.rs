@@ -0,0 +1,148 @@
+use itertools::Itertools;
+
+/// A VS Code `defaultSnippets` entry, offered as a snippet completion.
+///
+/// See https://code.visualstudio.com/Docs/languages/json#_define-snippets-in-json-schemas
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefaultSnippet {
+    pub label: Option<String>,
+    pub description: Option<String>,
+    pub body: DefaultSnippetBody,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DefaultSnippetBody {
+    /// `body`: a JSON value rendered as TOML.
+    Value(tombi_json::Value),
+    /// `bodyText`: the snippet text, inserted as is.
+    Text(String),
+}
+
+impl DefaultSnippet {
+    /// Reads the `defaultSnippets` of a schema object.
+    ///
+    /// Entries without `body` or `bodyText` are ignored.
+    pub fn new_list(object: &tombi_json::ObjectNode) -> Vec<Self> {
+        let Some(snippets) = object
+            .get("defaultSnippets")
+            .and_then(|value| value.as_array())
+        else {
+            return Vec::new();
+        };
+
+        snippets
+            .items
+            .iter()
+            .filter_map(|snippet| {
+                let snippet = snippet.as_object()?;
+                let body = match (snippet.get("body"), snippet.get("bodyText")) {
+                    (Some(body), _) => DefaultSnippetBody::Value(body.into()),
+                    (None, Some(body_text)) => {
+                        DefaultSnippetBody::Text(body_text.as_str()?.to_string())
+                    }
+                    (None, None) => return None,
+                };
+                Some(Self {
+                    label: snippet
+                        .get("label")
+                        .and_then(|value| value.as_str().map(ToString::to_string)),
+                    description: crate::description(snippet),
+                    body,
+                })
+            })
+            .collect()
+    }
+
+    /// The snippet text in TOML syntax.
+    ///
+    /// As in VS Code, strings keep their snippet syntax (`${1:name}`),
+    /// and a string starting with `^` is inserted without quotes.
+    pub fn insert_text(&self) -> String {
+        match &self.body {
+            DefaultSnippetBody::Value(value) => value_to_toml_snippet(value),
+            DefaultSnippetBody::Text(text) => text.clone(),
+        }
+    }
+}
+
+fn value_to_toml_snippet(value: &tombi_json::Value) -> String {
+    match value {
+        tombi_json::Value::Null => String::new(),
+        tombi_json::Value::Bool(value) => value.to_string(),
+        tombi_json::Value::Number(value) => value.to_string(),
+        tombi_json::Value::String(value) => match value.strip_prefix('^') {
+            Some(raw) => raw.to_string(),
+            None => format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\"")),
+        },
+        tombi_json::Value::Array(values) => format!(
+            "[{}]",
+            values
+                .iter()
+                .filter(|value| !value.is_null())
+                .map(value_to_toml_snippet)
+                .join(", ")
+        ),
+        tombi_json::Value::Object(object) => {
+            if object.is_empty() {
+                return "{}".to_string();
+            }
+            format!(
+                "{{ {} }}",
+                object
+                    .iter()
+                    .filter(|(_, value)| !value.is_null())
+                    .map(|(key, value)| format!(
+                        "{} = {}",
+                        toml_key(key),
+                        value_to_toml_snippet(value)
+                    ))
+                    .join(", ")
+            )
+        }
+    }
+}
+
+fn toml_key(key: &str) -> String {
+    if !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        key.to_string()
+    } else {
+        format!("\"{}\"", key.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snippets(source: &str) -> Vec<DefaultSnippet> {
+        let tombi_json::ValueNode::Object(object) =
+            source.parse::<tombi_json::ValueNode>().unwrap()
+        else {
+            unreachable!("the schema must be an object");
+        };
+        DefaultSnippet::new_list(&object)
+    }
+
+    #[test]
+    fn body_is_rendered_as_toml() {
+        let snippets = snippets(
+            r#"{"defaultSnippets": [{"label": "server", "body": {"host": "${1:localhost}", "port": "^${2:8080}", "tls key": false, "tags": ["a"]}}]}"#,
+        );
+
+        pretty_assertions::assert_eq!(
+            snippets[0].insert_text(),
+            r#"{ host = "${1:localhost}", port = ${2:8080}, "tls key" = false, tags = ["a"] }"#
+        );
+    }
+
+    #[test]
+    fn body_text_is_inserted_as_is() {
+        let snippets = snippets(r#"{"defaultSnippets": [{"bodyText": "{ name = \"$1\" }"}]}"#);
+
+        pretty_assertions::assert_eq!(snippets[0].insert_text(), r#"{ name = "$1" }"#);
+    }
+}
@@ -0,0 +1,42 @@
+/// A custom validation message (`errorMessage`), in the style of ajv-errors.
+///
+/// See https://ajv.js.org/packages/ajv-errors.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorMessage {
+    /// `"errorMessage": "..."` replaces every error of the schema.
+    All(String),
+    /// `"errorMessage": { "<keyword>": "..." }` replaces the errors of each keyword.
+    /// The `_` entry covers the keywords not listed.
+    Keywords(Vec<(String, String)>),
+}
+
+impl ErrorMessage {
+    pub fn new(object: &tombi_json::ObjectNode) -> Option<Self> {
+        match object.get("errorMessage")? {
+            tombi_json::ValueNode::String(message) => Some(Self::All(message.value.clone())),
+            tombi_json::ValueNode::Object(messages) => {
+                let messages = messages
+                    .properties
+                    .iter()
+                    .filter_map(|(keyword, message)| {
+                        Some((keyword.value.clone(), message.as_str()?.to_string()))
+                    })
+                    .collect::<Vec<_>>();
+                (!messages.is_empty()).then_some(Self::Keywords(messages))
+            }
+            _ => None,
+        }
+    }
+
+    /// The message replacing an error of `keyword`, if any.
+    pub fn message(&self, keyword: &str) -> Option<&str> {
+        match self {
+            Self::All(message) => Some(message),
+            Self::Keywords(messages) => messages
+                .iter()
+                .find(|(key, _)| key == keyword)
+                .or_else(|| messages.iter().find(|(key, _)| key == "_"))
+                .map(|(_, message)| message.as_str()),
+        }
+    }
+}
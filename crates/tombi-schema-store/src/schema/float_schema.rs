@@ -11,6 +11,7 @@ pub struct FloatSchema {
     pub exclusive_maximum: Option<f64>,
     pub multiple_of: Option<f64>,
     pub r#enum: Option<Vec<f64>>,
+    pub enum_descriptions: Option<Vec<Option<String>>>,
     pub default: Option<f64>,
     pub const_value: Option<f64>,
     pub examples: Option<Vec<f64>>,
@@ -40,14 +41,13 @@ impl FloatSchema {
             title: object
                 .get("title")
                 .and_then(|v| v.as_str().map(|s| s.to_string())),
-            description: object
-                .get("description")
-                .and_then(|v| v.as_str().map(|s| s.to_string())),
+            description: crate::description(object),
             minimum: object.get("minimum").and_then(|v| v.as_f64()),
             maximum: object.get("maximum").and_then(|v| v.as_f64()),
             exclusive_minimum: object.get("exclusiveMinimum").and_then(|v| v.as_f64()),
             exclusive_maximum: object.get("exclusiveMaximum").and_then(|v| v.as_f64()),
             multiple_of: object.get("multipleOf").and_then(|v| v.as_f64()),
+            enum_descriptions: crate::enum_descriptions(object, |item| item.as_f64().is_some()),
             r#enum: object
                 .get("enum")
                 .and_then(|v| v.as_array())
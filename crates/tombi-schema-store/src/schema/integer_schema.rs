@@ -11,6 +11,7 @@ pub struct IntegerSchema {
     pub exclusive_maximum: Option<i64>,
     pub multiple_of: Option<i64>,
     pub r#enum: Option<Vec<i64>>,
+    pub enum_descriptions: Option<Vec<Option<String>>>,
     pub default: Option<i64>,
     pub const_value: Option<i64>,
     pub examples: Option<Vec<i64>>,
@@ -40,14 +41,13 @@ impl IntegerSchema {
             title: object
                 .get("title")
                 .and_then(|v| v.as_str().map(|s| s.to_string())),
-            description: object
-                .get("description")
-                .and_then(|v| v.as_str().map(|s| s.to_string())),
+            description: crate::description(object),
             minimum: object.get("minimum").and_then(|v| v.as_i64()),
             maximum: object.get("maximum").and_then(|v| v.as_i64()),
             exclusive_minimum: object.get("exclusiveMinimum").and_then(|v| v.as_i64()),
             exclusive_maximum: object.get("exclusiveMaximum").and_then(|v| v.as_i64()),
             multiple_of: object.get("multipleOf").and_then(|v| v.as_i64()),
+            enum_descriptions: crate::enum_descriptions(object, |item| item.as_i64().is_some()),
             r#enum: object
                 .get("enum")
                 .and_then(|v| v.as_array())
@@ -6,6 +6,7 @@ pub struct OffsetDateTimeSchema {
     pub description: Option<String>,
     pub range: tombi_text::Range,
     pub r#enum: Option<Vec<String>>,
+    pub enum_descriptions: Option<Vec<Option<String>>>,
     pub default: Option<String>,
     pub const_value: Option<String>,
     pub examples: Option<Vec<String>>,
@@ -36,10 +37,9 @@ impl OffsetDateTimeSchema {
             title: object
                 .get("title")
                 .and_then(|v| v.as_str().map(|s| s.to_string())),
-            description: object
-                .get("description")
-                .and_then(|v| v.as_str().map(|s| s.to_string())),
+            description: crate::description(object),
             range: object.range,
+            enum_descriptions: crate::enum_descriptions(object, |item| item.is_string()),
             r#enum: object.get("enum").and_then(|v| v.as_array()).map(|a| {
                 a.items
                     .iter()
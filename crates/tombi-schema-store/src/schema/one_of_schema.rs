@@ -35,10 +35,7 @@ impl OneOfSchema {
             .get("title")
             .and_then(|v| v.as_str())
             .map(|s| s.to_string());
-        let description = object
-            .get("description")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
+        let description = crate::description(object);
         let schemas = object
             .get("oneOf")
             .and_then(|v| v.as_array())
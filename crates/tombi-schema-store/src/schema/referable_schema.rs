@@ -176,9 +176,7 @@ impl Referable<SchemaView> {
                 title: object
                     .get("title")
                     .and_then(|title| title.as_str().map(|s| s.to_string())),
-                description: object
-                    .get("description")
-                    .and_then(|description| description.as_str().map(|s| s.to_string())),
+                description: crate::description(object),
                 default: object.get("default").cloned().map(Into::into),
                 examples: object
                     .get("examples")
@@ -229,9 +227,7 @@ impl Referable<SchemaView> {
                     title: object
                         .get("title")
                         .and_then(|value| value.as_str().map(ToOwned::to_owned)),
-                    description: object
-                        .get("description")
-                        .and_then(|value| value.as_str().map(ToOwned::to_owned)),
+                    description: crate::description(object),
                     range: object.range,
                 }))
             };
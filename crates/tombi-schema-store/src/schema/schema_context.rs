@@ -31,6 +31,15 @@ pub struct SchemaContext<'a> {
     pub store: &'a crate::SchemaStore,
     /// strict setting on document comment-directive level.
     pub strict: Option<tombi_schema_type::BoolDefaultTrue>,
+    /// `lint.rules.table-style` setting, whose style overrides `x-tombi-table-style`.
+    pub table_style: Option<tombi_config::TableStyleRule>,
+    /// Validation results of root table values kept from the previous validation of the document.
+    pub validation_cache: Option<crate::ValidationCache>,
+    /// Whether incomplete values are JSON `null`s, as in the document trees of JSON documents.
+    pub incomplete_as_null: bool,
+    /// Whether the root table holds only part of the document, as a chunk of a streamed document,
+    /// so its required keys and minimum keys are not checked.
+    pub partial_root: bool,
 }
 
 impl SchemaContext<'_> {
@@ -51,6 +60,10 @@ impl SchemaContext<'_> {
             schema_visits: Default::default(),
             store,
             strict,
+            table_style: None,
+            validation_cache: None,
+            incomplete_as_null: false,
+            partial_root: false,
         }
     }
 
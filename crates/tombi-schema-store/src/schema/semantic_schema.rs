@@ -1,6 +1,6 @@
 use tombi_json::{Number, ObjectNode, Value, ValueNode};
 
-use super::{Deprecation, referable_schema::ReferenceKind};
+use super::{DefaultSnippet, Deprecation, ErrorMessage, referable_schema::ReferenceKind};
 use crate::JsonSchemaDialect;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -439,6 +439,51 @@ impl SemanticSchema {
         }
     }
 
+    /// The VS Code `enumDescriptions` entry of an `enum` value, searching the applicators too.
+    pub fn enum_description(&self, value: &Value) -> Option<&str> {
+        match self {
+            Self::Boolean(_) => None,
+            Self::Object(object) => object
+                .assertions
+                .enum_values
+                .as_ref()
+                .and_then(|enum_values| {
+                    enum_values
+                        .iter()
+                        .position(|enum_value| enum_value.value == *value)
+                })
+                .and_then(|index| object.annotations.enum_descriptions.get(index))
+                .and_then(|description| description.as_deref())
+                .or_else(|| {
+                    object
+                        .applicators
+                        .all_of
+                        .iter()
+                        .chain(&object.applicators.any_of)
+                        .chain(&object.applicators.one_of)
+                        .find_map(|schema| schema.enum_description(value))
+                }),
+            Self::Composite(composite) => composite
+                .schemas
+                .iter()
+                .find_map(|schema| schema.enum_description(value)),
+        }
+    }
+
+    pub fn default_snippets(&self) -> &[DefaultSnippet] {
+        match self {
+            Self::Object(object) => &object.annotations.default_snippets,
+            Self::Boolean(_) | Self::Composite(_) => &[],
+        }
+    }
+
+    pub fn error_message(&self) -> Option<&ErrorMessage> {
+        match self {
+            Self::Object(object) => object.annotations.error_message.as_ref(),
+            Self::Boolean(_) | Self::Composite(_) => None,
+        }
+    }
+
     pub fn has_applicators(&self) -> bool {
         match self {
             Self::Boolean(_) => false,
@@ -1164,13 +1209,19 @@ pub struct SemanticAnnotations {
     pub default: Option<Spanned<Value>>,
     pub examples: Vec<Spanned<Value>>,
     pub deprecation: Option<Deprecation>,
+    pub read_only: Option<Spanned<bool>>,
+    /// VS Code `markdownEnumDescriptions` or `enumDescriptions`, aligned with `enum`.
+    pub enum_descriptions: Vec<Option<String>>,
+    pub default_snippets: Vec<DefaultSnippet>,
+    pub error_message: Option<ErrorMessage>,
 }
 
 impl SemanticAnnotations {
     fn new(object: &ObjectNode) -> Self {
         Self {
             title: string_keyword(object, "title"),
-            description: string_keyword(object, "description"),
+            description: string_keyword(object, "markdownDescription")
+                .or_else(|| string_keyword(object, "description")),
             default: object
                 .get("default")
                 .map(|value| Spanned::new(value.into(), value.range())),
@@ -1186,6 +1237,10 @@ impl SemanticAnnotations {
                 })
                 .unwrap_or_default(),
             deprecation: Deprecation::new(object),
+            read_only: bool_keyword(object, "readOnly"),
+            enum_descriptions: super::enum_descriptions(object, |_| true).unwrap_or_default(),
+            default_snippets: DefaultSnippet::new_list(object),
+            error_message: ErrorMessage::new(object),
         }
     }
 }
@@ -1,6 +1,9 @@
-use tombi_x_keyword::StringFormat;
+use tombi_x_keyword::{
+    StringFormat, StringValuesOrderBy, X_TOMBI_ARRAY_VALUES_ORDER_BY, X_TOMBI_CONTENT_ASSERTION,
+    X_TOMBI_LANGUAGE,
+};
 
-use super::{AllOfSchema, AnyOfSchema, NotSchema, OneOfSchema};
+use super::{AllOfSchema, AnyOfSchema, NotSchema, OneOfSchema, SchemaItem};
 
 #[derive(Debug, Default, Clone)]
 pub struct StringSchema {
@@ -9,12 +12,19 @@ pub struct StringSchema {
     pub range: tombi_text::Range,
     pub content_encoding: Option<String>,
     pub content_media_type: Option<String>,
-    pub content_schema: Option<tombi_json::Value>,
+    pub content_schema: Option<SchemaItem>,
+    /// Whether the content keywords are asserted.
+    ///
+    /// They are annotations only since draft 2019-09, unless `x-tombi-content-assertion` is `true`.
+    pub content_assertion: bool,
+    pub language: Option<String>,
+    pub array_values_order_by: Option<StringValuesOrderBy>,
     pub min_length: Option<usize>,
     pub max_length: Option<usize>,
     pub format: Option<StringFormat>,
     pub pattern: Option<String>,
     pub r#enum: Option<Vec<String>>,
+    pub enum_descriptions: Option<Vec<Option<String>>>,
     pub examples: Option<Vec<String>>,
     pub default: Option<String>,
     pub const_value: Option<String>,
@@ -34,6 +44,17 @@ impl StringSchema {
         anchor_collector: Option<&mut crate::AnchorCollector>,
         dynamic_anchor_collector: Option<&mut crate::DynamicAnchorCollector>,
     ) -> Self {
+        let mut anchor_collector = anchor_collector;
+        let mut dynamic_anchor_collector = dynamic_anchor_collector;
+        let content_schema = object.get("contentSchema").and_then(|value| {
+            crate::schema_item_from_schema_value(
+                value,
+                string_formats,
+                dialect,
+                anchor_collector.as_deref_mut(),
+                dynamic_anchor_collector.as_deref_mut(),
+            )
+        });
         let (one_of, any_of, all_of, not) = crate::adjacent_applicators(
             object,
             string_formats,
@@ -45,9 +66,7 @@ impl StringSchema {
             title: object
                 .get("title")
                 .and_then(|v| v.as_str().map(|s| s.to_string())),
-            description: object
-                .get("description")
-                .and_then(|v| v.as_str().map(|s| s.to_string())),
+            description: crate::description(object),
             range: object.range,
             content_encoding: object
                 .get("contentEncoding")
@@ -55,7 +74,41 @@ impl StringSchema {
             content_media_type: object
                 .get("contentMediaType")
                 .and_then(|v| v.as_str().map(|s| s.to_string())),
-            content_schema: object.get("contentSchema").cloned().map(Into::into),
+            content_schema,
+            content_assertion: object
+                .get(X_TOMBI_CONTENT_ASSERTION)
+                .and_then(|v| v.as_bool())
+                .unwrap_or_else(|| {
+                    dialect.is_none_or(|dialect| dialect == crate::JsonSchemaDialect::Draft07)
+                }),
+            language: object
+                .get(X_TOMBI_LANGUAGE)
+                .and_then(|v| v.as_str().map(|s| s.to_string())),
+            array_values_order_by: object.get(X_TOMBI_ARRAY_VALUES_ORDER_BY).and_then(
+                |v| match v {
+                    tombi_json::ValueNode::String(string) => {
+                        match StringValuesOrderBy::try_from(string.value.as_str()) {
+                            Ok(v) => Some(v),
+                            Err(v) => {
+                                log::warn!("invalid {X_TOMBI_ARRAY_VALUES_ORDER_BY}: {v}");
+                                None
+                            }
+                        }
+                    }
+                    tombi_json::ValueNode::Object(object) => {
+                        if let Some(pattern) = object.get("pattern").and_then(|v| v.as_str()) {
+                            Some(StringValuesOrderBy::Pattern(pattern.to_string()))
+                        } else {
+                            log::warn!("invalid {X_TOMBI_ARRAY_VALUES_ORDER_BY}: {}", v);
+                            None
+                        }
+                    }
+                    _ => {
+                        log::warn!("invalid {X_TOMBI_ARRAY_VALUES_ORDER_BY}: {}", v);
+                        None
+                    }
+                },
+            ),
             min_length: object
                 .get("minLength")
                 .and_then(|v| v.as_u64().map(|n| n as usize)),
@@ -66,6 +119,7 @@ impl StringSchema {
             pattern: object
                 .get("pattern")
                 .and_then(|v| v.as_str().map(|s| s.to_string())),
+            enum_descriptions: crate::enum_descriptions(object, |item| item.is_string()),
             r#enum: object.get("enum").and_then(|v| v.as_array()).map(|a| {
                 a.items
                     .iter()
@@ -101,4 +155,34 @@ impl StringSchema {
     pub fn deprecated(&self) -> Option<bool> {
         self.deprecation.as_ref().map(|_| true)
     }
+
+    /// The language of the code embedded in the string.
+    ///
+    /// `x-tombi-language` takes priority, and well-known `contentMediaType`s are used as a fallback.
+    pub fn embedded_language(&self) -> Option<&str> {
+        if let Some(language) = &self.language {
+            return Some(language);
+        }
+
+        let media_type = self.content_media_type.as_deref()?;
+        let essence = media_type.split(';').next()?.trim().to_ascii_lowercase();
+        let language = match essence.as_str() {
+            "application/json" => "json",
+            "application/toml" => "toml",
+            "application/yaml" | "application/x-yaml" | "text/yaml" => "yaml",
+            "application/javascript" | "text/javascript" => "javascript",
+            "application/sql" => "sql",
+            "application/x-sh" | "text/x-shellscript" => "shellscript",
+            "text/x-python" | "application/x-python" => "python",
+            "text/markdown" => "markdown",
+            "text/html" => "html",
+            "text/css" => "css",
+            essence if essence.ends_with("+json") => "json",
+            essence if essence.ends_with("+toml") => "toml",
+            essence if essence.ends_with("+yaml") => "yaml",
+            _ => return None,
+        };
+
+        Some(language)
+    }
 }
@@ -5,8 +5,9 @@ use tombi_accessor::Accessors;
 use tombi_future::{BoxFuture, Boxable};
 use tombi_schema_type::BoolDefaultTrue;
 use tombi_x_keyword::{
-    ArrayValuesOrderBy, StringFormat, TableKeysOrder, TableKeysOrderGroupKind,
-    X_TOMBI_ADDITIONAL_KEY_LABEL, X_TOMBI_ARRAY_VALUES_ORDER_BY, X_TOMBI_TABLE_KEYS_ORDER,
+    ArrayValuesOrderBy, InlineTableLayout, StringFormat, TableKeysOrder, TableKeysOrderGroupKind,
+    TableStyle, X_TOMBI_ADDITIONAL_KEY_LABEL, X_TOMBI_ARRAY_VALUES_ORDER_BY,
+    X_TOMBI_INLINE_TABLE_LAYOUT, X_TOMBI_TABLE_KEYS_ORDER, X_TOMBI_TABLE_STYLE,
 };
 
 use super::{
@@ -51,6 +52,8 @@ pub struct TableSchema {
     pub max_properties: Option<usize>,
     pub keys_order: Option<XTombiTableKeysOrder>,
     pub array_values_order_by: Option<ArrayValuesOrderBy>,
+    pub inline_table_layout: Option<InlineTableLayout>,
+    pub table_style: Option<TableStyle>,
     pub default: Option<tombi_json::Object>,
     pub const_value: Option<tombi_json::Object>,
     pub r#enum: Option<Vec<tombi_json::Object>>,
@@ -172,6 +175,32 @@ impl TableSchema {
                     None
                 }
             });
+        let inline_table_layout = object_node.get(X_TOMBI_INLINE_TABLE_LAYOUT).and_then(|v| {
+            if let Some(v) = v.as_str() {
+                if let Ok(v) = InlineTableLayout::try_from(v) {
+                    Some(v)
+                } else {
+                    log::warn!("invalid {X_TOMBI_INLINE_TABLE_LAYOUT}: {v}");
+                    None
+                }
+            } else {
+                log::warn!("invalid {X_TOMBI_INLINE_TABLE_LAYOUT}: {}", v);
+                None
+            }
+        });
+        let table_style = object_node.get(X_TOMBI_TABLE_STYLE).and_then(|v| {
+            if let Some(v) = v.as_str() {
+                if let Ok(v) = TableStyle::try_from(v) {
+                    Some(v)
+                } else {
+                    log::warn!("invalid {X_TOMBI_TABLE_STYLE}: {v}");
+                    None
+                }
+            } else {
+                log::warn!("invalid {X_TOMBI_TABLE_STYLE}: {}", v);
+                None
+            }
+        });
         let (one_of, any_of, all_of, not) = crate::adjacent_applicators(
             object_node,
             string_formats,
@@ -184,9 +213,7 @@ impl TableSchema {
             title: object_node
                 .get("title")
                 .and_then(|v| v.as_str().map(|s| s.to_string())),
-            description: object_node
-                .get("description")
-                .and_then(|v| v.as_str().map(|s| s.to_string())),
+            description: crate::description(object_node),
             range: object_node.range,
             properties: Arc::new(properties.into()),
             pattern_properties: pattern_properties.map(|props| {
@@ -295,6 +322,8 @@ impl TableSchema {
                 .and_then(|v| v.as_u64().map(|u| u as usize)),
             keys_order,
             array_values_order_by,
+            inline_table_layout,
+            table_style,
             r#enum: object_node.get("enum").and_then(|v| v.as_array()).map(|v| {
                 v.items
                     .iter()
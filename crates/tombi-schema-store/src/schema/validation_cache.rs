@@ -0,0 +1,61 @@
+use std::{
+    any::Any,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+/// Results of validating the values of a root table, kept between validations of the same document.
+///
+/// An entry is looked up by the property key and the schema view the value was validated against.
+/// The entry holds the schema view, so that its address is not reused by another schema view.
+/// Results are stored type-erased, so the schema store does not depend on the validator.
+#[derive(Debug, Default, Clone)]
+pub struct ValidationCache(
+    Arc<Mutex<tombi_hashmap::HashMap<(String, usize), ValidationCacheEntry>>>,
+);
+
+#[derive(Debug)]
+struct ValidationCacheEntry {
+    _schema_view: Arc<crate::SchemaView>,
+    result: Arc<dyn Any + Send + Sync>,
+}
+
+impl ValidationCache {
+    pub fn get(
+        &self,
+        key: &str,
+        schema_view: &Arc<crate::SchemaView>,
+    ) -> Option<Arc<dyn Any + Send + Sync>> {
+        self.lock()
+            .get(&(key.to_string(), Arc::as_ptr(schema_view) as usize))
+            .map(|entry| Arc::clone(&entry.result))
+    }
+
+    pub fn insert(
+        &self,
+        key: String,
+        schema_view: Arc<crate::SchemaView>,
+        result: Arc<dyn Any + Send + Sync>,
+    ) {
+        let ptr = Arc::as_ptr(&schema_view) as usize;
+        self.lock().insert(
+            (key, ptr),
+            ValidationCacheEntry {
+                _schema_view: schema_view,
+                result,
+            },
+        );
+    }
+
+    /// Drop every entry, e.g. when the config or the schemas change.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(
+        &self,
+    ) -> MutexGuard<'_, tombi_hashmap::HashMap<(String, usize), ValidationCacheEntry>> {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
@@ -430,17 +430,12 @@ impl SchemaStore {
                     });
                 }
 
-                let file = std::fs::File::open(&schema_path)
+                let content = std::fs::read_to_string(&schema_path)
                     .map_err(|_| crate::Error::SchemaFileReadFailed { schema_path })?;
 
                 log::debug!("load schema from file: {}", schema_uri);
 
-                Ok(Some(tombi_json::ValueNode::from_reader(file).map_err(
-                    |err| crate::Error::SchemaFileParseFailed {
-                        schema_uri: schema_uri.to_owned(),
-                        reason: err.to_string(),
-                    },
-                )?))
+                Ok(Some(parse_schema_value(schema_uri, &content)?))
             }
             "http" | "https" => {
                 let schema_cache_path = get_cache_file_path(schema_uri).await;
@@ -495,14 +490,14 @@ impl SchemaStore {
                     log::warn!("{err}");
                 }
 
-                Ok(Some(
-                    tombi_json::ValueNode::from_reader(std::io::Cursor::new(bytes)).map_err(
-                        |err| crate::Error::SchemaFileParseFailed {
-                            schema_uri: schema_uri.to_owned(),
-                            reason: err.to_string(),
-                        },
-                    )?,
-                ))
+                let content = std::str::from_utf8(&bytes).map_err(|err| {
+                    crate::Error::SchemaFileParseFailed {
+                        schema_uri: schema_uri.to_owned(),
+                        reason: err.to_string(),
+                    }
+                })?;
+
+                Ok(Some(parse_schema_value(schema_uri, content)?))
             }
             "tombi" => {
                 let Some(content) = get_tombi_schemastore_content(schema_uri) else {
@@ -1291,19 +1286,49 @@ async fn load_json_schema_from_cache(
     {
         log::trace!("load schema from cache: {}", schema_uri);
 
-        return Ok(Some(
-            tombi_json::ValueNode::from_str(&schema_cache_content).map_err(|err| {
-                crate::Error::SchemaFileParseFailed {
-                    schema_uri: schema_uri.to_owned(),
-                    reason: err.to_string(),
-                }
-            })?,
-        ));
+        return Ok(Some(parse_schema_value(schema_uri, &schema_cache_content)?));
     }
 
     Ok(None)
 }
 
+/// Parse a schema document by the extension of its URI: `.yaml`/`.yml` as YAML, `.toml` as TOML and JSON otherwise.
+///
+/// The JSON parser also accepts comments and trailing commas, so JSONC and JSON5-style schemas load as well.
+fn parse_schema_value(
+    schema_uri: &SchemaUri,
+    content: &str,
+) -> Result<tombi_json::ValueNode, crate::Error> {
+    let path = schema_uri.path().to_ascii_lowercase();
+    let result = if path.ends_with(".yaml") || path.ends_with(".yml") {
+        tombi_yaml::parse(content).map_err(|err| err.to_string())
+    } else if path.ends_with(".toml") {
+        #[cfg(feature = "toml-schema")]
+        {
+            crate::toml_schema::parse_toml_schema(content)
+        }
+        #[cfg(not(feature = "toml-schema"))]
+        {
+            Err("JSON Schemas written in TOML require the `toml-schema` feature".to_string())
+        }
+    } else if path.ends_with(".jsonc") || path.ends_with(".json5") {
+        tombi_json::parse_with_options(
+            content,
+            tombi_json::LexOptions {
+                allow_comments: true,
+            },
+        )
+        .map_err(|err| err.to_string())
+    } else {
+        tombi_json::ValueNode::from_str(content).map_err(|err| err.to_string())
+    };
+
+    result.map_err(|reason| crate::Error::SchemaFileParseFailed {
+        schema_uri: schema_uri.to_owned(),
+        reason,
+    })
+}
+
 fn canonicalize_path_for_matching(path: &std::path::Path) -> std::path::PathBuf {
     std::fs::canonicalize(path).unwrap_or_else(|_| {
         if path.is_absolute() {
@@ -1401,7 +1426,7 @@ mod tests {
 
     use super::{
         SchemaStore, load_catalog_from_cache_ignoring_ttl,
-        load_json_schema_from_cache_ignoring_ttl, matches_schema_patterns,
+        load_json_schema_from_cache_ignoring_ttl, matches_schema_patterns, parse_schema_value,
     };
     use crate::{CatalogUri, SchemaView};
     use tombi_uri::SchemaUri;
@@ -1420,6 +1445,24 @@ mod tests {
         file.set_modified(modified).unwrap();
     }
 
+    #[test]
+    fn parse_schema_value_accepts_comments_only_in_jsonc_and_json5() {
+        let content = "// comment\n{\"type\": \"object\"}";
+
+        for (uri, is_ok) in [
+            ("https://example.com/schema.jsonc", true),
+            ("https://example.com/schema.json5", true),
+            ("https://example.com/schema.json", false),
+        ] {
+            let schema_uri = SchemaUri::from_str(uri).unwrap();
+            pretty_assertions::assert_eq!(
+                parse_schema_value(&schema_uri, content).is_ok(),
+                is_ok,
+                "{uri}"
+            );
+        }
+    }
+
     #[test]
     fn schema_include_matches_user_config_path_via_absolute_suffix() {
         assert!(matches_schema_patterns(
@@ -1606,6 +1649,57 @@ mod tests {
         let _ = std::fs::remove_file(schema_path);
     }
 
+    #[tokio::test]
+    async fn loads_jsonc_and_yaml_file_schemas() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "tombi_schema_formats_{}_{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        fs::create_dir_all(&temp_dir).unwrap();
+        let schema_store = SchemaStore::new();
+
+        let jsonc_path = temp_dir.join("schema.jsonc");
+        std::fs::write(
+            &jsonc_path,
+            r#"{
+                // A string schema.
+                "type": "string", /* trailing comma */
+            }"#,
+        )
+        .unwrap();
+        let document_schema = schema_store
+            .try_get_document_schema(&SchemaUri::from_file_path(&jsonc_path).unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        std::assert_matches!(
+            document_schema.schema_view.as_deref(),
+            Some(SchemaView::String(_))
+        );
+
+        let yaml_path = temp_dir.join("schema.yaml");
+        std::fs::write(
+            &yaml_path,
+            "# An integer schema.\ntype: integer\nminimum: 0\n",
+        )
+        .unwrap();
+        let document_schema = schema_store
+            .try_get_document_schema(&SchemaUri::from_file_path(&yaml_path).unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        std::assert_matches!(
+            document_schema.schema_view.as_deref(),
+            Some(SchemaView::Integer(_))
+        );
+
+        let _ = fs::remove_dir_all(temp_dir);
+    }
+
     #[tokio::test]
     async fn ignores_ttl_for_catalog_cache_without_cache_options() {
         let cache_path = temp_cache_path("catalog-cache-offline-default-options");
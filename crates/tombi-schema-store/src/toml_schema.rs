@@ -0,0 +1,141 @@
+use itertools::Itertools;
+use tombi_config::TomlVersion;
+use tombi_document_tree::IntoDocumentTreeAndErrors;
+use tombi_json::{ArrayNode, BoolNode, NumberNode, ObjectNode, StringNode, ValueNode};
+use tombi_json_value::Number;
+
+/// TOML version used to read `*.schema.toml` files.
+///
+/// TOML v1.1.0 allows newlines in inline tables, which keeps nested schemas readable.
+const TOML_SCHEMA_VERSION: TomlVersion = TomlVersion::V1_1_0;
+
+/// Parse a JSON Schema written in TOML into its JSON representation.
+///
+/// Date and time values are converted to their string representation.
+pub(crate) fn parse_toml_schema(content: &str) -> Result<ValueNode, String> {
+    let (root, errors) = tombi_parser::parse(content).into_root_and_errors();
+    if !errors.is_empty() {
+        return Err(errors.iter().map(ToString::to_string).join(", "));
+    }
+
+    let (document_tree, errors) = root
+        .into_document_tree_and_errors(TOML_SCHEMA_VERSION)
+        .into();
+    if !errors.is_empty() {
+        return Err(errors.iter().map(ToString::to_string).join(", "));
+    }
+
+    Ok(table_to_value_node(&document_tree.into()))
+}
+
+fn table_to_value_node(table: &tombi_document_tree::Table) -> ValueNode {
+    let mut properties = tombi_json_value::Map::new();
+    for (key, value) in table.key_values() {
+        properties.insert(
+            StringNode {
+                value: key.value.clone(),
+                range: key.range(),
+            },
+            value_to_value_node(value),
+        );
+    }
+
+    ValueNode::Object(ObjectNode {
+        properties,
+        range: table.range(),
+    })
+}
+
+fn value_to_value_node(value: &tombi_document_tree::Value) -> ValueNode {
+    let range = value.range();
+    match value {
+        tombi_document_tree::Value::Boolean(boolean) => ValueNode::Bool(BoolNode {
+            value: boolean.value(),
+            range,
+        }),
+        tombi_document_tree::Value::Integer(integer) => ValueNode::Number(NumberNode {
+            value: Number::from_i64(integer.value()),
+            range,
+        }),
+        tombi_document_tree::Value::Float(float) => ValueNode::Number(NumberNode {
+            value: Number::from_f64(float.value()),
+            range,
+        }),
+        tombi_document_tree::Value::String(string) => ValueNode::String(StringNode {
+            value: string.value().to_string(),
+            range,
+        }),
+        tombi_document_tree::Value::OffsetDateTime(offset_date_time) => {
+            ValueNode::String(StringNode {
+                value: offset_date_time.value().to_string(),
+                range,
+            })
+        }
+        tombi_document_tree::Value::LocalDateTime(local_date_time) => {
+            ValueNode::String(StringNode {
+                value: local_date_time.value().to_string(),
+                range,
+            })
+        }
+        tombi_document_tree::Value::LocalDate(local_date) => ValueNode::String(StringNode {
+            value: local_date.value().to_string(),
+            range,
+        }),
+        tombi_document_tree::Value::LocalTime(local_time) => ValueNode::String(StringNode {
+            value: local_time.value().to_string(),
+            range,
+        }),
+        tombi_document_tree::Value::Array(array) => ValueNode::Array(ArrayNode {
+            items: array.values().iter().map(value_to_value_node).collect(),
+            range,
+        }),
+        tombi_document_tree::Value::Table(table) => table_to_value_node(table),
+        tombi_document_tree::Value::Incomplete { .. } => {
+            ValueNode::Null(tombi_json::NullNode { range })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_toml_schema() {
+        let value = parse_toml_schema(
+            r#"
+"$schema" = "http://json-schema.org/draft-07/schema#"
+type = "object"
+required = ["name"]
+
+[properties.name]
+type = "string"
+minLength = 1
+
+[properties.port]
+type = "integer"
+default = 8080
+"#,
+        )
+        .unwrap();
+
+        pretty_assertions::assert_eq!(
+            tombi_json_value::Value::from(value),
+            tombi_json_value::json!({
+                "$schema": "http://json-schema.org/draft-07/schema#",
+                "type": "object",
+                "required": ["name"],
+                "properties": {
+                    "name": {"type": "string", "minLength": 1},
+                    "port": {"type": "integer", "default": 8080}
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_toml_schema() {
+        assert!(parse_toml_schema("type = \n").is_err());
+        assert!(parse_toml_schema("type = \"object\"\ntype = \"string\"\n").is_err());
+    }
+}
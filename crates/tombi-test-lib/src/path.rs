@@ -247,3 +247,57 @@ pub fn union_best_match_one_of_test_schema_path() -> PathBuf {
         .join("schemas")
         .join("union-best-match-one-of-test.schema.json")
 }
+
+pub fn x_tombi_array_of_tables_order_test_schema_path() -> PathBuf {
+    project_root_path()
+        .join("schemas")
+        .join("x-tombi-array-of-tables-order-test.schema.json")
+}
+
+pub fn x_tombi_array_values_order_by_test_schema_path() -> PathBuf {
+    project_root_path()
+        .join("schemas")
+        .join("x-tombi-array-values-order-by-test.schema.json")
+}
+
+pub fn x_tombi_layout_test_schema_path() -> PathBuf {
+    project_root_path()
+        .join("schemas")
+        .join("x-tombi-layout-test.schema.json")
+}
+
+pub fn x_tombi_table_style_test_schema_path() -> PathBuf {
+    project_root_path()
+        .join("schemas")
+        .join("x-tombi-table-style-test.schema.json")
+}
+
+pub fn toml_schema_test_schema_path() -> PathBuf {
+    project_root_path()
+        .join("schemas")
+        .join("toml-schema-test.schema.toml")
+}
+
+pub fn content_test_schema_path() -> PathBuf {
+    project_root_path()
+        .join("schemas")
+        .join("content-test.schema.json")
+}
+
+pub fn content_annotation_test_schema_path() -> PathBuf {
+    project_root_path()
+        .join("schemas")
+        .join("content-annotation-test.schema.json")
+}
+
+pub fn vscode_extension_test_schema_path() -> PathBuf {
+    project_root_path()
+        .join("schemas")
+        .join("vscode-extension-test.schema.json")
+}
+
+pub fn table_snippet_test_schema_path() -> PathBuf {
+    project_root_path()
+        .join("schemas")
+        .join("table-snippet-test.schema.json")
+}
@@ -70,6 +70,38 @@ impl FromLsp<crate::Range> for tower_lsp::lsp_types::Range {
     }
 }
 
+impl FromLsp<tower_lsp::lsp_types::Position> for crate::Offset {
+    fn from_lsp(
+        source: tower_lsp::lsp_types::Position,
+        line_index: &crate::LineIndex,
+    ) -> crate::Offset {
+        match (
+            line_index.iter().nth(source.line as usize),
+            line_index.line_text(source.line),
+        ) {
+            (Some(line_span), Some(line_text)) => {
+                let column_text =
+                    take_column_text(line_text, source.character, line_index.encoding_kind);
+                line_span.start + crate::Offset::of(column_text)
+            }
+            _ => line_index
+                .iter()
+                .last()
+                .map(|line_span| line_span.end)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl FromLsp<tower_lsp::lsp_types::Range> for crate::Span {
+    fn from_lsp(source: tower_lsp::lsp_types::Range, line_index: &crate::LineIndex) -> crate::Span {
+        crate::Span::new(
+            crate::Offset::from_lsp(source.start, line_index),
+            crate::Offset::from_lsp(source.end, line_index),
+        )
+    }
+}
+
 fn take_column_text(line_text: &str, target_units: u32, encoding: EncodingKind) -> &str {
     if target_units == 0 {
         return "";
@@ -99,7 +131,7 @@ fn take_column_text(line_text: &str, target_units: u32, encoding: EncodingKind)
 #[cfg(test)]
 mod tests {
     use super::FromLsp;
-    use crate::{LineIndex, Position, features::lsp::EncodingKind};
+    use crate::{LineIndex, Offset, Position, features::lsp::EncodingKind};
 
     #[test]
     fn converts_utf16_column_to_graphemes() {
@@ -124,4 +156,13 @@ mod tests {
             Position::new(0, 5)
         );
     }
+
+    #[test]
+    fn converts_utf16_position_to_offset() {
+        let text = "a = 1\n🦅 = 2";
+        let line_index = LineIndex::new(text, EncodingKind::Utf16);
+        let lsp_position = tower_lsp::lsp_types::Position::new(1, 2);
+
+        assert_eq!(Offset::from_lsp(lsp_position, &line_index), Offset::new(10));
+    }
 }
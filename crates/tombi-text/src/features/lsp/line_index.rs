@@ -24,36 +24,73 @@ impl LineIndex {
     /// Computes the line index for an owned shared string without cloning it.
     pub fn from_arc(text: Arc<str>, encoding_kind: EncodingKind) -> Self {
         let mut lines = Vec::new();
-        let mut start: usize = 0;
-        let bytes = text.as_bytes();
+        let end = scan_lines(&text, 0, &mut lines);
+        lines.push(Span::new(
+            offset_from_usize(end),
+            offset_from_usize(text.len()),
+        ));
 
-        for (idx, ch) in text.char_indices() {
-            if ch == '\n' {
-                let line_end = if idx > start && bytes.get(idx - 1) == Some(&b'\r') {
-                    idx - 1
-                } else {
-                    idx
-                };
+        LineIndex {
+            text,
+            lines,
+            encoding_kind,
+        }
+    }
 
+    /// Computes the line index of `text`, the result of replacing `span` of the indexed text
+    /// with `new_text_len` bytes.
+    ///
+    /// Only the lines touched by the edit are scanned again;
+    /// the lines before it are kept and the lines after it are shifted.
+    pub fn edit(&self, text: Arc<str>, span: Span, new_text_len: usize) -> Self {
+        let span_start = usize::from(span.start);
+        let span_end = usize::from(span.end);
+        let line_of = |offset: usize| {
+            self.lines
+                .partition_point(|line| usize::from(line.start) <= offset)
+                .saturating_sub(1)
+        };
+        let first_line = line_of(span_start);
+        let last_line = line_of(span_end);
+
+        let mut lines = Vec::with_capacity(self.lines.len());
+        lines.extend_from_slice(&self.lines[..first_line]);
+        let scan_start = self
+            .lines
+            .get(first_line)
+            .map_or(0, |line| usize::from(line.start));
+
+        match self.lines.get(last_line + 1) {
+            Some(next_line) => {
+                // The next line start is kept past the edit, so the new text ends with a line break there.
+                let next_line_start =
+                    usize::from(next_line.start) + new_text_len - (span_end - span_start);
+                scan_lines(&text[..next_line_start], scan_start, &mut lines);
+                lines.extend(self.lines[last_line + 1..].iter().map(|line| {
+                    Span::new(
+                        offset_from_usize(
+                            usize::from(line.start) + next_line_start
+                                - usize::from(next_line.start),
+                        ),
+                        offset_from_usize(
+                            usize::from(line.end) + next_line_start - usize::from(next_line.start),
+                        ),
+                    )
+                }));
+            }
+            None => {
+                let end = scan_lines(&text, scan_start, &mut lines);
                 lines.push(Span::new(
-                    offset_from_usize(start),
-                    offset_from_usize(line_end),
+                    offset_from_usize(end),
+                    offset_from_usize(text.len()),
                 ));
-
-                start = idx + ch.len_utf8();
             }
         }
 
-        let final_end = text.len();
-        lines.push(Span::new(
-            offset_from_usize(start),
-            offset_from_usize(final_end),
-        ));
-
         LineIndex {
             text,
             lines,
-            encoding_kind,
+            encoding_kind: self.encoding_kind,
         }
     }
 
@@ -80,6 +117,27 @@ impl LineIndex {
     }
 }
 
+/// Pushes the lines terminated by a line break in `text[start..]`,
+/// and returns the start of the unterminated rest.
+fn scan_lines(text: &str, mut start: usize, lines: &mut Vec<Span>) -> usize {
+    let bytes = text.as_bytes();
+    for (idx, byte) in bytes.iter().enumerate().skip(start) {
+        if *byte == b'\n' {
+            let line_end = if idx > start && bytes[idx - 1] == b'\r' {
+                idx - 1
+            } else {
+                idx
+            };
+            lines.push(Span::new(
+                offset_from_usize(start),
+                offset_from_usize(line_end),
+            ));
+            start = idx + 1;
+        }
+    }
+    start
+}
+
 #[inline]
 fn offset_from_usize(value: usize) -> Offset {
     debug_assert!(value <= u32::MAX as usize, "text is too long to index");
@@ -88,7 +146,9 @@ fn offset_from_usize(value: usize) -> Offset {
 
 #[cfg(test)]
 mod tests {
-    use crate::features::lsp::EncodingKind;
+    use std::sync::Arc;
+
+    use crate::{Offset, Span, features::lsp::EncodingKind};
 
     use super::LineIndex;
 
@@ -118,6 +178,37 @@ mod tests {
         assert_eq!(lines, ["foo", ""]);
     }
 
+    #[test]
+    fn edit_matches_full_index() {
+        let texts = ["foo\nbar\r\nbaz\n\nqux", "foo", "\n", ""];
+        let replacements = ["", "x", "\n", "a\r\nb\n", "\r"];
+
+        for text in texts {
+            let index = LineIndex::new(text, EncodingKind::Utf8);
+            for start in 0..=text.len() {
+                for end in start..=text.len() {
+                    for replacement in replacements {
+                        let mut new_text = text.to_string();
+                        new_text.replace_range(start..end, replacement);
+                        let new_text = Arc::<str>::from(new_text);
+
+                        let edited = index.edit(
+                            Arc::clone(&new_text),
+                            Span::new(Offset::new(start as u32), Offset::new(end as u32)),
+                            replacement.len(),
+                        );
+
+                        assert_eq!(
+                            edited,
+                            LineIndex::from_arc(new_text, EncodingKind::Utf8),
+                            "replace {start}..{end} of {text:?} with {replacement:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
     #[test]
     fn indexes_windows_newlines() {
         let text = "foo\r\nbar";
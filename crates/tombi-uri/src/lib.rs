@@ -26,6 +26,13 @@ macro_rules! comment_directive_schemastore_hostname {
     };
 }
 
+#[macro_export]
+macro_rules! json_schema_hostname {
+    () => {
+        "json-schema.org"
+    };
+}
+
 #[repr(transparent)]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub struct Uri(url::Url);
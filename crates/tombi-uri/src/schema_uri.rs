@@ -84,7 +84,7 @@ mod tests {
         let schema_uri = SchemaUri::from_str(uri_text).unwrap();
         assert_eq!(
             schema_uri.to_string(),
-            format!("file://./schema.json#/definitions/TableValue")
+            "file://./schema.json#/definitions/TableValue".to_string()
         );
         assert_eq!(schema_uri.fragment(), Some("/definitions/TableValue"))
     }
@@ -61,6 +61,10 @@ pub async fn get_tombi_document_comment_directive_and_diagnostics(
             schema_visits: Default::default(),
             store: schema_store,
             strict: None,
+            table_style: None,
+            validation_cache: None,
+            incomplete_as_null: false,
+            partial_root: false,
         };
 
         for tombi_ast::TombiDocumentCommentDirective {
@@ -100,7 +104,7 @@ pub async fn get_tombi_document_comment_directive_and_diagnostics(
                         .map(|diagnostic| into_directive_diagnostic(&diagnostic, content_range)),
                 );
             } else if let Err(diagnostics) =
-                crate::validate(document_tree.clone(), Some(&source_schema), &schema_context).await
+                crate::validate(&document_tree, Some(&source_schema), &schema_context).await
             {
                 total_diagnostics.extend(
                     diagnostics
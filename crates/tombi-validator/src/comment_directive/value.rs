@@ -426,6 +426,10 @@ pub async fn get_comment_directive_document_tree_and_diagnostics<'a>(
         schema_visits: Default::default(),
         store: schema_store,
         strict: None,
+        table_style: None,
+        validation_cache: None,
+        incomplete_as_null: false,
+        partial_root: false,
     };
 
     for tombi_ast::TombiValueCommentDirective {
@@ -464,7 +468,7 @@ pub async fn get_comment_directive_document_tree_and_diagnostics<'a>(
                     .map(|diagnostic| into_directive_diagnostic(&diagnostic, *content_range)),
             );
         } else if let Err(diagnostics) =
-            crate::validate(document_tree.clone(), Some(&source_schema), &schema_context).await
+            crate::validate(&document_tree, Some(&source_schema), &schema_context).await
         {
             total_diagnostics.extend(
                 diagnostics
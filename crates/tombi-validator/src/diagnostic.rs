@@ -3,7 +3,7 @@ use tombi_accessor::MarkdownSchemaAccessors;
 use tombi_schema_store::SchemaAccessors;
 use tombi_severity_level::SeverityLevel;
 use tombi_uri::SchemaUri;
-use tombi_x_keyword::StringFormat;
+use tombi_x_keyword::{StringFormat, TableStyle};
 
 #[derive(thiserror::Error, Debug)]
 pub enum DiagnosticKind {
@@ -66,6 +66,11 @@ pub enum DiagnosticKind {
         actual: tombi_document_tree::ValueType,
     },
 
+    #[error("expected a value of type {expected}, but found null")]
+    NullTypeMismatch {
+        expected: tombi_schema_store::ValueType,
+    },
+
     #[error("the value must be const value \"{expected}\", but found \"{actual}\"")]
     Const { expected: String, actual: String },
 
@@ -120,6 +125,12 @@ pub enum DiagnosticKind {
     #[error("{actual} does not match the pattern `{pattern}`")]
     StringPattern { pattern: String, actual: String },
 
+    #[error("the value is not valid `{encoding}` encoded content")]
+    StringContentEncoding { encoding: String },
+
+    #[error("the value is not valid `{media_type}` content: {reason}")]
+    StringContentMediaType { media_type: String, reason: String },
+
     #[error("array must contain at most {max_values} values, but found {actual}")]
     ArrayMaxValues { max_values: usize, actual: usize },
 
@@ -177,6 +188,18 @@ pub enum DiagnosticKind {
         dependent_key: String,
         required_key: String,
     },
+
+    #[error("table should be written {}", table_style_description(*.expected))]
+    TableStyle { expected: TableStyle },
+}
+
+fn table_style_description(table_style: TableStyle) -> &'static str {
+    match table_style {
+        TableStyle::Inline => "as an inline table",
+        TableStyle::Table => "under a table header",
+        TableStyle::Dotted => "with dotted keys",
+        TableStyle::Any => "in any style",
+    }
 }
 
 #[derive(Debug)]
@@ -199,7 +222,9 @@ impl DiagnosticKind {
                 "unevaluated-property-not-allowed"
             }
             DiagnosticKind::KeyPattern { .. } => "key-pattern",
-            DiagnosticKind::TypeMismatch { .. } => "type-mismatch",
+            DiagnosticKind::TypeMismatch { .. } | DiagnosticKind::NullTypeMismatch { .. } => {
+                "type-mismatch"
+            }
             DiagnosticKind::Const { .. } => "const",
             DiagnosticKind::Enum { .. } => "enum",
             DiagnosticKind::IntegerMaximum { .. } => "integer-maximum",
@@ -216,6 +241,8 @@ impl DiagnosticKind {
             DiagnosticKind::StringMinLength { .. } => "string-min-length",
             DiagnosticKind::StringFormat { .. } => "string-format",
             DiagnosticKind::StringPattern { .. } => "string-pattern",
+            DiagnosticKind::StringContentEncoding { .. } => "string-content-encoding",
+            DiagnosticKind::StringContentMediaType { .. } => "string-content-media-type",
             DiagnosticKind::ArrayMaxValues { .. } => "array-max-values",
             DiagnosticKind::ArrayMinValues { .. } => "array-min-values",
             DiagnosticKind::ArrayContains => "array-contains",
@@ -235,8 +262,96 @@ impl DiagnosticKind {
             DiagnosticKind::NotSchemaMatch => "not-schema-match",
             DiagnosticKind::KeyEmpty => "key-empty",
             DiagnosticKind::TableDependencyRequired { .. } => "table-dependency-required",
+            DiagnosticKind::TableStyle { .. } => "table-style",
         }
     }
+
+    /// Additional guidance shown as `help:` below the message.
+    pub fn help(&self) -> Option<String> {
+        match self {
+            DiagnosticKind::Enum { expected, actual } => expected
+                .iter()
+                .find(|expected| expected.eq_ignore_ascii_case(actual))
+                .map(|expected| format!("did you mean {expected}?")),
+            _ => None,
+        }
+    }
+}
+
+/// The JSON Schema keyword a diagnostic code reports, used to look up `errorMessage`.
+pub(crate) fn schema_keyword(code: &str) -> Option<&'static str> {
+    Some(match code {
+        "type-mismatch" => "type",
+        "const" => "const",
+        "enum" => "enum",
+        "integer-maximum" | "float-maximum" => "maximum",
+        "integer-minimum" | "float-minimum" => "minimum",
+        "integer-exclusive-maximum" | "float-exclusive-maximum" => "exclusiveMaximum",
+        "integer-exclusive-minimum" | "float-exclusive-minimum" => "exclusiveMinimum",
+        "integer-multiple-of" | "float-multiple-of" => "multipleOf",
+        "string-max-length" => "maxLength",
+        "string-min-length" => "minLength",
+        "string-format" => "format",
+        "string-pattern" => "pattern",
+        "string-content-encoding" => "contentEncoding",
+        "string-content-media-type" => "contentMediaType",
+        "array-max-values" => "maxItems",
+        "array-min-values" => "minItems",
+        "array-contains" => "contains",
+        "array-min-contains" => "minContains",
+        "array-max-contains" => "maxContains",
+        "array-unique-values" => "uniqueItems",
+        "array-additional-items" => "additionalItems",
+        "array-unevaluated-item-not-allowed" => "unevaluatedItems",
+        "table-max-keys" => "maxProperties",
+        "table-min-keys" => "minProperties",
+        "table-key-required" => "required",
+        "table-dependency-required" => "dependentRequired",
+        "key-not-allowed" | "table-strict-additional-keys" => "additionalProperties",
+        "unevaluated-property-not-allowed" => "unevaluatedProperties",
+        "key-pattern" => "propertyNames",
+        "one-of-multiple-match" | "one-of-no-match" => "oneOf",
+        "not-schema-match" => "not",
+        _ => return None,
+    })
+}
+
+/// Whether a keyword reports its errors on the keys of a table or the items of an array.
+pub(crate) fn is_element_keyword(keyword: &str) -> bool {
+    matches!(
+        keyword,
+        "additionalProperties"
+            | "unevaluatedProperties"
+            | "propertyNames"
+            | "uniqueItems"
+            | "additionalItems"
+            | "unevaluatedItems"
+    )
+}
+
+/// Build a `help:` listing the keys the schema expects instead of a disallowed key.
+pub(crate) fn expected_keys_help(expected_keys: &[String]) -> Option<String> {
+    const MAX_EXPECTED_KEYS: usize = 8;
+
+    if expected_keys.is_empty() {
+        return None;
+    }
+
+    let mut help = format!(
+        "expected one of {}",
+        expected_keys
+            .iter()
+            .take(MAX_EXPECTED_KEYS)
+            .map(|key| format!("\"{key}\""))
+            .join(", ")
+    );
+    if expected_keys.len() > MAX_EXPECTED_KEYS {
+        help.push_str(&format!(
+            " and {} others",
+            expected_keys.len() - MAX_EXPECTED_KEYS
+        ));
+    }
+    Some(help)
 }
 
 impl Diagnostic {
@@ -257,19 +372,35 @@ impl Diagnostic {
         level: impl Into<SeverityLevel>,
         diagnostics: &mut Vec<tombi_diagnostic::Diagnostic>,
     ) {
-        match level.into() {
-            SeverityLevel::Error => diagnostics.push(tombi_diagnostic::Diagnostic::new_error(
+        let help = self.kind.help();
+        self.push_diagnostic_with_help(level, help, diagnostics);
+    }
+
+    /// Push the diagnostic with a `help:` that depends on the schema, not only on the kind.
+    pub fn push_diagnostic_with_help(
+        self,
+        level: impl Into<SeverityLevel>,
+        help: Option<String>,
+        diagnostics: &mut Vec<tombi_diagnostic::Diagnostic>,
+    ) {
+        let diagnostic = match level.into() {
+            SeverityLevel::Error => tombi_diagnostic::Diagnostic::new_error(
                 self.kind.to_string(),
                 self.code(),
                 self.range,
-            )),
-            SeverityLevel::Warn => diagnostics.push(tombi_diagnostic::Diagnostic::new_warning(
+            ),
+            SeverityLevel::Warn => tombi_diagnostic::Diagnostic::new_warning(
                 self.kind.to_string(),
                 self.code(),
                 self.range,
-            )),
-            SeverityLevel::Off => {}
-        }
+            ),
+            SeverityLevel::Off => return,
+        };
+
+        diagnostics.push(match help {
+            Some(help) => diagnostic.with_help(help),
+            None => diagnostic,
+        });
     }
 }
 
@@ -1,4 +1,4 @@
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Invalid {
     /// Whether a JSON Schema assertion failed independently of diagnostic severity.
     pub assertion_failed: bool,
@@ -2,6 +2,7 @@ mod all_of;
 mod any_of;
 mod array;
 mod boolean;
+mod content;
 mod float;
 mod if_then_else;
 mod integer;
@@ -9,6 +10,7 @@ mod local_date;
 mod local_date_time;
 mod local_time;
 mod not_schema;
+mod null;
 mod offset_date_time;
 mod one_of;
 mod string;
@@ -46,7 +48,7 @@ use tombi_severity_level::{SeverityLevel, SeverityLevelDefaultError, SeverityLev
 use tombi_text::RelativePosition;
 
 pub fn validate<'a: 'b, 'b>(
-    tree: tombi_document_tree::DocumentTree,
+    tree: &'a tombi_document_tree::DocumentTree,
     source_schema: Option<&'a tombi_schema_store::SourceSchema>,
     schema_context: &'a tombi_schema_store::SchemaContext,
 ) -> BoxFuture<'b, Result<(), Vec<tombi_diagnostic::Diagnostic>>> {
@@ -441,6 +443,58 @@ pub(crate) fn with_lint_diagnostics(
     }
 }
 
+/// Replace the messages of the errors reported for `value` by the `errorMessage` of its schema.
+///
+/// Only the errors of the value itself are replaced, not those of its nested values.
+/// The errors a table reports on its keys and an array reports on its items
+/// (such as `additionalProperties` and `uniqueItems`) are its own.
+#[allow(clippy::result_large_err)]
+pub(crate) fn with_error_message(
+    result: Result<crate::Valid, crate::Invalid>,
+    value: &tombi_document_tree::Value,
+    current_schema: Option<&tombi_schema_store::CurrentSchema<'_>>,
+) -> Result<crate::Valid, crate::Invalid> {
+    let Err(mut error) = result else {
+        return result;
+    };
+    let Some(error_message) = current_schema
+        .and_then(|schema| schema.semantic_schema.as_deref())
+        .and_then(|schema| schema.error_message())
+    else {
+        return Err(error);
+    };
+
+    let element_ranges = match value {
+        tombi_document_tree::Value::Table(table) => table
+            .key_values()
+            .iter()
+            .map(|(key, value)| key.range() + value.range())
+            .collect(),
+        tombi_document_tree::Value::Array(array) => {
+            array.values().iter().map(|value| value.range()).collect()
+        }
+        _ => Vec::new(),
+    };
+
+    error.diagnostics = std::mem::take(&mut error.diagnostics)
+        .into_iter()
+        .map(|diagnostic| {
+            let Some(keyword) = crate::diagnostic::schema_keyword(diagnostic.code()) else {
+                return diagnostic;
+            };
+            let is_own = diagnostic.range() == value.range()
+                || (crate::diagnostic::is_element_keyword(keyword)
+                    && element_ranges.contains(&diagnostic.range()));
+            match error_message.message(keyword) {
+                Some(message) if is_own => diagnostic.with_message(message),
+                _ => diagnostic,
+            }
+        })
+        .collect();
+
+    Err(error)
+}
+
 pub(crate) fn schema_resolution_diagnostic(
     error: &tombi_schema_store::Error,
     range: tombi_text::Range,
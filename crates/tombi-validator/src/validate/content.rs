@@ -0,0 +1,384 @@
+use base64::Engine;
+use tombi_document_tree::IntoDocumentTreeAndErrors;
+use tombi_schema_store::{CurrentSchema, SchemaContext, StringSchema};
+use tombi_severity_level::SeverityLevelDefaultWarn;
+use tombi_text::{Position, Range, RelativePosition};
+
+use crate::Validate;
+
+/// The key the decoded JSON content is stored under, when converted to TOML for `contentSchema`.
+const JSON_CONTENT_KEY: &str = "content";
+
+/// Validate a string by `contentEncoding`, `contentMediaType` and `contentSchema`.
+///
+/// Base64 content is decoded, and JSON/TOML content is syntax-checked and validated against `contentSchema`.
+/// Diagnostics in the content point inside the string literal when the value is written without escapes,
+/// and at the whole string otherwise.
+///
+/// Encoding and media type errors are warnings, and nothing is checked for the dialects
+/// where the content keywords are annotations only, unless `x-tombi-content-assertion` is set.
+pub(crate) async fn validate_content(
+    value: &str,
+    range: Range,
+    string_schema: &StringSchema,
+    current_schema: &CurrentSchema<'_>,
+    schema_context: &SchemaContext<'_>,
+) -> Result<crate::Valid, crate::Invalid> {
+    if !string_schema.content_assertion
+        || (string_schema.content_encoding.is_none() && string_schema.content_media_type.is_none())
+    {
+        return Ok(crate::Valid::new());
+    }
+
+    let mut location = ContentLocation::new(value, range);
+
+    let decoded;
+    let content = match string_schema.content_encoding.as_deref() {
+        Some(encoding) if encoding.eq_ignore_ascii_case("base64") => {
+            let encoded = value
+                .chars()
+                .filter(|c| !c.is_ascii_whitespace())
+                .collect::<String>();
+            let Ok(bytes) = base64::engine::general_purpose::STANDARD.decode(encoded) else {
+                return Err(content_error(
+                    crate::DiagnosticKind::StringContentEncoding {
+                        encoding: encoding.to_string(),
+                    },
+                    range,
+                ));
+            };
+            location = ContentLocation::Whole(range);
+
+            if string_schema.content_media_type.is_none() {
+                return Ok(crate::Valid::new());
+            }
+            let Ok(text) = String::from_utf8(bytes) else {
+                return Err(content_error(
+                    crate::DiagnosticKind::StringContentMediaType {
+                        media_type: string_schema.content_media_type.clone().unwrap_or_default(),
+                        reason: "invalid UTF-8".to_string(),
+                    },
+                    range,
+                ));
+            };
+            decoded = text;
+            decoded.as_str()
+        }
+        _ => value,
+    };
+
+    let Some(media_type) = string_schema.content_media_type.as_deref() else {
+        return Ok(crate::Valid::new());
+    };
+    let essence = media_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+
+    let (content_value, spans) = if essence == "application/json" || essence.ends_with("+json") {
+        let json_value = match content.parse::<tombi_json::ValueNode>() {
+            Ok(json_value) => json_value,
+            Err(err) => {
+                return Err(content_error(
+                    crate::DiagnosticKind::StringContentMediaType {
+                        media_type: media_type.to_string(),
+                        reason: err.to_string(),
+                    },
+                    location.whole_range(),
+                ));
+            }
+        };
+        if string_schema.content_schema.is_none() {
+            return Ok(crate::Valid::new());
+        }
+
+        // The validator works on TOML document trees, so the JSON is converted to TOML first.
+        let mut converter = JsonToToml::default();
+        converter.push(JSON_CONTENT_KEY);
+        converter.push(" = ");
+        if converter.push_value(&json_value).is_none() {
+            // TOML has no null, so the content cannot be validated against `contentSchema`.
+            return Ok(crate::Valid::new());
+        }
+        let Ok(root) = tombi_parser::parse(&converter.text).try_into_root() else {
+            return Ok(crate::Valid::new());
+        };
+        let (document_tree, _) = root
+            .into_document_tree_and_errors(schema_context.toml_version)
+            .into();
+        let Some(content_value) = tombi_document_tree::Table::from(document_tree)
+            .get(JSON_CONTENT_KEY)
+            .cloned()
+        else {
+            return Ok(crate::Valid::new());
+        };
+        (content_value, Some(converter.spans))
+    } else if essence == "application/toml" || essence.ends_with("+toml") {
+        let (root, errors) = tombi_parser::parse(content).into_root_and_errors();
+        if let Some(error) = errors.first() {
+            return Err(content_error(
+                crate::DiagnosticKind::StringContentMediaType {
+                    media_type: media_type.to_string(),
+                    reason: error.to_message(),
+                },
+                location.map_range(error.range()),
+            ));
+        }
+        let (document_tree, errors) = root
+            .into_document_tree_and_errors(schema_context.toml_version)
+            .into();
+        if let Some(error) = errors.first() {
+            return Err(content_error(
+                crate::DiagnosticKind::StringContentMediaType {
+                    media_type: media_type.to_string(),
+                    reason: error.to_string(),
+                },
+                location.map_range(error.range()),
+            ));
+        }
+        (tombi_document_tree::Value::from(document_tree), None)
+    } else {
+        return Ok(crate::Valid::new());
+    };
+
+    let Some(content_schema) = &string_schema.content_schema else {
+        return Ok(crate::Valid::new());
+    };
+    let Ok(Some(content_schema)) = tombi_schema_store::resolve_schema_item(
+        content_schema,
+        current_schema.schema_uri.clone(),
+        current_schema.definitions.clone(),
+        current_schema.strict,
+        schema_context.store,
+    )
+    .await
+    else {
+        return Ok(crate::Valid::new());
+    };
+
+    match content_value
+        .validate(&[], Some(&content_schema), schema_context)
+        .await
+    {
+        Ok(valid) => Ok(valid),
+        Err(mut invalid) => {
+            invalid.diagnostics = std::mem::take(&mut invalid.diagnostics)
+                .into_iter()
+                .map(|diagnostic| {
+                    diagnostic.map_ranges(|range| match &spans {
+                        Some(spans) => match json_range(spans, range) {
+                            Some(range) => location.map_range(range),
+                            None => location.whole_range(),
+                        },
+                        None => location.map_range(range),
+                    })
+                })
+                .collect();
+            Err(invalid)
+        }
+    }
+}
+
+fn content_error(kind: crate::DiagnosticKind, range: Range) -> crate::Invalid {
+    let mut diagnostics = vec![];
+    crate::Diagnostic {
+        kind: Box::new(kind),
+        range,
+    }
+    .push_diagnostic_with_level(SeverityLevelDefaultWarn::default(), &mut diagnostics);
+
+    crate::Invalid {
+        assertion_failed: false,
+        match_evidence: Default::default(),
+        diagnostics,
+        local_evaluated_locations: Default::default(),
+    }
+}
+
+/// Where the content of a string starts in the document.
+enum ContentLocation {
+    /// The content is written verbatim, starting at the position.
+    Verbatim { start: Position, range: Range },
+    /// The content cannot be located precisely, such as when it contains escapes.
+    Whole(Range),
+}
+
+impl ContentLocation {
+    fn new(value: &str, range: Range) -> Self {
+        let value_size = RelativePosition::of(value);
+
+        // Bare keys, `"…"`/`'…'` strings, and `"""…"""`/`'''…'''` strings with or without a leading newline.
+        let candidates = [
+            (range.start, 0),
+            (range.start + RelativePosition::from((0, 1)), 1),
+            (range.start + RelativePosition::from((0, 3)), 3),
+            (range.start + RelativePosition::from((1, 0)), 3),
+        ];
+        for (start, delimiter_size) in candidates {
+            let end = start + value_size;
+            if end.line == range.end.line && end.column + delimiter_size == range.end.column {
+                return Self::Verbatim { start, range };
+            }
+        }
+
+        Self::Whole(range)
+    }
+
+    fn map_range(&self, content_range: Range) -> Range {
+        match self {
+            Self::Verbatim { start, range } => {
+                let mapped = Range::new(
+                    *start + RelativePosition::from(content_range.start),
+                    *start + RelativePosition::from(content_range.end),
+                );
+                if range.contains(mapped.start) && range.contains(mapped.end) {
+                    mapped
+                } else {
+                    *range
+                }
+            }
+            Self::Whole(range) => *range,
+        }
+    }
+
+    fn whole_range(&self) -> Range {
+        match self {
+            Self::Verbatim { range, .. } | Self::Whole(range) => *range,
+        }
+    }
+}
+
+/// The range of the innermost JSON node that was converted to the TOML `range`.
+fn json_range(spans: &[(Range, Range)], range: Range) -> Option<Range> {
+    spans
+        .iter()
+        .filter(|(toml_range, _)| {
+            toml_range.contains(range.start) && toml_range.contains(range.end)
+        })
+        .min_by_key(|(toml_range, _)| toml_range.end - toml_range.start)
+        .map(|(_, json_range)| *json_range)
+}
+
+/// Convert JSON into a single line of TOML, remembering which JSON node each TOML value came from.
+#[derive(Default)]
+struct JsonToToml {
+    text: String,
+    position: Position,
+    spans: Vec<(Range, Range)>,
+}
+
+impl JsonToToml {
+    fn push(&mut self, text: &str) {
+        self.text.push_str(text);
+        self.position += RelativePosition::of(text);
+    }
+
+    fn push_value(&mut self, value: &tombi_json::ValueNode) -> Option<()> {
+        let start = self.position;
+        match value {
+            tombi_json::ValueNode::Null(_) => return None,
+            tombi_json::ValueNode::Bool(bool) => self.push(&bool.value.to_string()),
+            tombi_json::ValueNode::Number(number) => {
+                match (number.value.as_i64(), number.value.as_f64()) {
+                    (Some(integer), _) => self.push(&integer.to_string()),
+                    (None, Some(float)) => self.push(&format!("{float:?}")),
+                    (None, None) => return None,
+                }
+            }
+            tombi_json::ValueNode::String(string) => {
+                self.push(&tombi_toml_text::to_basic_string(&string.value))
+            }
+            tombi_json::ValueNode::Array(array) => {
+                self.push("[");
+                for (index, item) in array.items.iter().enumerate() {
+                    if index > 0 {
+                        self.push(", ");
+                    }
+                    self.push_value(item)?;
+                }
+                self.push("]");
+            }
+            tombi_json::ValueNode::Object(object) => {
+                self.push("{");
+                let mut is_first = true;
+                for (key, value) in object.properties.iter() {
+                    // A null member is treated as a missing key.
+                    if matches!(value, tombi_json::ValueNode::Null(_)) {
+                        continue;
+                    }
+                    if !is_first {
+                        self.push(", ");
+                    }
+                    is_first = false;
+
+                    let key_start = self.position;
+                    self.push(&tombi_toml_text::to_basic_string(&key.value));
+                    self.spans
+                        .push((Range::new(key_start, self.position), key.range));
+                    self.push(" = ");
+                    self.push_value(value)?;
+                }
+                self.push("}");
+            }
+        }
+        self.spans
+            .push((Range::new(start, self.position), value.range()));
+
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(start: (u32, u32), end: (u32, u32)) -> Range {
+        Range::new(Position::new(start.0, start.1), Position::new(end.0, end.1))
+    }
+
+    #[test]
+    fn maps_content_range_into_basic_string() {
+        // toml = "name = 1"
+        let location = ContentLocation::new("name = 1", range((0, 7), (0, 17)));
+        pretty_assertions::assert_eq!(
+            location.map_range(range((0, 7), (0, 8))),
+            range((0, 15), (0, 16))
+        );
+    }
+
+    #[test]
+    fn maps_content_range_into_multi_line_string() {
+        // toml = """
+        // name = 1
+        // """
+        let location = ContentLocation::new("name = 1\n", range((0, 7), (2, 3)));
+        pretty_assertions::assert_eq!(
+            location.map_range(range((0, 7), (0, 8))),
+            range((1, 7), (1, 8))
+        );
+    }
+
+    #[test]
+    fn falls_back_to_whole_range_for_escaped_string() {
+        // json = "{\"a\": 1}"
+        let location = ContentLocation::new("{\"a\": 1}", range((0, 7), (0, 19)));
+        pretty_assertions::assert_eq!(
+            location.map_range(range((0, 6), (0, 7))),
+            range((0, 7), (0, 19))
+        );
+    }
+
+    #[test]
+    fn json_range_selects_innermost_span() {
+        let spans = [
+            (range((0, 14), (0, 15)), range((0, 9), (0, 10))),
+            (range((0, 10), (0, 16)), range((0, 0), (0, 11))),
+        ];
+        pretty_assertions::assert_eq!(
+            json_range(&spans, range((0, 14), (0, 15))),
+            Some(range((0, 9), (0, 10)))
+        );
+    }
+}
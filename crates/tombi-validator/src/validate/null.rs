@@ -0,0 +1,85 @@
+use tombi_future::{BoxFuture, Boxable};
+use tombi_schema_store::{CurrentSchema, SchemaView};
+use tombi_severity_level::SeverityLevelDefaultError;
+
+/// Validate a JSON `null`, which the document tree holds as an incomplete value.
+///
+/// TOML has no null, so only `"type": "null"` and schemas accepting anything allow it.
+pub(crate) fn validate_null<'a: 'b, 'b>(
+    range: tombi_text::Range,
+    accessors: &'a [tombi_schema_store::Accessor],
+    current_schema: Option<&'a CurrentSchema<'a>>,
+    schema_context: &'a tombi_schema_store::SchemaContext,
+) -> BoxFuture<'b, Result<crate::Valid, crate::Invalid>> {
+    async move {
+        let Some(current_schema) = current_schema else {
+            return Ok(crate::Valid::new());
+        };
+
+        let (schemas, requires_all) = match current_schema.schema_view.as_ref() {
+            SchemaView::Null | SchemaView::Anything(_) => return Ok(crate::Valid::new()),
+            SchemaView::OneOf(one_of_schema) => (&one_of_schema.schemas, false),
+            SchemaView::AnyOf(any_of_schema) => (&any_of_schema.schemas, false),
+            SchemaView::AllOf(all_of_schema) => (&all_of_schema.schemas, true),
+            SchemaView::Nothing(_) => {
+                return Err(null_error(crate::DiagnosticKind::Nothing, range));
+            }
+            schema_view => {
+                return Err(null_error(
+                    crate::DiagnosticKind::NullTypeMismatch {
+                        expected: schema_view.value_type().await,
+                    },
+                    range,
+                ));
+            }
+        };
+
+        let Some(schemas) = tombi_schema_store::resolve_and_collect_schemas(
+            schemas,
+            current_schema.schema_uri.clone(),
+            current_schema.definitions.clone(),
+            current_schema.strict,
+            schema_context.store,
+            &schema_context.schema_visits,
+            accessors,
+        )
+        .await
+        else {
+            return Ok(crate::Valid::new());
+        };
+
+        let mut allowed = requires_all;
+        for schema in &schemas {
+            let is_valid = validate_null(range, accessors, Some(schema), schema_context)
+                .await
+                .is_ok();
+            if is_valid != requires_all {
+                allowed = is_valid;
+                break;
+            }
+        }
+
+        if allowed || schemas.is_empty() {
+            Ok(crate::Valid::new())
+        } else {
+            Err(null_error(
+                crate::DiagnosticKind::NullTypeMismatch {
+                    expected: current_schema.schema_view.value_type().await,
+                },
+                range,
+            ))
+        }
+    }
+    .boxed()
+}
+
+fn null_error(kind: crate::DiagnosticKind, range: tombi_text::Range) -> crate::Invalid {
+    let mut diagnostics = vec![];
+    crate::Diagnostic {
+        kind: Box::new(kind),
+        range,
+    }
+    .push_diagnostic_with_level(SeverityLevelDefaultError::default(), &mut diagnostics);
+
+    diagnostics.into()
+}
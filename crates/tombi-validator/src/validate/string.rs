@@ -15,7 +15,7 @@ use crate::{
         get_tombi_key_rules_and_diagnostics, get_tombi_key_table_value_rules_and_diagnostics,
     },
     validate::{
-        format, handle_anything_schema, handle_deprecated_value, handle_nothing_schema,
+        content, format, handle_anything_schema, handle_deprecated_value, handle_nothing_schema,
         handle_type_mismatch, handle_unused_noqa, validate_adjacent_applicators,
     },
 };
@@ -339,6 +339,18 @@ where
         string_value.comment_directives(),
     );
 
+    let result = crate::validate::merge_validation_results(
+        result,
+        content::validate_content(
+            string_value.value(),
+            ValueImpl::range(string_value),
+            string_schema,
+            current_schema,
+            schema_context,
+        )
+        .await,
+    );
+
     let base_result = match result {
         Ok(result) => {
             let mut diagnostics = vec![];
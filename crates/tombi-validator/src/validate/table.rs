@@ -1,10 +1,12 @@
 use itertools::Itertools;
 use tombi_accessor::MarkdownSchemaAccessors;
 use tombi_comment_directive::value::TableCommonLintRules;
+use tombi_document_tree::TableKind;
 use tombi_future::{BoxFuture, Boxable};
 use tombi_hashmap::HashSet;
 use tombi_schema_store::{Accessor, CompositeSchema, CurrentSchema, SchemaAccessor, SchemaView};
 use tombi_severity_level::{SeverityLevel, SeverityLevelDefaultError};
+use tombi_x_keyword::TableStyle;
 
 use crate::{
     comment_directive::{
@@ -198,9 +200,14 @@ async fn validate_table(
                 .await
             {
                 Ok(Some(current_schema)) => {
-                    let result = value
-                        .validate(&new_accessors, Some(&current_schema), schema_context)
-                        .await;
+                    let result = validate_property_value(
+                        accessor_raw_text,
+                        value,
+                        &new_accessors,
+                        &current_schema,
+                        schema_context,
+                    )
+                    .await;
                     declared_schema_applied = true;
                     declared_value_matched &= crate::validate::is_assertion_success(&result);
                     child_match_evidence
@@ -262,9 +269,14 @@ async fn validate_table(
                         .await
                     {
                         Ok(Some(current_schema)) => {
-                            let result = value
-                                .validate(&new_accessors, Some(&current_schema), schema_context)
-                                .await;
+                            let result = validate_property_value(
+                                accessor_raw_text,
+                                value,
+                                &new_accessors,
+                                &current_schema,
+                                schema_context,
+                            )
+                            .await;
                             declared_schema_applied = true;
                             declared_value_matched &=
                                 crate::validate::is_assertion_success(&result);
@@ -382,9 +394,14 @@ async fn validate_table(
                             table_rules.as_ref().map(|rules| &rules.common),
                         );
 
-                        let result = value
-                            .validate(&new_accessors, Some(&current_schema), schema_context)
-                            .await;
+                        let result = validate_property_value(
+                            accessor_raw_text,
+                            value,
+                            &new_accessors,
+                            &current_schema,
+                            schema_context,
+                        )
+                        .await;
                         if crate::validate::is_assertion_success(&result) {
                             match_evidence.mark_fallback_child_value(new_accessors.clone());
                         }
@@ -509,13 +526,32 @@ async fn validate_table(
                     })
                     .unwrap_or_default();
 
+                let expected_keys = if table_schema.pattern_properties.is_none() {
+                    table_schema
+                        .properties
+                        .read()
+                        .await
+                        .keys()
+                        .map(ToString::to_string)
+                        .filter(|expected_key| {
+                            !table_value.keys().any(|key| &key.value == expected_key)
+                        })
+                        .collect_vec()
+                } else {
+                    Vec::new()
+                };
+
                 crate::Diagnostic {
                     kind: Box::new(crate::DiagnosticKind::KeyNotAllowed {
                         key: key.to_string(),
                     }),
                     range: key.range() + value.range(),
                 }
-                .push_diagnostic_with_level(level, &mut total_diagnostics);
+                .push_diagnostic_with_help(
+                    level,
+                    crate::diagnostic::expected_keys_help(&expected_keys),
+                    &mut total_diagnostics,
+                );
                 continue;
             } else if schema_context.strict(Some(current_schema))
                 && key_rules
@@ -534,8 +570,10 @@ async fn validate_table(
     }
 
     let keys = table_value.keys().map(|key| &key.value).collect_vec();
+    // The other keys of a partial root are in the rest of the document.
+    let is_partial_root = schema_context.partial_root && accessors.is_empty();
 
-    if let Some(required) = &table_schema.required {
+    if !is_partial_root && let Some(required) = &table_schema.required {
         for required_key in required {
             if !keys.contains(&required_key) {
                 assertion_failed = true;
@@ -580,6 +618,24 @@ async fn validate_table(
         }
     }
 
+    let table_style_rule = schema_context.table_style.unwrap_or_default();
+    if let Some(expected) = table_schema
+        .table_style
+        .map(|table_style| table_style_rule.style.unwrap_or(table_style))
+        && let Some(actual) = table_style(table_value.kind())
+        && expected != TableStyle::Any
+        && expected != actual
+    {
+        crate::Diagnostic {
+            kind: Box::new(crate::DiagnosticKind::TableStyle { expected }),
+            range: table_value.range(),
+        }
+        .push_diagnostic_with_level(
+            table_style_rule.level.unwrap_or_default(),
+            &mut total_diagnostics,
+        );
+    }
+
     if let Some(max_properties) = table_schema.max_properties
         && table_value.keys().count() > max_properties
     {
@@ -617,6 +673,7 @@ async fn validate_table(
     }
 
     if let Some(min_properties) = table_schema.min_properties
+        && !is_partial_root
         && table_value.keys().count() < min_properties
     {
         assertion_failed = true;
@@ -661,7 +718,7 @@ async fn validate_table(
             match dependency {
                 tombi_schema_store::Dependency::Property(required_keys) => {
                     for required_key in required_keys {
-                        if !keys.contains(&required_key) {
+                        if !is_partial_root && !keys.contains(&required_key) {
                             assertion_failed = true;
                             crate::Diagnostic {
                                 kind: Box::new(crate::DiagnosticKind::TableDependencyRequired {
@@ -703,6 +760,11 @@ async fn validate_table(
                                 schema_visits: schema_context.schema_visits.clone(),
                                 store: schema_context.store,
                                 strict: Some(false.into()),
+                                table_style: schema_context.table_style,
+                                // Results depend on `strict`, so they are not shared with the parent context.
+                                validation_cache: None,
+                                incomplete_as_null: schema_context.incomplete_as_null,
+                                partial_root: schema_context.partial_root,
                             };
 
                             if let Err(crate::Invalid {
@@ -740,7 +802,7 @@ async fn validate_table(
             }
 
             for required_key in required_keys {
-                if !keys.contains(&required_key) {
+                if !is_partial_root && !keys.contains(&required_key) {
                     assertion_failed = true;
                     crate::Diagnostic {
                         kind: Box::new(crate::DiagnosticKind::TableDependencyRequired {
@@ -786,6 +848,10 @@ async fn validate_table(
                         schema_visits: schema_context.schema_visits.clone(),
                         store: schema_context.store,
                         strict: Some(false.into()),
+                        table_style: schema_context.table_style,
+                        validation_cache: None,
+                        incomplete_as_null: schema_context.incomplete_as_null,
+                        partial_root: schema_context.partial_root,
                     };
 
                     if let Err(crate::Invalid {
@@ -1434,6 +1500,72 @@ fn collect_evaluated_properties_from_if_then_else_schema<'a>(
     .boxed()
 }
 
+/// A validation result of a root table value, kept in [`tombi_schema_store::ValidationCache`].
+struct CachedValidation {
+    value: tombi_document_tree::Value,
+    toml_version: tombi_toml_version::TomlVersion,
+    strict: bool,
+    result: Result<crate::Valid, crate::Invalid>,
+}
+
+/// Validate the value of a table property.
+///
+/// The values of the root table are looked up in the validation cache of the schema context first,
+/// so that only the values changed since the previous validation are validated again.
+async fn validate_property_value(
+    key: &str,
+    value: &tombi_document_tree::Value,
+    accessors: &[tombi_schema_store::Accessor],
+    current_schema: &CurrentSchema<'_>,
+    schema_context: &tombi_schema_store::SchemaContext<'_>,
+) -> Result<crate::Valid, crate::Invalid> {
+    let Some(validation_cache) = schema_context
+        .validation_cache
+        .as_ref()
+        .filter(|_| accessors.len() == 1)
+    else {
+        return value
+            .validate(accessors, Some(current_schema), schema_context)
+            .await;
+    };
+
+    let strict = schema_context.strict(Some(current_schema));
+    if let Some(cached) = validation_cache.get(key, &current_schema.schema_view)
+        && let Some(cached) = cached.downcast_ref::<CachedValidation>()
+        && cached.toml_version == schema_context.toml_version
+        && cached.strict == strict
+        && cached.value == *value
+    {
+        return cached.result.clone();
+    }
+
+    let result = value
+        .validate(accessors, Some(current_schema), schema_context)
+        .await;
+    validation_cache.insert(
+        key.to_string(),
+        current_schema.schema_view.clone(),
+        std::sync::Arc::new(CachedValidation {
+            value: value.clone(),
+            toml_version: schema_context.toml_version,
+            strict,
+            result: result.clone(),
+        }),
+    );
+
+    result
+}
+
+/// The style a table is written in, or None for tables without a style such as the root.
+fn table_style(kind: TableKind) -> Option<TableStyle> {
+    match kind {
+        TableKind::InlineTable { .. } => Some(TableStyle::Inline),
+        TableKind::Table | TableKind::ParentTable => Some(TableStyle::Table),
+        TableKind::ParentKey | TableKind::KeyValue => Some(TableStyle::Dotted),
+        TableKind::Root => None,
+    }
+}
+
 async fn validate_table_without_schema(
     table_value: &tombi_document_tree::Table,
     accessors: &[tombi_schema_store::Accessor],
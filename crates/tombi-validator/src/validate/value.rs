@@ -19,7 +19,7 @@ impl Validate for tombi_document_tree::Value {
                     .await;
             }
 
-            match self {
+            let result = match self {
                 Self::Boolean(boolean) => {
                     boolean
                         .validate(accessors, current_schema, schema_context)
@@ -70,8 +70,14 @@ impl Validate for tombi_document_tree::Value {
                         .validate(accessors, current_schema, schema_context)
                         .await
                 }
+                Self::Incomplete { range } if schema_context.incomplete_as_null => {
+                    super::null::validate_null(*range, accessors, current_schema, schema_context)
+                        .await
+                }
                 Self::Incomplete { .. } => Ok(Default::default()),
-            }
+            };
+
+            crate::validate::with_error_message(result, self, current_schema)
         }
         .boxed()
     }
@@ -3,9 +3,15 @@ use std::str::FromStr;
 pub const X_TOMBI_TOML_VERSION: &str = "x-tombi-toml-version";
 pub const X_TOMBI_ARRAY_VALUES_ORDER: &str = "x-tombi-array-values-order";
 pub const X_TOMBI_ARRAY_VALUES_ORDER_BY: &str = "x-tombi-array-values-order-by";
+pub const X_TOMBI_ARRAY_OF_TABLES_ORDER: &str = "x-tombi-array-of-tables-order";
+pub const X_TOMBI_ARRAY_LAYOUT: &str = "x-tombi-array-layout";
+pub const X_TOMBI_INLINE_TABLE_LAYOUT: &str = "x-tombi-inline-table-layout";
 pub const X_TOMBI_TABLE_KEYS_ORDER: &str = "x-tombi-table-keys-order";
+pub const X_TOMBI_TABLE_STYLE: &str = "x-tombi-table-style";
 pub const X_TOMBI_STRING_FORMATS: &str = "x-tombi-string-formats";
 pub const X_TOMBI_ADDITIONAL_KEY_LABEL: &str = "x-tombi-additional-key-label";
+pub const X_TOMBI_LANGUAGE: &str = "x-tombi-language";
+pub const X_TOMBI_CONTENT_ASSERTION: &str = "x-tombi-content-assertion";
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -72,6 +78,169 @@ impl PartialEq<ArrayValuesOrderBy> for String {
     }
 }
 
+/// How the sort key of a string value is extracted, when the array element is a string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StringValuesOrderBy {
+    /// The package name of a PEP 508 requirement, normalized as in PEP 503.
+    Pep503Name,
+    /// The last component of a path.
+    Basename,
+    /// The first capture group of the regex, or the whole match if it has no groups.
+    Pattern(String),
+}
+
+impl std::fmt::Display for StringValuesOrderBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pep503Name => write!(f, "pep503-name"),
+            Self::Basename => write!(f, "basename"),
+            Self::Pattern(pattern) => write!(f, "{{ pattern = {pattern:?} }}"),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for StringValuesOrderBy {
+    type Error = &'a str;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        match value {
+            "pep503-name" => Ok(Self::Pep503Name),
+            "basename" => Ok(Self::Basename),
+            _ => Err(value),
+        }
+    }
+}
+
+/// A sub-key used to sort the entries of an array of tables, such as `[[package]]`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub struct ArrayOfTablesOrderKey {
+    /// The key of the entry whose value is compared.
+    pub key: String,
+    /// How the values are compared.
+    pub order: ArrayValuesOrder,
+}
+
+/// How the formatter lays out an array, regardless of whether it fits in `line-width`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum ArrayLayout {
+    /// Every non-empty array is multi-line.
+    AlwaysMultiline,
+    /// Arrays with two or more values are multi-line, one value per line.
+    OnePerLine,
+    /// Arrays stay on a single line while they fit in `line-width`,
+    /// even if they end with a trailing comma.
+    Compact,
+}
+
+impl std::fmt::Display for ArrayLayout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AlwaysMultiline => write!(f, "always-multiline"),
+            Self::OnePerLine => write!(f, "one-per-line"),
+            Self::Compact => write!(f, "compact"),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for ArrayLayout {
+    type Error = &'a str;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        match value {
+            "always-multiline" => Ok(Self::AlwaysMultiline),
+            "one-per-line" => Ok(Self::OnePerLine),
+            "compact" => Ok(Self::Compact),
+            _ => Err(value),
+        }
+    }
+}
+
+/// How the formatter lays out an inline table.
+///
+/// Multi-line inline tables require TOML v1.1.0, so the layout is ignored for older versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum InlineTableLayout {
+    /// Every non-empty inline table is multi-line.
+    AlwaysMultiline,
+    /// Inline tables with two or more key-values are multi-line, one key-value per line.
+    OnePerLine,
+    /// Inline tables stay on a single line while they fit in `line-width`,
+    /// even if they end with a trailing comma.
+    Compact,
+}
+
+impl std::fmt::Display for InlineTableLayout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AlwaysMultiline => write!(f, "always-multiline"),
+            Self::OnePerLine => write!(f, "one-per-line"),
+            Self::Compact => write!(f, "compact"),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for InlineTableLayout {
+    type Error = &'a str;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        match value {
+            "always-multiline" => Ok(Self::AlwaysMultiline),
+            "one-per-line" => Ok(Self::OnePerLine),
+            "compact" => Ok(Self::Compact),
+            _ => Err(value),
+        }
+    }
+}
+
+/// How a table is preferred to be written, checked by the `table-style` lint rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum TableStyle {
+    /// An inline table, such as `serde = { version = "1" }`.
+    Inline,
+    /// A table header, such as `[dependencies.serde]`.
+    Table,
+    /// Dotted keys, such as `serde.version = "1"`.
+    Dotted,
+    /// Any style is accepted.
+    Any,
+}
+
+impl std::fmt::Display for TableStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Inline => write!(f, "inline"),
+            Self::Table => write!(f, "table"),
+            Self::Dotted => write!(f, "dotted"),
+            Self::Any => write!(f, "any"),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for TableStyle {
+    type Error = &'a str;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        match value {
+            "inline" => Ok(Self::Inline),
+            "table" => Ok(Self::Table),
+            "dotted" => Ok(Self::Dotted),
+            "any" => Ok(Self::Any),
+            _ => Err(value),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
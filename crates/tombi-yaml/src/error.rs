@@ -0,0 +1,38 @@
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{kind} at line {} column {}", .range.start.line + 1, .range.start.column + 1)]
+pub struct Error {
+    kind: ErrorKind,
+    range: tombi_text::Range,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ErrorKind {
+    #[error("{0}")]
+    Syntax(String),
+
+    #[error("duplicate key: {0}")]
+    DuplicateKey(String),
+
+    #[error("{0} are not supported")]
+    Unsupported(&'static str),
+
+    #[error("maximum nesting depth ({0}) exceeded")]
+    RecursionLimitExceeded(usize),
+}
+
+impl Error {
+    #[inline]
+    pub fn new(kind: ErrorKind, range: tombi_text::Range) -> Self {
+        Self { kind, range }
+    }
+
+    #[inline]
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    #[inline]
+    pub fn range(&self) -> tombi_text::Range {
+        self.range
+    }
+}
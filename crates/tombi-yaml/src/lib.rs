@@ -0,0 +1,13 @@
+//! A YAML parser for JSON Schemas authored in YAML.
+//!
+//! The source is read with [`saphyr_parser`], and the subset of YAML that can be represented
+//! as JSON is converted with the ranges of its nodes.
+//! Anchors, aliases, tags, complex keys and multiple documents are not supported.
+//!
+//! The result is a [`tombi_json::ValueNode`], so YAML schemas are used just like JSON schemas.
+
+mod error;
+mod parser;
+
+pub use error::{Error, ErrorKind};
+pub use parser::parse;
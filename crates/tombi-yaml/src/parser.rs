@@ -0,0 +1,385 @@
+use saphyr_parser::{Event, Marker, ScalarStyle, ScanError, Span, StrInput, Tag};
+use tombi_json::{ArrayNode, BoolNode, NullNode, NumberNode, ObjectNode, StringNode, ValueNode};
+use tombi_json_value::Number;
+use tombi_text::{Position, Range};
+
+use crate::{Error, ErrorKind};
+
+/// Maximum nesting depth for sequences and mappings.
+///
+/// Matches the limit of the JSON parser.
+const MAX_RECURSION_DEPTH: usize = 128;
+
+/// Parse a YAML document into a JSON value tree.
+pub fn parse(source: &str) -> Result<ValueNode, Error> {
+    Parser {
+        events: saphyr_parser::Parser::new_from_str(source),
+    }
+    .parse()
+}
+
+/// Builds a JSON value tree from the events of the YAML parser.
+struct Parser<'a> {
+    events: saphyr_parser::Parser<'a, StrInput<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    fn parse(mut self) -> Result<ValueNode, Error> {
+        let mut value = None;
+
+        loop {
+            let (event, span) = self.next_event()?;
+            match event {
+                Event::StreamEnd => break,
+                Event::DocumentStart(_) if value.is_some() => {
+                    return Err(Error::new(
+                        ErrorKind::Unsupported("multiple documents"),
+                        range(span),
+                    ));
+                }
+                Event::DocumentStart(_) => {
+                    let (event, span) = self.next_event()?;
+                    value = Some(self.parse_node(event, span, 0)?);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(value.unwrap_or_else(|| {
+            ValueNode::Null(NullNode {
+                range: Range::default(),
+            })
+        }))
+    }
+
+    fn parse_node(
+        &mut self,
+        event: Event<'a>,
+        span: Span,
+        depth: usize,
+    ) -> Result<ValueNode, Error> {
+        match event {
+            Event::Scalar(value, style, anchor_id, tag) => {
+                check_node_properties(anchor_id, tag.as_deref(), span)?;
+
+                let value = value.into_owned();
+                let range = range(span);
+                Ok(match style {
+                    ScalarStyle::Plain => resolve_plain_scalar(value, range),
+                    _ => ValueNode::String(StringNode { value, range }),
+                })
+            }
+            Event::SequenceStart(anchor_id, tag) => {
+                check_node_properties(anchor_id, tag.as_deref(), span)?;
+                check_depth(depth, span)?;
+
+                let mut items = Vec::new();
+                loop {
+                    let (event, item_span) = self.next_event()?;
+                    if matches!(event, Event::SequenceEnd) {
+                        return Ok(ValueNode::Array(ArrayNode {
+                            items,
+                            range: Range::new(position(span.start), position(item_span.end)),
+                        }));
+                    }
+                    items.push(self.parse_node(event, item_span, depth + 1)?);
+                }
+            }
+            Event::MappingStart(anchor_id, tag) => {
+                check_node_properties(anchor_id, tag.as_deref(), span)?;
+                check_depth(depth, span)?;
+
+                let mut properties = tombi_json_value::Map::new();
+                loop {
+                    let (event, key_span) = self.next_event()?;
+                    let key = match event {
+                        Event::MappingEnd => {
+                            return Ok(ValueNode::Object(ObjectNode {
+                                properties,
+                                range: Range::new(position(span.start), position(key_span.end)),
+                            }));
+                        }
+                        Event::Scalar(value, _, anchor_id, tag) => {
+                            check_node_properties(anchor_id, tag.as_deref(), key_span)?;
+                            StringNode {
+                                value: value.into_owned(),
+                                range: range(key_span),
+                            }
+                        }
+                        Event::Alias(_) => {
+                            return Err(Error::new(
+                                ErrorKind::Unsupported("aliases"),
+                                range(key_span),
+                            ));
+                        }
+                        _ => {
+                            return Err(Error::new(
+                                ErrorKind::Unsupported("complex keys"),
+                                range(key_span),
+                            ));
+                        }
+                    };
+                    if properties.contains_key(&key) {
+                        return Err(Error::new(ErrorKind::DuplicateKey(key.value), key.range));
+                    }
+
+                    let (event, value_span) = self.next_event()?;
+                    let value = self.parse_node(event, value_span, depth + 1)?;
+                    properties.insert(key, value);
+                }
+            }
+            Event::Alias(_) => Err(Error::new(ErrorKind::Unsupported("aliases"), range(span))),
+            _ => Err(Error::new(
+                ErrorKind::Syntax("expected a node".to_string()),
+                range(span),
+            )),
+        }
+    }
+
+    fn next_event(&mut self) -> Result<(Event<'a>, Span), Error> {
+        match self.events.next_event() {
+            Some(Ok(event)) => Ok(event),
+            Some(Err(error)) => Err(scan_error(&error)),
+            None => Err(Error::new(
+                ErrorKind::Syntax("unexpected end of stream".to_string()),
+                Range::default(),
+            )),
+        }
+    }
+}
+
+/// Reject the anchors and tags of a node, which have no JSON counterpart.
+fn check_node_properties(anchor_id: usize, tag: Option<&Tag>, span: Span) -> Result<(), Error> {
+    let unsupported = if anchor_id != 0 {
+        "anchors"
+    } else if tag.is_some() {
+        "tags"
+    } else {
+        return Ok(());
+    };
+
+    Err(Error::new(ErrorKind::Unsupported(unsupported), range(span)))
+}
+
+fn check_depth(depth: usize, span: Span) -> Result<(), Error> {
+    if depth >= MAX_RECURSION_DEPTH {
+        return Err(Error::new(
+            ErrorKind::RecursionLimitExceeded(MAX_RECURSION_DEPTH),
+            range(span),
+        ));
+    }
+    Ok(())
+}
+
+fn scan_error(error: &ScanError) -> Error {
+    let position = position(*error.marker());
+    Error::new(
+        ErrorKind::Syntax(error.info().to_string()),
+        Range::new(position, position),
+    )
+}
+
+/// The markers of the YAML parser have 1-based lines and 0-based character columns.
+fn position(marker: Marker) -> Position {
+    Position::new(marker.line().saturating_sub(1) as u32, marker.col() as u32)
+}
+
+fn range(span: Span) -> Range {
+    Range::new(position(span.start), position(span.end))
+}
+
+/// Resolve a plain scalar with the YAML 1.2 core schema.
+fn resolve_plain_scalar(text: String, range: Range) -> ValueNode {
+    match text.as_str() {
+        "" | "~" | "null" | "Null" | "NULL" => ValueNode::Null(NullNode { range }),
+        "true" | "True" | "TRUE" => ValueNode::Bool(BoolNode { value: true, range }),
+        "false" | "False" | "FALSE" => ValueNode::Bool(BoolNode {
+            value: false,
+            range,
+        }),
+        _ => match parse_number(&text) {
+            Some(value) => ValueNode::Number(NumberNode { value, range }),
+            None => ValueNode::String(StringNode { value: text, range }),
+        },
+    }
+}
+
+fn parse_number(text: &str) -> Option<Number> {
+    if let Some(hex) = text.strip_prefix("0x") {
+        return u64::from_str_radix(hex, 16).ok().map(Number::from_u64);
+    }
+    if let Some(octal) = text.strip_prefix("0o") {
+        return u64::from_str_radix(octal, 8).ok().map(Number::from_u64);
+    }
+
+    let digits = text.strip_prefix(['-', '+']).unwrap_or(text);
+    if digits.is_empty() || !digits.starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+        return None;
+    }
+    if digits.chars().all(|c| c.is_ascii_digit()) {
+        let text = text.strip_prefix('+').unwrap_or(text);
+        if let Ok(value) = text.parse::<i64>() {
+            return Some(Number::from_i64(value));
+        }
+        if let Ok(value) = text.parse::<u64>() {
+            return Some(Number::from_u64(value));
+        }
+    }
+    if digits
+        .chars()
+        .all(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '-' | '+'))
+        && digits.chars().any(|c| c.is_ascii_digit())
+    {
+        return text.parse::<f64>().ok().map(Number::from_f64);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_json(source: &str) -> tombi_json_value::Value {
+        parse(source).unwrap().into()
+    }
+
+    #[test]
+    fn test_parse_block_mapping() {
+        let source = r#"
+# A schema
+$schema: "http://json-schema.org/draft-07/schema#"
+type: object
+required: [name]
+properties:
+  name:
+    type: string
+    description: The name.
+  port:
+    type: integer
+    minimum: 0
+    maximum: 0xFFFF
+  enabled: {type: boolean, default: true}
+  url: http://example.com # not a key
+"#;
+
+        pretty_assertions::assert_eq!(
+            to_json(source),
+            tombi_json_value::json!({
+                "$schema": "http://json-schema.org/draft-07/schema#",
+                "type": "object",
+                "required": ["name"],
+                "properties": {
+                    "name": {"type": "string", "description": "The name."},
+                    "port": {"type": "integer", "minimum": 0, "maximum": 65535},
+                    "enabled": {"type": "boolean", "default": true},
+                    "url": "http://example.com"
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_block_sequence() {
+        let source = r#"
+enum:
+- a
+- 'b'
+- "c\td"
+oneOf:
+  - type: string
+    minLength: 1
+  - type: 'null'
+  -
+    - 1.5
+    - -2
+    - ~
+"#;
+
+        pretty_assertions::assert_eq!(
+            to_json(source),
+            tombi_json_value::json!({
+                "enum": ["a", "b", "c\td"],
+                "oneOf": [
+                    {"type": "string", "minLength": 1},
+                    {"type": "null"},
+                    [1.5, (-2), null]
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_multiline_scalars() {
+        let source = r#"
+literal: |
+  line 1
+    line 2
+
+folded: >-
+  folded
+  text
+
+  paragraph
+plain: first
+  second
+quoted: "first
+  second"
+"#;
+
+        pretty_assertions::assert_eq!(
+            to_json(source),
+            tombi_json_value::json!({
+                "literal": "line 1\n  line 2\n",
+                "folded": "folded text\nparagraph",
+                "plain": "first second",
+                "quoted": "first second"
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_range() {
+        let source = "type: object\nproperties:\n  name: {}\n";
+        let value = parse(source).unwrap();
+        let name = value
+            .as_object()
+            .and_then(|object| object.get("properties"))
+            .and_then(|properties| properties.as_object())
+            .and_then(|properties| properties.get("name"))
+            .unwrap();
+
+        pretty_assertions::assert_eq!(
+            name.range(),
+            Range::new(Position::new(2, 8), Position::new(2, 10))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        pretty_assertions::assert_eq!(
+            parse("a: 1\na: 2\n").unwrap_err().kind(),
+            &ErrorKind::DuplicateKey("a".to_string())
+        );
+        pretty_assertions::assert_eq!(
+            parse("a: &anchor 1\n").unwrap_err().kind(),
+            &ErrorKind::Unsupported("anchors")
+        );
+        pretty_assertions::assert_eq!(
+            parse("a: 1\n---\nb: 2\n").unwrap_err().kind(),
+            &ErrorKind::Unsupported("multiple documents")
+        );
+        pretty_assertions::assert_eq!(
+            parse("a: !!str 1\n").unwrap_err().kind(),
+            &ErrorKind::Unsupported("tags")
+        );
+        assert!(matches!(
+            parse("a:\n    b: 1\n  c: 2\n").unwrap_err().kind(),
+            ErrorKind::Syntax(_)
+        ));
+        assert!(matches!(
+            parse("a: [1, 2\n").unwrap_err().kind(),
+            ErrorKind::Syntax(_)
+        ));
+    }
+}
@@ -1,4 +1,5 @@
 import {
+  type Range,
   RequestType,
   type TextDocumentIdentifier,
 } from "vscode-languageclient";
@@ -84,3 +85,25 @@ export const getBuiltInSchema = new RequestType<
   string | null,
   void
 >("tombi/getBuiltInSchema");
+
+export type EmbeddedLanguagesParams = TextDocumentIdentifier;
+export type EmbeddedLanguage = {
+  range: Range;
+  language: string;
+};
+export const embeddedLanguages = new RequestType<
+  EmbeddedLanguagesParams,
+  { embeddedLanguages: EmbeddedLanguage[] },
+  void
+>("tombi/embeddedLanguages");
+
+export type ListAssociatedFilesParams = TextDocumentIdentifier;
+export type AssociatedFile = {
+  uri: string;
+  accessor?: string;
+};
+export const listAssociatedFiles = new RequestType<
+  ListAssociatedFilesParams,
+  { files: AssociatedFile[] },
+  void
+>("tombi/listAssociatedFiles");
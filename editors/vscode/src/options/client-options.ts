@@ -10,10 +10,14 @@ export function clientOptions(
 ): languageclient.LanguageClientOptions {
   const options = {
     diagnosticCollectionName: "tombi",
-    documentSelector: SUPPORT_TOML_LANGUAGES.flatMap((language) => [
-      { scheme: "file", language },
-      { scheme: "untitled", language },
-    ]),
+    documentSelector: [
+      ...SUPPORT_TOML_LANGUAGES.flatMap((language) => [
+        { scheme: "file", language },
+        { scheme: "untitled", language },
+      ]),
+      // JSON Schemas authored in the workspace.
+      { scheme: "file", pattern: "**/*.schema.json" },
+    ],
     workspaceFolder,
     synchronize: {
       // Notify the server about config file changes contained in the workspace.
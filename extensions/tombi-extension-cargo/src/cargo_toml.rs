@@ -72,3 +72,84 @@ pub(crate) fn get_uri_relative_to_cargo_toml(
 ) -> Option<tombi_uri::Uri> {
     tombi_extension_manifest::resolve_relative_file_uri(cargo_toml_path, relative_path)
 }
+
+const DEPENDENCY_KINDS: [&str; 3] = ["dependencies", "dev-dependencies", "build-dependencies"];
+
+/// A registry dependency written in the current `Cargo.toml` with an explicit version requirement.
+#[derive(Debug, Clone)]
+pub(crate) struct VersionedDependency<'a> {
+    pub(crate) crate_name: &'a str,
+    pub(crate) version: &'a tombi_document_tree::String,
+    /// Declared in `[workspace.dependencies]`, which may be unused by any member.
+    pub(crate) is_workspace_dependency: bool,
+}
+
+pub(crate) fn collect_versioned_dependencies(
+    document_tree: &tombi_document_tree::DocumentTree,
+) -> Vec<VersionedDependency<'_>> {
+    use tombi_document_tree::{Value, dig_keys};
+
+    let mut dependency_tables = Vec::new();
+
+    if let Some((_, Value::Table(table))) = dig_keys(document_tree, &["workspace", "dependencies"])
+    {
+        dependency_tables.push((table, true));
+    }
+
+    for dependency_kind in DEPENDENCY_KINDS {
+        if let Some((_, Value::Table(table))) = dig_keys(document_tree, &[dependency_kind]) {
+            dependency_tables.push((table, false));
+        }
+    }
+
+    if let Some((_, Value::Table(targets))) = dig_keys(document_tree, &["target"]) {
+        for target in targets.values() {
+            let Value::Table(target) = target else {
+                continue;
+            };
+            for dependency_kind in DEPENDENCY_KINDS {
+                if let Some(Value::Table(table)) = target.get(dependency_kind) {
+                    dependency_tables.push((table, false));
+                }
+            }
+        }
+    }
+
+    dependency_tables
+        .into_iter()
+        .flat_map(|(table, is_workspace_dependency)| {
+            table
+                .key_values()
+                .iter()
+                .map(move |(key, value)| (key, value, is_workspace_dependency))
+        })
+        .filter_map(
+            |(dependency_key, dependency_value, is_workspace_dependency)| match dependency_value {
+                Value::String(version) => Some(VersionedDependency {
+                    crate_name: dependency_key.value.as_str(),
+                    version,
+                    is_workspace_dependency,
+                }),
+                Value::Table(table)
+                    if !(table.contains_key("path")
+                        || table.contains_key("git")
+                        || table.contains_key("registry")
+                        || table.contains_key("workspace")) =>
+                {
+                    let Some(Value::String(version)) = table.get("version") else {
+                        return None;
+                    };
+                    Some(VersionedDependency {
+                        crate_name: dependency_package_name(
+                            dependency_key.value.as_str(),
+                            dependency_value,
+                        ),
+                        version,
+                        is_workspace_dependency,
+                    })
+                }
+                _ => None,
+            },
+        )
+        .collect()
+}
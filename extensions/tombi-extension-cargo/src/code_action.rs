@@ -8,6 +8,9 @@ use tower_lsp::lsp_types::{
 };
 
 use crate::{
+    cargo_lock::load_cached_cargo_lock,
+    collect_versioned_dependencies,
+    crates_io::{CratesIoVersion, fetch_crates_io_versions},
     dependency_parent_accessors, fetch_crates_io_crate, find_workspace_cargo_toml,
     get_workspace_cargo_toml_path, is_any_dependency_accessor,
 };
@@ -142,6 +145,68 @@ impl std::fmt::Display for CodeActionRefactorRewriteName {
     }
 }
 
+pub enum CodeActionSourceName {
+    /// Update All Dependencies in This File
+    ///
+    /// Bumps every registry dependency to the latest version its requirement is compatible with,
+    /// keeping the precision of the requirement.
+    /// A requirement is never bumped below the version resolved in `Cargo.lock`,
+    /// which is also used for dependencies whose versions cannot be looked up.
+    /// Requirements with operators such as `>=1, <2` are left as they are.
+    ///
+    /// Before
+    ///
+    /// ```toml
+    /// [dependencies]
+    /// serde = "1.0"
+    /// tokio = { version = "1.0.0", features = ["full"] }
+    /// ```
+    ///
+    /// After applying "Update All Dependencies in This File"
+    ///
+    /// ```toml
+    /// [dependencies]
+    /// serde = "1.0"
+    /// tokio = { version = "1.47.1", features = ["full"] }
+    /// ```
+    UpdateAllDependencies,
+
+    /// Update All Dependencies in This File Including Breaking Changes
+    ///
+    /// Like "Update All Dependencies in This File", but bumps to the latest version even across a breaking change.
+    ///
+    /// Before
+    ///
+    /// ```toml
+    /// [dependencies]
+    /// rand = "0.8"
+    /// ```
+    ///
+    /// After applying "Update All Dependencies in This File Including Breaking Changes"
+    ///
+    /// ```toml
+    /// [dependencies]
+    /// rand = "0.9"
+    /// ```
+    UpdateAllDependenciesIncludingBreaking,
+}
+
+impl std::fmt::Display for CodeActionSourceName {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CodeActionSourceName::UpdateAllDependencies => {
+                write!(f, "Update All Dependencies in This File")
+            }
+            CodeActionSourceName::UpdateAllDependenciesIncludingBreaking => {
+                write!(
+                    f,
+                    "Update All Dependencies in This File Including Breaking Changes"
+                )
+            }
+        }
+    }
+}
+
 pub async fn code_action(
     text_document_uri: &tombi_uri::Uri,
     line_index: &tombi_text::LineIndex,
@@ -153,6 +218,7 @@ pub async fn code_action(
     features: Option<&tombi_config::CargoExtensionFeatures>,
     offline: bool,
     cache_options: Option<&tombi_cache::Options>,
+    only: Option<&[CodeActionKind]>,
 ) -> Result<Option<Vec<CodeActionOrCommand>>, tower_lsp::jsonrpc::Error> {
     if !text_document_uri.path().ends_with("Cargo.toml") {
         return Ok(None);
@@ -205,6 +271,32 @@ pub async fn code_action(
         );
     }
 
+    // Source actions fetch every dependency from crates.io, so they are only built when explicitly requested.
+    if only.is_some_and(|only| only.contains(&CodeActionKind::SOURCE))
+        && features
+            .and_then(|features| features.lsp())
+            .and_then(|lsp| lsp.code_action())
+            .and_then(|code_action| code_action.update_all_dependencies())
+            .map(|feature| feature.enabled())
+            .unwrap_or_default()
+            .value()
+    {
+        code_actions.extend(
+            update_all_dependencies_code_action(
+                text_document_uri,
+                line_index,
+                document_tree,
+                &cargo_toml_path,
+                toml_version,
+                offline,
+                cache_options,
+            )
+            .await
+            .into_iter()
+            .map(CodeActionOrCommand::CodeAction),
+        );
+    }
+
     Ok((!code_actions.is_empty()).then_some(code_actions))
 }
 
@@ -476,6 +568,140 @@ async fn update_dependency_to_latest_version_code_action(
     }))
 }
 
+async fn update_all_dependencies_code_action(
+    text_document_uri: &tombi_uri::Uri,
+    line_index: &tombi_text::LineIndex,
+    document_tree: &tombi_document_tree::DocumentTree,
+    cargo_toml_path: &std::path::Path,
+    toml_version: tombi_config::TomlVersion,
+    offline: bool,
+    cache_options: Option<&tombi_cache::Options>,
+) -> Vec<CodeAction> {
+    let dependencies = collect_versioned_dependencies(document_tree);
+    if dependencies.is_empty() {
+        return Vec::new();
+    }
+
+    let cargo_lock = load_cached_cargo_lock(cargo_toml_path, toml_version).await;
+    let versions =
+        futures::future::join_all(dependencies.iter().map(|dependency| {
+            fetch_crates_io_versions(dependency.crate_name, offline, cache_options)
+        }))
+        .await;
+
+    [
+        (CodeActionSourceName::UpdateAllDependencies, false),
+        (
+            CodeActionSourceName::UpdateAllDependenciesIncludingBreaking,
+            true,
+        ),
+    ]
+    .into_iter()
+    .map(|(name, breaking)| {
+        let edits = dependencies
+            .iter()
+            .zip(&versions)
+            .filter_map(|(dependency, versions)| {
+                let locked_version = cargo_lock.as_ref().and_then(|cargo_lock| {
+                    cargo_lock.resolve_dependency_version(
+                        dependency.crate_name,
+                        dependency.version.value(),
+                    )
+                });
+                // Dependencies whose versions cannot be fetched are bumped to the locked version only.
+                let new_requirement = bump_version_requirement(
+                    dependency.version.value(),
+                    versions
+                        .as_ref()
+                        .map(|versions| versions.versions.as_slice())
+                        .unwrap_or_default(),
+                    locked_version.as_deref(),
+                    breaking,
+                )?;
+                Some(OneOf::Left(TextEdit {
+                    range: dependency.version.range().into_lsp(line_index),
+                    new_text: format!("\"{new_requirement}\""),
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        CodeAction {
+            title: name.to_string(),
+            kind: Some(CodeActionKind::SOURCE.clone()),
+            diagnostics: None,
+            disabled: edits.is_empty().then(|| CodeActionDisabled {
+                reason: "All dependencies are already at the latest version".to_string(),
+            }),
+            edit: Some(WorkspaceEdit {
+                changes: None,
+                document_changes: Some(DocumentChanges::Edits(vec![TextDocumentEdit {
+                    text_document: OptionalVersionedTextDocumentIdentifier {
+                        uri: text_document_uri.to_owned().into(),
+                        version: None,
+                    },
+                    edits,
+                }])),
+                change_annotations: None,
+            }),
+            ..Default::default()
+        }
+    })
+    .collect()
+}
+
+/// Bump a version requirement to the latest non-yanked stable release, keeping its operator and precision.
+///
+/// Only bare, caret (`^`) and tilde (`~`) requirements with a single comparator are bumped,
+/// and unless `breaking` is set, only to a release the requirement already accepts.
+/// For example, `"1.0"` becomes `"1.5"` rather than `"1.5.3"` or `"2.0"`.
+///
+/// The `locked_version` resolved in `Cargo.lock` counts as a release when the requirement accepts it,
+/// so the requirement is not bumped below it even if `versions` is outdated or empty.
+pub(crate) fn bump_version_requirement(
+    version_requirement: &str,
+    versions: &[CratesIoVersion],
+    locked_version: Option<&str>,
+    breaking: bool,
+) -> Option<String> {
+    let version_requirement = version_requirement.trim();
+    let version_req = semver::VersionReq::parse(version_requirement).ok()?;
+    let [comparator] = version_req.comparators.as_slice() else {
+        return None;
+    };
+    if !matches!(comparator.op, semver::Op::Caret | semver::Op::Tilde) || !comparator.pre.is_empty()
+    {
+        return None;
+    }
+
+    let prefix = &version_requirement[..version_requirement.find(|c: char| c.is_ascii_digit())?];
+    let lower_bound = semver::Version::new(
+        comparator.major,
+        comparator.minor.unwrap_or_default(),
+        comparator.patch.unwrap_or_default(),
+    );
+
+    let locked_version = locked_version
+        .and_then(|version| semver::Version::parse(version).ok())
+        .filter(|version| version_req.matches(version));
+    let latest = versions
+        .iter()
+        .filter(|version| !version.yanked)
+        .filter_map(|version| semver::Version::parse(&version.num).ok())
+        .filter(|version| version.pre.is_empty())
+        .filter(|version| breaking || version_req.matches(version))
+        .chain(locked_version)
+        .max()
+        .filter(|latest| *latest > lower_bound)?;
+
+    let new_requirement = match (comparator.minor, comparator.patch) {
+        (None, _) => format!("{prefix}{}", latest.major),
+        (Some(_), None) => format!("{prefix}{}.{}", latest.major, latest.minor),
+        (Some(_), Some(_)) => format!("{prefix}{}.{}.{}", latest.major, latest.minor, latest.patch),
+    };
+
+    (new_requirement != version_requirement).then_some(new_requirement)
+}
+
 /// Convert a package field to inherit from workspace configuration.
 ///
 /// Before
@@ -1134,6 +1360,94 @@ mod tests {
     use tombi_schema_store::{AccessorContext, AccessorKeyKind, KeyContext};
     use tombi_text::{EncodingKind, LineIndex, Position, Range, RelativePosition};
 
+    #[test]
+    fn test_code_action_source_name_display() {
+        assert_eq!(
+            CodeActionSourceName::UpdateAllDependencies.to_string(),
+            "Update All Dependencies in This File"
+        );
+        assert_eq!(
+            CodeActionSourceName::UpdateAllDependenciesIncludingBreaking.to_string(),
+            "Update All Dependencies in This File Including Breaking Changes"
+        );
+    }
+
+    fn crates_io_versions(nums: &[&str]) -> Vec<CratesIoVersion> {
+        nums.iter()
+            .map(|num| CratesIoVersion {
+                num: num.to_string(),
+                features: Default::default(),
+                yanked: false,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn bump_version_requirement_keeps_precision_and_operator() {
+        let versions = crates_io_versions(&["2.0.0", "1.5.3", "1.0.200"]);
+
+        assert_eq!(bump_version_requirement("1", &versions, None, false), None);
+        assert_eq!(
+            bump_version_requirement("1.0", &versions, None, false),
+            Some("1.5".to_string())
+        );
+        assert_eq!(
+            bump_version_requirement("^1.0.0", &versions, None, false),
+            Some("^1.5.3".to_string())
+        );
+        assert_eq!(
+            bump_version_requirement("~1.0.1", &versions, None, false),
+            Some("~1.0.200".to_string())
+        );
+    }
+
+    #[test]
+    fn bump_version_requirement_crosses_majors_only_when_breaking() {
+        let versions = crates_io_versions(&["2.0.0", "1.5.3"]);
+
+        assert_eq!(
+            bump_version_requirement("1", &versions, None, true),
+            Some("2".to_string())
+        );
+        assert_eq!(
+            bump_version_requirement("1.5.3", &versions, None, false),
+            None
+        );
+    }
+
+    #[test]
+    fn bump_version_requirement_never_goes_below_locked_version() {
+        let versions = crates_io_versions(&["1.2.0"]);
+
+        assert_eq!(
+            bump_version_requirement("1.0.0", &versions, Some("1.4.1"), false),
+            Some("1.4.1".to_string())
+        );
+        assert_eq!(
+            bump_version_requirement("1.0.0", &[], Some("1.4.1"), true),
+            Some("1.4.1".to_string())
+        );
+        assert_eq!(
+            bump_version_requirement("2.0.0", &versions, Some("1.4.1"), false),
+            None
+        );
+    }
+
+    #[test]
+    fn bump_version_requirement_skips_operator_requirements() {
+        let versions = crates_io_versions(&["2.0.0", "1.5.3"]);
+
+        assert_eq!(
+            bump_version_requirement(">=1, <2", &versions, None, true),
+            None
+        );
+        assert_eq!(
+            bump_version_requirement("=1.0.0", &versions, None, true),
+            None
+        );
+        assert_eq!(bump_version_requirement("1.*", &versions, None, true), None);
+    }
+
     #[test]
     fn test_code_action_refactor_rewrite_name_display() {
         assert_eq!(
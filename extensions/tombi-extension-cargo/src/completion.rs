@@ -21,7 +21,7 @@ use tower_lsp::lsp_types::InsertTextFormat;
 use crate::cargo_lock::{exact_crates_io_version, load_cached_cargo_lock};
 use crate::{
     crates_io::{
-        CratesIoCrateVersionsResponse, CratesIoVersionDetailResponse, CratesIoVersionsResponse,
+        CratesIoCrateVersionsResponse, CratesIoVersionDetailResponse, fetch_crates_io_versions,
     },
     find_cargo_toml, find_workspace_cargo_toml, get_workspace_cargo_toml_path,
     is_any_dependency_path_accessor, is_dependency_accessor,
@@ -765,9 +765,7 @@ async fn fetch_crate_versions(
     offline: bool,
     cache_options: Option<&tombi_cache::Options>,
 ) -> Option<Vec<String>> {
-    let url = format!("https://crates.io/api/v1/crates/{crate_name}/versions");
-    let resp =
-        fetch_cached_remote_json::<CratesIoVersionsResponse>(&url, offline, cache_options).await?;
+    let resp = fetch_crates_io_versions(crate_name, offline, cache_options).await?;
     Some(resp.versions.into_iter().map(|v| v.num).collect())
 }
 
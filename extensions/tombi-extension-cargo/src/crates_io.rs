@@ -23,7 +23,10 @@ pub(crate) struct CratesIoVersionsResponse {
 #[derive(Debug, Deserialize)]
 pub(crate) struct CratesIoVersion {
     pub(crate) num: String,
+    #[serde(default)]
     pub(crate) features: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    pub(crate) yanked: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -45,3 +48,12 @@ pub(crate) async fn fetch_crates_io_crate(
     let url = format!("https://crates.io/api/v1/crates/{crate_name}");
     Ok(fetch_cached_remote_json::<CratesIoCrateResponse>(&url, offline, cache_options).await)
 }
+
+pub(crate) async fn fetch_crates_io_versions(
+    crate_name: &str,
+    offline: bool,
+    cache_options: Option<&tombi_cache::Options>,
+) -> Option<CratesIoVersionsResponse> {
+    let url = format!("https://crates.io/api/v1/crates/{crate_name}/versions");
+    fetch_cached_remote_json::<CratesIoVersionsResponse>(&url, offline, cache_options).await
+}
@@ -0,0 +1,570 @@
+use futures::future::join_all;
+use tombi_config::{CargoExtensionFeatures, SeverityLevel, TomlVersion};
+use tombi_document_tree::DocumentTree;
+use tombi_extension::push_diagnostic;
+
+use crate::{
+    VersionedDependency,
+    cargo_lock::{CargoLock, load_cached_cargo_lock},
+    collect_versioned_dependencies,
+    crates_io::{CratesIoVersion, fetch_crates_io_versions},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum OutdatedRequirement {
+    /// The requirement excludes the newest release with the same semver compatibility.
+    LatestCompatible(semver::Version),
+    /// The requirement excludes the newest release, which is a breaking upgrade.
+    Latest(semver::Version),
+}
+
+pub async fn diagnostic(
+    text_document_uri: &tombi_uri::Uri,
+    document_tree: &DocumentTree,
+    toml_version: TomlVersion,
+    offline: bool,
+    cache_options: Option<&tombi_cache::Options>,
+    features: Option<&CargoExtensionFeatures>,
+) -> Result<Option<Vec<tombi_diagnostic::Diagnostic>>, tower_lsp::jsonrpc::Error> {
+    if !text_document_uri.path().ends_with("Cargo.toml") {
+        return Ok(None);
+    }
+
+    let diagnostic_features = features
+        .and_then(|features| features.lsp())
+        .and_then(|lsp| lsp.diagnostic());
+
+    if !diagnostic_features
+        .as_ref()
+        .map(|diagnostic| diagnostic.enabled())
+        .unwrap_or_default()
+        .value()
+    {
+        return Ok(None);
+    }
+
+    let outdated_level: SeverityLevel = diagnostic_features
+        .as_ref()
+        .and_then(|diagnostic| diagnostic.outdated_dependency())
+        .unwrap_or_default()
+        .into();
+    let stale_lock_file_level: SeverityLevel = diagnostic_features
+        .as_ref()
+        .and_then(|diagnostic| diagnostic.stale_lock_file())
+        .unwrap_or_default()
+        .into();
+    let yanked_level: SeverityLevel = diagnostic_features
+        .as_ref()
+        .and_then(|diagnostic| diagnostic.yanked_dependency())
+        .unwrap_or_default()
+        .into();
+
+    if outdated_level == SeverityLevel::Off
+        && stale_lock_file_level == SeverityLevel::Off
+        && yanked_level == SeverityLevel::Off
+    {
+        return Ok(None);
+    }
+
+    let Ok(cargo_toml_path) = text_document_uri.to_file_path() else {
+        return Ok(None);
+    };
+
+    let cargo_lock =
+        if stale_lock_file_level != SeverityLevel::Off || yanked_level != SeverityLevel::Off {
+            load_cached_cargo_lock(&cargo_toml_path, toml_version).await
+        } else {
+            None
+        };
+
+    let dependencies = collect_versioned_dependencies(document_tree);
+    let mut diagnostics = Vec::new();
+
+    if stale_lock_file_level != SeverityLevel::Off
+        && let Some(cargo_lock) = cargo_lock.as_ref()
+    {
+        for dependency in &dependencies {
+            let Some(message) = stale_lock_file_message(dependency, cargo_lock) else {
+                continue;
+            };
+            push_diagnostic(
+                stale_lock_file_level,
+                message,
+                "stale-lock-file",
+                dependency.version.range(),
+                &mut diagnostics,
+            );
+        }
+    }
+
+    if outdated_level == SeverityLevel::Off && yanked_level == SeverityLevel::Off {
+        return Ok(Some(diagnostics));
+    }
+
+    let dependency_versions =
+        join_all(dependencies.iter().map(|dependency| {
+            fetch_crates_io_versions(dependency.crate_name, offline, cache_options)
+        }))
+        .await;
+
+    for (dependency, versions) in dependencies.iter().zip(dependency_versions) {
+        let Some(versions) = versions else {
+            continue;
+        };
+
+        if let Some(outdated) = outdated_requirement(dependency.version.value(), &versions.versions)
+        {
+            push_diagnostic(
+                outdated_level,
+                outdated_dependency_message(dependency, &outdated),
+                "outdated-dependency",
+                dependency.version.range(),
+                &mut diagnostics,
+            );
+        }
+
+        if let Some(yanked_version) =
+            yanked_resolved_version(dependency, cargo_lock.as_ref(), &versions.versions)
+        {
+            push_diagnostic(
+                yanked_level,
+                format!(
+                    "`{}` {yanked_version} has been yanked from crates.io",
+                    dependency.crate_name
+                ),
+                "yanked-dependency",
+                dependency.version.range(),
+                &mut diagnostics,
+            );
+        }
+    }
+
+    Ok(Some(diagnostics))
+}
+
+fn outdated_dependency_message(
+    dependency: &VersionedDependency,
+    outdated: &OutdatedRequirement,
+) -> String {
+    match outdated {
+        OutdatedRequirement::LatestCompatible(version) => format!(
+            "`{}` version requirement \"{}\" excludes the latest compatible version {version}",
+            dependency.crate_name,
+            dependency.version.value()
+        ),
+        OutdatedRequirement::Latest(version) => format!(
+            "`{}` version requirement \"{}\" excludes the latest version {version}",
+            dependency.crate_name,
+            dependency.version.value()
+        ),
+    }
+}
+
+/// Compare a version requirement with the non-yanked stable releases published on crates.io.
+pub(crate) fn outdated_requirement(
+    version_requirement: &str,
+    versions: &[CratesIoVersion],
+) -> Option<OutdatedRequirement> {
+    let version_req = semver::VersionReq::parse(version_requirement.trim()).ok()?;
+    let releases = versions
+        .iter()
+        .filter(|version| !version.yanked)
+        .filter_map(|version| semver::Version::parse(&version.num).ok())
+        .filter(|version| version.pre.is_empty())
+        .collect::<Vec<_>>();
+
+    // The lowest version the requirement accepts, whatever the order of its comparators.
+    // Upper bounds such as `<2` are excluded, so `>=1.2, <2` is compatible with `^1.2`.
+    if let Some(comparator) = version_req
+        .comparators
+        .iter()
+        .filter(|comparator| !matches!(comparator.op, semver::Op::Less | semver::Op::LessEq))
+        .max_by_key(|comparator| {
+            (
+                comparator.major,
+                comparator.minor.unwrap_or_default(),
+                comparator.patch.unwrap_or_default(),
+            )
+        })
+    {
+        let compatible_req = semver::VersionReq {
+            comparators: vec![semver::Comparator {
+                op: semver::Op::Caret,
+                major: comparator.major,
+                minor: comparator.minor,
+                patch: comparator.patch,
+                pre: semver::Prerelease::EMPTY,
+            }],
+        };
+        if let Some(latest_compatible) = releases
+            .iter()
+            .filter(|version| compatible_req.matches(version))
+            .max()
+            && !version_req.matches(latest_compatible)
+        {
+            return Some(OutdatedRequirement::LatestCompatible(
+                latest_compatible.clone(),
+            ));
+        }
+    }
+
+    releases
+        .into_iter()
+        .max()
+        .filter(|latest| !version_req.matches(latest))
+        .map(OutdatedRequirement::Latest)
+}
+
+/// Compare a version requirement of `Cargo.toml` with the versions locked in `Cargo.lock`.
+fn stale_lock_file_message(
+    dependency: &VersionedDependency,
+    cargo_lock: &CargoLock,
+) -> Option<String> {
+    let locked_versions = cargo_lock
+        .packages
+        .iter()
+        .filter(|package| package.name == dependency.crate_name)
+        .map(|package| package.version.as_str())
+        .collect::<Vec<_>>();
+
+    if locked_versions.is_empty() {
+        // `[workspace.dependencies]` entries that no member uses are never locked.
+        if dependency.is_workspace_dependency {
+            return None;
+        }
+        return Some(format!(
+            "`{}` is missing from Cargo.lock",
+            dependency.crate_name
+        ));
+    }
+
+    let version_req = semver::VersionReq::parse(dependency.version.value().trim()).ok()?;
+    if locked_versions
+        .iter()
+        .filter_map(|version| semver::Version::parse(version).ok())
+        .any(|version| version_req.matches(&version))
+    {
+        return None;
+    }
+
+    Some(format!(
+        "`{}` version requirement \"{}\" is not satisfied by the locked {} {} in Cargo.lock",
+        dependency.crate_name,
+        dependency.version.value(),
+        if locked_versions.len() == 1 {
+            "version"
+        } else {
+            "versions"
+        },
+        locked_versions.join(", ")
+    ))
+}
+
+fn yanked_resolved_version(
+    dependency: &VersionedDependency,
+    cargo_lock: Option<&CargoLock>,
+    versions: &[CratesIoVersion],
+) -> Option<String> {
+    let resolved_version = match cargo_lock {
+        Some(cargo_lock) => {
+            cargo_lock.resolve_dependency_version(dependency.crate_name, dependency.version.value())
+        }
+        None => crate::cargo_lock::exact_crates_io_version(dependency.version.value()),
+    }?;
+
+    versions
+        .iter()
+        .any(|version| version.yanked && version.num == resolved_version)
+        .then_some(resolved_version)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{str::FromStr, time::Duration};
+
+    use tombi_ast::AstNode;
+    use tombi_config::{
+        CargoDiagnosticFeatureTree, CargoDiagnosticFeatures, CargoExtensionFeatureTree,
+        CargoLspFeatureTree, CargoLspFeatures,
+    };
+    use tombi_document_tree::TryIntoDocumentTree;
+    use tombi_test_lib::TestCacheHome;
+
+    use super::*;
+
+    fn version(num: &str, yanked: bool) -> CratesIoVersion {
+        CratesIoVersion {
+            num: num.to_string(),
+            features: Default::default(),
+            yanked,
+        }
+    }
+
+    fn parse_document_tree(source: &str) -> DocumentTree {
+        let root = tombi_ast::Root::cast(tombi_parser::parse(source).into_syntax_node()).unwrap();
+        root.try_into_document_tree(TomlVersion::default()).unwrap()
+    }
+
+    fn diagnostic_features(
+        outdated_dependency: SeverityLevel,
+        stale_lock_file: SeverityLevel,
+        yanked_dependency: SeverityLevel,
+    ) -> CargoExtensionFeatures {
+        CargoExtensionFeatures::Features(CargoExtensionFeatureTree {
+            lsp: Some(CargoLspFeatures::Features(CargoLspFeatureTree {
+                diagnostic: Some(CargoDiagnosticFeatures::Features(
+                    CargoDiagnosticFeatureTree {
+                        outdated_dependency: Some(outdated_dependency.into()),
+                        stale_lock_file: Some(stale_lock_file.into()),
+                        yanked_dependency: Some(yanked_dependency.into()),
+                    },
+                )),
+                ..Default::default()
+            })),
+        })
+    }
+
+    async fn write_cached_response(url: &str, body: &str) {
+        let uri = tombi_uri::Uri::from_str(url).unwrap();
+        let cache_path = tombi_cache::get_cache_file_path(&uri).await.unwrap();
+        if let Some(parent) = cache_path.parent() {
+            std::fs::create_dir_all(parent).unwrap();
+        }
+        std::fs::write(&cache_path, body).unwrap();
+    }
+
+    #[test]
+    fn requirement_excluding_latest_compatible_version_is_outdated() {
+        let versions = [
+            version("2.0.0", false),
+            version("1.0.228", false),
+            version("1.0.100", false),
+        ];
+
+        assert_eq!(
+            outdated_requirement("=1.0.100", &versions),
+            Some(OutdatedRequirement::LatestCompatible(semver::Version::new(
+                1, 0, 228
+            )))
+        );
+    }
+
+    #[test]
+    fn requirement_excluding_latest_major_version_is_outdated() {
+        let versions = [version("2.0.0", false), version("1.0.228", false)];
+
+        assert_eq!(
+            outdated_requirement("1.0", &versions),
+            Some(OutdatedRequirement::Latest(semver::Version::new(2, 0, 0)))
+        );
+    }
+
+    #[test]
+    fn requirement_ignores_yanked_and_prerelease_versions() {
+        let versions = [
+            version("2.0.0-rc.1", false),
+            version("1.1.0", true),
+            version("1.0.5", false),
+        ];
+
+        assert_eq!(outdated_requirement("1.0", &versions), None);
+        assert_eq!(outdated_requirement("~1.0.5", &versions), None);
+    }
+
+    #[test]
+    fn range_requirement_is_evaluated_as_a_whole() {
+        let versions = [
+            version("2.0.0", false),
+            version("1.5.0", false),
+            version("1.2.0", false),
+        ];
+
+        assert_eq!(
+            outdated_requirement("<2, >=1.2", &versions),
+            Some(OutdatedRequirement::Latest(semver::Version::new(2, 0, 0)))
+        );
+        assert_eq!(
+            outdated_requirement(">=1.2, <1.5", &versions),
+            Some(OutdatedRequirement::LatestCompatible(semver::Version::new(
+                1, 5, 0
+            )))
+        );
+        assert_eq!(outdated_requirement(">=1.2", &versions), None);
+    }
+
+    #[test]
+    fn zero_major_requirement_treats_minor_bump_as_breaking() {
+        let versions = [version("0.3.0", false), version("0.2.9", false)];
+
+        assert_eq!(
+            outdated_requirement("0.2", &versions),
+            Some(OutdatedRequirement::Latest(semver::Version::new(0, 3, 0)))
+        );
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn reports_outdated_and_yanked_dependencies_from_cached_responses() {
+        let _cache_home = TestCacheHome::new();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cargo_toml_path = temp_dir.path().join("Cargo.toml");
+        let source = r#"
+[package]
+name = "demo"
+version = "0.1.0"
+
+[dependencies]
+serde = "1.0"
+"#;
+        std::fs::write(&cargo_toml_path, source).unwrap();
+        std::fs::write(
+            temp_dir.path().join("Cargo.lock"),
+            r#"
+[[package]]
+name = "demo"
+version = "0.1.0"
+dependencies = ["serde"]
+
+[[package]]
+name = "serde"
+version = "1.0.100"
+"#,
+        )
+        .unwrap();
+        write_cached_response(
+            "https://crates.io/api/v1/crates/serde/versions",
+            r#"{"versions":[
+                {"num":"2.0.0","yanked":false},
+                {"num":"1.0.228","yanked":false},
+                {"num":"1.0.100","yanked":true}
+            ]}"#,
+        )
+        .await;
+
+        let diagnostics = diagnostic(
+            &tombi_uri::Uri::from_file_path(&cargo_toml_path).unwrap(),
+            &parse_document_tree(source),
+            TomlVersion::default(),
+            true,
+            Some(&tombi_cache::Options {
+                no_cache: None,
+                cache_ttl: Some(Duration::from_secs(60)),
+            }),
+            Some(&diagnostic_features(
+                SeverityLevel::Warn,
+                SeverityLevel::Warn,
+                SeverityLevel::Error,
+            )),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|diagnostic| (
+                    diagnostic.code(),
+                    diagnostic.message(),
+                    diagnostic.is_error()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "outdated-dependency",
+                    "`serde` version requirement \"1.0\" excludes the latest version 2.0.0",
+                    false,
+                ),
+                (
+                    "yanked-dependency",
+                    "`serde` 1.0.100 has been yanked from crates.io",
+                    true,
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn reports_dependencies_not_satisfied_by_cargo_lock() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cargo_toml_path = temp_dir.path().join("Cargo.toml");
+        let source = r#"
+[workspace.dependencies]
+unused = "1"
+
+[package]
+name = "demo"
+version = "0.1.0"
+
+[dependencies]
+serde = "2"
+anyhow = "1.0"
+tokio = { version = "1", features = ["full"] }
+"#;
+        std::fs::write(&cargo_toml_path, source).unwrap();
+        std::fs::write(
+            temp_dir.path().join("Cargo.lock"),
+            r#"
+[[package]]
+name = "demo"
+version = "0.1.0"
+dependencies = ["anyhow", "serde"]
+
+[[package]]
+name = "anyhow"
+version = "1.0.86"
+
+[[package]]
+name = "serde"
+version = "1.0.228"
+"#,
+        )
+        .unwrap();
+
+        let diagnostics = diagnostic(
+            &tombi_uri::Uri::from_file_path(&cargo_toml_path).unwrap(),
+            &parse_document_tree(source),
+            TomlVersion::default(),
+            true,
+            None,
+            None,
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|diagnostic| (diagnostic.code(), diagnostic.message()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "stale-lock-file",
+                    "`serde` version requirement \"2\" is not satisfied by the locked version 1.0.228 in Cargo.lock",
+                ),
+                ("stale-lock-file", "`tokio` is missing from Cargo.lock"),
+            ]
+        );
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn crates_io_diagnostics_are_opt_in() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cargo_toml_path = temp_dir.path().join("Cargo.toml");
+        let source = "[dependencies]\nserde = \"1.0\"\n";
+        std::fs::write(&cargo_toml_path, source).unwrap();
+
+        let diagnostics = diagnostic(
+            &tombi_uri::Uri::from_file_path(&cargo_toml_path).unwrap(),
+            &parse_document_tree(source),
+            TomlVersion::default(),
+            true,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(diagnostics.map(|diagnostics| diagnostics.len()), Some(0));
+    }
+}
@@ -4,6 +4,7 @@ mod cargo_toml;
 mod code_action;
 mod completion;
 mod crates_io;
+mod diagnostic;
 mod did_open;
 mod document_link;
 mod feature_navigation;
@@ -14,8 +15,9 @@ mod inlay_hint;
 mod references;
 mod workspace;
 
-pub use code_action::{CodeActionRefactorRewriteName, code_action};
+pub use code_action::{CodeActionRefactorRewriteName, CodeActionSourceName, code_action};
 pub use completion::completion;
+pub use diagnostic::diagnostic;
 pub use did_open::did_open;
 pub use document_link::{DocumentLinkToolTip, document_link};
 pub use goto_declaration::get_current_declaration;
@@ -33,8 +35,8 @@ pub(crate) use accessors::{
     is_workspace_managed_dependency_accessor,
 };
 pub(crate) use cargo_toml::{
-    CrateLocation, dependency_package_name, find_cargo_toml, get_uri_relative_to_cargo_toml,
-    load_cargo_toml,
+    CrateLocation, VersionedDependency, collect_versioned_dependencies, dependency_package_name,
+    find_cargo_toml, get_uri_relative_to_cargo_toml, load_cargo_toml,
 };
 pub(crate) use crates_io::fetch_crates_io_crate;
 pub(crate) use feature_navigation::{
@@ -52,3 +52,10 @@ pub(crate) fn is_uv_workspace_accessors(accessors: &[Accessor]) -> bool {
         ["tool", "uv", "workspace"]
     )
 }
+
+#[inline]
+pub(crate) fn is_entry_point_accessors(accessors: &[Accessor]) -> bool {
+    matches_accessors!(accessors, ["project", "scripts", _])
+        || matches_accessors!(accessors, ["project", "gui-scripts", _])
+        || matches_accessors!(accessors, ["project", "entry-points", _, _])
+}
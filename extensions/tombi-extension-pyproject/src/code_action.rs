@@ -16,6 +16,12 @@ use crate::{
     DependencyRequirement, collect_dependency_requirements_from_document_tree, fetch_pypi_project,
     find_workspace_pyproject_toml, get_dependency_accessors, parse_dependency_requirement,
     parse_requirement,
+    poetry::{
+        PoetryDependencyGroup, get_poetry_dependency_accessors,
+        get_poetry_dependency_table_accessors, is_poetry_registry_dependency,
+        poetry_constraint_to_pep440, poetry_dependency_group, poetry_dependency_to_pep508,
+        poetry_dependency_version,
+    },
 };
 
 pub enum CodeActionRefactorRewriteName {
@@ -80,6 +86,28 @@ pub enum CodeActionRefactorRewriteName {
     /// dependencies = ["requests==2.33.1"]
     /// ```
     UpdateDependencyToLatestVersion,
+
+    /// Convert Poetry Dependencies to PEP 621
+    ///
+    /// Move the dependencies of a `[tool.poetry]` dependency table to PEP 621
+    /// `[project]` dependencies or PEP 735 `[dependency-groups]`.
+    /// Dependencies without a PEP 508 equivalent, such as `path` or `git`
+    /// dependencies, are left in place.
+    ///
+    /// Before:
+    /// ```toml
+    /// [tool.poetry.group.test.dependencies]
+    /// pytest = "^8.0"
+    /// ```
+    ///
+    /// After applying "Convert Poetry Dependencies to PEP 621":
+    /// ```toml
+    /// [dependency-groups]
+    /// test = [
+    ///   "pytest>=8.0,<9.0",
+    /// ]
+    /// ```
+    ConvertPoetryDependenciesToPep621,
 }
 
 impl CodeActionRefactorRewriteName {
@@ -90,6 +118,7 @@ impl CodeActionRefactorRewriteName {
                 "Add to Workspace and Use Workspace Dependency"
             }
             Self::UpdateDependencyToLatestVersion => "Update Dependency to Latest Version",
+            Self::ConvertPoetryDependenciesToPep621 => "Convert Poetry Dependencies to PEP 621",
         }
     }
 }
@@ -102,7 +131,7 @@ impl std::fmt::Display for CodeActionRefactorRewriteName {
 
 pub async fn code_action(
     text_document_uri: &tombi_uri::Uri,
-    _root: &tombi_ast::Root,
+    root: &tombi_ast::Root,
     document_tree: &tombi_document_tree::DocumentTree,
     accessors: &[Accessor],
     toml_version: tombi_config::TomlVersion,
@@ -126,6 +155,21 @@ pub async fn code_action(
         return Ok(None);
     }
 
+    if get_poetry_dependency_table_accessors(accessors).is_some() {
+        return poetry_code_action(
+            text_document_uri,
+            root,
+            document_tree,
+            accessors,
+            toml_version,
+            line_index,
+            features,
+            offline,
+            cache_options,
+        )
+        .await;
+    }
+
     let Some(dependency_accessors) = get_dependency_accessors(accessors) else {
         return Ok(None);
     };
@@ -306,6 +350,426 @@ async fn update_dependency_to_latest_version_code_action(
     }))
 }
 
+async fn poetry_code_action(
+    text_document_uri: &tombi_uri::Uri,
+    root: &tombi_ast::Root,
+    document_tree: &tombi_document_tree::DocumentTree,
+    accessors: &[Accessor],
+    toml_version: tombi_config::TomlVersion,
+    line_index: &tombi_text::LineIndex,
+    features: Option<&tombi_config::PyprojectExtensionFeatures>,
+    offline: bool,
+    cache_options: Option<&tombi_cache::Options>,
+) -> Result<Option<Vec<CodeActionOrCommand>>, tower_lsp::jsonrpc::Error> {
+    let code_action_features = features
+        .and_then(|features| features.lsp())
+        .and_then(|lsp| lsp.code_action());
+    let mut actions = Vec::new();
+
+    if code_action_features
+        .as_ref()
+        .and_then(|code_action| code_action.update_dependency_to_latest_version())
+        .map(|feature| feature.enabled())
+        .unwrap_or_default()
+        .value()
+        && let Some(dependency_accessors) = get_poetry_dependency_accessors(accessors)
+        && let Some(action) = update_poetry_dependency_to_latest_version_code_action(
+            text_document_uri,
+            line_index,
+            document_tree,
+            dependency_accessors,
+            offline,
+            cache_options,
+        )
+        .await?
+    {
+        actions.push(CodeActionOrCommand::CodeAction(action));
+    }
+
+    if code_action_features
+        .as_ref()
+        .and_then(|code_action| code_action.convert_poetry_dependencies_to_pep621())
+        .map(|feature| feature.enabled())
+        .unwrap_or_default()
+        .value()
+        && let Some(table_accessors) = get_poetry_dependency_table_accessors(accessors)
+        && let Some(action) = convert_poetry_dependencies_to_pep621_code_action(
+            text_document_uri,
+            root,
+            document_tree,
+            table_accessors,
+            toml_version,
+            line_index,
+        )
+    {
+        actions.push(CodeActionOrCommand::CodeAction(action));
+    }
+
+    Ok((!actions.is_empty()).then_some(actions))
+}
+
+/// Update a Poetry version constraint to the latest version on PyPI,
+/// keeping its operator (`^` when the constraint has several clauses).
+async fn update_poetry_dependency_to_latest_version_code_action(
+    text_document_uri: &tombi_uri::Uri,
+    line_index: &tombi_text::LineIndex,
+    document_tree: &tombi_document_tree::DocumentTree,
+    dependency_accessors: &[Accessor],
+    offline: bool,
+    cache_options: Option<&tombi_cache::Options>,
+) -> Result<Option<CodeAction>, tower_lsp::jsonrpc::Error> {
+    let Some(Accessor::Key(package_name)) = dependency_accessors.last() else {
+        return Ok(None);
+    };
+    let Some((_, dependency)) =
+        tombi_document_tree::dig_accessors(document_tree, dependency_accessors)
+    else {
+        return Ok(None);
+    };
+    if !is_poetry_registry_dependency(dependency) {
+        return Ok(None);
+    }
+    let Some(version) = poetry_dependency_version(dependency) else {
+        return Ok(None);
+    };
+
+    let Some(latest_version) = fetch_pypi_project(package_name, offline, cache_options)
+        .await?
+        .and_then(|response| response.info.version)
+    else {
+        return Ok(None);
+    };
+
+    let constraint = version.value().trim();
+    let operator = ["~=", ">=", "==", "^", "~", "="]
+        .into_iter()
+        .find(|operator| constraint.starts_with(operator))
+        .filter(|_| !constraint.contains([',', ' ', '|']))
+        .unwrap_or(if constraint.starts_with(|c: char| c.is_ascii_digit()) {
+            ""
+        } else {
+            "^"
+        });
+    let new_constraint = format!("{operator}{latest_version}");
+    let already_latest = constraint == new_constraint;
+
+    Ok(Some(CodeAction {
+        title: CodeActionRefactorRewriteName::UpdateDependencyToLatestVersion.to_string(),
+        kind: Some(CodeActionKind::REFACTOR_REWRITE.clone()),
+        diagnostics: None,
+        edit: Some(WorkspaceEdit {
+            changes: None,
+            document_changes: Some(DocumentChanges::Edits(vec![TextDocumentEdit {
+                text_document: OptionalVersionedTextDocumentIdentifier {
+                    uri: text_document_uri.to_owned().into(),
+                    version: None,
+                },
+                edits: vec![OneOf::Left(TextEdit {
+                    range: version.unquoted_range().into_lsp(line_index),
+                    new_text: new_constraint,
+                })],
+            }])),
+            change_annotations: None,
+        }),
+        disabled: already_latest.then(|| CodeActionDisabled {
+            reason: "Already at latest version".to_string(),
+        }),
+        ..Default::default()
+    }))
+}
+
+fn convert_poetry_dependencies_to_pep621_code_action(
+    text_document_uri: &tombi_uri::Uri,
+    root: &tombi_ast::Root,
+    document_tree: &tombi_document_tree::DocumentTree,
+    table_accessors: &[Accessor],
+    toml_version: tombi_config::TomlVersion,
+    line_index: &tombi_text::LineIndex,
+) -> Option<CodeAction> {
+    let group = poetry_dependency_group(table_accessors)?;
+    let Some((_, tombi_document_tree::Value::Table(poetry_table))) =
+        tombi_document_tree::dig_accessors(document_tree, table_accessors)
+    else {
+        return None;
+    };
+    let ast_table = find_ast_table(root, table_accessors, toml_version)?;
+    let document_end = root.syntax().range().end;
+
+    let (parent_keys, array_name) = match group {
+        PoetryDependencyGroup::Main => (["project"], "dependencies"),
+        PoetryDependencyGroup::Dev => (["dependency-groups"], "dev"),
+        PoetryDependencyGroup::Group(group_name) => (["dependency-groups"], group_name),
+    };
+    let parent = dig_keys(document_tree, &parent_keys);
+    let existing_requirements = match parent {
+        Some((_, tombi_document_tree::Value::Table(parent))) => match parent.get(array_name) {
+            Some(tombi_document_tree::Value::Array(array)) => array
+                .values()
+                .iter()
+                .filter_map(|value| match value {
+                    tombi_document_tree::Value::String(value) => Some(value.value().to_string()),
+                    _ => None,
+                })
+                .collect(),
+            Some(_) => return None,
+            None => Vec::new(),
+        },
+        Some(_) => return None,
+        None => Vec::new(),
+    };
+    let has_requires_python = matches!(
+        parent,
+        Some((_, tombi_document_tree::Value::Table(parent))) if parent.contains_key("requires-python")
+    );
+
+    let mut requirements = Vec::new();
+    let mut requires_python = None;
+    let mut converted_ranges = Vec::new();
+    let mut key_value_count = 0;
+
+    for key_value in ast_table.key_values() {
+        key_value_count += 1;
+        let Some(keys) = key_value.keys() else {
+            continue;
+        };
+        let key_accessors = keys.accessors(toml_version);
+        let [Accessor::Key(package_name)] = key_accessors.as_slice() else {
+            continue;
+        };
+        let Some(dependency) = poetry_table.get(package_name.as_str()) else {
+            continue;
+        };
+
+        if group == PoetryDependencyGroup::Main && package_name == "python" {
+            let tombi_document_tree::Value::String(python) = dependency else {
+                continue;
+            };
+            let Some(specifiers) = poetry_constraint_to_pep440(python.value()) else {
+                continue;
+            };
+            if !has_requires_python && !specifiers.is_empty() {
+                requires_python = Some(specifiers);
+            }
+        } else {
+            let Some(requirement) = poetry_dependency_to_pep508(package_name, dependency) else {
+                continue;
+            };
+            if !existing_requirements.contains(&requirement) && !requirements.contains(&requirement)
+            {
+                requirements.push(requirement);
+            }
+        }
+        converted_ranges.push(tombi_text::Range::new(
+            keys.range().start,
+            key_value.range().end,
+        ));
+    }
+
+    if converted_ranges.is_empty() {
+        return None;
+    }
+
+    let mut edits = Vec::new();
+    let table_start = tombi_text::Position::new(ast_table.range().start.line, 0);
+    if converted_ranges.len() == key_value_count {
+        edits.push(tombi_extension::TextEdit {
+            range: tombi_text::Range::new(
+                table_start,
+                next_line_start(ast_table.range().end, document_end),
+            ),
+            new_text: String::new(),
+        });
+    } else {
+        for range in &converted_ranges {
+            edits.push(tombi_extension::TextEdit {
+                range: tombi_text::Range::new(
+                    tombi_text::Position::new(range.start.line, 0),
+                    next_line_start(range.end, document_end),
+                ),
+                new_text: String::new(),
+            });
+        }
+    }
+
+    let array_key = tombi_toml_text::to_key_string(array_name);
+    let new_array = |requirements: &[String]| {
+        format!(
+            "[\n{}]",
+            requirements
+                .iter()
+                .map(|requirement| format!(
+                    "  {},\n",
+                    tombi_toml_text::to_basic_string(requirement)
+                ))
+                .collect::<String>()
+        )
+    };
+
+    match parent {
+        Some(_) => {
+            let parent_accessors = parent_keys.map(|key| Accessor::Key(key.to_string()));
+            let ast_parent = find_ast_table(root, &parent_accessors, toml_version)?;
+            let mut new_key_values = String::new();
+            if let Some(requires_python) = &requires_python {
+                new_key_values.push_str(&format!(
+                    "requires-python = {}\n",
+                    tombi_toml_text::to_basic_string(requires_python)
+                ));
+            }
+
+            let mut array_accessors = parent_accessors.to_vec();
+            array_accessors.push(Accessor::Key(array_name.to_string()));
+            if existing_requirements.is_empty() && !requirements.is_empty() {
+                if get_ast_array_from_document_tree(root, document_tree, &array_accessors).is_some()
+                {
+                    // An empty array is replaced as a whole.
+                    let (_, array) =
+                        tombi_document_tree::dig_accessors(document_tree, &array_accessors)?;
+                    edits.push(tombi_extension::TextEdit {
+                        range: array.range(),
+                        new_text: new_array(&requirements),
+                    });
+                } else {
+                    new_key_values
+                        .push_str(&format!("{array_key} = {}\n", new_array(&requirements)));
+                }
+            } else if !requirements.is_empty() {
+                let ast_array =
+                    get_ast_array_from_document_tree(root, document_tree, &array_accessors)?;
+                edits.push(append_to_ast_array(&ast_array, &requirements)?);
+            }
+
+            if !new_key_values.is_empty() {
+                let position = match ast_parent.key_values().last() {
+                    Some(key_value) => next_line_start(key_value.range().end, document_end),
+                    None => next_line_start(ast_parent.header()?.range().end, document_end),
+                };
+                let new_text = if position == document_end && position.column != 0 {
+                    format!("\n{new_key_values}")
+                } else {
+                    new_key_values
+                };
+                edits.push(tombi_extension::TextEdit {
+                    range: tombi_text::Range::at(position),
+                    new_text,
+                });
+            }
+        }
+        None if requires_python.is_some() || !requirements.is_empty() => {
+            let mut new_table = format!("[{}]\n", parent_keys.join("."));
+            if let Some(requires_python) = &requires_python {
+                new_table.push_str(&format!(
+                    "requires-python = {}\n",
+                    tombi_toml_text::to_basic_string(requires_python)
+                ));
+            }
+            if !requirements.is_empty() {
+                new_table.push_str(&format!("{array_key} = {}\n", new_array(&requirements)));
+            }
+            new_table.push('\n');
+
+            match edits
+                .iter_mut()
+                .find(|edit| edit.range.start == table_start)
+            {
+                Some(edit) => edit.new_text = new_table,
+                None => edits.push(tombi_extension::TextEdit {
+                    range: tombi_text::Range::at(table_start),
+                    new_text: new_table,
+                }),
+            }
+        }
+        None => {}
+    }
+
+    Some(CodeAction {
+        title: CodeActionRefactorRewriteName::ConvertPoetryDependenciesToPep621.to_string(),
+        kind: Some(CodeActionKind::REFACTOR_REWRITE.clone()),
+        diagnostics: None,
+        edit: Some(WorkspaceEdit {
+            changes: None,
+            document_changes: Some(DocumentChanges::Edits(vec![TextDocumentEdit {
+                text_document: OptionalVersionedTextDocumentIdentifier {
+                    uri: text_document_uri.to_owned().into(),
+                    version: None,
+                },
+                edits: edits
+                    .into_iter()
+                    .map(|edit| {
+                        OneOf::Left(TextEdit {
+                            range: edit.range.into_lsp(line_index),
+                            new_text: edit.new_text,
+                        })
+                    })
+                    .collect(),
+            }])),
+            change_annotations: None,
+        }),
+        ..Default::default()
+    })
+}
+
+/// Find the `[header]` table whose keys match `accessors`.
+fn find_ast_table(
+    root: &tombi_ast::Root,
+    accessors: &[Accessor],
+    toml_version: tombi_config::TomlVersion,
+) -> Option<tombi_ast::Table> {
+    root.items().find_map(|item| match item {
+        tombi_ast::RootItem::Table(table)
+            if table
+                .header()
+                .is_some_and(|header| header.accessors(toml_version) == accessors) =>
+        {
+            Some(table)
+        }
+        _ => None,
+    })
+}
+
+/// Append requirements after the last value of a non-empty array,
+/// following its single-line or multi-line layout.
+fn append_to_ast_array(
+    ast_array: &tombi_ast::Array,
+    requirements: &[String],
+) -> Option<tombi_extension::TextEdit> {
+    let (last_value, last_comma) = ast_array.values_with_comma().last()?;
+    let is_multi_line = ast_array.range().start.line != ast_array.range().end.line;
+    let separator = if is_multi_line { "\n  " } else { " " };
+    let items = requirements
+        .iter()
+        .map(|requirement| tombi_toml_text::to_basic_string(requirement))
+        .collect::<Vec<_>>();
+
+    Some(match last_comma {
+        Some(last_comma) => tombi_extension::TextEdit {
+            range: tombi_text::Range::at(last_comma.range().end),
+            new_text: items
+                .iter()
+                .map(|item| format!("{separator}{item},"))
+                .collect(),
+        },
+        None => tombi_extension::TextEdit {
+            range: tombi_text::Range::at(last_value.syntax().range().end),
+            new_text: items
+                .iter()
+                .map(|item| format!(",{separator}{item}"))
+                .collect::<String>()
+                + if is_multi_line { "," } else { "" },
+        },
+    })
+}
+
+fn next_line_start(
+    position: tombi_text::Position,
+    document_end: tombi_text::Position,
+) -> tombi_text::Position {
+    std::cmp::min(
+        tombi_text::Position::new(position.line + 1, 0),
+        document_end,
+    )
+}
+
 fn format_dependency_without_version(requirement: &Requirement<VerbatimUrl>) -> String {
     let name = requirement.name.to_string();
     if requirement.extras.is_empty() {
@@ -1559,4 +2023,220 @@ dependencies = ["pytest>=7.0,<8.0"]
         // Should work for optional-dependencies too
         assert!(result.is_some());
     }
+
+    // Tests for Poetry dependency tables
+
+    fn apply_code_action(text: &str, action: &CodeAction) -> String {
+        let Some(DocumentChanges::Edits(document_edits)) = action
+            .edit
+            .as_ref()
+            .and_then(|edit| edit.document_changes.as_ref())
+        else {
+            panic!("code action should have document edits");
+        };
+        let line_offsets = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(offset, _)| offset + 1))
+            .collect::<Vec<_>>();
+        let offset = |position: tower_lsp::lsp_types::Position| {
+            line_offsets
+                .get(position.line as usize)
+                .map_or(text.len(), |line_offset| {
+                    line_offset + position.character as usize
+                })
+                .min(text.len())
+        };
+
+        let mut edits = document_edits
+            .iter()
+            .flat_map(|document_edit| &document_edit.edits)
+            .map(|edit| match edit {
+                OneOf::Left(edit) => edit,
+                OneOf::Right(edit) => &edit.text_edit,
+            })
+            .collect::<Vec<_>>();
+        edits.sort_by_key(|edit| std::cmp::Reverse(offset(edit.range.start)));
+
+        let mut result = text.to_string();
+        for edit in edits {
+            result.replace_range(
+                offset(edit.range.start)..offset(edit.range.end),
+                &edit.new_text,
+            );
+        }
+        result
+    }
+
+    fn convert_poetry_dependencies(toml_text: &str, table_accessors: &[&str]) -> String {
+        let uri = tombi_uri::Uri::from_file_path("/path/to/pyproject.toml").unwrap();
+        let root =
+            tombi_ast::Root::cast(tombi_parser::parse(toml_text).into_syntax_node()).unwrap();
+        let document_tree = root
+            .clone()
+            .try_into_document_tree(tombi_config::TomlVersion::default())
+            .unwrap();
+        let line_index = tombi_text::LineIndex::new(toml_text, tombi_text::EncodingKind::default());
+        let table_accessors = table_accessors
+            .iter()
+            .map(|key| Accessor::Key(key.to_string()))
+            .collect::<Vec<_>>();
+
+        let action = convert_poetry_dependencies_to_pep621_code_action(
+            &uri,
+            &root,
+            &document_tree,
+            &table_accessors,
+            tombi_config::TomlVersion::default(),
+            &line_index,
+        )
+        .expect("code action should be available");
+        assert_eq!(action.title, "Convert Poetry Dependencies to PEP 621");
+
+        apply_code_action(toml_text, &action)
+    }
+
+    #[test]
+    fn test_convert_poetry_dependencies_creates_project_table() {
+        let toml_text = r#"[tool.poetry]
+name = "demo"
+
+[tool.poetry.dependencies]
+python = "^3.10"
+requests = { version = "^2.31", extras = ["socks"] }
+my-lib = { path = "../my-lib" }
+"#;
+
+        assert_eq!(
+            convert_poetry_dependencies(toml_text, &["tool", "poetry", "dependencies"]),
+            r#"[tool.poetry]
+name = "demo"
+
+[project]
+requires-python = ">=3.10,<4.0"
+dependencies = [
+  "requests[socks]>=2.31,<3.0",
+]
+
+[tool.poetry.dependencies]
+my-lib = { path = "../my-lib" }
+"#
+        );
+    }
+
+    #[test]
+    fn test_convert_poetry_dependencies_appends_to_existing_group() {
+        let toml_text = r#"[dependency-groups]
+test = [
+  "pytest>=8.0,<9.0",
+]
+
+[tool.poetry.group.test.dependencies]
+pytest = "^8.0"
+coverage = "^7.4"
+"#;
+
+        assert_eq!(
+            convert_poetry_dependencies(
+                toml_text,
+                &["tool", "poetry", "group", "test", "dependencies"]
+            ),
+            r#"[dependency-groups]
+test = [
+  "pytest>=8.0,<9.0",
+  "coverage>=7.4,<8.0",
+]
+
+"#
+        );
+    }
+
+    #[test]
+    fn test_convert_poetry_dev_dependencies_adds_group_to_existing_table() {
+        let toml_text = r#"[project]
+name = "demo"
+
+[dependency-groups]
+lint = ["ruff"]
+
+[tool.poetry.dev-dependencies]
+mypy = "~1.8"
+"#;
+
+        assert_eq!(
+            convert_poetry_dependencies(toml_text, &["tool", "poetry", "dev-dependencies"]),
+            r#"[project]
+name = "demo"
+
+[dependency-groups]
+lint = ["ruff"]
+dev = [
+  "mypy>=1.8,<1.9",
+]
+
+"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_poetry_dependency_to_latest_version_keeps_operator() {
+        let uri = tombi_uri::Uri::from_file_path("/path/to/pyproject.toml").unwrap();
+        let toml_text = r#"[tool.poetry.dependencies]
+requests = "^2.0"
+httpx = { version = ">=0.20", extras = ["http2"] }
+"#;
+        let root =
+            tombi_ast::Root::cast(tombi_parser::parse(toml_text).into_syntax_node()).unwrap();
+        let document_tree = root
+            .clone()
+            .try_into_document_tree(tombi_config::TomlVersion::default())
+            .unwrap();
+        let line_index = tombi_text::LineIndex::new(toml_text, tombi_text::EncodingKind::default());
+
+        let _cache_home = TestCacheHome::new();
+        let cache_options = tombi_cache::Options {
+            no_cache: None,
+            cache_ttl: None,
+        };
+        write_cached_response(
+            "https://pypi.org/pypi/requests/json",
+            r#"{"info":{"version":"2.33.1"}}"#,
+        )
+        .await;
+        write_cached_response(
+            "https://pypi.org/pypi/httpx/json",
+            r#"{"info":{"version":"0.28.1"}}"#,
+        )
+        .await;
+
+        let mut updated = toml_text.to_string();
+        for package_name in ["requests", "httpx"] {
+            let action = update_poetry_dependency_to_latest_version_code_action(
+                &uri,
+                &line_index,
+                &document_tree,
+                &[
+                    Accessor::Key("tool".to_string()),
+                    Accessor::Key("poetry".to_string()),
+                    Accessor::Key("dependencies".to_string()),
+                    Accessor::Key(package_name.to_string()),
+                ],
+                true,
+                Some(&cache_options),
+            )
+            .await
+            .unwrap()
+            .expect("code action should be available");
+            assert!(action.disabled.is_none());
+
+            // Both edits keep the line layout, so they can be applied in turn.
+            updated = apply_code_action(&updated, &action);
+        }
+
+        assert_eq!(
+            updated,
+            r#"[tool.poetry.dependencies]
+requests = "^2.33.1"
+httpx = { version = ">=0.28.1", extras = ["http2"] }
+"#
+        );
+    }
 }
@@ -1,18 +1,38 @@
 use tombi_config::TomlVersion;
+use tombi_document_tree::{Value, dig_accessors};
 use tombi_extension::{
-    CommentContext, CompletionContent, CompletionHint, completion_directory_path,
-    completion_file_path_from_uri,
+    CommentContext, CompletionContent, CompletionContentPriority, CompletionEdit, CompletionHint,
+    CompletionKind, completion_directory_path, completion_file_path_from_uri,
 };
 use tombi_schema_store::{Accessor, matches_accessors};
 
+use crate::{
+    entry_point::completion_entry_point,
+    fetch_pypi_project, get_dependency_accessors, is_entry_point_accessors, parse_requirement,
+    poetry::{
+        completion_poetry_dependency_version, get_poetry_dependency_accessors,
+        is_poetry_dependency_path_accessors, is_poetry_dependency_version_accessors,
+    },
+    pypi_org::cached_pypi_package_names,
+};
+
+enum PyprojectCompletionFeature {
+    DependencyName,
+    DependencyVersion,
+    EntryPoint,
+    Path,
+}
+
 pub async fn completion(
     text_document_uri: &tombi_uri::Uri,
     document_tree: &tombi_document_tree::DocumentTree,
     position: tombi_text::Position,
     accessors: &[Accessor],
     _toml_version: TomlVersion,
-    _completion_hint: Option<CompletionHint>,
+    completion_hint: Option<CompletionHint>,
     comment_context: Option<&CommentContext>,
+    offline: bool,
+    cache_options: Option<&tombi_cache::Options>,
     features: Option<&tombi_config::PyprojectExtensionFeatures>,
 ) -> Result<Option<Vec<CompletionContent>>, tower_lsp::jsonrpc::Error> {
     if comment_context.is_some() {
@@ -31,22 +51,294 @@ pub async fn completion(
         return Ok(None);
     }
 
-    if let Some(completions) = features
+    if is_entry_point_accessors(accessors)
+        && let Some(completions) =
+            pyproject_completion_enabled(features, PyprojectCompletionFeature::EntryPoint)
+                .then(|| {
+                    completion_pyproject_entry_point(
+                        text_document_uri,
+                        document_tree,
+                        position,
+                        accessors,
+                    )
+                })
+                .flatten()
+    {
+        return Ok(Some(completions));
+    }
+
+    if let Some(completions) =
+        pyproject_completion_enabled(features, PyprojectCompletionFeature::Path)
+            .then(|| {
+                completion_pyproject_file_path(
+                    text_document_uri,
+                    document_tree,
+                    position,
+                    accessors,
+                )
+            })
+            .flatten()
+    {
+        return Ok(Some(completions));
+    }
+
+    if is_poetry_dependency_version_accessors(accessors) {
+        return completion_poetry_dependency(
+            document_tree,
+            position,
+            accessors,
+            completion_hint,
+            offline,
+            cache_options,
+            features,
+        )
+        .await;
+    }
+
+    if get_dependency_accessors(accessors).is_some() {
+        return completion_pep508_dependency(
+            document_tree,
+            position,
+            accessors,
+            offline,
+            cache_options,
+            features,
+        )
+        .await;
+    }
+
+    Ok(None)
+}
+
+fn pyproject_completion_enabled(
+    features: Option<&tombi_config::PyprojectExtensionFeatures>,
+    feature: PyprojectCompletionFeature,
+) -> bool {
+    features
         .and_then(|features| features.lsp())
         .and_then(|lsp| lsp.completion())
-        .and_then(|completion| completion.path())
-        .map(|path| path.enabled())
+        .and_then(|completion| match feature {
+            PyprojectCompletionFeature::DependencyName => completion.dependency_name(),
+            PyprojectCompletionFeature::DependencyVersion => completion.dependency_version(),
+            PyprojectCompletionFeature::EntryPoint => completion.entry_point(),
+            PyprojectCompletionFeature::Path => completion.path(),
+        })
+        .map(|feature| feature.enabled())
         .unwrap_or_default()
         .value()
-        .then(|| {
-            completion_pyproject_file_path(text_document_uri, document_tree, position, accessors)
-        })
-        .flatten()
+}
+
+/// Completes Poetry dependency keys with cached package names and their values with versions.
+///
+/// ```toml
+/// [tool.poetry.dependencies]
+/// requests = "^2.32.3"
+/// httpx = { version = "^0.27.0" }
+/// ```
+async fn completion_poetry_dependency(
+    document_tree: &tombi_document_tree::DocumentTree,
+    position: tombi_text::Position,
+    accessors: &[Accessor],
+    completion_hint: Option<CompletionHint>,
+    offline: bool,
+    cache_options: Option<&tombi_cache::Options>,
+    features: Option<&tombi_config::PyprojectExtensionFeatures>,
+) -> Result<Option<Vec<CompletionContent>>, tower_lsp::jsonrpc::Error> {
+    let Some(dependency_accessors) = get_poetry_dependency_accessors(accessors) else {
+        return Ok(None);
+    };
+    let Some(Accessor::Key(package_name)) = dependency_accessors.last() else {
+        return Ok(None);
+    };
+    let Some((_, version_value)) = dig_accessors(document_tree, accessors) else {
+        return Ok(None);
+    };
+
+    let mut completions = Vec::new();
+
+    if pyproject_completion_enabled(features, PyprojectCompletionFeature::DependencyVersion)
+        && let Some(project) = fetch_pypi_project(package_name, offline, cache_options).await?
+        && let Some(items) = completion_poetry_dependency_version(
+            &project.versions(),
+            version_value,
+            position,
+            completion_hint,
+        )
     {
-        return Ok(Some(completions));
+        completions.extend(items);
     }
 
-    Ok(None)
+    // A bare key such as `req` in `[tool.poetry.dependencies]` is still being typed.
+    if completions.is_empty()
+        && matches!(version_value, Value::Incomplete { .. })
+        && pyproject_completion_enabled(features, PyprojectCompletionFeature::DependencyName)
+        && let Some((_, Value::Table(table))) = dig_accessors(
+            document_tree,
+            &dependency_accessors[..dependency_accessors.len() - 1],
+        )
+        && let Some((package_key, _)) = table.get_key_value(package_name.as_str())
+    {
+        completions.extend(
+            cached_pypi_package_names()
+                .await
+                .into_iter()
+                .enumerate()
+                .map(|(i, name)| {
+                    package_name_completion(
+                        i,
+                        name.clone(),
+                        CompletionEdit::new_string_literal_while_editing(
+                            &name,
+                            package_key.range(),
+                        ),
+                    )
+                }),
+        );
+    }
+
+    Ok((!completions.is_empty()).then_some(completions))
+}
+
+/// Completes package names and versions inside PEP 508 dependency strings.
+///
+/// ```toml
+/// [project]
+/// dependencies = ["requests>=2.32.3"]
+/// ```
+async fn completion_pep508_dependency(
+    document_tree: &tombi_document_tree::DocumentTree,
+    position: tombi_text::Position,
+    accessors: &[Accessor],
+    offline: bool,
+    cache_options: Option<&tombi_cache::Options>,
+    features: Option<&tombi_config::PyprojectExtensionFeatures>,
+) -> Result<Option<Vec<CompletionContent>>, tower_lsp::jsonrpc::Error> {
+    let Some((_, Value::String(dependency))) = dig_accessors(document_tree, accessors) else {
+        return Ok(None);
+    };
+    let unquoted_range = dependency.unquoted_range();
+    // Only complete while typing at the end of a single-line string.
+    if position != unquoted_range.end || unquoted_range.start.line != unquoted_range.end.line {
+        return Ok(None);
+    }
+
+    let text = dependency.value();
+    let Some(operator_start) = text.find(['<', '>', '=', '!', '~']) else {
+        if !pyproject_completion_enabled(features, PyprojectCompletionFeature::DependencyName)
+            || !text
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        {
+            return Ok(None);
+        }
+
+        let completions = cached_pypi_package_names()
+            .await
+            .into_iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let label = format!("\"{name}\"");
+                package_name_completion(
+                    i,
+                    label.clone(),
+                    CompletionEdit::new_string_literal_while_editing(&label, dependency.range()),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        return Ok((!completions.is_empty()).then_some(completions));
+    };
+
+    if !pyproject_completion_enabled(features, PyprojectCompletionFeature::DependencyVersion) {
+        return Ok(None);
+    }
+
+    let (name_with_extras, specifier) = text.split_at(operator_start);
+    let operator_end = specifier
+        .find(|c: char| !matches!(c, '<' | '>' | '=' | '!' | '~'))
+        .unwrap_or(specifier.len());
+    let (operator, partial_version) = specifier.split_at(operator_end);
+    if partial_version
+        .chars()
+        .any(|c| !(c.is_ascii_alphanumeric() || c == '.'))
+    {
+        return Ok(None);
+    }
+    let Some(requirement) = parse_requirement(name_with_extras.trim_end()) else {
+        return Ok(None);
+    };
+    let Some(project) =
+        fetch_pypi_project(requirement.name.as_ref(), offline, cache_options).await?
+    else {
+        return Ok(None);
+    };
+
+    let completions = project
+        .versions()
+        .into_iter()
+        .take(100)
+        .enumerate()
+        .map(|(i, version)| {
+            let label = format!("\"{name_with_extras}{operator}{version}\"");
+            CompletionContent {
+                edit: CompletionEdit::new_string_literal_while_editing(&label, dependency.range()),
+                label,
+                kind: CompletionKind::Enum,
+                emoji_icon: Some('🐍'),
+                priority: CompletionContentPriority::Custom(format!(
+                    "10__pyproject_version_{i:>03}__",
+                )),
+                detail: Some("Package version".to_string()),
+                documentation: None,
+                filter_text: None,
+                schema_uri: None,
+                deprecated: None,
+                preselect: (i == 0).then_some(true),
+                in_comment: false,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Ok((!completions.is_empty()).then_some(completions))
+}
+
+fn package_name_completion(
+    index: usize,
+    label: String,
+    edit: Option<CompletionEdit>,
+) -> CompletionContent {
+    CompletionContent {
+        label,
+        kind: CompletionKind::Key,
+        emoji_icon: Some('🐍'),
+        priority: CompletionContentPriority::Custom(format!("10__pyproject_package_{index:>03}__")),
+        detail: Some("Package name".to_string()),
+        documentation: None,
+        filter_text: None,
+        schema_uri: None,
+        deprecated: None,
+        edit,
+        preselect: None,
+        in_comment: false,
+    }
+}
+
+fn completion_pyproject_entry_point(
+    text_document_uri: &tombi_uri::Uri,
+    document_tree: &tombi_document_tree::DocumentTree,
+    position: tombi_text::Position,
+    accessors: &[Accessor],
+) -> Option<Vec<CompletionContent>> {
+    let Ok(pyproject_toml_path) = text_document_uri.to_file_path() else {
+        return None;
+    };
+    let Some((_, tombi_document_tree::Value::String(entry_point))) =
+        tombi_document_tree::dig_accessors(document_tree, accessors)
+    else {
+        return None;
+    };
+
+    completion_entry_point(document_tree, entry_point, position, &pyproject_toml_path)
 }
 
 fn completion_pyproject_file_path(
@@ -63,7 +355,9 @@ fn completion_pyproject_file_path(
     }
 
     // Pyproject sources: path to local package (file or directory)
-    if matches_accessors!(accessors, ["tool", "uv", "sources", _, "path"]) {
+    if matches_accessors!(accessors, ["tool", "uv", "sources", _, "path"])
+        || is_poetry_dependency_path_accessors(accessors)
+    {
         return completion_file_path_from_uri(
             text_document_uri,
             document_tree,
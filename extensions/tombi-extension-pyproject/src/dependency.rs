@@ -71,6 +71,33 @@ pub(crate) fn collect_all_dependency_requirements_from_document_tree<'a>(
         );
     }
 
+    if let Some((_, Value::Table(envs))) = dig_keys(document_tree, &["tool", "hatch", "envs"]) {
+        for env in envs.values() {
+            let Value::Table(env) = env else {
+                continue;
+            };
+            for key in ["dependencies", "extra-dependencies"] {
+                if let Some(Value::Array(dep_array)) = env.get(key) {
+                    dependency_requirements.extend(collect_dependency_requirements_from_values(
+                        dep_array.iter(),
+                    ));
+                }
+            }
+        }
+    }
+
+    if let Some((_, Value::Table(groups))) =
+        dig_keys(document_tree, &["tool", "pdm", "dev-dependencies"])
+    {
+        for group in groups.values() {
+            if let Value::Array(dep_array) = group {
+                dependency_requirements.extend(collect_dependency_requirements_from_values(
+                    dep_array.iter(),
+                ));
+            }
+        }
+    }
+
     dependency_requirements
 }
 
@@ -80,6 +107,7 @@ pub(crate) fn get_dependency_accessors(accessors: &[Accessor]) -> Option<&[Acces
         || matches_accessors!(accessors, ["dependency-groups", _, _])
         || matches_accessors!(accessors, ["project", "optional-dependencies", _, _])
         || is_uv_dependency_accessor(accessors)
+        || is_tool_dependency_accessor(accessors)
     {
         Some(accessors)
     } else {
@@ -98,6 +126,16 @@ fn is_uv_dependency_accessor(accessors: &[Accessor]) -> bool {
     )
 }
 
+/// PEP 508 dependency arrays of Hatch environments and PDM development groups.
+fn is_tool_dependency_accessor(accessors: &[Accessor]) -> bool {
+    matches_accessors!(accessors, ["tool", "hatch", "envs", _, "dependencies", _])
+        || matches_accessors!(
+            accessors,
+            ["tool", "hatch", "envs", _, "extra-dependencies", _]
+        )
+        || matches_accessors!(accessors, ["tool", "pdm", "dev-dependencies", _, _])
+}
+
 fn collect_standard_dependency_requirements<'a>(
     document_tree: &'a tombi_document_tree::DocumentTree,
     dependency_requirements: &mut Vec<DependencyRequirement<'a>>,
@@ -258,6 +296,13 @@ mod tests {
             constraint-dependencies = ["pytest<9"]
             override-dependencies = ["werkzeug==2.3.0"]
             build-constraint-dependencies = ["setuptools==60.0.0"]
+
+            [tool.hatch.envs.test]
+            dependencies = ["pytest-cov"]
+            extra-dependencies = ["coverage[toml]"]
+
+            [tool.pdm.dev-dependencies]
+            lint = ["mypy>=1.0", "-e file:///${PROJECT_ROOT}/../local"]
             "#,
         );
 
@@ -269,10 +314,39 @@ mod tests {
 
         assert_eq!(
             dependency_names,
-            vec!["requests", "ruff", "pytest", "werkzeug", "setuptools"]
+            vec![
+                "requests",
+                "ruff",
+                "pytest",
+                "werkzeug",
+                "setuptools",
+                "pytest-cov",
+                "coverage",
+                "mypy"
+            ]
         );
     }
 
+    #[test]
+    fn recognizes_hatch_and_pdm_dependency_accessors() {
+        for keys in [
+            vec!["tool", "hatch", "envs", "test", "dependencies"],
+            vec!["tool", "hatch", "envs", "test", "extra-dependencies"],
+            vec!["tool", "pdm", "dev-dependencies", "lint"],
+        ] {
+            let mut accessors = keys
+                .into_iter()
+                .map(|key| Accessor::Key(key.to_string()))
+                .collect::<Vec<_>>();
+            accessors.push(Accessor::Index(0));
+
+            assert_eq!(
+                get_dependency_accessors(&accessors),
+                Some(accessors.as_slice())
+            );
+        }
+    }
+
     #[test]
     fn recognizes_tool_uv_dependency_accessors() {
         let accessors = vec![
@@ -0,0 +1,112 @@
+use pep508_rs::PackageName;
+use tombi_config::{SeverityLevel, TomlVersion};
+use tombi_document_tree::DocumentTree;
+use tombi_extension::push_diagnostic;
+
+use crate::{
+    collect_dependency_requirements_from_document_tree,
+    entry_point::{EntryPointReference, PackageLayout, check_entry_point, collect_entry_points},
+    get_project_name,
+    lock_file::load_cached_lock_file,
+};
+
+pub async fn diagnostic(
+    text_document_uri: &tombi_uri::Uri,
+    document_tree: &DocumentTree,
+    toml_version: TomlVersion,
+    features: Option<&tombi_config::PyprojectExtensionFeatures>,
+) -> Result<Option<Vec<tombi_diagnostic::Diagnostic>>, tower_lsp::jsonrpc::Error> {
+    if !text_document_uri.path().ends_with("pyproject.toml") {
+        return Ok(None);
+    }
+
+    let diagnostic_features = features
+        .and_then(|features| features.lsp())
+        .and_then(|lsp| lsp.diagnostic());
+
+    if !diagnostic_features
+        .as_ref()
+        .map(|diagnostic| diagnostic.enabled())
+        .unwrap_or_default()
+        .value()
+    {
+        return Ok(None);
+    }
+
+    let entry_point_level: SeverityLevel = diagnostic_features
+        .as_ref()
+        .and_then(|diagnostic| diagnostic.entry_point())
+        .unwrap_or_default()
+        .into();
+    let stale_lock_file_level: SeverityLevel = diagnostic_features
+        .as_ref()
+        .and_then(|diagnostic| diagnostic.stale_lock_file())
+        .unwrap_or_default()
+        .into();
+
+    if entry_point_level == SeverityLevel::Off && stale_lock_file_level == SeverityLevel::Off {
+        return Ok(None);
+    }
+
+    let Ok(pyproject_toml_path) = text_document_uri.to_file_path() else {
+        return Ok(None);
+    };
+    let Some(project_dir) = pyproject_toml_path.parent() else {
+        return Ok(None);
+    };
+
+    let mut diagnostics = Vec::new();
+
+    if entry_point_level != SeverityLevel::Off {
+        let entry_points = collect_entry_points(document_tree);
+        if !entry_points.is_empty() {
+            let layout = PackageLayout::new(document_tree, project_dir);
+            for entry_point in entry_points {
+                let Some(reference) = EntryPointReference::parse(entry_point.value.value()) else {
+                    continue;
+                };
+                let Some(problem) = check_entry_point(&layout, &reference) else {
+                    continue;
+                };
+
+                push_diagnostic(
+                    entry_point_level,
+                    problem.to_string(),
+                    problem.code(),
+                    entry_point.value.range(),
+                    &mut diagnostics,
+                );
+            }
+        }
+    }
+
+    if stale_lock_file_level != SeverityLevel::Off {
+        let project_name = get_project_name(document_tree)
+            .and_then(|project_name| PackageName::new(project_name.value().to_string()).ok());
+        let Some(lock_file) =
+            load_cached_lock_file(&pyproject_toml_path, project_name.as_ref(), toml_version).await
+        else {
+            return Ok(Some(diagnostics));
+        };
+
+        for dependency in collect_dependency_requirements_from_document_tree(document_tree) {
+            // Self-references such as `mypkg[dev]` in optional dependencies are not locked.
+            if project_name.as_ref() == Some(&dependency.requirement.name) {
+                continue;
+            }
+            let Some(problem) = lock_file.check_requirement(&dependency.requirement) else {
+                continue;
+            };
+
+            push_diagnostic(
+                stale_lock_file_level,
+                problem.to_string(),
+                "stale-lock-file",
+                dependency.dependency.range(),
+                &mut diagnostics,
+            );
+        }
+    }
+
+    Ok(Some(diagnostics))
+}
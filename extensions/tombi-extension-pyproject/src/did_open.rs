@@ -8,6 +8,9 @@ use tombi_future::Boxable;
 
 use crate::{
     collect_all_dependency_requirements_from_document_tree, find_workspace_pyproject_toml,
+    poetry::{
+        PoetryDependencyGroup, collect_poetry_dependency_tables, is_poetry_registry_dependency,
+    },
 };
 
 const PREFETCH_CONCURRENCY: usize = 10;
@@ -109,6 +112,20 @@ fn collect_prefetch_urls(
         package_names.insert(package_name.to_string());
     }
 
+    for poetry_table in collect_poetry_dependency_tables(document_tree) {
+        for (package_name, value) in poetry_table.table.key_values() {
+            if poetry_table.group == PoetryDependencyGroup::Main && package_name.value == "python" {
+                continue;
+            }
+            if !is_poetry_registry_dependency(value) {
+                continue;
+            }
+            if let Ok(package_name) = pep508_rs::PackageName::new(package_name.value.clone()) {
+                package_names.insert(package_name.to_string());
+            }
+        }
+    }
+
     package_names
         .into_iter()
         .map(|package_name| format!("https://pypi.org/pypi/{package_name}/json"))
@@ -0,0 +1,1000 @@
+use std::path::{Path, PathBuf};
+
+use tombi_document_tree::{Value, dig_keys};
+use tombi_extension::{
+    CompletionContent, CompletionContentPriority, CompletionEdit, CompletionKind,
+};
+
+/// An object reference of an entry point, such as `"mypkg.cli:main"`.
+///
+/// See https://packaging.python.org/en/latest/specifications/entry-points/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct EntryPointReference<'a> {
+    pub module: &'a str,
+    pub object: Option<&'a str>,
+}
+
+impl<'a> EntryPointReference<'a> {
+    pub fn parse(value: &'a str) -> Option<Self> {
+        // Strip legacy extras, e.g. `"mypkg.cli:main [extra]"`.
+        let value = value.split('[').next().unwrap_or(value).trim();
+        let (module, object) = match value.split_once(':') {
+            Some((module, object)) => (module.trim(), Some(object.trim())),
+            None => (value, None),
+        };
+
+        if module.is_empty() || !module.split('.').all(is_python_identifier) {
+            return None;
+        }
+        if let Some(object) = object
+            && !object.split('.').all(is_python_identifier)
+        {
+            return None;
+        }
+
+        Some(Self { module, object })
+    }
+
+    /// The name looked up at the top level of the module.
+    ///
+    /// For `"mypkg.cli:App.run"`, this is `App`.
+    pub fn top_level_object(&self) -> Option<&'a str> {
+        self.object
+            .and_then(|object| object.split('.').next())
+            .filter(|object| !object.is_empty())
+    }
+}
+
+/// An entry point declared in `[project.scripts]`, `[project.gui-scripts]` or
+/// `[project.entry-points.<group>]`.
+pub(crate) struct EntryPoint<'a> {
+    pub value: &'a tombi_document_tree::String,
+}
+
+pub(crate) fn collect_entry_points(
+    document_tree: &tombi_document_tree::DocumentTree,
+) -> Vec<EntryPoint<'_>> {
+    let mut entry_points = Vec::new();
+
+    for table_name in ["scripts", "gui-scripts"] {
+        if let Some((_, Value::Table(scripts))) = dig_keys(document_tree, &["project", table_name])
+        {
+            collect_entry_point_values(scripts, &mut entry_points);
+        }
+    }
+
+    if let Some((_, Value::Table(groups))) = dig_keys(document_tree, &["project", "entry-points"]) {
+        for group in groups.values() {
+            if let Value::Table(group) = group {
+                collect_entry_point_values(group, &mut entry_points);
+            }
+        }
+    }
+
+    entry_points
+}
+
+fn collect_entry_point_values<'a>(
+    table: &'a tombi_document_tree::Table,
+    entry_points: &mut Vec<EntryPoint<'a>>,
+) {
+    for value in table.values() {
+        if let Value::String(value) = value {
+            entry_points.push(EntryPoint { value });
+        }
+    }
+}
+
+/// Where the importable packages of a project live on disk.
+///
+/// Honors `[tool.setuptools.packages.find] where`, `[tool.setuptools.package-dir]`
+/// and hatch `packages` settings, falling back to the `src/` and flat layouts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PackageLayout {
+    roots: Vec<PathBuf>,
+    package_dirs: Vec<(String, PathBuf)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ModuleLocation {
+    /// A `.py` module or a package `__init__.py`.
+    File(PathBuf),
+    /// A namespace package directory without `__init__.py`.
+    Namespace(PathBuf),
+}
+
+impl PackageLayout {
+    pub fn new(document_tree: &tombi_document_tree::DocumentTree, project_dir: &Path) -> Self {
+        let mut roots = Vec::new();
+        let mut package_dirs = Vec::new();
+
+        if let Some((_, Value::Array(wheres))) = dig_keys(
+            document_tree,
+            &["tool", "setuptools", "packages", "find", "where"],
+        ) {
+            roots.extend(
+                wheres
+                    .values()
+                    .iter()
+                    .filter_map(string_value)
+                    .map(|where_dir| project_dir.join(where_dir)),
+            );
+        }
+
+        if let Some((_, Value::Table(package_dir))) =
+            dig_keys(document_tree, &["tool", "setuptools", "package-dir"])
+        {
+            for (key, value) in package_dir.key_values() {
+                let Some(dir) = string_value(value) else {
+                    continue;
+                };
+                if key.value.is_empty() {
+                    roots.push(project_dir.join(dir));
+                } else {
+                    package_dirs.push((key.value.clone(), project_dir.join(dir)));
+                }
+            }
+        }
+
+        for keys in [
+            &["tool", "hatch", "build", "packages"][..],
+            &["tool", "hatch", "build", "targets", "wheel", "packages"][..],
+        ] {
+            if let Some((_, Value::Array(packages))) = dig_keys(document_tree, keys) {
+                for package in packages.values().iter().filter_map(string_value) {
+                    let package_path = project_dir.join(package);
+                    if let Some(parent) = package_path.parent()
+                        && !roots.iter().any(|root| root == parent)
+                    {
+                        roots.push(parent.to_path_buf());
+                    }
+                }
+            }
+        }
+
+        if roots.is_empty() {
+            roots.push(project_dir.join("src"));
+            roots.push(project_dir.to_path_buf());
+        }
+
+        Self {
+            roots,
+            package_dirs,
+        }
+    }
+
+    fn top_level_package_dirs(&self, package_name: &str) -> Vec<PathBuf> {
+        let mut dirs = self
+            .package_dirs
+            .iter()
+            .filter(|(name, _)| name == package_name)
+            .map(|(_, dir)| dir.clone())
+            .collect::<Vec<_>>();
+        dirs.extend(self.roots.iter().map(|root| root.join(package_name)));
+        dirs
+    }
+
+    /// Whether the top-level package of `module` is part of this project.
+    ///
+    /// Entry points may reference modules provided by dependencies,
+    /// which cannot be checked locally.
+    pub fn contains_top_level_package(&self, module: &str) -> bool {
+        let top_level = module.split('.').next().unwrap_or(module);
+        self.top_level_package_dirs(top_level).iter().any(|dir| {
+            tombi_fs::is_dir(dir)
+                || tombi_fs::is_file(&dir.with_file_name(format!("{top_level}.py")))
+        })
+    }
+
+    pub fn resolve_module(&self, module: &str) -> Option<ModuleLocation> {
+        let mut segments = module.split('.');
+        let top_level = segments.next()?;
+        let rest = segments.collect::<Vec<_>>();
+
+        for package_dir in self.top_level_package_dirs(top_level) {
+            let module_path = rest
+                .iter()
+                .fold(package_dir.clone(), |path, segment| path.join(segment));
+
+            let init_file = module_path.join("__init__.py");
+            if tombi_fs::is_file(&init_file) {
+                return Some(ModuleLocation::File(init_file));
+            }
+
+            if let Some(file_name) = module_path.file_name().and_then(|name| name.to_str()) {
+                let module_file = module_path.with_file_name(format!("{file_name}.py"));
+                if tombi_fs::is_file(&module_file) {
+                    return Some(ModuleLocation::File(module_file));
+                }
+            }
+
+            if tombi_fs::is_dir(&module_path) {
+                return Some(ModuleLocation::Namespace(module_path));
+            }
+        }
+
+        None
+    }
+
+    /// Lists the dotted names of all modules that belong to the project.
+    pub fn module_names(&self) -> Vec<String> {
+        const MAX_DEPTH: usize = 8;
+
+        let mut module_names = Vec::new();
+        for (name, dir) in &self.package_dirs {
+            collect_module_names(dir, name, MAX_DEPTH, &mut module_names);
+        }
+        for root in &self.roots {
+            let Ok(entries) = tombi_fs::read_dir(root) else {
+                continue;
+            };
+            for entry in entries {
+                let path = entry.path();
+                let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+                    continue;
+                };
+                if entry.is_dir() {
+                    if is_python_identifier(file_name)
+                        && tombi_fs::is_file(&path.join("__init__.py"))
+                    {
+                        collect_module_names(path, file_name, MAX_DEPTH, &mut module_names);
+                    }
+                } else if let Some(stem) = file_name.strip_suffix(".py")
+                    && is_python_identifier(stem)
+                {
+                    module_names.push(stem.to_string());
+                }
+            }
+        }
+
+        module_names.sort();
+        module_names.dedup();
+        module_names
+    }
+}
+
+fn collect_module_names(dir: &Path, name: &str, depth: usize, module_names: &mut Vec<String>) {
+    module_names.push(name.to_string());
+    if depth == 0 {
+        return;
+    }
+
+    let Ok(entries) = tombi_fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries {
+        let path = entry.path();
+        let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if entry.is_dir() {
+            if is_python_identifier(file_name) && tombi_fs::is_file(&path.join("__init__.py")) {
+                collect_module_names(
+                    path,
+                    &format!("{name}.{file_name}"),
+                    depth - 1,
+                    module_names,
+                );
+            }
+        } else if let Some(stem) = file_name.strip_suffix(".py")
+            && stem != "__init__"
+            && is_python_identifier(stem)
+        {
+            module_names.push(format!("{name}.{stem}"));
+        }
+    }
+}
+
+fn string_value(value: &Value) -> Option<&str> {
+    match value {
+        Value::String(string) => Some(string.value()),
+        _ => None,
+    }
+}
+
+fn is_python_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|first| first == '_' || first.is_alphabetic())
+        && chars.all(|char| char == '_' || char.is_alphanumeric())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PythonDefinitionKind {
+    Function,
+    Class,
+    Variable,
+    Import,
+}
+
+/// A name bound at the top level of a Python module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PythonDefinition {
+    pub name: String,
+    pub kind: PythonDefinitionKind,
+    pub range: tombi_text::Range,
+}
+
+/// Scans a Python source for names bound at the top level.
+///
+/// This is a line-based scan that recognizes `def`, `async def`, `class`,
+/// simple assignments and imports; it does not evaluate the module.
+/// Lines inside triple-quoted strings are skipped.
+pub(crate) fn collect_python_top_level_definitions(source: &str) -> Vec<PythonDefinition> {
+    let mut definitions = Vec::new();
+    let mut in_parenthesized_import = false;
+    let mut triple_quote = None;
+
+    for (line_number, line) in source.lines().enumerate() {
+        let line_number = line_number as tombi_text::Line;
+
+        if let Some(delimiter) = triple_quote {
+            if line.matches(delimiter).count() % 2 == 1 {
+                triple_quote = None;
+            }
+            continue;
+        }
+        triple_quote = open_triple_quote(line);
+
+        if in_parenthesized_import {
+            let names = line.split('#').next().unwrap_or_default();
+            in_parenthesized_import = !names.contains(')');
+            push_imported_names(
+                names.trim_end_matches([')', ' ']),
+                line,
+                line_number,
+                &mut definitions,
+            );
+            continue;
+        }
+
+        if line.starts_with(char::is_whitespace) || line.starts_with('#') {
+            continue;
+        }
+
+        let statement = line.strip_prefix("async ").unwrap_or(line);
+        if let Some(rest) = statement.strip_prefix("def ") {
+            push_definition(
+                rest,
+                line,
+                line_number,
+                PythonDefinitionKind::Function,
+                &mut definitions,
+            );
+        } else if let Some(rest) = statement.strip_prefix("class ") {
+            push_definition(
+                rest,
+                line,
+                line_number,
+                PythonDefinitionKind::Class,
+                &mut definitions,
+            );
+        } else if let Some(rest) = line.strip_prefix("from ") {
+            let Some((_, names)) = rest.split_once(" import ") else {
+                continue;
+            };
+            let names = names.split('#').next().unwrap_or_default().trim();
+            if let Some(names) = names.strip_prefix('(') {
+                in_parenthesized_import = !names.contains(')');
+                push_imported_names(
+                    names.trim_end_matches([')', ' ']),
+                    line,
+                    line_number,
+                    &mut definitions,
+                );
+            } else {
+                push_imported_names(names, line, line_number, &mut definitions);
+            }
+        } else if let Some(names) = line.strip_prefix("import ") {
+            push_imported_names(
+                names.split('#').next().unwrap_or_default(),
+                line,
+                line_number,
+                &mut definitions,
+            );
+        } else if let Some(name) = leading_identifier(line) {
+            let rest = line[name.len()..].trim_start();
+            let is_assignment = (rest.starts_with('=') && !rest.starts_with("=="))
+                || (rest.starts_with(':') && !rest.starts_with(":="));
+            if is_assignment {
+                push_definition(
+                    line,
+                    line,
+                    line_number,
+                    PythonDefinitionKind::Variable,
+                    &mut definitions,
+                );
+            }
+        }
+    }
+
+    definitions
+}
+
+/// Returns the triple-quote delimiter left open at the end of `line`, if any.
+fn open_triple_quote(line: &str) -> Option<&'static str> {
+    if line.trim_start().starts_with('#') {
+        return None;
+    }
+    let delimiter = match (line.find("\"\"\""), line.find("'''")) {
+        (Some(double), Some(single)) if single < double => "'''",
+        (Some(_), _) => "\"\"\"",
+        (None, Some(_)) => "'''",
+        (None, None) => return None,
+    };
+    (line.matches(delimiter).count() % 2 == 1).then_some(delimiter)
+}
+
+/// Returns `true` if the module may bind `name` in a way the line-based scan cannot see.
+///
+/// This covers star imports, a module-level `__getattr__`, and definitions nested
+/// under `if`/`try:` blocks or other indented statements.
+fn python_module_may_bind_dynamically(source: &str, name: &str) -> bool {
+    if find_python_top_level_definition(source, "__getattr__").is_some() {
+        return true;
+    }
+
+    source.lines().any(|line| {
+        let statement = line.trim_start();
+        if statement.starts_with("from ")
+            && statement
+                .split_once(" import ")
+                .is_some_and(|(_, names)| names.trim().starts_with('*'))
+        {
+            return true;
+        }
+        if !line.starts_with(char::is_whitespace) {
+            return false;
+        }
+
+        let statement = statement.strip_prefix("async ").unwrap_or(statement);
+        if let Some(rest) = statement
+            .strip_prefix("def ")
+            .or_else(|| statement.strip_prefix("class "))
+        {
+            return leading_identifier(rest.trim_start()) == Some(name);
+        }
+        if leading_identifier(statement) != Some(name) {
+            return false;
+        }
+        let rest = statement[name.len()..].trim_start();
+        (rest.starts_with('=') && !rest.starts_with("=="))
+            || (rest.starts_with(':') && !rest.starts_with(":="))
+    })
+}
+
+fn push_definition(
+    rest: &str,
+    line: &str,
+    line_number: tombi_text::Line,
+    kind: PythonDefinitionKind,
+    definitions: &mut Vec<PythonDefinition>,
+) {
+    let rest = rest.trim_start();
+    let Some(name) = leading_identifier(rest) else {
+        return;
+    };
+    let column = line.len() - rest.len();
+    definitions.push(PythonDefinition {
+        name: name.to_string(),
+        kind,
+        range: name_range(line, line_number, column, name),
+    });
+}
+
+fn push_imported_names(
+    names: &str,
+    line: &str,
+    line_number: tombi_text::Line,
+    definitions: &mut Vec<PythonDefinition>,
+) {
+    for imported in names.split(',') {
+        // `import a.b` binds `a`; `x as y` binds `y`.
+        let bound = match imported.rsplit_once(" as ") {
+            Some((_, alias)) => alias.trim(),
+            None => imported.trim().split('.').next().unwrap_or_default(),
+        };
+        if !is_python_identifier(bound) {
+            continue;
+        }
+        let column = line.find(bound).unwrap_or_default();
+        definitions.push(PythonDefinition {
+            name: bound.to_string(),
+            kind: PythonDefinitionKind::Import,
+            range: name_range(line, line_number, column, bound),
+        });
+    }
+}
+
+fn leading_identifier(text: &str) -> Option<&str> {
+    let end = text
+        .char_indices()
+        .find(|(_, char)| !(*char == '_' || char.is_alphanumeric()))
+        .map(|(index, _)| index)
+        .unwrap_or(text.len());
+    let name = &text[..end];
+    is_python_identifier(name).then_some(name)
+}
+
+fn name_range(
+    line: &str,
+    line_number: tombi_text::Line,
+    byte_column: usize,
+    name: &str,
+) -> tombi_text::Range {
+    let start = line[..byte_column].chars().count() as tombi_text::Column;
+    let end = start + name.chars().count() as tombi_text::Column;
+    tombi_text::Range::new(
+        tombi_text::Position::new(line_number, start),
+        tombi_text::Position::new(line_number, end),
+    )
+}
+
+pub(crate) fn find_python_top_level_definition(
+    source: &str,
+    name: &str,
+) -> Option<PythonDefinition> {
+    collect_python_top_level_definitions(source)
+        .into_iter()
+        .find(|definition| definition.name == name)
+}
+
+pub(crate) fn goto_definition_for_entry_point(
+    document_tree: &tombi_document_tree::DocumentTree,
+    entry_point: &tombi_document_tree::String,
+    pyproject_toml_path: &Path,
+) -> Vec<tombi_extension::Location> {
+    let Some(reference) = EntryPointReference::parse(entry_point.value()) else {
+        return Vec::new();
+    };
+    let Some(project_dir) = pyproject_toml_path.parent() else {
+        return Vec::new();
+    };
+    let layout = PackageLayout::new(document_tree, project_dir);
+    let Some(ModuleLocation::File(module_path)) = layout.resolve_module(reference.module) else {
+        return Vec::new();
+    };
+    let Ok(uri) = tombi_uri::Uri::from_file_path(&module_path) else {
+        return Vec::new();
+    };
+
+    let range = reference
+        .top_level_object()
+        .and_then(|object| {
+            let source = tombi_fs::read_to_string(&module_path).ok()?;
+            find_python_top_level_definition(&source, object)
+        })
+        .map(|definition| definition.range)
+        .unwrap_or_default();
+
+    vec![tombi_extension::Location { uri, range }]
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum EntryPointProblem {
+    ModuleNotFound { module: String },
+    ObjectNotFound { module: String, object: String },
+}
+
+impl std::fmt::Display for EntryPointProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ModuleNotFound { module } => {
+                write!(f, "Python module `{module}` not found in the project")
+            }
+            Self::ObjectNotFound { module, object } => {
+                write!(
+                    f,
+                    "`{object}` is not defined at the top level of `{module}`"
+                )
+            }
+        }
+    }
+}
+
+impl EntryPointProblem {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::ModuleNotFound { .. } => "entry-point-module-not-found",
+            Self::ObjectNotFound { .. } => "entry-point-object-not-found",
+        }
+    }
+}
+
+pub(crate) fn check_entry_point(
+    layout: &PackageLayout,
+    reference: &EntryPointReference,
+) -> Option<EntryPointProblem> {
+    if !layout.contains_top_level_package(reference.module) {
+        return None;
+    }
+
+    let module_path = match layout.resolve_module(reference.module) {
+        Some(ModuleLocation::File(module_path)) => module_path,
+        Some(ModuleLocation::Namespace(_)) => return None,
+        None => {
+            return Some(EntryPointProblem::ModuleNotFound {
+                module: reference.module.to_string(),
+            });
+        }
+    };
+
+    let object = reference.top_level_object()?;
+    let source = tombi_fs::read_to_string(&module_path).ok()?;
+    if find_python_top_level_definition(&source, object).is_some()
+        || python_module_may_bind_dynamically(&source, object)
+    {
+        return None;
+    }
+
+    Some(EntryPointProblem::ObjectNotFound {
+        module: reference.module.to_string(),
+        object: object.to_string(),
+    })
+}
+
+pub(crate) fn completion_entry_point(
+    document_tree: &tombi_document_tree::DocumentTree,
+    entry_point: &tombi_document_tree::String,
+    position: tombi_text::Position,
+    pyproject_toml_path: &Path,
+) -> Option<Vec<CompletionContent>> {
+    if !entry_point.range().contains(position) {
+        return None;
+    }
+    let project_dir = pyproject_toml_path.parent()?;
+    let layout = PackageLayout::new(document_tree, project_dir);
+    let value = entry_point.value();
+    let value_range = entry_point.unquoted_range();
+
+    let candidates = match value.split_once(':') {
+        Some((module, object_prefix)) => {
+            let Some(ModuleLocation::File(module_path)) = layout.resolve_module(module.trim())
+            else {
+                return None;
+            };
+            let source = tombi_fs::read_to_string(&module_path).ok()?;
+            collect_python_top_level_definitions(&source)
+                .into_iter()
+                .filter(|definition| definition.kind != PythonDefinitionKind::Import)
+                .filter(|definition| definition.name.starts_with(object_prefix.trim()))
+                .map(|definition| {
+                    let detail = match definition.kind {
+                        PythonDefinitionKind::Function => "Python function",
+                        PythonDefinitionKind::Class => "Python class",
+                        PythonDefinitionKind::Variable | PythonDefinitionKind::Import => {
+                            "Python object"
+                        }
+                    };
+                    (format!("{}:{}", module.trim(), definition.name), detail)
+                })
+                .collect::<Vec<_>>()
+        }
+        None => layout
+            .module_names()
+            .into_iter()
+            .filter(|module_name| module_name.starts_with(value))
+            .map(|module_name| (module_name, "Python module"))
+            .collect(),
+    };
+
+    let completions = candidates
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, (label, detail))| {
+            let edit = CompletionEdit::new_string_literal_while_editing(&label, value_range)?;
+            Some(CompletionContent {
+                label,
+                kind: CompletionKind::String,
+                emoji_icon: Some('🐍'),
+                priority: CompletionContentPriority::Custom(format!(
+                    "10__pyproject_entry_point_{index:>03}__"
+                )),
+                detail: Some(detail.to_string()),
+                documentation: None,
+                filter_text: None,
+                schema_uri: None,
+                deprecated: None,
+                edit: Some(edit),
+                preselect: None,
+                in_comment: false,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if completions.is_empty() {
+        None
+    } else {
+        Some(completions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tombi_ast::AstNode;
+    use tombi_config::TomlVersion;
+    use tombi_document_tree::TryIntoDocumentTree;
+
+    use super::*;
+
+    fn parse_document_tree(source: &str) -> tombi_document_tree::DocumentTree {
+        let root = tombi_ast::Root::cast(tombi_parser::parse(source).into_syntax_node()).unwrap();
+        root.try_into_document_tree(TomlVersion::default()).unwrap()
+    }
+
+    fn write_file(path: &Path, content: &str) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn parse_entry_point_reference() {
+        assert_eq!(
+            EntryPointReference::parse("mypkg.cli:main"),
+            Some(EntryPointReference {
+                module: "mypkg.cli",
+                object: Some("main"),
+            })
+        );
+        assert_eq!(
+            EntryPointReference::parse("mypkg.cli:App.run [extra]")
+                .and_then(|reference| reference.top_level_object()),
+            Some("App")
+        );
+        assert_eq!(
+            EntryPointReference::parse("mypkg"),
+            Some(EntryPointReference {
+                module: "mypkg",
+                object: None,
+            })
+        );
+        assert_eq!(EntryPointReference::parse("my-pkg:main"), None);
+        assert_eq!(EntryPointReference::parse(":main"), None);
+    }
+
+    #[test]
+    fn collect_top_level_definitions() {
+        let source = r#"import os, sys as system
+from typing import (
+    Any,
+    Optional as Opt,
+)
+
+VERSION = "1.0"
+count: int = 0
+
+def main():
+    def nested():
+        pass
+
+async def serve():
+    pass
+
+class App(Base):
+    value = 1
+
+if __name__ == "__main__":
+    main()
+"#;
+        let definitions = collect_python_top_level_definitions(source)
+            .into_iter()
+            .map(|definition| (definition.name, definition.kind))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            definitions,
+            vec![
+                ("os".to_string(), PythonDefinitionKind::Import),
+                ("system".to_string(), PythonDefinitionKind::Import),
+                ("Any".to_string(), PythonDefinitionKind::Import),
+                ("Opt".to_string(), PythonDefinitionKind::Import),
+                ("VERSION".to_string(), PythonDefinitionKind::Variable),
+                ("count".to_string(), PythonDefinitionKind::Variable),
+                ("main".to_string(), PythonDefinitionKind::Function),
+                ("serve".to_string(), PythonDefinitionKind::Function),
+                ("App".to_string(), PythonDefinitionKind::Class),
+            ]
+        );
+    }
+
+    #[test]
+    fn top_level_definition_range_points_to_name() {
+        let definition =
+            find_python_top_level_definition("import os\n\nasync def main():\n    pass\n", "main")
+                .unwrap();
+
+        assert_eq!(
+            definition.range,
+            tombi_text::Range::new(
+                tombi_text::Position::new(2, 10),
+                tombi_text::Position::new(2, 14),
+            )
+        );
+    }
+
+    #[test]
+    fn resolve_module_in_src_layout() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        write_file(&temp_dir.path().join("src/mypkg/__init__.py"), "");
+        write_file(
+            &temp_dir.path().join("src/mypkg/cli.py"),
+            "def main():\n    pass\n",
+        );
+
+        let layout = PackageLayout::new(&parse_document_tree(""), temp_dir.path());
+
+        assert_eq!(
+            layout.resolve_module("mypkg.cli"),
+            Some(ModuleLocation::File(
+                temp_dir.path().join("src/mypkg/cli.py")
+            ))
+        );
+        assert_eq!(
+            layout.resolve_module("mypkg"),
+            Some(ModuleLocation::File(
+                temp_dir.path().join("src/mypkg/__init__.py")
+            ))
+        );
+        assert_eq!(
+            layout.module_names(),
+            vec!["mypkg".to_string(), "mypkg.cli".to_string()]
+        );
+    }
+
+    #[test]
+    fn resolve_module_with_hatch_packages() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        write_file(&temp_dir.path().join("lib/mypkg/__init__.py"), "");
+        write_file(&temp_dir.path().join("lib/mypkg/cli.py"), "");
+
+        let document_tree = parse_document_tree(
+            r#"
+[tool.hatch.build.targets.wheel]
+packages = ["lib/mypkg"]
+"#,
+        );
+        let layout = PackageLayout::new(&document_tree, temp_dir.path());
+
+        assert_eq!(
+            layout.resolve_module("mypkg.cli"),
+            Some(ModuleLocation::File(
+                temp_dir.path().join("lib/mypkg/cli.py")
+            ))
+        );
+    }
+
+    #[test]
+    fn resolve_module_with_setuptools_package_dir() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        write_file(&temp_dir.path().join("python/cli.py"), "");
+
+        let document_tree = parse_document_tree(
+            r#"
+[tool.setuptools.package-dir]
+mypkg = "python"
+"#,
+        );
+        let layout = PackageLayout::new(&document_tree, temp_dir.path());
+
+        assert_eq!(
+            layout.resolve_module("mypkg.cli"),
+            Some(ModuleLocation::File(temp_dir.path().join("python/cli.py")))
+        );
+    }
+
+    #[test]
+    fn check_entry_point_problems() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        write_file(&temp_dir.path().join("mypkg/__init__.py"), "");
+        write_file(
+            &temp_dir.path().join("mypkg/cli.py"),
+            "def main():\n    pass\n",
+        );
+
+        let layout = PackageLayout::new(&parse_document_tree(""), temp_dir.path());
+        let check = |value| check_entry_point(&layout, &EntryPointReference::parse(value).unwrap());
+
+        assert_eq!(check("mypkg.cli:main"), None);
+        assert_eq!(
+            check("mypkg.cli:run"),
+            Some(EntryPointProblem::ObjectNotFound {
+                module: "mypkg.cli".to_string(),
+                object: "run".to_string(),
+            })
+        );
+        assert_eq!(
+            check("mypkg.missing:main"),
+            Some(EntryPointProblem::ModuleNotFound {
+                module: "mypkg.missing".to_string(),
+            })
+        );
+        // Modules provided by dependencies are not checked.
+        assert_eq!(check("othertool.cli:main"), None);
+    }
+
+    #[test]
+    fn check_entry_point_skips_dynamic_bindings() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        write_file(&temp_dir.path().join("mypkg/__init__.py"), "");
+        write_file(
+            &temp_dir.path().join("mypkg/star.py"),
+            "from mypkg._impl import *\n",
+        );
+        write_file(
+            &temp_dir.path().join("mypkg/lazy.py"),
+            "def __getattr__(name):\n    raise AttributeError(name)\n",
+        );
+        write_file(
+            &temp_dir.path().join("mypkg/nested.py"),
+            "try:\n    from fast import main\nexcept ImportError:\n    def main():\n        pass\n\nif True:\n    run = main\n",
+        );
+        write_file(
+            &temp_dir.path().join("mypkg/docstring.py"),
+            "\"\"\"Usage:\n\ndef main():\n\"\"\"\n",
+        );
+
+        let layout = PackageLayout::new(&parse_document_tree(""), temp_dir.path());
+        let check = |value| check_entry_point(&layout, &EntryPointReference::parse(value).unwrap());
+
+        assert_eq!(check("mypkg.star:main"), None);
+        assert_eq!(check("mypkg.lazy:main"), None);
+        assert_eq!(check("mypkg.nested:main"), None);
+        assert_eq!(check("mypkg.nested:run"), None);
+        assert_eq!(
+            check("mypkg.nested:other"),
+            Some(EntryPointProblem::ObjectNotFound {
+                module: "mypkg.nested".to_string(),
+                object: "other".to_string(),
+            })
+        );
+        assert_eq!(
+            check("mypkg.docstring:main"),
+            Some(EntryPointProblem::ObjectNotFound {
+                module: "mypkg.docstring".to_string(),
+                object: "main".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn skip_definitions_in_triple_quoted_strings() {
+        let source = "HELP = \"\"\"\ndef hidden():\n\"\"\"\n\n\'\'\'\nclass Hidden:\n\'\'\'\n\ndef main():\n    \"\"\"Docstring.\"\"\"\n";
+        let names = collect_python_top_level_definitions(source)
+            .into_iter()
+            .map(|definition| definition.name)
+            .collect::<Vec<_>>();
+
+        assert_eq!(names, vec!["HELP".to_string(), "main".to_string()]);
+    }
+
+    #[test]
+    fn complete_entry_point_object() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        write_file(&temp_dir.path().join("src/mypkg/__init__.py"), "");
+        write_file(
+            &temp_dir.path().join("src/mypkg/cli.py"),
+            "import sys\n\ndef main():\n    pass\n\nclass App:\n    pass\n",
+        );
+
+        let document_tree = parse_document_tree("[project.scripts]\nmypkg = \"mypkg.cli:m\"\n");
+        let Some((_, Value::String(entry_point))) =
+            dig_keys(&document_tree, &["project", "scripts", "mypkg"])
+        else {
+            panic!("entry point not found");
+        };
+
+        let labels = completion_entry_point(
+            &document_tree,
+            entry_point,
+            tombi_text::Position::new(1, 20),
+            &temp_dir.path().join("pyproject.toml"),
+        )
+        .unwrap()
+        .into_iter()
+        .map(|completion| completion.label)
+        .collect::<Vec<_>>();
+
+        assert_eq!(labels, vec!["mypkg.cli:main".to_string()]);
+    }
+}
@@ -147,7 +147,7 @@ fn pyproject_goto_declaration_enabled(
             |goto_declaration| match classify_pyproject_navigation_feature(accessors) {
                 PyprojectNavigationFeature::Dependency => goto_declaration.dependency(),
                 PyprojectNavigationFeature::Member => goto_declaration.member(),
-                PyprojectNavigationFeature::Path => None,
+                PyprojectNavigationFeature::EntryPoint | PyprojectNavigationFeature::Path => None,
             },
         )
         .map(|feature| feature.enabled())
@@ -11,13 +11,18 @@ use crate::{
         is_uv_sources_accessors,
     },
     classify_pyproject_navigation_feature, collect_dependency_requirements_from_document_tree,
+    entry_point::goto_definition_for_entry_point,
     find_dependency_group_key, find_member_project_toml, find_workspace_pyproject_toml,
     get_project_name, goto_definition_for_member_pyproject_toml,
     goto_definition_for_workspace_pyproject_toml, has_uv_sources_accessors,
-    is_dependency_name_accessors, is_project_name_accessors, is_pyproject_path_accessors,
-    is_uv_source_path_accessors, is_uv_source_workspace_accessors, is_uv_workspace_accessors,
-    load_pyproject_toml_document_tree, parse_requirement, resolve_member_pyproject_toml_path,
-    resolve_relative_path_uri,
+    is_dependency_name_accessors, is_entry_point_accessors, is_project_name_accessors,
+    is_pyproject_path_accessors, is_uv_source_path_accessors, is_uv_source_workspace_accessors,
+    is_uv_workspace_accessors, load_pyproject_toml_document_tree, parse_requirement,
+    poetry::{
+        get_poetry_dependency_accessors, is_poetry_dependency_path_accessors,
+        poetry_dependency_path,
+    },
+    resolve_member_pyproject_toml_path, resolve_relative_path_uri,
 };
 
 pub async fn goto_definition(
@@ -43,13 +48,24 @@ pub async fn goto_definition(
         goto_definition_for_project_name(document_tree, text_document_uri)
     } else if is_dependency_group_name_accessors(accessors) {
         goto_definition_for_dependency_group_name(document_tree, accessors, text_document_uri)
-    } else if is_uv_source_path_accessors(accessors) {
+    } else if is_uv_source_path_accessors(accessors)
+        || is_poetry_dependency_path_accessors(accessors)
+    {
         goto_definition_for_relative_package(
             document_tree,
             accessors,
             &pyproject_toml_path,
             toml_version,
         )
+    } else if get_poetry_dependency_accessors(accessors)
+        .is_some_and(|dependency_accessors| dependency_accessors.len() == accessors.len())
+    {
+        goto_definition_for_poetry_path_dependency(
+            document_tree,
+            accessors,
+            &pyproject_toml_path,
+            toml_version,
+        )
     } else if has_uv_sources_accessors(accessors) {
         let jump_to_package = is_uv_sources_accessors(accessors)
             || !source_is_workspace_managed(document_tree, accessors);
@@ -60,6 +76,13 @@ pub async fn goto_definition(
             toml_version,
             jump_to_package,
         )?
+    } else if is_entry_point_accessors(accessors) {
+        match dig_accessors(document_tree, accessors) {
+            Some((_, Value::String(entry_point))) => {
+                goto_definition_for_entry_point(document_tree, entry_point, &pyproject_toml_path)
+            }
+            _ => Vec::new(),
+        }
     } else if is_pyproject_path_accessors(accessors) {
         goto_definition_for_relative_file(document_tree, accessors, &pyproject_toml_path)
     } else if is_uv_workspace_accessors(accessors) {
@@ -210,6 +233,29 @@ fn goto_definition_for_relative_package(
         .collect()
 }
 
+/// Jumps from the package name of a Poetry path dependency to its `pyproject.toml`.
+///
+/// ```toml
+/// [tool.poetry.dependencies]
+/// my-lib = { path = "../my-lib", develop = true }
+/// ```
+fn goto_definition_for_poetry_path_dependency(
+    document_tree: &tombi_document_tree::DocumentTree,
+    accessors: &[Accessor],
+    pyproject_toml_path: &Path,
+    toml_version: TomlVersion,
+) -> Vec<tombi_extension::Location> {
+    let Some(path_value) = dig_accessors(document_tree, accessors)
+        .and_then(|(_, dependency)| poetry_dependency_path(dependency))
+    else {
+        return Vec::new();
+    };
+
+    get_path_dependency_definition(pyproject_toml_path, path_value.value(), toml_version)
+        .into_iter()
+        .collect()
+}
+
 fn goto_definition_for_relative_file(
     document_tree: &tombi_document_tree::DocumentTree,
     accessors: &[Accessor],
@@ -241,6 +287,7 @@ fn pyproject_goto_definition_enabled(
         .and_then(
             |goto_definition| match classify_pyproject_navigation_feature(accessors) {
                 PyprojectNavigationFeature::Dependency => goto_definition.dependency(),
+                PyprojectNavigationFeature::EntryPoint => goto_definition.entry_point(),
                 PyprojectNavigationFeature::Member => goto_definition.member(),
                 PyprojectNavigationFeature::Path => goto_definition.path(),
             },
@@ -9,7 +9,9 @@ use tombi_schema_store::{Accessor, matches_accessors};
 use crate::{
     fetch_pypi_project, find_member_project_toml, find_workspace_pyproject_toml,
     get_dependency_accessors, get_project_name, load_pyproject_toml_document_tree,
-    parse_requirement, resolve_member_pyproject_toml_path,
+    parse_requirement,
+    poetry::{get_poetry_dependency_accessors, is_poetry_registry_dependency},
+    resolve_member_pyproject_toml_path,
 };
 
 pub async fn hover(
@@ -46,6 +48,31 @@ pub async fn hover(
         ));
     }
 
+    if let Some(dependency_accessors) = get_poetry_dependency_accessors(accessors) {
+        let (Some(Accessor::Key(package_name)), Some((_, dependency))) = (
+            dependency_accessors.last(),
+            dig_accessors(document_tree, dependency_accessors),
+        ) else {
+            return Ok(None);
+        };
+
+        if let Some(metadata) = resolve_source_value_metadata(
+            package_name,
+            dependency,
+            document_tree,
+            &pyproject_toml_path,
+            toml_version,
+        ) {
+            return Ok(Some(metadata));
+        }
+
+        if !is_poetry_registry_dependency(dependency) {
+            return Ok(None);
+        }
+
+        return fetch_pypi_metadata(package_name, offline, cache_options).await;
+    }
+
     let Some(dependency_accessors) = get_dependency_accessors(accessors) else {
         return Ok(None);
     };
@@ -15,10 +15,9 @@ use tombi_document_tree::{TryIntoDocumentTree, Value, dig_keys};
 use tombi_extension::{InlayHint, file_cache_version, get_or_load_json};
 use tombi_hashmap::{HashMap, HashSet};
 
-use crate::{UV_DEPENDENCY_KEYS, parse_dependency_requirement};
+use crate::{UV_DEPENDENCY_KEYS, lock_file::PYPROJECT_EXTENSION_ID, parse_dependency_requirement};
 
 const RESOLVED_VERSION_TOOLTIP: &str = "Resolved version in uv.lock";
-const PYPROJECT_EXTENSION_ID: &str = "tombi-toml/pyproject";
 const INLAY_HINT_LOCKFILE_KEY: &str = "inlay_hint.lockfile";
 
 #[derive(Debug)]
@@ -2,19 +2,24 @@ mod accessors;
 mod code_action;
 mod completion;
 mod dependency;
+mod diagnostic;
 mod did_open;
 mod document_link;
+mod entry_point;
 mod goto_declaration;
 mod goto_definition;
 mod hover;
 mod inlay_hint;
+mod lock_file;
 mod manifest;
+mod poetry;
 mod pypi_org;
 mod references;
 mod workspace;
 
 pub use code_action::{CodeActionRefactorRewriteName, code_action};
 pub use completion::completion;
+pub use diagnostic::diagnostic;
 pub use did_open::did_open;
 pub use document_link::document_link;
 pub use goto_declaration::get_current_declaration;
@@ -25,8 +30,9 @@ pub use inlay_hint::inlay_hint;
 pub use references::references;
 
 pub(crate) use accessors::{
-    has_uv_sources_accessors, is_dependency_name_accessors, is_project_name_accessors,
-    is_uv_source_path_accessors, is_uv_source_workspace_accessors, is_uv_workspace_accessors,
+    has_uv_sources_accessors, is_dependency_name_accessors, is_entry_point_accessors,
+    is_project_name_accessors, is_uv_source_path_accessors, is_uv_source_workspace_accessors,
+    is_uv_workspace_accessors,
 };
 pub(crate) use dependency::{
     DependencyRequirement, UV_DEPENDENCY_KEYS,
@@ -53,6 +59,7 @@ pub(crate) use workspace::{
 
 pub(crate) enum PyprojectNavigationFeature {
     Dependency,
+    EntryPoint,
     Member,
     Path,
 }
@@ -63,6 +70,8 @@ pub(crate) fn classify_pyproject_navigation_feature(
 ) -> PyprojectNavigationFeature {
     if is_pyproject_path_accessors(accessors) {
         PyprojectNavigationFeature::Path
+    } else if is_entry_point_accessors(accessors) {
+        PyprojectNavigationFeature::EntryPoint
     } else if matches_accessors!(
         accessors[..accessors.len().min(3)],
         ["tool", "uv", "workspace"]
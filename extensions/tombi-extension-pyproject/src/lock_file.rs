@@ -0,0 +1,407 @@
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use pep508_rs::{PackageName, Requirement, VerbatimUrl, VersionOrUrl, pep440_rs::Version};
+use serde::{Deserialize, Serialize};
+use tombi_ast::AstNode;
+use tombi_config::TomlVersion;
+use tombi_document_tree::{TryIntoDocumentTree, Value, dig_keys};
+use tombi_extension::{file_cache_version, get_or_load_json};
+use tombi_hashmap::HashMap;
+
+pub(crate) const PYPROJECT_EXTENSION_ID: &str = "tombi-toml/pyproject";
+const DIAGNOSTIC_LOCKFILE_KEY: &str = "diagnostic.lockfile";
+
+/// Lock files that record the resolved packages of a Python project.
+///
+/// `uv.lock`, `poetry.lock` and `pdm.lock` all list the resolved packages
+/// as `[[package]]` tables with `name` and `version`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum LockFileKind {
+    Uv,
+    Poetry,
+    Pdm,
+}
+
+impl LockFileKind {
+    const ALL: [Self; 3] = [Self::Uv, Self::Poetry, Self::Pdm];
+
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            Self::Uv => "uv.lock",
+            Self::Poetry => "poetry.lock",
+            Self::Pdm => "pdm.lock",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct LockFile {
+    pub kind: LockFileKind,
+    /// Locked versions keyed by the normalized package name.
+    packages: HashMap<String, Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum LockFileProblem {
+    Unsatisfied {
+        package_name: String,
+        version_specifiers: String,
+        locked_versions: Vec<String>,
+        lock_file_name: &'static str,
+    },
+    Missing {
+        package_name: String,
+        lock_file_name: &'static str,
+    },
+}
+
+impl std::fmt::Display for LockFileProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unsatisfied {
+                package_name,
+                version_specifiers,
+                locked_versions,
+                lock_file_name,
+            } => write!(
+                f,
+                "`{package_name}` requirement \"{version_specifiers}\" is not satisfied by the locked {} {} in {lock_file_name}",
+                if locked_versions.len() == 1 {
+                    "version"
+                } else {
+                    "versions"
+                },
+                locked_versions.join(", ")
+            ),
+            Self::Missing {
+                package_name,
+                lock_file_name,
+            } => write!(f, "`{package_name}` is missing from {lock_file_name}"),
+        }
+    }
+}
+
+impl LockFile {
+    fn from_document_tree(
+        kind: LockFileKind,
+        document_tree: &tombi_document_tree::DocumentTree,
+    ) -> Option<Self> {
+        let (_, Value::Array(packages)) = dig_keys(document_tree, &["package"])? else {
+            return None;
+        };
+
+        let mut locked_packages = HashMap::<String, Vec<String>>::new();
+        for package in packages.iter() {
+            let Value::Table(package) = package else {
+                continue;
+            };
+            let Some(Value::String(name)) = package.get("name") else {
+                continue;
+            };
+            let Ok(name) = PackageName::from_str(name.value()) else {
+                continue;
+            };
+
+            let versions = locked_packages.entry(name.to_string()).or_default();
+            if let Some(Value::String(version)) = package.get("version")
+                && !versions.iter().any(|locked| locked == version.value())
+            {
+                versions.push(version.value().to_string());
+            }
+        }
+
+        Some(Self {
+            kind,
+            packages: locked_packages,
+        })
+    }
+
+    /// Checks whether the lock file still satisfies a requirement of `pyproject.toml`.
+    pub fn check_requirement(
+        &self,
+        requirement: &Requirement<VerbatimUrl>,
+    ) -> Option<LockFileProblem> {
+        let package_name = requirement.name.as_ref();
+        let lock_file_name = self.kind.file_name();
+
+        let Some(locked_versions) = self.packages.get(package_name) else {
+            // Requirements guarded by a marker may legitimately be absent from the lock.
+            if !requirement.marker.is_true() {
+                return None;
+            }
+            return Some(LockFileProblem::Missing {
+                package_name: package_name.to_string(),
+                lock_file_name,
+            });
+        };
+
+        let Some(VersionOrUrl::VersionSpecifier(version_specifiers)) =
+            requirement.version_or_url.as_ref()
+        else {
+            return None;
+        };
+        if version_specifiers.is_empty() {
+            return None;
+        }
+
+        let versions = locked_versions
+            .iter()
+            .filter_map(|version| Version::from_str(version).ok())
+            .collect::<Vec<_>>();
+        if versions.is_empty()
+            || versions
+                .iter()
+                .any(|version| version_specifiers.contains(version))
+        {
+            return None;
+        }
+
+        Some(LockFileProblem::Unsatisfied {
+            package_name: package_name.to_string(),
+            version_specifiers: version_specifiers.to_string(),
+            locked_versions: locked_versions.clone(),
+            lock_file_name,
+        })
+    }
+}
+
+/// Loads the lock file that records the resolution of `pyproject.toml`.
+///
+/// `poetry.lock` and `pdm.lock` live next to `pyproject.toml`, while `uv.lock`
+/// may live at the root of a uv workspace, in which case it must list the project.
+pub(crate) async fn load_cached_lock_file(
+    pyproject_toml_path: &Path,
+    project_name: Option<&PackageName>,
+    toml_version: TomlVersion,
+) -> Option<LockFile> {
+    let project_dir = pyproject_toml_path.parent()?;
+    if let Some((kind, lock_file_path)) = LockFileKind::ALL.into_iter().find_map(|kind| {
+        let candidate = project_dir.join(kind.file_name());
+        tombi_fs::is_file(&candidate).then_some((kind, candidate))
+    }) {
+        return load_lock_file(kind, lock_file_path, toml_version).await;
+    }
+
+    let project_name = project_name?;
+    let uv_lock_path = find_ancestor_uv_lock_path(project_dir)?;
+    load_lock_file(LockFileKind::Uv, uv_lock_path, toml_version)
+        .await
+        .filter(|lock_file| lock_file.packages.contains_key(project_name.as_ref()))
+}
+
+async fn load_lock_file(
+    kind: LockFileKind,
+    lock_file_path: PathBuf,
+    toml_version: TomlVersion,
+) -> Option<LockFile> {
+    let lock_file_path = tombi_fs::canonicalize(&lock_file_path).unwrap_or(lock_file_path);
+    let cache_key = lock_file_cache_key(&lock_file_path);
+    let cache_version = file_cache_version(&lock_file_path);
+
+    let cache_value = get_or_load_json(&cache_key, cache_version, move || async move {
+        load_lock_file_json(kind, lock_file_path, toml_version).await
+    })
+    .await?;
+
+    LockFile::deserialize(cache_value.as_ref()).ok()
+}
+
+fn find_ancestor_uv_lock_path(project_dir: &Path) -> Option<PathBuf> {
+    let mut current_dir = project_dir.parent()?;
+
+    loop {
+        let candidate = current_dir.join(LockFileKind::Uv.file_name());
+        if tombi_fs::is_file(&candidate) {
+            return Some(candidate);
+        }
+
+        current_dir = current_dir.parent()?;
+    }
+}
+
+async fn load_lock_file_json(
+    kind: LockFileKind,
+    lock_file_path: PathBuf,
+    toml_version: TomlVersion,
+) -> Option<serde_json::Value> {
+    let lock_file_text = tombi_fs::read_to_string_async(&lock_file_path).await.ok()?;
+    tombi_fs::run_blocking(move || parse_lock_file_json(kind, lock_file_text, toml_version))
+        .await
+        .ok()
+        .flatten()
+}
+
+fn parse_lock_file_json(
+    kind: LockFileKind,
+    lock_file_text: String,
+    toml_version: TomlVersion,
+) -> Option<serde_json::Value> {
+    let root = tombi_ast::Root::cast(tombi_parser::parse(&lock_file_text).into_syntax_node())?;
+    let document_tree = root.try_into_document_tree(toml_version).ok()?;
+    let lock_file = LockFile::from_document_tree(kind, &document_tree)?;
+
+    serde_json::to_value(lock_file).ok()
+}
+
+fn lock_file_cache_key(lock_file_path: &Path) -> String {
+    format!(
+        "{PYPROJECT_EXTENSION_ID}:{DIAGNOSTIC_LOCKFILE_KEY}:{}",
+        lock_file_path.display()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_requirement;
+
+    fn parse_lock_file(kind: LockFileKind, source: &str) -> LockFile {
+        let root = tombi_ast::Root::cast(tombi_parser::parse(source).into_syntax_node()).unwrap();
+        let document_tree = root.try_into_document_tree(TomlVersion::default()).unwrap();
+        LockFile::from_document_tree(kind, &document_tree).unwrap()
+    }
+
+    fn check(lock_file: &LockFile, requirement: &str) -> Option<LockFileProblem> {
+        lock_file.check_requirement(&parse_requirement(requirement).unwrap())
+    }
+
+    #[test]
+    fn poetry_lock_reports_unsatisfied_and_missing_requirements() {
+        let lock_file = parse_lock_file(
+            LockFileKind::Poetry,
+            r#"
+[[package]]
+name = "Requests"
+version = "2.31.0"
+description = "Python HTTP for Humans."
+
+[package.dependencies]
+urllib3 = ">=1.21.1,<3"
+
+[[package]]
+name = "urllib3"
+version = "2.2.1"
+
+[metadata]
+lock-version = "2.0"
+"#,
+        );
+
+        assert_eq!(check(&lock_file, "requests>=2.0"), None);
+        assert_eq!(
+            check(&lock_file, "requests>=3.0"),
+            Some(LockFileProblem::Unsatisfied {
+                package_name: "requests".to_string(),
+                version_specifiers: ">=3.0".to_string(),
+                locked_versions: vec!["2.31.0".to_string()],
+                lock_file_name: "poetry.lock",
+            })
+        );
+        assert_eq!(
+            check(&lock_file, "httpx"),
+            Some(LockFileProblem::Missing {
+                package_name: "httpx".to_string(),
+                lock_file_name: "poetry.lock",
+            })
+        );
+        assert_eq!(check(&lock_file, "pywin32; sys_platform == 'win32'"), None);
+    }
+
+    #[test]
+    fn pdm_lock_normalizes_package_names() {
+        let lock_file = parse_lock_file(
+            LockFileKind::Pdm,
+            r#"
+[metadata]
+groups = ["default"]
+
+[[package]]
+name = "typing_extensions"
+version = "4.12.2"
+groups = ["default"]
+"#,
+        );
+
+        assert_eq!(check(&lock_file, "typing-extensions>=4.10"), None);
+        assert_eq!(
+            LockFileProblem::Missing {
+                package_name: "httpx".to_string(),
+                lock_file_name: lock_file.kind.file_name(),
+            }
+            .to_string(),
+            "`httpx` is missing from pdm.lock"
+        );
+    }
+
+    #[test]
+    fn uv_lock_accepts_any_locked_version() {
+        let lock_file = parse_lock_file(
+            LockFileKind::Uv,
+            r#"
+version = 1
+
+[[package]]
+name = "numpy"
+version = "1.26.4"
+
+[[package]]
+name = "numpy"
+version = "2.1.0"
+"#,
+        );
+
+        assert_eq!(check(&lock_file, "numpy>=2"), None);
+        assert_eq!(
+            check(&lock_file, "numpy>=3").map(|problem| problem.to_string()),
+            Some(
+                "`numpy` requirement \">=3\" is not satisfied by the locked versions 1.26.4, 2.1.0 in uv.lock"
+                    .to_string()
+            )
+        );
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn ancestor_uv_lock_must_list_the_project() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let member_dir = temp_dir.path().join("packages/member");
+        let other_dir = temp_dir.path().join("examples/other");
+        std::fs::create_dir_all(&member_dir).unwrap();
+        std::fs::create_dir_all(&other_dir).unwrap();
+        std::fs::write(
+            temp_dir.path().join("uv.lock"),
+            r#"
+version = 1
+
+[[package]]
+name = "member"
+version = "0.1.0"
+"#,
+        )
+        .unwrap();
+
+        let member_name = PackageName::from_str("member").unwrap();
+        let other_name = PackageName::from_str("other").unwrap();
+
+        let lock_file = load_cached_lock_file(
+            &member_dir.join("pyproject.toml"),
+            Some(&member_name),
+            TomlVersion::default(),
+        )
+        .await;
+        assert_eq!(
+            lock_file.map(|lock_file| lock_file.kind),
+            Some(LockFileKind::Uv)
+        );
+
+        let lock_file = load_cached_lock_file(
+            &other_dir.join("pyproject.toml"),
+            Some(&other_name),
+            TomlVersion::default(),
+        )
+        .await;
+        assert!(lock_file.is_none());
+    }
+}
@@ -0,0 +1,562 @@
+use std::str::FromStr;
+
+use pep508_rs::pep440_rs::{Version, VersionSpecifiers};
+use tombi_document_tree::{TableKind, Value, dig_keys};
+use tombi_extension::{CompletionContent, CompletionHint, CompletionKind, CompletionTextEdit};
+use tombi_schema_store::{Accessor, matches_accessors};
+use tower_lsp::lsp_types::InsertTextFormat;
+
+use crate::parse_requirement;
+
+/// Keys of a Poetry dependency inline table that have a PEP 508 equivalent.
+const PEP508_COMPATIBLE_KEYS: &[&str] = &["version", "extras", "markers", "python"];
+
+/// Where the dependencies of a Poetry dependency table belong in PEP 621 / PEP 735.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PoetryDependencyGroup<'a> {
+    /// `[tool.poetry.dependencies]` → `[project] dependencies`
+    Main,
+    /// `[tool.poetry.dev-dependencies]` → `[dependency-groups] dev`
+    Dev,
+    /// `[tool.poetry.group.<name>.dependencies]` → `[dependency-groups] <name>`
+    Group(&'a str),
+}
+
+/// Returns the accessors of a Poetry dependency table, if `accessors` point inside one.
+pub(crate) fn get_poetry_dependency_table_accessors(accessors: &[Accessor]) -> Option<&[Accessor]> {
+    if matches_accessors!(
+        accessors[..accessors.len().min(3)],
+        ["tool", "poetry", "dependencies"]
+    ) || matches_accessors!(
+        accessors[..accessors.len().min(3)],
+        ["tool", "poetry", "dev-dependencies"]
+    ) {
+        Some(&accessors[..3])
+    } else if matches_accessors!(
+        accessors[..accessors.len().min(5)],
+        ["tool", "poetry", "group", _, "dependencies"]
+    ) {
+        Some(&accessors[..5])
+    } else {
+        None
+    }
+}
+
+/// Returns the accessors up to the package name key of a Poetry dependency.
+///
+/// ```toml
+/// [tool.poetry.dependencies]
+/// requests = { version = "^2.31", extras = ["socks"] }
+/// ^^^^^^^^
+/// ```
+pub(crate) fn get_poetry_dependency_accessors(accessors: &[Accessor]) -> Option<&[Accessor]> {
+    let table_accessors = get_poetry_dependency_table_accessors(accessors)?;
+    let dependency_accessors = accessors.get(..table_accessors.len() + 1)?;
+
+    match dependency_accessors.last() {
+        Some(Accessor::Key(package_name))
+            if !(table_accessors.len() == 3 && package_name == "python") =>
+        {
+            Some(dependency_accessors)
+        }
+        _ => None,
+    }
+}
+
+#[inline]
+pub(crate) fn is_poetry_dependency_path_accessors(accessors: &[Accessor]) -> bool {
+    get_poetry_dependency_accessors(accessors).is_some_and(|dependency_accessors| {
+        accessors.len() == dependency_accessors.len() + 1
+            && matches!(accessors.last(), Some(Accessor::Key(key)) if key == "path")
+    })
+}
+
+#[inline]
+pub(crate) fn is_poetry_dependency_version_accessors(accessors: &[Accessor]) -> bool {
+    get_poetry_dependency_accessors(accessors).is_some_and(|dependency_accessors| {
+        accessors.len() == dependency_accessors.len()
+            || (accessors.len() == dependency_accessors.len() + 1
+                && matches!(accessors.last(), Some(Accessor::Key(key)) if key == "version"))
+    })
+}
+
+pub(crate) fn poetry_dependency_group<'a>(
+    table_accessors: &'a [Accessor],
+) -> Option<PoetryDependencyGroup<'a>> {
+    match table_accessors {
+        [_, _, Accessor::Key(key)] if key == "dependencies" => Some(PoetryDependencyGroup::Main),
+        [_, _, Accessor::Key(key)] if key == "dev-dependencies" => Some(PoetryDependencyGroup::Dev),
+        [_, _, _, Accessor::Key(group_name), _] => Some(PoetryDependencyGroup::Group(group_name)),
+        _ => None,
+    }
+}
+
+/// Returns the version constraint string of a Poetry dependency value.
+pub(crate) fn poetry_dependency_version(value: &Value) -> Option<&tombi_document_tree::String> {
+    match value {
+        Value::String(version) => Some(version),
+        Value::Table(table) => match table.get("version") {
+            Some(Value::String(version)) => Some(version),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns the local path of a Poetry path dependency.
+pub(crate) fn poetry_dependency_path(value: &Value) -> Option<&tombi_document_tree::String> {
+    match value {
+        Value::Table(table) => match table.get("path") {
+            Some(Value::String(path)) => Some(path),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Whether a Poetry dependency is resolved from a package index rather than a local or VCS source.
+pub(crate) fn is_poetry_registry_dependency(value: &Value) -> bool {
+    match value {
+        Value::String(_) => true,
+        Value::Table(table) => !["path", "git", "url", "file"]
+            .iter()
+            .any(|key| table.contains_key(key)),
+        _ => false,
+    }
+}
+
+/// Converts a Poetry version constraint to PEP 440 version specifiers.
+///
+/// Returns an empty string for `*`, and `None` for constraints that have no
+/// PEP 440 equivalent, such as `^1.0 || ^2.0`.
+///
+/// ```text
+/// ^1.2.3  -> >=1.2.3,<2.0.0
+/// ~1.2    -> >=1.2,<1.3
+/// 1.2.*   -> ==1.2.*
+/// 1.2.3   -> ==1.2.3
+/// ```
+pub(crate) fn poetry_constraint_to_pep440(constraint: &str) -> Option<String> {
+    let constraint = constraint.trim();
+    if constraint.contains("||") || constraint.contains('|') {
+        return None;
+    }
+
+    let mut specifiers = Vec::new();
+    for clause in split_poetry_constraint(constraint) {
+        if let Some(version) = clause.strip_prefix('^') {
+            specifiers.extend(caret_specifiers(version.trim())?);
+        } else if clause.starts_with("~=") {
+            specifiers.push(clause);
+        } else if let Some(version) = clause.strip_prefix('~') {
+            specifiers.extend(tilde_specifiers(version.trim())?);
+        } else if clause.starts_with(['<', '>', '!']) || clause.starts_with("==") {
+            specifiers.push(clause);
+        } else if let Some(version) = clause.strip_prefix('=') {
+            specifiers.push(format!("=={}", version.trim()));
+        } else if clause == "*" {
+            continue;
+        } else {
+            specifiers.push(format!("=={clause}"));
+        }
+    }
+
+    let specifiers = specifiers.join(",");
+    if !specifiers.is_empty() && VersionSpecifiers::from_str(&specifiers).is_err() {
+        return None;
+    }
+
+    Some(specifiers)
+}
+
+/// Splits `">= 1.2, < 2"` and `">=1.2 <2"` into `[">=1.2", "<2"]`.
+fn split_poetry_constraint(constraint: &str) -> Vec<String> {
+    let mut clauses = Vec::new();
+    let mut pending_operator = String::new();
+
+    for token in constraint
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|token| !token.is_empty())
+    {
+        if token.chars().all(|c| "<>=!~^".contains(c)) {
+            pending_operator.push_str(token);
+        } else {
+            clauses.push(format!("{pending_operator}{token}"));
+            pending_operator.clear();
+        }
+    }
+
+    clauses
+}
+
+fn caret_specifiers(version: &str) -> Option<[String; 2]> {
+    let release = parse_release(version)?;
+    let bump_index = release
+        .iter()
+        .position(|part| *part != 0)
+        .unwrap_or(release.len() - 1);
+
+    Some([
+        format!(">={version}"),
+        format!("<{}", bump_release(&release, bump_index)),
+    ])
+}
+
+fn tilde_specifiers(version: &str) -> Option<[String; 2]> {
+    let release = parse_release(version)?;
+    let bump_index = if release.len() == 1 { 0 } else { 1 };
+
+    Some([
+        format!(">={version}"),
+        format!("<{}", bump_release(&release, bump_index)),
+    ])
+}
+
+fn parse_release(version: &str) -> Option<Vec<u64>> {
+    Version::from_str(version)
+        .ok()
+        .map(|version| version.release().to_vec())
+        .filter(|release| !release.is_empty())
+}
+
+fn bump_release(release: &[u64], bump_index: usize) -> String {
+    release
+        .iter()
+        .enumerate()
+        .map(|(index, part)| match index.cmp(&bump_index) {
+            std::cmp::Ordering::Less => part.to_string(),
+            std::cmp::Ordering::Equal => (part + 1).to_string(),
+            std::cmp::Ordering::Greater => "0".to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Converts a Poetry `python` constraint to a PEP 508 environment marker.
+fn poetry_python_to_marker(constraint: &str) -> Option<String> {
+    let specifiers = poetry_constraint_to_pep440(constraint)?;
+    if specifiers.is_empty() {
+        return Some(String::new());
+    }
+
+    Some(
+        specifiers
+            .split(',')
+            .map(|specifier| {
+                let version_start = specifier
+                    .find(|c: char| c.is_ascii_digit())
+                    .unwrap_or(specifier.len());
+                let (operator, version) = specifier.split_at(version_start);
+                format!("python_version {operator} '{version}'")
+            })
+            .collect::<Vec<_>>()
+            .join(" and "),
+    )
+}
+
+/// Converts a Poetry dependency to a PEP 508 requirement string.
+///
+/// Returns `None` for dependencies that PEP 508 cannot express without
+/// tool-specific sources, such as `path`, `git` or `optional` dependencies.
+pub(crate) fn poetry_dependency_to_pep508(package_name: &str, value: &Value) -> Option<String> {
+    let (constraint, extras, markers, python) = match value {
+        Value::String(constraint) => (constraint.value(), Vec::new(), None, None),
+        Value::Table(table) if matches!(table.kind(), TableKind::InlineTable { .. }) => {
+            if table
+                .keys()
+                .any(|key| !PEP508_COMPATIBLE_KEYS.contains(&key.value.as_str()))
+            {
+                return None;
+            }
+            let constraint = match table.get("version") {
+                Some(Value::String(version)) => version.value(),
+                None => "*",
+                Some(_) => return None,
+            };
+            let extras = match table.get("extras") {
+                Some(Value::Array(extras)) => extras
+                    .values()
+                    .iter()
+                    .map(|extra| match extra {
+                        Value::String(extra) => Some(extra.value()),
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<_>>>()?,
+                None => Vec::new(),
+                Some(_) => return None,
+            };
+            let markers = match table.get("markers") {
+                Some(Value::String(markers)) => Some(markers.value()),
+                None => None,
+                Some(_) => return None,
+            };
+            let python = match table.get("python") {
+                Some(Value::String(python)) => Some(python.value()),
+                None => None,
+                Some(_) => return None,
+            };
+            (constraint, extras, markers, python)
+        }
+        _ => return None,
+    };
+
+    let mut requirement = package_name.to_string();
+    if !extras.is_empty() {
+        requirement.push_str(&format!("[{}]", extras.join(",")));
+    }
+    requirement.push_str(&poetry_constraint_to_pep440(constraint)?);
+
+    let python_marker = match python {
+        Some(python) => Some(poetry_python_to_marker(python)?).filter(|marker| !marker.is_empty()),
+        None => None,
+    };
+    let marker = match (python_marker, markers) {
+        (Some(python_marker), Some(markers)) => Some(format!("{python_marker} and ({markers})")),
+        (Some(marker), None) => Some(marker),
+        (None, Some(markers)) => Some(markers.to_string()),
+        (None, None) => None,
+    };
+    if let Some(marker) = marker {
+        requirement.push_str(&format!("; {marker}"));
+    }
+
+    parse_requirement(&requirement).map(|_| requirement)
+}
+
+/// A Poetry dependency table and the PEP 621 / PEP 735 group it maps to.
+pub(crate) struct PoetryDependencyTable<'a> {
+    pub(crate) group: PoetryDependencyGroup<'a>,
+    pub(crate) table: &'a tombi_document_tree::Table,
+}
+
+pub(crate) fn collect_poetry_dependency_tables(
+    document_tree: &tombi_document_tree::DocumentTree,
+) -> Vec<PoetryDependencyTable<'_>> {
+    let mut tables = Vec::new();
+
+    if let Some((_, Value::Table(table))) =
+        dig_keys(document_tree, &["tool", "poetry", "dependencies"])
+    {
+        tables.push(PoetryDependencyTable {
+            group: PoetryDependencyGroup::Main,
+            table,
+        });
+    }
+    if let Some((_, Value::Table(table))) =
+        dig_keys(document_tree, &["tool", "poetry", "dev-dependencies"])
+    {
+        tables.push(PoetryDependencyTable {
+            group: PoetryDependencyGroup::Dev,
+            table,
+        });
+    }
+    if let Some((_, Value::Table(groups))) = dig_keys(document_tree, &["tool", "poetry", "group"]) {
+        for (group_name, group) in groups.key_values() {
+            if let Value::Table(group) = group
+                && let Some(Value::Table(table)) = group.get("dependencies")
+            {
+                tables.push(PoetryDependencyTable {
+                    group: PoetryDependencyGroup::Group(group_name.value.as_str()),
+                    table,
+                });
+            }
+        }
+    }
+
+    tables
+}
+
+/// Completes Poetry version constraints with `^<version>` for each published version.
+pub(crate) fn completion_poetry_dependency_version(
+    versions: &[String],
+    version_value: &Value,
+    position: tombi_text::Position,
+    completion_hint: Option<CompletionHint>,
+) -> Option<Vec<CompletionContent>> {
+    if !matches!(version_value, Value::String(_) | Value::Incomplete { .. }) {
+        return None;
+    }
+
+    Some(
+        versions
+            .iter()
+            .take(100)
+            .enumerate()
+            .map(|(i, version)| {
+                let label = format!("\"^{version}\"");
+                CompletionContent {
+                    edit: match version_value {
+                        Value::String(value_string) => {
+                            tombi_extension::CompletionEdit::new_string_literal_while_editing(
+                                &label,
+                                value_string.range(),
+                            )
+                        }
+                        Value::Incomplete { .. } => Some(tombi_extension::CompletionEdit {
+                            text_edit: CompletionTextEdit::Edit(tombi_extension::TextEdit {
+                                range: tombi_text::Range::at(position),
+                                new_text: format!(" = {label}"),
+                            }),
+                            insert_text_format: Some(InsertTextFormat::PLAIN_TEXT),
+                            additional_text_edits: match completion_hint {
+                                Some(
+                                    CompletionHint::DotTrigger { range, .. }
+                                    | CompletionHint::EqualTrigger { range, .. },
+                                ) => Some(vec![tombi_extension::TextEdit {
+                                    range,
+                                    new_text: "".to_string(),
+                                }]),
+                                _ => None,
+                            },
+                        }),
+                        _ => None,
+                    },
+                    label,
+                    kind: CompletionKind::Enum,
+                    emoji_icon: Some('🐍'),
+                    priority: tombi_extension::CompletionContentPriority::Custom(format!(
+                        "10__pyproject_version_{i:>03}__",
+                    )),
+                    detail: Some("Package version".to_string()),
+                    documentation: None,
+                    filter_text: None,
+                    schema_uri: None,
+                    deprecated: None,
+                    preselect: (i == 0).then_some(true),
+                    in_comment: false,
+                }
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use tombi_ast::AstNode;
+    use tombi_config::TomlVersion;
+    use tombi_document_tree::TryIntoDocumentTree;
+
+    use super::*;
+
+    fn parse_document_tree(source: &str) -> tombi_document_tree::DocumentTree {
+        let root = tombi_ast::Root::cast(tombi_parser::parse(source).into_syntax_node()).unwrap();
+        root.try_into_document_tree(TomlVersion::default()).unwrap()
+    }
+
+    fn keys(keys: &[&str]) -> Vec<Accessor> {
+        keys.iter()
+            .map(|key| Accessor::Key(key.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn converts_poetry_constraints_to_pep440() {
+        for (constraint, expected) in [
+            ("^1.2.3", Some(">=1.2.3,<2.0.0")),
+            ("^1.2", Some(">=1.2,<2.0")),
+            ("^0.2.3", Some(">=0.2.3,<0.3.0")),
+            ("^0.0.3", Some(">=0.0.3,<0.0.4")),
+            ("^0.0", Some(">=0.0,<0.1")),
+            ("~1.2.3", Some(">=1.2.3,<1.3.0")),
+            ("~1", Some(">=1,<2")),
+            ("~=1.4", Some("~=1.4")),
+            ("1.2.*", Some("==1.2.*")),
+            ("1.2.3", Some("==1.2.3")),
+            ("=1.2.3", Some("==1.2.3")),
+            (">= 1.2, < 2.0", Some(">=1.2,<2.0")),
+            (">=1.2 <2.0", Some(">=1.2,<2.0")),
+            ("*", Some("")),
+            ("^1.0 || ^2.0", None),
+        ] {
+            assert_eq!(
+                poetry_constraint_to_pep440(constraint).as_deref(),
+                expected,
+                "{constraint}"
+            );
+        }
+    }
+
+    #[test]
+    fn converts_poetry_dependencies_to_pep508() {
+        let document_tree = parse_document_tree(
+            r#"
+            [tool.poetry.dependencies]
+            python = "^3.10"
+            requests = { version = "^2.31", extras = ["socks"] }
+            tomli = { version = ">=2", python = "<3.11" }
+            pywin32 = { version = "*", markers = "sys_platform == 'win32'" }
+            black = "*"
+            local = { path = "../local", develop = true }
+            plugin = { version = "^1.0", optional = true }
+            "#,
+        );
+        let Some((_, Value::Table(table))) =
+            dig_keys(&document_tree, &["tool", "poetry", "dependencies"])
+        else {
+            panic!("expected dependency table");
+        };
+
+        let converted = table
+            .key_values()
+            .iter()
+            .skip(1)
+            .map(|(key, value)| poetry_dependency_to_pep508(&key.value, value))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            converted,
+            vec![
+                Some("requests[socks]>=2.31,<3.0".to_string()),
+                Some("tomli>=2; python_version < '3.11'".to_string()),
+                Some("pywin32; sys_platform == 'win32'".to_string()),
+                Some("black".to_string()),
+                None,
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn recognizes_poetry_dependency_accessors() {
+        let main = keys(&["tool", "poetry", "dependencies", "requests", "version"]);
+        let group = keys(&["tool", "poetry", "group", "dev", "dependencies", "pytest"]);
+        let python = keys(&["tool", "poetry", "dependencies", "python"]);
+
+        assert_eq!(get_poetry_dependency_accessors(&main), Some(&main[..4]));
+        assert_eq!(get_poetry_dependency_accessors(&group), Some(&group[..]));
+        assert_eq!(get_poetry_dependency_accessors(&python), None);
+        assert!(is_poetry_dependency_version_accessors(&main));
+        assert_eq!(
+            poetry_dependency_group(get_poetry_dependency_table_accessors(&group).unwrap()),
+            Some(PoetryDependencyGroup::Group("dev"))
+        );
+    }
+
+    #[test]
+    fn collects_poetry_dependency_tables() {
+        let document_tree = parse_document_tree(
+            r#"
+            [tool.poetry.dependencies]
+            requests = "^2.31"
+
+            [tool.poetry.dev-dependencies]
+            black = "^24.0"
+
+            [tool.poetry.group.test.dependencies]
+            pytest = "^8.0"
+            "#,
+        );
+
+        assert_eq!(
+            collect_poetry_dependency_tables(&document_tree)
+                .into_iter()
+                .map(|table| table.group)
+                .collect::<Vec<_>>(),
+            vec![
+                PoetryDependencyGroup::Main,
+                PoetryDependencyGroup::Dev,
+                PoetryDependencyGroup::Group("test"),
+            ]
+        );
+    }
+}
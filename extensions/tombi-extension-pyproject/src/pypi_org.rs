@@ -1,9 +1,15 @@
+use std::str::FromStr;
+
+use pep508_rs::pep440_rs::Version;
 use serde::Deserialize;
 use tombi_extension::fetch_cached_remote_json;
+use tombi_hashmap::HashMap;
 
 #[derive(Debug, Deserialize)]
 pub(crate) struct PypiProjectResponse {
     pub(crate) info: PypiProjectInfo,
+    #[serde(default)]
+    pub(crate) releases: HashMap<String, Vec<PypiReleaseFile>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -13,6 +19,31 @@ pub(crate) struct PypiProjectInfo {
     pub(crate) version: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+pub(crate) struct PypiReleaseFile {
+    #[serde(default)]
+    pub(crate) yanked: bool,
+}
+
+impl PypiProjectResponse {
+    /// Released versions, newest first. Releases whose files are all yanked are skipped.
+    pub(crate) fn versions(&self) -> Vec<String> {
+        let mut versions = self
+            .releases
+            .iter()
+            .filter(|(_, files)| files.is_empty() || files.iter().any(|file| !file.yanked))
+            .filter_map(|(version, _)| {
+                Version::from_str(version)
+                    .ok()
+                    .map(|parsed| (parsed, version.to_owned()))
+            })
+            .collect::<Vec<_>>();
+        versions.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+        versions.into_iter().map(|(_, version)| version).collect()
+    }
+}
+
 pub(crate) async fn fetch_pypi_project(
     package_name: &str,
     offline: bool,
@@ -21,3 +52,54 @@ pub(crate) async fn fetch_pypi_project(
     let url = format!("https://pypi.org/pypi/{package_name}/json");
     Ok(fetch_cached_remote_json::<PypiProjectResponse>(&url, offline, cache_options).await)
 }
+
+/// Package names whose PyPI metadata is already in the Tombi cache.
+///
+/// PyPI has no search API, so name completion is limited to packages
+/// that have been looked up before.
+pub(crate) async fn cached_pypi_package_names() -> Vec<String> {
+    let Some(cache_dir_path) = tombi_cache::get_tombi_cache_dir_path().await else {
+        return Vec::new();
+    };
+    let Ok(entries) = tombi_fs::read_dir(&cache_dir_path.join("https/pypi.org/pypi")) else {
+        return Vec::new();
+    };
+
+    let mut package_names = entries
+        .into_iter()
+        .filter(|entry| entry.is_dir())
+        .filter_map(|entry| {
+            entry
+                .path()
+                .file_name()
+                .and_then(|file_name| file_name.to_str())
+                .map(ToString::to_string)
+        })
+        .collect::<Vec<_>>();
+    package_names.sort();
+
+    package_names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versions_are_sorted_newest_first_without_yanked_releases() {
+        let response: PypiProjectResponse = serde_json::from_str(
+            r#"{
+                "info": {"name": "requests", "summary": null, "version": "2.32.3"},
+                "releases": {
+                    "2.9.0": [{"yanked": false}],
+                    "2.32.3": [{"yanked": false}],
+                    "2.32.0": [{"yanked": true}],
+                    "2.10.0": []
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(response.versions(), vec!["2.32.3", "2.10.0", "2.9.0"]);
+    }
+}
@@ -24,10 +24,13 @@ pub fn from_str(
     let root = tombi_ast::Root::cast(parsed.syntax_node()).expect("AST Root must be present");
     // Check if there are any parsing errors
     if !parsed.errors.is_empty() {
-        return Err(parsed.errors.into());
+        return Err(crate::de::Error::from(parsed.errors).with_source_path(Some(config_path)));
     }
 
-    deserializer.from_document(deserializer.try_to_document(root, TOMBI_CONFIG_TOML_VERSION)?)
+    deserializer.deserialize_document_tree(
+        deserializer.try_to_document_tree(root, TOMBI_CONFIG_TOML_VERSION)?,
+        TOMBI_CONFIG_TOML_VERSION,
+    )
 }
 
 #[doc(hidden)]
@@ -46,10 +49,13 @@ impl PyProjectToml {
         let root = tombi_ast::Root::cast(parsed.syntax_node()).expect("AST Root must be present");
         // Check if there are any parsing errors
         if !parsed.errors.is_empty() {
-            return Err(parsed.errors.into());
+            return Err(crate::de::Error::from(parsed.errors).with_source_path(Some(config_path)));
         }
 
-        deserializer.from_document(deserializer.try_to_document(root, TomlVersion::V1_0_0)?)
+        deserializer.deserialize_document_tree(
+            deserializer.try_to_document_tree(root, TomlVersion::V1_0_0)?,
+            TomlVersion::V1_0_0,
+        )
     }
 }
 
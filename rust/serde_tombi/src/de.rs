@@ -1,8 +1,11 @@
 mod error;
+mod spanned;
+mod value;
 
 pub use error::Error;
 use itertools::Either;
 use serde::de::DeserializeOwned;
+pub use spanned::Spanned;
 use tombi_ast::AstNode;
 use tombi_document::IntoDocument;
 use tombi_document_tree::IntoDocumentTreeAndErrors;
@@ -48,6 +51,100 @@ where
     Deserializer::new().from_str_async(toml_text).await
 }
 
+/// Deserialize TOML read from `reader` into a Rust data structure.
+///
+/// The source is parsed one table at a time, so neither the source text nor the lossless syntax tree
+/// of the whole document is held in memory. This is useful for very large files.
+///
+/// The values of the whole document are still merged into one document tree,
+/// which is validated and deserialized at once, so memory stays proportional to the values.
+///
+/// # Examples
+///
+/// ```
+/// use serde::Deserialize;
+/// use tokio;
+///
+/// #[derive(Deserialize)]
+/// struct Lock {
+///     package: Vec<Package>,
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct Package {
+///     name: String,
+///     version: String,
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let toml = r#"
+///     [[package]]
+///     name = "serde"
+///     version = "1.0.0"
+///     "#;
+///
+///     let lock: Lock = serde_tombi::from_reader_async(toml.as_bytes()).await.unwrap();
+/// }
+/// ```
+pub async fn from_reader_async<T, R>(reader: R) -> Result<T, crate::de::Error>
+where
+    T: DeserializeOwned,
+    R: std::io::BufRead,
+{
+    Deserializer::new().from_reader_async(reader).await
+}
+
+/// Deserialize a TOML string into a Rust data structure
+/// after validating it against the JSON Schema of `schema_uri`.
+///
+/// The schema can be a local file or a remote schema, such as one listed in a catalog.
+/// If the document does not match the schema, the error has all the validation diagnostics,
+/// which [`Error::to_diagnostics`] returns.
+///
+/// # Examples
+///
+/// ```no_run
+/// use serde::Deserialize;
+/// use serde_tombi::SchemaUri;
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     port: u16,
+/// }
+///
+/// let schema_uri = SchemaUri::from_file_path("schemas/config.schema.json").unwrap();
+/// let config: Config = serde_tombi::from_str_with_schema("port = 8080", &schema_uri).unwrap();
+/// ```
+#[cfg(not(feature = "wasm"))]
+pub fn from_str_with_schema<T>(
+    toml_text: &str,
+    schema_uri: &tombi_schema_store::SchemaUri,
+) -> Result<T, crate::de::Error>
+where
+    T: DeserializeOwned,
+{
+    tokio::runtime::Runtime::new()?.block_on(from_str_with_schema_async(toml_text, schema_uri))
+}
+
+/// Deserialize a TOML string into a Rust data structure
+/// after validating it against the JSON Schema of `schema_uri`.
+///
+/// See [`from_str_with_schema`] for details.
+pub async fn from_str_with_schema_async<T>(
+    toml_text: &str,
+    schema_uri: &tombi_schema_store::SchemaUri,
+) -> Result<T, crate::de::Error>
+where
+    T: DeserializeOwned,
+{
+    Deserializer::builder()
+        .schema_uri(schema_uri)
+        .build()
+        .from_str_async(toml_text)
+        .await
+}
+
 pub fn from_document<T>(document: tombi_document::Document) -> Result<T, crate::de::Error>
 where
     T: DeserializeOwned,
@@ -55,6 +152,24 @@ where
     Deserializer::new().from_document(document)
 }
 
+/// Deserialize a value of a document tree, keeping track of its ranges.
+///
+/// Only the ranges are kept aside, and the tree itself is converted into the document value.
+pub(crate) fn deserialize_value<T>(
+    value: tombi_document_tree::Value,
+    toml_version: TomlVersion,
+) -> Result<T, crate::de::Error>
+where
+    T: DeserializeOwned,
+{
+    let ranges = value::Ranges::new(&value);
+    let document_value = value.into_document(toml_version);
+    Ok(T::deserialize(value::ValueDeserializer::new(
+        &document_value,
+        &ranges,
+    ))?)
+}
+
 // Actual deserializer implementation
 #[derive(TypedBuilder)]
 pub struct Deserializer<'de> {
@@ -69,6 +184,10 @@ pub struct Deserializer<'de> {
 
     #[builder(default, setter(into, strip_option))]
     schema_store: Option<&'de tombi_schema_store::SchemaStore>,
+
+    /// Validate the document against this schema before deserializing it.
+    #[builder(default, setter(into, strip_option))]
+    schema_uri: Option<&'de tombi_schema_store::SchemaUri>,
 }
 
 impl Default for Deserializer<'_> {
@@ -84,6 +203,7 @@ impl Deserializer<'_> {
             config_path: None,
             source_path: None,
             schema_store: None,
+            schema_uri: None,
         }
     }
 
@@ -94,12 +214,47 @@ impl Deserializer<'_> {
         let parsed = tombi_parser::parse(toml_text);
         // Check if there are any parsing errors
         if !parsed.errors.is_empty() {
-            return Err(parsed.errors.into());
+            return Err(self.with_source_path(parsed.errors.into()));
         }
 
         let root = tombi_ast::Root::cast(parsed.syntax_node()).expect("AST Root must be present");
         let toml_version = self.get_toml_version(&root).await?;
-        from_document(self.try_to_document(root, toml_version)?)
+        let document_tree = self.try_to_document_tree(root, toml_version)?;
+        self.validate(&document_tree, toml_version).await?;
+        self.deserialize_document_tree(document_tree, toml_version)
+    }
+
+    pub async fn from_reader_async<T, R>(&self, reader: R) -> Result<T, crate::de::Error>
+    where
+        T: DeserializeOwned,
+        R: std::io::BufRead,
+    {
+        let mut document_tree: Option<tombi_document_tree::DocumentTree> = None;
+        let mut toml_version = TomlVersion::default();
+
+        for chunk in tombi_parser::parse_stream(reader) {
+            let (root, errors) = chunk?.into_root_and_errors();
+            if !errors.is_empty() {
+                return Err(self.with_source_path(errors.into()));
+            }
+
+            // The document comment directives are in the first chunk.
+            if document_tree.is_none() {
+                toml_version = self.get_toml_version(&root).await?;
+            }
+
+            let tree = self.try_to_document_tree(root, toml_version)?;
+            match document_tree.as_mut() {
+                Some(document_tree) => document_tree
+                    .merge(tree)
+                    .map_err(|errors| self.with_source_path(errors.into()))?,
+                None => document_tree = Some(tree),
+            }
+        }
+
+        let document_tree = document_tree.expect("the first chunk must be present");
+        self.validate(&document_tree, toml_version).await?;
+        self.deserialize_document_tree(document_tree, toml_version)
     }
 
     pub fn from_document<T>(
@@ -112,6 +267,20 @@ impl Deserializer<'_> {
         Ok(T::deserialize(&document)?)
     }
 
+    /// Deserialize while following the document tree, so that errors and [`crate::Spanned`]
+    /// values know their ranges in the source.
+    pub(crate) fn deserialize_document_tree<T>(
+        &self,
+        document_tree: tombi_document_tree::DocumentTree,
+        toml_version: TomlVersion,
+    ) -> Result<T, crate::de::Error>
+    where
+        T: DeserializeOwned,
+    {
+        deserialize_value(document_tree.into(), toml_version)
+            .map_err(|error| self.with_source_path(error))
+    }
+
     async fn get_toml_version(
         &self,
         root: &tombi_ast::Root,
@@ -178,21 +347,82 @@ impl Deserializer<'_> {
         Ok(toml_version)
     }
 
-    pub(crate) fn try_to_document(
+    /// Validate the document tree against the schema of `schema_uri`, if it is set.
+    ///
+    /// Warnings alone do not fail the validation.
+    async fn validate(
+        &self,
+        document_tree: &tombi_document_tree::DocumentTree,
+        toml_version: TomlVersion,
+    ) -> Result<(), crate::de::Error> {
+        let Some(schema_uri) = self.schema_uri else {
+            return Ok(());
+        };
+
+        let schema_store = match self.schema_store {
+            Some(schema_store) => schema_store,
+            None => &SchemaStore::new(),
+        };
+        let Some(root_schema) = schema_store.try_get_document_schema(schema_uri).await? else {
+            return Err(tombi_schema_store::Error::SchemaResourceNotFound {
+                schema_uri: schema_uri.clone(),
+            }
+            .into());
+        };
+        let source_schema = tombi_schema_store::SourceSchema::new(
+            Some(root_schema),
+            Default::default(),
+            None,
+            None,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        );
+
+        let schema_context = tombi_schema_store::SchemaContext {
+            toml_version,
+            root_schema: source_schema.root_schema.as_deref(),
+            sub_schema_link_map: Some(&source_schema.sub_schema_link_map),
+            deprecated_lint_level: source_schema.deprecated_lint_level,
+            schema_format_rules: Some(&source_schema.schema_format_rules),
+            schema_lint_rules: Some(&source_schema.schema_lint_rules),
+            schema_overrides: Some(&source_schema.schema_overrides),
+            schema_visits: Default::default(),
+            store: schema_store,
+            strict: None,
+            table_style: None,
+            validation_cache: None,
+            incomplete_as_null: false,
+            partial_root: false,
+        };
+
+        match tombi_validator::validate(document_tree, Some(&source_schema), &schema_context).await
+        {
+            Err(diagnostics) if diagnostics.iter().any(|diagnostic| diagnostic.is_error()) => {
+                Err(self.with_source_path(diagnostics.into()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn try_to_document_tree(
         &self,
         root: tombi_ast::Root,
         toml_version: TomlVersion,
-    ) -> Result<tombi_document::Document, crate::de::Error> {
+    ) -> Result<tombi_document_tree::DocumentTree, crate::de::Error> {
         // Convert the AST to a document tree
         let (document_tree, errors) = root.into_document_tree_and_errors(toml_version).into();
 
         // Check for errors during document tree construction
         if !errors.is_empty() {
-            return Err(errors.into());
+            return Err(self.with_source_path(errors.into()));
         }
 
-        // Convert to a Document
-        Ok(document_tree.into_document(toml_version))
+        Ok(document_tree)
+    }
+
+    fn with_source_path(&self, error: crate::de::Error) -> crate::de::Error {
+        error.with_source_path(self.source_path.or(self.config_path))
     }
 }
 
@@ -201,6 +431,7 @@ mod tests {
     use super::*;
     use chrono::{DateTime, TimeZone, Utc};
     use serde::Deserialize;
+    use tombi_schema_store::SchemaUri;
     use tombi_test_lib::project_root_path;
 
     #[tokio::test]
@@ -269,6 +500,382 @@ value = "nested value"
         pretty_assertions::assert_eq!(result, expected);
     }
 
+    #[tokio::test]
+    async fn test_deserialize_from_reader() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Package {
+            name: String,
+            dependencies: Option<Vec<String>>,
+        }
+
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Test {
+            version: i32,
+            package: Vec<Package>,
+            metadata: tombi_hashmap::IndexMap<String, String>,
+        }
+
+        let toml = r#"
+version = 4
+
+[[package]]
+name = "a"
+dependencies = [
+  "b",
+]
+
+[[package]]
+name = "b"
+
+[metadata]
+key = "value"
+"#;
+
+        let expected = Test {
+            version: 4,
+            package: vec![
+                Package {
+                    name: "a".to_string(),
+                    dependencies: Some(vec!["b".to_string()]),
+                },
+                Package {
+                    name: "b".to_string(),
+                    dependencies: None,
+                },
+            ],
+            metadata: tombi_hashmap::indexmap! {
+                "key".to_string() => "value".to_string(),
+            },
+        };
+
+        let schema_store = SchemaStore::new();
+        let result: Test = Deserializer::builder()
+            .schema_store(&schema_store)
+            .build()
+            .from_reader_async(toml.as_bytes())
+            .await
+            .expect("TOML deserialization failed");
+        pretty_assertions::assert_eq!(result, expected);
+    }
+
+    #[tokio::test]
+    async fn test_deserialize_from_reader_with_conflicting_tables() {
+        let toml = r#"
+[table]
+key = 1
+
+[table]
+key = 2
+"#;
+
+        let schema_store = SchemaStore::new();
+        let result: Result<
+            tombi_hashmap::IndexMap<String, tombi_hashmap::IndexMap<String, i32>>,
+            _,
+        > = Deserializer::builder()
+            .schema_store(&schema_store)
+            .build()
+            .from_reader_async(toml.as_bytes())
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_deserialize_from_reader_with_sub_tables_of_array_of_tables() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Info {
+            color: String,
+        }
+
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Fruit {
+            name: String,
+            info: Info,
+        }
+
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Test {
+            fruit: Vec<Fruit>,
+        }
+
+        let toml = r#"
+[[fruit]]
+name = "apple"
+
+[fruit.info]
+color = "red"
+
+[[fruit]]
+name = "banana"
+
+[fruit.info]
+color = "yellow"
+"#;
+
+        let expected = Test {
+            fruit: vec![
+                Fruit {
+                    name: "apple".to_string(),
+                    info: Info {
+                        color: "red".to_string(),
+                    },
+                },
+                Fruit {
+                    name: "banana".to_string(),
+                    info: Info {
+                        color: "yellow".to_string(),
+                    },
+                },
+            ],
+        };
+
+        let schema_store = SchemaStore::new();
+        let result: Test = Deserializer::builder()
+            .schema_store(&schema_store)
+            .build()
+            .from_reader_async(toml.as_bytes())
+            .await
+            .expect("TOML deserialization failed");
+        pretty_assertions::assert_eq!(result, expected);
+    }
+
+    #[tokio::test]
+    async fn test_deserialize_spanned() {
+        #[derive(Debug, Deserialize)]
+        struct Server {
+            host: Spanned<String>,
+            ports: Spanned<Vec<Spanned<u16>>>,
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct Test {
+            server: Spanned<Server>,
+        }
+
+        let toml = r#"
+[server]
+host = "localhost"
+ports = [8080, 8081]
+"#;
+
+        let schema_store = SchemaStore::new();
+        let result: Test = Deserializer::builder()
+            .schema_store(&schema_store)
+            .build()
+            .from_str_async(toml)
+            .await
+            .expect("TOML deserialization failed");
+
+        let server = result.server.get_ref();
+        pretty_assertions::assert_eq!(server.host.get_ref(), "localhost");
+        pretty_assertions::assert_eq!(
+            server.host.range(),
+            tombi_text::Range::new(
+                tombi_text::Position::new(2, 7),
+                tombi_text::Position::new(2, 18),
+            )
+        );
+        pretty_assertions::assert_eq!(
+            server.ports.range(),
+            tombi_text::Range::new(
+                tombi_text::Position::new(3, 8),
+                tombi_text::Position::new(3, 20),
+            )
+        );
+        pretty_assertions::assert_eq!(
+            server.ports.get_ref()[1].range(),
+            tombi_text::Range::new(
+                tombi_text::Position::new(3, 15),
+                tombi_text::Position::new(3, 19),
+            )
+        );
+        pretty_assertions::assert_eq!(*server.ports.get_ref()[1].get_ref(), 8081);
+    }
+
+    #[tokio::test]
+    async fn test_deserialize_error_with_range() {
+        #[derive(Debug, Deserialize)]
+        #[allow(dead_code)]
+        struct Package {
+            name: String,
+            version: String,
+        }
+
+        #[derive(Debug, Deserialize)]
+        #[allow(dead_code)]
+        struct Test {
+            package: Package,
+        }
+
+        let toml = r#"
+[package]
+name = "tombi"
+version = 1
+"#;
+
+        let schema_store = SchemaStore::new();
+        let error = Deserializer::builder()
+            .schema_store(&schema_store)
+            .source_path(std::path::Path::new("example.toml"))
+            .build()
+            .from_str_async::<Test>(toml)
+            .await
+            .unwrap_err();
+
+        pretty_assertions::assert_eq!(
+            error.range(),
+            Some(tombi_text::Range::new(
+                tombi_text::Position::new(3, 10),
+                tombi_text::Position::new(3, 11),
+            ))
+        );
+        assert!(error.to_string().starts_with("example.toml:4:11: "));
+
+        let diagnostics = error.to_diagnostics();
+        pretty_assertions::assert_eq!(diagnostics.len(), 1);
+        pretty_assertions::assert_eq!(diagnostics[0].code(), "deserialize-error");
+        pretty_assertions::assert_eq!(
+            diagnostics[0].source_file(),
+            Some(std::path::Path::new("example.toml"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_deserialize_missing_field_error_with_table_range() {
+        #[derive(Debug, Deserialize)]
+        #[allow(dead_code)]
+        struct Package {
+            name: String,
+            version: String,
+        }
+
+        #[derive(Debug, Deserialize)]
+        #[allow(dead_code)]
+        struct Test {
+            package: Package,
+        }
+
+        let toml = r#"
+[package]
+name = "tombi"
+"#;
+
+        let schema_store = SchemaStore::new();
+        let error = Deserializer::builder()
+            .schema_store(&schema_store)
+            .build()
+            .from_str_async::<Test>(toml)
+            .await
+            .unwrap_err();
+
+        pretty_assertions::assert_eq!(error.range().map(|range| range.start.line), Some(1));
+        assert!(error.to_string().contains("version"));
+    }
+
+    #[tokio::test]
+    async fn test_deserialize_unknown_field_error_with_key_range() {
+        #[derive(Debug, Deserialize)]
+        #[serde(deny_unknown_fields)]
+        #[allow(dead_code)]
+        struct Test {
+            name: String,
+        }
+
+        let toml = r#"name = "tombi"
+nmae = "tombi"
+"#;
+
+        let schema_store = SchemaStore::new();
+        let error = Deserializer::builder()
+            .schema_store(&schema_store)
+            .build()
+            .from_str_async::<Test>(toml)
+            .await
+            .unwrap_err();
+
+        pretty_assertions::assert_eq!(
+            error.range(),
+            Some(tombi_text::Range::new(
+                tombi_text::Position::new(1, 0),
+                tombi_text::Position::new(1, 4),
+            ))
+        );
+        assert!(error.to_string().ends_with("at line 2 column 1"));
+    }
+
+    fn write_test_schema(dir: &std::path::Path) -> SchemaUri {
+        let schema_path = dir.join("test.schema.json");
+        std::fs::write(
+            &schema_path,
+            r#"{
+  "type": "object",
+  "properties": {
+    "name": { "type": "string" },
+    "port": { "type": "integer", "minimum": 1024 }
+  },
+  "required": ["name"]
+}"#,
+        )
+        .unwrap();
+        SchemaUri::from_file_path(&schema_path).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_deserialize_with_schema() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Test {
+            name: String,
+            port: u16,
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let schema_uri = write_test_schema(dir.path());
+
+        let schema_store = SchemaStore::new();
+        let result: Test = Deserializer::builder()
+            .schema_store(&schema_store)
+            .schema_uri(&schema_uri)
+            .build()
+            .from_str_async("name = \"tombi\"\nport = 8080\n")
+            .await
+            .unwrap();
+
+        pretty_assertions::assert_eq!(
+            result,
+            Test {
+                name: "tombi".to_string(),
+                port: 8080,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_deserialize_with_schema_returns_all_diagnostics() {
+        #[derive(Debug, Deserialize)]
+        #[allow(dead_code)]
+        struct Test {
+            name: Option<i64>,
+            port: i64,
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let schema_uri = write_test_schema(dir.path());
+
+        let schema_store = SchemaStore::new();
+        let error = Deserializer::builder()
+            .schema_store(&schema_store)
+            .schema_uri(&schema_uri)
+            .build()
+            .from_str_async::<Test>("name = 1\nport = 80\n")
+            .await
+            .unwrap_err();
+
+        let diagnostics = error.to_diagnostics();
+        pretty_assertions::assert_eq!(diagnostics.len(), 2);
+        assert!(diagnostics.iter().all(|diagnostic| diagnostic.is_error()));
+        pretty_assertions::assert_eq!(error.range().map(|range| range.start.line), Some(0));
+    }
+
     #[tokio::test]
     async fn test_deserialize_array() {
         #[derive(Debug, Deserialize, PartialEq)]
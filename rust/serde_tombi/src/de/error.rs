@@ -1,8 +1,115 @@
 use itertools::Itertools;
 
-#[derive(Debug, thiserror::Error)]
-#[error(transparent)]
-pub struct Error(Box<InnerError>);
+/// Error that can occur when deserializing TOML.
+///
+/// Errors found in the source carry their ranges,
+/// which can be rendered with the `tombi_diagnostic` printers through [`Error::to_diagnostics`].
+#[derive(Debug)]
+pub struct Error {
+    inner: Box<InnerError>,
+    source_path: Option<std::path::PathBuf>,
+}
+
+impl Error {
+    /// The range of the value that failed to be deserialized.
+    pub fn range(&self) -> Option<tombi_text::Range> {
+        match self.inner.as_ref() {
+            InnerError::Deserialize { range, .. } => *range,
+            InnerError::Parser(errors) => errors.first().map(|error| error.range()),
+            InnerError::DocumentTree(errors) => errors.first().map(|error| error.range()),
+            InnerError::Validation(diagnostics) => {
+                diagnostics.first().map(|diagnostic| diagnostic.range())
+            }
+            _ => None,
+        }
+    }
+
+    /// The path of the source file, if the deserializer knows it.
+    pub fn source_path(&self) -> Option<&std::path::Path> {
+        self.source_path.as_deref()
+    }
+
+    /// Converts the errors found in the source into diagnostics.
+    ///
+    /// Errors without a location in the source (e.g. I/O errors) have no diagnostics.
+    pub fn to_diagnostics(&self) -> Vec<tombi_diagnostic::Diagnostic> {
+        use tombi_diagnostic::SetDiagnostics;
+
+        let mut diagnostics = Vec::new();
+        match self.inner.as_ref() {
+            InnerError::Parser(errors) => errors.clone().set_diagnostics(&mut diagnostics),
+            InnerError::DocumentTree(errors) => errors.clone().set_diagnostics(&mut diagnostics),
+            InnerError::Validation(validation_diagnostics) => {
+                diagnostics.extend(validation_diagnostics.iter().cloned())
+            }
+            InnerError::Deserialize {
+                message,
+                range: Some(range),
+            } => {
+                diagnostics.push(tombi_diagnostic::Diagnostic::new_error(
+                    message.clone(),
+                    "deserialize-error",
+                    *range,
+                ));
+            }
+            _ => {}
+        }
+
+        match &self.source_path {
+            Some(source_path) => diagnostics
+                .into_iter()
+                .map(|diagnostic| diagnostic.with_source_file(source_path))
+                .collect(),
+            None => diagnostics,
+        }
+    }
+
+    pub(crate) fn with_source_path(mut self, source_path: Option<&std::path::Path>) -> Self {
+        if self.source_path.is_none() {
+            self.source_path = source_path.map(ToOwned::to_owned);
+        }
+        self
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.inner.as_ref(), &self.source_path) {
+            (
+                InnerError::Deserialize {
+                    message,
+                    range: Some(range),
+                },
+                Some(source_path),
+            ) => write!(
+                f,
+                "{}:{}:{}: {message}",
+                source_path.display(),
+                range.start.line + 1,
+                range.start.column + 1
+            ),
+            (
+                InnerError::Deserialize {
+                    message,
+                    range: Some(range),
+                },
+                None,
+            ) => write!(
+                f,
+                "{message} at line {} column {}",
+                range.start.line + 1,
+                range.start.column + 1
+            ),
+            (inner, _) => inner.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        std::error::Error::source(self.inner.as_ref())
+    }
+}
 
 #[derive(Debug, thiserror::Error)]
 enum InnerError {
@@ -23,46 +130,74 @@ enum InnerError {
 
     #[error("{}", .0.iter().map(|e| e.to_string()).collect_vec().join(", "))]
     DocumentTree(Vec<tombi_document_tree::Error>),
+
+    #[error("{}", .0.iter().map(|diagnostic| diagnostic.message()).collect_vec().join(", "))]
+    Validation(Vec<tombi_diagnostic::Diagnostic>),
+
+    #[error("{message}")]
+    Deserialize {
+        message: String,
+        range: Option<tombi_text::Range>,
+    },
 }
 
 impl From<InnerError> for Error {
     fn from(error: InnerError) -> Self {
-        Self(Box::new(error))
+        Self {
+            inner: Box::new(error),
+            source_path: None,
+        }
     }
 }
 
 impl From<std::io::Error> for Error {
     fn from(error: std::io::Error) -> Self {
-        Self(Box::new(InnerError::Io(error)))
+        InnerError::Io(error).into()
     }
 }
 
 impl From<tombi_config::Error> for Error {
     fn from(error: tombi_config::Error) -> Self {
-        Self(Box::new(InnerError::Config(error)))
+        InnerError::Config(error).into()
     }
 }
 
 impl From<Vec<tombi_parser::Error>> for Error {
     fn from(errors: Vec<tombi_parser::Error>) -> Self {
-        Self(Box::new(InnerError::Parser(errors)))
+        InnerError::Parser(errors).into()
     }
 }
 
 impl From<Vec<tombi_document_tree::Error>> for Error {
     fn from(errors: Vec<tombi_document_tree::Error>) -> Self {
-        Self(Box::new(InnerError::DocumentTree(errors)))
+        InnerError::DocumentTree(errors).into()
+    }
+}
+
+impl From<Vec<tombi_diagnostic::Diagnostic>> for Error {
+    fn from(diagnostics: Vec<tombi_diagnostic::Diagnostic>) -> Self {
+        InnerError::Validation(diagnostics).into()
     }
 }
 
 impl From<tombi_schema_store::Error> for Error {
     fn from(error: tombi_schema_store::Error) -> Self {
-        Self(Box::new(InnerError::SchemaStore(error)))
+        InnerError::SchemaStore(error).into()
     }
 }
 
 impl From<tombi_document::de::Error> for Error {
     fn from(error: tombi_document::de::Error) -> Self {
-        Self(Box::new(InnerError::DocumentDeserialize(error)))
+        InnerError::DocumentDeserialize(error).into()
+    }
+}
+
+impl From<crate::de::value::Error> for Error {
+    fn from(error: crate::de::value::Error) -> Self {
+        InnerError::Deserialize {
+            message: error.message,
+            range: error.range,
+        }
+        .into()
     }
 }
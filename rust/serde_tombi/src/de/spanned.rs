@@ -0,0 +1,145 @@
+pub(crate) const NAME: &str = "$__serde_tombi_private_Spanned";
+pub(crate) const RANGE: &str = "$__serde_tombi_private_range";
+pub(crate) const VALUE: &str = "$__serde_tombi_private_value";
+pub(crate) const FIELDS: &[&str] = &[RANGE, VALUE];
+
+/// A deserialized value together with its range in the TOML source.
+///
+/// Only the deserializers of this crate that read TOML text (e.g. [`crate::from_str_async`]) know
+/// the ranges, so deserializing `Spanned<T>` from any other deserializer fails.
+///
+/// # Examples
+///
+/// ```
+/// use serde::Deserialize;
+/// use serde_tombi::Spanned;
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     port: Spanned<u16>,
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let config: Config = serde_tombi::Deserializer::builder()
+///         .schema_store(&tombi_schema_store::SchemaStore::new())
+///         .build()
+///         .from_str_async("port = 8080")
+///         .await
+///         .unwrap();
+///
+///     assert_eq!(*config.port.get_ref(), 8080);
+///     assert_eq!(config.port.range().start.column, 7);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Spanned<T> {
+    range: tombi_text::Range,
+    value: T,
+}
+
+impl<T> Spanned<T> {
+    pub fn new(range: tombi_text::Range, value: T) -> Self {
+        Self { range, value }
+    }
+
+    /// The range of the value in the TOML source.
+    #[inline]
+    pub fn range(&self) -> tombi_text::Range {
+        self.range
+    }
+
+    #[inline]
+    pub fn get_ref(&self) -> &T {
+        &self.value
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: PartialEq> PartialEq for Spanned<T> {
+    /// Compares only the values, like the values themselves would be compared.
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq> Eq for Spanned<T> {}
+
+impl<T: std::hash::Hash> std::hash::Hash for Spanned<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl<T> AsRef<T> for Spanned<T> {
+    fn as_ref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> AsMut<T> for Spanned<T> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T: serde::Serialize> serde::Serialize for Spanned<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Spanned<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct SpannedVisitor<T>(std::marker::PhantomData<T>);
+
+        impl<'de, T: serde::Deserialize<'de>> serde::de::Visitor<'de> for SpannedVisitor<T> {
+            type Value = Spanned<T>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a TOML value with its range")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                if map.next_key::<&str>()? != Some(RANGE) {
+                    return Err(serde::de::Error::custom("spanned range key not found"));
+                }
+                let [start_line, start_column, end_line, end_column] =
+                    map.next_value::<[u32; 4]>()?;
+
+                if map.next_key::<&str>()? != Some(VALUE) {
+                    return Err(serde::de::Error::custom("spanned value key not found"));
+                }
+                let value = map.next_value()?;
+
+                Ok(Spanned::new(
+                    tombi_text::Range::new(
+                        tombi_text::Position::new(start_line, start_column),
+                        tombi_text::Position::new(end_line, end_column),
+                    ),
+                    value,
+                ))
+            }
+        }
+
+        deserializer.deserialize_struct(NAME, FIELDS, SpannedVisitor(std::marker::PhantomData))
+    }
+}
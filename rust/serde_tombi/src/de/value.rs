@@ -0,0 +1,356 @@
+use serde::de::{Deserializer as _, IntoDeserializer};
+use tombi_document::Value;
+
+/// Error raised while deserializing a value, with the range of the innermost value that failed.
+#[derive(Debug)]
+pub(crate) struct Error {
+    pub(crate) message: String,
+    pub(crate) range: Option<tombi_text::Range>,
+}
+
+impl Error {
+    fn or_range(mut self, range: tombi_text::Range) -> Self {
+        self.range.get_or_insert(range);
+        self
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.message.fmt(f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl serde::de::Error for Error {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Self {
+            message: msg.to_string(),
+            range: None,
+        }
+    }
+}
+
+impl From<tombi_document::de::Error> for Error {
+    fn from(error: tombi_document::de::Error) -> Self {
+        serde::de::Error::custom(error)
+    }
+}
+
+/// The ranges of a document tree value, kept while the tree itself is converted into a document value.
+pub(crate) enum Ranges {
+    Value(tombi_text::Range),
+    Table {
+        range: tombi_text::Range,
+        key_values: Vec<(tombi_text::Range, Ranges)>,
+    },
+    Array {
+        range: tombi_text::Range,
+        values: Vec<Ranges>,
+    },
+}
+
+impl Ranges {
+    pub(crate) fn new(value: &tombi_document_tree::Value) -> Self {
+        match value {
+            tombi_document_tree::Value::Table(table) => Self::Table {
+                range: table.range(),
+                key_values: table
+                    .key_values()
+                    .iter()
+                    .map(|(key, value)| (key.range(), Self::new(value)))
+                    .collect(),
+            },
+            tombi_document_tree::Value::Array(array) => Self::Array {
+                range: array.range(),
+                values: array.values().iter().map(Self::new).collect(),
+            },
+            value => Self::Value(value.range()),
+        }
+    }
+
+    fn range(&self) -> tombi_text::Range {
+        match self {
+            Self::Value(range) | Self::Table { range, .. } | Self::Array { range, .. } => *range,
+        }
+    }
+}
+
+/// Deserializes a [`tombi_document::Value`] while following the ranges of the document tree it was built from,
+/// so that errors and [`crate::Spanned`] values know their ranges in the source.
+pub(crate) struct ValueDeserializer<'de> {
+    value: &'de Value,
+    ranges: &'de Ranges,
+}
+
+impl<'de> ValueDeserializer<'de> {
+    pub(crate) fn new(value: &'de Value, ranges: &'de Ranges) -> Self {
+        Self { value, ranges }
+    }
+
+    fn deserialize_container<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        let range = self.ranges.range();
+        match (self.value, self.ranges) {
+            (Value::Table(table), Ranges::Table { key_values, .. }) => {
+                visitor.visit_map(TableAccess {
+                    iter: table.key_values().iter().zip(key_values.iter()),
+                    value: None,
+                })
+            }
+            (Value::Array(array), Ranges::Array { values, .. }) => visitor.visit_seq(ArrayAccess {
+                iter: array.values().iter().zip(values.iter()),
+            }),
+            _ => self.value.deserialize_any(visitor).map_err(Error::from),
+        }
+        .map_err(|error| error.or_range(range))
+    }
+
+    fn is_container(&self) -> bool {
+        matches!(self.value, Value::Table(_) | Value::Array(_))
+    }
+}
+
+macro_rules! deserialize_leaf {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: serde::de::Visitor<'de>,
+            {
+                if self.is_container() {
+                    return self.deserialize_container(visitor);
+                }
+                self.value
+                    .$method(visitor)
+                    .map_err(|error| Error::from(error).or_range(self.ranges.range()))
+            }
+        )*
+    };
+}
+
+impl<'de> serde::Deserializer<'de> for ValueDeserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        self.deserialize_container(visitor)
+    }
+
+    deserialize_leaf! {
+        deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64
+        deserialize_i128 deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64
+        deserialize_u128 deserialize_f32 deserialize_f64 deserialize_char deserialize_str
+        deserialize_string deserialize_bytes deserialize_byte_buf deserialize_unit
+        deserialize_seq deserialize_map deserialize_identifier
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        if self.is_container() {
+            return visitor.visit_newtype_struct(self);
+        }
+        // Date-times are deserialized as newtype structs of the document values.
+        self.value
+            .deserialize_newtype_struct(name, visitor)
+            .map_err(|error| Error::from(error).or_range(self.ranges.range()))
+    }
+
+    fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        if name == crate::de::spanned::NAME && fields == crate::de::spanned::FIELDS {
+            return visitor.visit_map(SpannedAccess {
+                range: Some(self.ranges.range()),
+                value: Some(self),
+            });
+        }
+        if self.is_container() {
+            return self.deserialize_container(visitor);
+        }
+        self.value
+            .deserialize_struct(name, fields, visitor)
+            .map_err(|error| Error::from(error).or_range(self.ranges.range()))
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        self.value
+            .deserialize_enum(name, variants, visitor)
+            .map_err(|error| Error::from(error).or_range(self.ranges.range()))
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+}
+
+struct TableAccess<'de, I> {
+    iter: I,
+    value: Option<ValueDeserializer<'de>>,
+}
+
+impl<'de, I> serde::de::MapAccess<'de> for TableAccess<'de, I>
+where
+    I: Iterator<
+        Item = (
+            (&'de tombi_document::Key, &'de Value),
+            &'de (tombi_text::Range, Ranges),
+        ),
+    >,
+{
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: serde::de::DeserializeSeed<'de>,
+    {
+        match self.iter.next() {
+            Some(((key, value), (key_range, ranges))) => {
+                self.value = Some(ValueDeserializer::new(value, ranges));
+                seed.deserialize(key)
+                    .map(Some)
+                    .map_err(|error| Error::from(error).or_range(*key_range))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<T>(&mut self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: serde::de::DeserializeSeed<'de>,
+    {
+        match self.value.take() {
+            Some(value) => seed.deserialize(value),
+            None => Err(serde::de::Error::custom("value is missing")),
+        }
+    }
+}
+
+struct ArrayAccess<I> {
+    iter: I,
+}
+
+impl<'de, I> serde::de::SeqAccess<'de> for ArrayAccess<I>
+where
+    I: Iterator<Item = (&'de Value, &'de Ranges)>,
+{
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: serde::de::DeserializeSeed<'de>,
+    {
+        match self.iter.next() {
+            Some((value, ranges)) => seed
+                .deserialize(ValueDeserializer::new(value, ranges))
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Presents a value as the private struct that [`crate::Spanned`] deserializes from.
+struct SpannedAccess<'de> {
+    range: Option<tombi_text::Range>,
+    value: Option<ValueDeserializer<'de>>,
+}
+
+impl<'de> serde::de::MapAccess<'de> for SpannedAccess<'de> {
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: serde::de::DeserializeSeed<'de>,
+    {
+        let key = if self.range.is_some() {
+            crate::de::spanned::RANGE
+        } else if self.value.is_some() {
+            crate::de::spanned::VALUE
+        } else {
+            return Ok(None);
+        };
+        seed.deserialize(serde::de::value::BorrowedStrDeserializer::new(key))
+            .map(Some)
+    }
+
+    fn next_value_seed<T>(&mut self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: serde::de::DeserializeSeed<'de>,
+    {
+        if let Some(range) = self.range.take() {
+            let (start, end) = (range.start, range.end);
+            return seed.deserialize(
+                vec![start.line, start.column, end.line, end.column].into_deserializer(),
+            );
+        }
+        match self.value.take() {
+            Some(value) => seed.deserialize(value),
+            None => Err(serde::de::Error::custom("value is missing")),
+        }
+    }
+}
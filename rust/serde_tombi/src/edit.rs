@@ -0,0 +1,1300 @@
+//! Edit TOML documents while keeping their comments and layout.
+//!
+//! Only the edited regions of the text change. New values are written in the formatter's style,
+//! and everything else keeps its original text.
+//!
+//! The parse result and the document tree are kept between operations,
+//! and an edit reparses only the tables it touches.
+//!
+//! # Examples
+//!
+//! ```
+//! use serde::Serialize;
+//! use serde_tombi::DocumentMut;
+//!
+//! #[derive(Serialize)]
+//! struct Dependency {
+//!     version: &'static str,
+//!     features: Vec<&'static str>,
+//! }
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let mut document: DocumentMut = r#"
+//! [package]
+//! name = "example"  # The crate name
+//!
+//! [dependencies]
+//! itertools = "0.14"
+//! "#
+//!     .parse()
+//!     .unwrap();
+//!
+//!     document
+//!         .insert(
+//!             &["dependencies", "serde"],
+//!             &Dependency {
+//!                 version: "1.0",
+//!                 features: vec!["derive"],
+//!             },
+//!         )
+//!         .await
+//!         .unwrap();
+//!
+//!     assert_eq!(
+//!         document.to_string(),
+//!         r#"
+//! [package]
+//! name = "example"  # The crate name
+//!
+//! [dependencies]
+//! itertools = "0.14"
+//! serde = { version = "1.0", features = ["derive"] }
+//! "#
+//!     );
+//! }
+//! ```
+mod error;
+
+use std::ops::Range;
+
+pub use error::Error;
+use itertools::Itertools;
+use serde::{Serialize, de::DeserializeOwned};
+use tombi_ast::AstNode;
+use tombi_syntax::SyntaxKind::{COMMENT, LINE_BREAK, WHITESPACE};
+use tombi_toml_version::TomlVersion;
+
+use crate::document::ToTomlString;
+
+/// A TOML document that can be edited by key paths.
+#[derive(Debug, Clone)]
+pub struct DocumentMut {
+    text: String,
+    toml_version: TomlVersion,
+    parsed: tombi_parser::Parsed<tombi_syntax::SyntaxNode>,
+    document_tree: tombi_document_tree::DocumentTree,
+    document_tree_items: tombi_document_tree::DocumentTreeItems,
+    /// The schema store of the formatter that writes new values.
+    schema_store: tombi_schema_store::SchemaStore,
+}
+
+impl DocumentMut {
+    pub fn parse(text: &str, toml_version: TomlVersion) -> Result<Self, crate::de::Error> {
+        let parsed = tombi_parser::parse(text);
+        let (document_tree, document_tree_items) =
+            build_document_tree(&parsed, toml_version, &Default::default())?;
+
+        Ok(Self {
+            text: text.to_string(),
+            toml_version,
+            parsed,
+            document_tree,
+            document_tree_items,
+            schema_store: tombi_schema_store::SchemaStore::new(),
+        })
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    #[inline]
+    pub fn toml_version(&self) -> TomlVersion {
+        self.toml_version
+    }
+
+    /// Whether the document has a value at the key path.
+    pub fn contains_key(&self, keys: &[&str]) -> bool {
+        get_value((&self.document_tree).into(), keys).is_some()
+    }
+
+    /// Deserialize the value at the key path.
+    pub fn get<T>(&self, keys: &[&str]) -> Result<Option<T>, crate::de::Error>
+    where
+        T: DeserializeOwned,
+    {
+        get_value((&self.document_tree).into(), keys)
+            .map(|value| crate::de::deserialize_value(value.clone(), self.toml_version))
+            .transpose()
+    }
+
+    /// Insert or replace the value at the key path.
+    ///
+    /// An existing key-value keeps its key and comments and only its value is replaced.
+    /// A new key-value is added after the last key-value of the nearest existing table,
+    /// and a new table is appended to the document when the parent table does not exist.
+    ///
+    /// Inserting `None` removes the key path.
+    pub async fn insert<T>(&mut self, keys: &[&str], value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        let keys = to_keys(keys)?;
+        let Some(mut value) = crate::ser::to_value(value)? else {
+            self.remove_keys(&keys)?;
+            return Ok(());
+        };
+        into_inline(&mut value);
+
+        let is_table = matches!(value, tombi_document::Value::Table(_));
+        let plan = match self.plan_insert(&keys, is_table)? {
+            Some(plan) => plan,
+            None => {
+                // Tables and arrays of tables are replaced by a key-value.
+                self.remove_keys(&keys)?;
+                self.plan_insert(&keys, is_table)?
+                    .ok_or_else(|| Error::Uninsertable(to_accessors(&keys)))?
+            }
+        };
+
+        let line_ending = self.line_ending();
+        let content = match &plan.content {
+            Content::Value { indent } => self
+                .format_value(&value)
+                .await?
+                .replace('\n', &format!("{line_ending}{indent}")),
+            Content::KeyValue { keys, indent } => {
+                format!(
+                    "{} = {}",
+                    to_dotted_keys(keys),
+                    self.format_value(&value)
+                        .await?
+                        .replace('\n', &format!("{line_ending}{indent}"))
+                )
+            }
+            Content::KeyValueLines => {
+                let tombi_document::Value::Table(table) = &value else {
+                    unreachable!("only a table is written as key-value lines");
+                };
+                let mut content = String::new();
+                for (key, value) in table.key_values() {
+                    content.push_str(&format!(
+                        "{} = {}{line_ending}",
+                        tombi_toml_text::to_key_string(key.value()),
+                        self.format_value(value).await?.replace('\n', line_ending)
+                    ));
+                }
+                content
+            }
+        };
+
+        let mut edits = plan
+            .removals
+            .into_iter()
+            .map(|span| (span, String::new()))
+            .collect_vec();
+        edits.push((
+            plan.span,
+            format!("{}{content}{}", plan.prefix, plan.suffix),
+        ));
+
+        self.apply(edits)
+    }
+
+    /// Remove the key-value, table or array of tables at the key path, with the tables under it.
+    ///
+    /// Returns `false` if nothing is found at the key path.
+    pub fn remove(&mut self, keys: &[&str]) -> Result<bool, Error> {
+        let keys = to_keys(keys)?;
+        self.remove_keys(&keys)
+    }
+
+    fn root(&self) -> tombi_ast::Root {
+        tombi_ast::Root::cast(self.parsed.syntax_node()).expect("AST Root must be present")
+    }
+
+    fn line_ending(&self) -> &'static str {
+        if self.text.contains("\r\n") {
+            "\r\n"
+        } else {
+            "\n"
+        }
+    }
+
+    async fn format_value(&self, value: &tombi_document::Value) -> Result<String, Error> {
+        const PREFIX: &str = "value = ";
+
+        let mut toml_text = PREFIX.to_string();
+        value.to_toml_string(&mut toml_text, &[]);
+
+        let format_options = tombi_formatter::FormatOptions::default();
+        let formatter = tombi_formatter::Formatter::new(
+            self.toml_version,
+            &format_options,
+            None,
+            &self.schema_store,
+        );
+
+        match formatter.format(&toml_text).await {
+            Ok(formatted) => match formatted.trim_end().strip_prefix(PREFIX) {
+                Some(value) => Ok(value.to_string()),
+                None => Err(Error::UnexpectedFormat {
+                    prefix: PREFIX,
+                    formatted,
+                }),
+            },
+            Err(errors) => {
+                log::trace!("toml_text:\n{}", toml_text);
+                log::trace!("errors: {:?}", errors);
+                Err(Error::InvalidEdit(errors.into()))
+            }
+        }
+    }
+
+    /// Plan where the value at the key path is written.
+    ///
+    /// Returns `None` if an existing table or array of tables must be removed first.
+    fn plan_insert(&self, keys: &[String], is_table: bool) -> Result<Option<Plan>, Error> {
+        let root = self.root();
+        let entries = collect_entries(&root, self.toml_version);
+        check_array_of_tables(&entries, keys)?;
+
+        if let Some(entry) = find_entry(&entries, keys) {
+            return Ok(match &entry.kind {
+                EntryKind::KeyValue { key_value, .. } => key_value
+                    .value()
+                    .and_then(|value| token_span(value.syntax(), false))
+                    .map(|span| {
+                        let indent = indent_at(&self.text, span.start).to_string();
+                        Plan::new(span, Content::Value { indent })
+                    }),
+                EntryKind::Table(table) if is_table => Some(
+                    self.plan_table_body(
+                        table,
+                        entries
+                            .iter()
+                            .filter(|entry| {
+                                entry.keys.len() > keys.len()
+                                    && entry.keys.starts_with(keys)
+                                    && !matches!(entry.kind, EntryKind::KeyValue { .. })
+                            })
+                            .filter_map(|entry| self.removal_span(entry))
+                            .collect_vec(),
+                    ),
+                ),
+                _ => None,
+            });
+        }
+
+        for i in (1..keys.len()).rev() {
+            let parent_keys = &keys[..i];
+            if let Some(entry) = find_entry(&entries, parent_keys) {
+                return match &entry.kind {
+                    EntryKind::KeyValue { key_value, .. } => match key_value.value() {
+                        Some(tombi_ast::Value::InlineTable(inline_table)) => Ok(Some(
+                            self.plan_inline_table(&inline_table, keys[i..].to_vec()),
+                        )),
+                        _ => Err(Error::NotTable(to_accessors(parent_keys))),
+                    },
+                    EntryKind::Table(table) => Ok(Some(self.plan_table(table, keys[i..].to_vec()))),
+                    EntryKind::ArrayOfTable(_) => {
+                        Err(Error::ArrayOfTables(to_accessors(parent_keys)))
+                    }
+                };
+            }
+
+            // A table defined by dotted keys, such as `parent.key = 1`.
+            if let Some(entry) = entries.iter().rev().find(|entry| {
+                !entry.in_array_of_tables
+                    && entry.keys.len() > i
+                    && entry.keys.starts_with(parent_keys)
+                    && matches!(entry.kind, EntryKind::KeyValue { parent_keys_len, .. } if parent_keys_len < i)
+            }) && let EntryKind::KeyValue {
+                key_value,
+                parent,
+                parent_keys_len,
+            } = &entry.kind
+            {
+                let keys = keys[*parent_keys_len..].to_vec();
+                return Ok(Some(match parent {
+                    Parent::Table => self.plan_after_line(key_value.syntax(), keys),
+                    Parent::InlineTable(inline_table) => {
+                        self.plan_inline_table_after(inline_table, key_value, keys)
+                    }
+                }));
+            }
+        }
+
+        Ok(Some(match keys {
+            [_] => self.plan_root(&root, keys.to_vec()),
+            [parent_keys @ .., key] => self.plan_new_table(parent_keys, key),
+            [] => unreachable!("key path must not be empty"),
+        }))
+    }
+
+    fn plan_root(&self, root: &tombi_ast::Root, keys: Vec<String>) -> Plan {
+        if let Some(key_value) = root.key_values().last() {
+            return self.plan_after_line(key_value.syntax(), keys);
+        }
+
+        if let Some(table_or_array_of_table) = root.table_or_array_of_tables().next()
+            && let Some(span) = token_span(table_or_array_of_table.syntax(), true)
+        {
+            let offset = line_start(&self.text, span.start);
+            let line_ending = self.line_ending();
+            let mut plan = Plan::new(
+                offset..offset,
+                Content::KeyValue {
+                    keys,
+                    indent: String::new(),
+                },
+            );
+            plan.suffix = format!("{line_ending}{line_ending}");
+            return plan;
+        }
+
+        self.plan_end_of_document(Content::KeyValue {
+            keys,
+            indent: String::new(),
+        })
+    }
+
+    fn plan_new_table(&self, parent_keys: &[String], key: &str) -> Plan {
+        let line_ending = self.line_ending();
+        let mut plan = self.plan_end_of_document(Content::KeyValue {
+            keys: vec![key.to_string()],
+            indent: String::new(),
+        });
+        plan.prefix
+            .push_str(&format!("[{}]{line_ending}", to_dotted_keys(parent_keys)));
+        plan
+    }
+
+    fn plan_end_of_document(&self, content: Content) -> Plan {
+        let line_ending = self.line_ending();
+        if self.text.trim().is_empty() {
+            let mut plan = Plan::new(0..self.text.len(), content);
+            plan.suffix = line_ending.to_string();
+            return plan;
+        }
+
+        let mut plan = Plan::new(self.text.len()..self.text.len(), content);
+        if !self.text.ends_with('\n') {
+            plan.prefix.push_str(line_ending);
+        }
+        plan.prefix.push_str(line_ending);
+        plan.suffix = line_ending.to_string();
+        plan
+    }
+
+    fn plan_table(&self, table: &tombi_ast::Table, keys: Vec<String>) -> Plan {
+        if let Some(key_value) = table.key_values().last() {
+            return self.plan_after_line(key_value.syntax(), keys);
+        }
+
+        let header_end = table
+            .bracket_end()
+            .map(|bracket_end| usize::from(bracket_end.span().end))
+            .unwrap_or_else(|| usize::from(table.syntax().span().end));
+        self.plan_next_line(
+            header_end,
+            Content::KeyValue {
+                keys,
+                indent: String::new(),
+            },
+        )
+    }
+
+    fn plan_table_body(&self, table: &tombi_ast::Table, removals: Vec<Range<usize>>) -> Plan {
+        let key_value_spans = table
+            .key_values()
+            .filter_map(|key_value| token_span(key_value.syntax(), true))
+            .collect_vec();
+
+        let mut plan = match (key_value_spans.first(), key_value_spans.last()) {
+            (Some(first), Some(last)) => Plan::new(
+                line_start(&self.text, first.start)..next_line_start(&self.text, last.end),
+                Content::KeyValueLines,
+            ),
+            _ => {
+                let header_end = table
+                    .bracket_end()
+                    .map(|bracket_end| usize::from(bracket_end.span().end))
+                    .unwrap_or_else(|| usize::from(table.syntax().span().end));
+                self.plan_next_line(header_end, Content::KeyValueLines)
+            }
+        };
+        plan.suffix.clear();
+        plan.removals = removals;
+        plan
+    }
+
+    /// Plan a new key-value on the line after the node, with the same indentation.
+    fn plan_after_line(&self, node: &tombi_syntax::SyntaxNode, keys: Vec<String>) -> Plan {
+        let span = token_span(node, true)
+            .unwrap_or_else(|| usize::from(node.span().start)..usize::from(node.span().end));
+        let indent = indent_at(&self.text, span.start).to_string();
+        let mut plan = self.plan_next_line(
+            span.end,
+            Content::KeyValue {
+                keys,
+                indent: indent.clone(),
+            },
+        );
+        plan.prefix.push_str(&indent);
+        plan
+    }
+
+    fn plan_next_line(&self, offset: usize, content: Content) -> Plan {
+        let offset = next_line_start(&self.text, offset);
+        let mut plan = Plan::new(offset..offset, content);
+        if offset == self.text.len() && !self.text.ends_with('\n') {
+            plan.prefix.push_str(self.line_ending());
+        }
+        plan.suffix = self.line_ending().to_string();
+        plan
+    }
+
+    fn plan_inline_table(&self, inline_table: &tombi_ast::InlineTable, keys: Vec<String>) -> Plan {
+        if let Some((key_value, _)) = inline_table.key_values_with_comma().last() {
+            return self.plan_inline_table_after(inline_table, &key_value, keys);
+        }
+
+        let content = Content::KeyValue {
+            keys,
+            indent: String::new(),
+        };
+        let has_comments = inline_table
+            .syntax()
+            .descendants_with_tokens()
+            .any(|element| element.kind() == COMMENT);
+        match (has_comments, token_span(inline_table.syntax(), false)) {
+            (false, Some(span)) => {
+                let mut plan = Plan::new(span, content);
+                plan.prefix = "{ ".to_string();
+                plan.suffix = " }".to_string();
+                plan
+            }
+            _ => {
+                let offset = inline_table
+                    .brace_end()
+                    .map(|brace_end| usize::from(brace_end.span().start))
+                    .unwrap_or_else(|| usize::from(inline_table.syntax().span().end));
+                let mut plan = Plan::new(offset..offset, content);
+                plan.suffix = " ".to_string();
+                plan
+            }
+        }
+    }
+
+    /// Plan a new key-value right after a key-value of an inline table.
+    fn plan_inline_table_after(
+        &self,
+        inline_table: &tombi_ast::InlineTable,
+        key_value: &tombi_ast::KeyValue,
+        keys: Vec<String>,
+    ) -> Plan {
+        let comma = inline_table
+            .key_values_with_comma()
+            .find(|(other, _)| other.syntax() == key_value.syntax())
+            .and_then(|(_, comma)| comma);
+        let span = token_span(key_value.syntax(), false).unwrap_or_default();
+        let offset = comma
+            .as_ref()
+            .and_then(|comma| token_span(comma.syntax(), false))
+            .map_or(span.end, |comma_span| comma_span.end);
+        let separator = if inline_table.has_newlines_between_braces() {
+            format!(
+                "{}{}",
+                self.line_ending(),
+                indent_at(&self.text, span.start)
+            )
+        } else {
+            " ".to_string()
+        };
+
+        let mut plan = Plan::new(
+            offset..offset,
+            Content::KeyValue {
+                keys,
+                indent: String::new(),
+            },
+        );
+        if comma.is_some() {
+            plan.prefix = separator;
+            plan.suffix = ",".to_string();
+        } else {
+            plan.prefix = format!(",{separator}");
+        }
+        plan
+    }
+
+    fn remove_keys(&mut self, keys: &[String]) -> Result<bool, Error> {
+        let root = self.root();
+        let entries = collect_entries(&root, self.toml_version);
+        check_array_of_tables(&entries, keys)?;
+
+        let mut spans = entries
+            .iter()
+            .filter(|entry| {
+                entry.keys.starts_with(keys)
+                    && (!entry.in_array_of_tables
+                        || !matches!(entry.kind, EntryKind::KeyValue { .. }))
+            })
+            .filter_map(|entry| self.removal_span(entry))
+            .sorted_by_key(|span| span.start)
+            .collect_vec();
+        if spans.is_empty() {
+            return Ok(false);
+        }
+
+        // Merge the overlapping spans, such as key-values in a removed table.
+        let mut merged_spans: Vec<Range<usize>> = Vec::with_capacity(spans.len());
+        for span in spans.drain(..) {
+            match merged_spans.last_mut() {
+                Some(last) if span.start <= last.end => last.end = last.end.max(span.end),
+                _ => merged_spans.push(span),
+            }
+        }
+
+        self.apply(
+            merged_spans
+                .into_iter()
+                .map(|span| (span, String::new()))
+                .collect_vec(),
+        )?;
+        Ok(true)
+    }
+
+    fn removal_span(&self, entry: &Entry) -> Option<Range<usize>> {
+        match &entry.kind {
+            EntryKind::KeyValue {
+                key_value,
+                parent: Parent::Table,
+                ..
+            } => token_span(key_value.syntax(), true).map(|span| {
+                line_start(&self.text, span.start)..next_line_start(&self.text, span.end)
+            }),
+            EntryKind::KeyValue {
+                key_value,
+                parent: Parent::InlineTable(inline_table),
+                ..
+            } => inline_key_value_removal_span(inline_table, key_value),
+            EntryKind::Table(table) => self.section_removal_span(table.syntax()),
+            EntryKind::ArrayOfTable(array_of_table) => {
+                self.section_removal_span(array_of_table.syntax())
+            }
+        }
+    }
+
+    /// The lines of a table or array of tables, with the blank lines after it.
+    fn section_removal_span(&self, node: &tombi_syntax::SyntaxNode) -> Option<Range<usize>> {
+        let span = token_span(node, true)?;
+        let mut start = line_start(&self.text, span.start);
+        let mut end = next_line_start(&self.text, span.end);
+        while end < self.text.len() && self.text[end..].starts_with(['\n', '\r']) {
+            end = next_line_start(&self.text, end);
+        }
+        if end == self.text.len() {
+            while start > 0 && self.text[..start].trim_end_matches('\r').ends_with("\n\n") {
+                start = line_start(&self.text, start - 1);
+            }
+        }
+        Some(start..end)
+    }
+
+    /// Apply text edits, which must not overlap, and keep them only if the document stays valid.
+    ///
+    /// Only the blocks containing the edits are reparsed,
+    /// and only the root items that changed are converted into the document tree again.
+    fn apply(&mut self, mut edits: Vec<(Range<usize>, String)>) -> Result<(), Error> {
+        edits.sort_by_key(|(span, _)| std::cmp::Reverse((span.start, span.end)));
+
+        let mut text = self.text.clone();
+        let mut parsed = self.parsed.clone();
+        for (span, new_text) in edits {
+            parsed = parsed.reparse(
+                tombi_text::Span::new(
+                    tombi_text::Offset::new(span.start as u32),
+                    tombi_text::Offset::new(span.end as u32),
+                ),
+                &new_text,
+            );
+            text.replace_range(span, &new_text);
+        }
+        let (document_tree, document_tree_items) =
+            build_document_tree(&parsed, self.toml_version, &self.document_tree_items)
+                .map_err(Error::InvalidEdit)?;
+
+        self.text = text;
+        self.parsed = parsed;
+        self.document_tree = document_tree;
+        self.document_tree_items = document_tree_items;
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for DocumentMut {
+    type Err = crate::de::Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text, TomlVersion::default())
+    }
+}
+
+impl std::fmt::Display for DocumentMut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// Where and how a value is written.
+#[derive(Debug)]
+struct Plan {
+    span: Range<usize>,
+    removals: Vec<Range<usize>>,
+    prefix: String,
+    content: Content,
+    suffix: String,
+}
+
+impl Plan {
+    fn new(span: Range<usize>, content: Content) -> Self {
+        Self {
+            span,
+            removals: Vec::new(),
+            prefix: String::new(),
+            content,
+            suffix: String::new(),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Content {
+    /// The value alone, whose continuation lines are indented with `indent`.
+    Value { indent: String },
+    /// A key-value, whose continuation lines are indented with `indent`.
+    KeyValue { keys: Vec<String>, indent: String },
+    /// The entries of a table value, one key-value per line.
+    KeyValueLines,
+}
+
+struct Entry {
+    keys: Vec<String>,
+    kind: EntryKind,
+    /// Entries inside an array of tables belong to one of its tables,
+    /// so a key path cannot address them.
+    in_array_of_tables: bool,
+}
+
+enum EntryKind {
+    KeyValue {
+        key_value: tombi_ast::KeyValue,
+        parent: Parent,
+        parent_keys_len: usize,
+    },
+    Table(tombi_ast::Table),
+    ArrayOfTable(tombi_ast::ArrayOfTable),
+}
+
+enum Parent {
+    /// The root or a table with a header, whose key-values are on their own lines.
+    Table,
+    InlineTable(tombi_ast::InlineTable),
+}
+
+fn collect_entries(root: &tombi_ast::Root, toml_version: TomlVersion) -> Vec<Entry> {
+    let mut entries = Vec::new();
+    collect_key_values(
+        &mut entries,
+        root.key_values(),
+        &[],
+        Parent::Table,
+        false,
+        toml_version,
+    );
+
+    let mut array_of_tables_keys: Vec<Vec<String>> = Vec::new();
+    for table_or_array_of_table in root.table_or_array_of_tables() {
+        let (header, key_values, kind) = match table_or_array_of_table {
+            tombi_ast::TableOrArrayOfTable::Table(table) => (
+                table.header(),
+                table.key_values().collect_vec(),
+                EntryKind::Table(table),
+            ),
+            tombi_ast::TableOrArrayOfTable::ArrayOfTable(array_of_table) => (
+                array_of_table.header(),
+                array_of_table.key_values().collect_vec(),
+                EntryKind::ArrayOfTable(array_of_table),
+            ),
+        };
+        let Some(header) = header else {
+            continue;
+        };
+        let keys = to_raw_keys(header.keys(), toml_version);
+        let in_array_of_tables = array_of_tables_keys.iter().any(|array_of_tables_keys| {
+            keys.len() > array_of_tables_keys.len() && keys.starts_with(array_of_tables_keys)
+        });
+        let is_array_of_table = matches!(kind, EntryKind::ArrayOfTable(_));
+        if is_array_of_table {
+            array_of_tables_keys.push(keys.clone());
+        }
+
+        collect_key_values(
+            &mut entries,
+            key_values.into_iter(),
+            &keys,
+            Parent::Table,
+            in_array_of_tables || is_array_of_table,
+            toml_version,
+        );
+        entries.push(Entry {
+            keys,
+            kind,
+            in_array_of_tables,
+        });
+    }
+
+    entries
+}
+
+fn collect_key_values(
+    entries: &mut Vec<Entry>,
+    key_values: impl Iterator<Item = tombi_ast::KeyValue>,
+    parent_keys: &[String],
+    parent: Parent,
+    in_array_of_tables: bool,
+    toml_version: TomlVersion,
+) {
+    for key_value in key_values {
+        let Some(keys) = key_value.keys() else {
+            continue;
+        };
+        let keys = parent_keys
+            .iter()
+            .cloned()
+            .chain(to_raw_keys(keys.keys(), toml_version))
+            .collect_vec();
+
+        if let Some(tombi_ast::Value::InlineTable(inline_table)) = key_value.value() {
+            collect_key_values(
+                entries,
+                inline_table.key_values(),
+                &keys,
+                Parent::InlineTable(inline_table.clone()),
+                in_array_of_tables,
+                toml_version,
+            );
+        }
+
+        entries.push(Entry {
+            keys,
+            kind: EntryKind::KeyValue {
+                key_value,
+                parent: match &parent {
+                    Parent::Table => Parent::Table,
+                    Parent::InlineTable(inline_table) => Parent::InlineTable(inline_table.clone()),
+                },
+                parent_keys_len: parent_keys.len(),
+            },
+            in_array_of_tables,
+        });
+    }
+}
+
+fn find_entry<'a>(entries: &'a [Entry], keys: &[String]) -> Option<&'a Entry> {
+    entries
+        .iter()
+        .find(|entry| !entry.in_array_of_tables && entry.keys == keys)
+}
+
+/// Key paths through an array of tables are ambiguous, because they do not tell which table.
+fn check_array_of_tables(entries: &[Entry], keys: &[String]) -> Result<(), Error> {
+    match entries.iter().find(|entry| {
+        matches!(entry.kind, EntryKind::ArrayOfTable(_))
+            && entry.keys.len() < keys.len()
+            && keys.starts_with(&entry.keys)
+    }) {
+        Some(entry) => Err(Error::ArrayOfTables(to_accessors(&entry.keys))),
+        None => Ok(()),
+    }
+}
+
+fn get_value<'a>(
+    mut value: &'a tombi_document_tree::Value,
+    keys: &[&str],
+) -> Option<&'a tombi_document_tree::Value> {
+    for key in keys {
+        let tombi_document_tree::Value::Table(table) = value else {
+            return None;
+        };
+        value = table.get(key)?;
+    }
+    Some(value)
+}
+
+/// Build the document tree of a valid parse result, reusing the unchanged root items of `document_tree_items`.
+fn build_document_tree(
+    parsed: &tombi_parser::Parsed<tombi_syntax::SyntaxNode>,
+    toml_version: TomlVersion,
+    document_tree_items: &tombi_document_tree::DocumentTreeItems,
+) -> Result<
+    (
+        tombi_document_tree::DocumentTree,
+        tombi_document_tree::DocumentTreeItems,
+    ),
+    crate::de::Error,
+> {
+    if !parsed.errors.is_empty() {
+        return Err(parsed.errors.clone().into());
+    }
+
+    let root = tombi_ast::Root::cast(parsed.syntax_node()).expect("AST Root must be present");
+    let (document_tree, document_tree_items) = document_tree_items.build(&root, toml_version);
+    let (document_tree, errors) = document_tree.into();
+    if !errors.is_empty() {
+        return Err(errors.into());
+    }
+
+    Ok((document_tree, document_tree_items))
+}
+
+/// Inserted values are written inline, so that they stay next to their keys.
+fn into_inline(value: &mut tombi_document::Value) {
+    match value {
+        tombi_document::Value::Table(table) => {
+            *table.kind_mut() = tombi_document::TableKind::InlineTable;
+            table.key_values_mut().values_mut().for_each(into_inline);
+        }
+        tombi_document::Value::Array(array) => {
+            *array.kind_mut() = tombi_document::ArrayKind::Array;
+            array.values_mut().iter_mut().for_each(into_inline);
+        }
+        _ => {}
+    }
+}
+
+fn inline_key_value_removal_span(
+    inline_table: &tombi_ast::InlineTable,
+    key_value: &tombi_ast::KeyValue,
+) -> Option<Range<usize>> {
+    let key_values_with_comma = inline_table.key_values_with_comma().collect_vec();
+    let index = key_values_with_comma
+        .iter()
+        .position(|(other, _)| other.syntax() == key_value.syntax())?;
+    let span = token_span(key_value.syntax(), false)?;
+    let end = key_values_with_comma[index]
+        .1
+        .as_ref()
+        .and_then(|comma| token_span(comma.syntax(), false))
+        .map_or(span.end, |comma_span| comma_span.end);
+
+    if let Some((next, _)) = key_values_with_comma.get(index + 1) {
+        Some(span.start..token_span(next.syntax(), true)?.start)
+    } else if let Some(prev) = index
+        .checked_sub(1)
+        .and_then(|index| key_values_with_comma.get(index))
+    {
+        Some(token_span(prev.0.syntax(), false)?.end..end)
+    } else if !inline_table
+        .syntax()
+        .descendants_with_tokens()
+        .any(|element| element.kind() == COMMENT)
+    {
+        // Leave `{}` instead of `{  }`.
+        let brace_start = usize::from(inline_table.brace_start()?.span().end);
+        let brace_end = usize::from(inline_table.brace_end()?.span().start);
+        Some(brace_start..brace_end)
+    } else {
+        Some(span.start..end)
+    }
+}
+
+/// The span from the first to the last token of the node, skipping whitespaces and line breaks.
+fn token_span(node: &tombi_syntax::SyntaxNode, with_comments: bool) -> Option<Range<usize>> {
+    let mut tokens = node
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter(|token| match token.kind() {
+            WHITESPACE | LINE_BREAK => false,
+            COMMENT => with_comments,
+            _ => true,
+        });
+    let first = tokens.next()?;
+    let last = tokens.last().unwrap_or_else(|| first.clone());
+    Some(usize::from(first.span().start)..usize::from(last.span().end))
+}
+
+fn line_start(text: &str, offset: usize) -> usize {
+    text[..offset].rfind('\n').map_or(0, |index| index + 1)
+}
+
+fn next_line_start(text: &str, offset: usize) -> usize {
+    text[offset..]
+        .find('\n')
+        .map_or(text.len(), |index| offset + index + 1)
+}
+
+fn indent_at(text: &str, offset: usize) -> &str {
+    let line = &text[line_start(text, offset)..offset];
+    &line[..line.len() - line.trim_start_matches([' ', '\t']).len()]
+}
+
+fn to_keys(keys: &[&str]) -> Result<Vec<String>, Error> {
+    if keys.is_empty() {
+        return Err(Error::EmptyKeys);
+    }
+    Ok(keys.iter().map(ToString::to_string).collect_vec())
+}
+
+fn to_raw_keys(
+    keys: tombi_ast::AstChildren<tombi_ast::Key>,
+    toml_version: TomlVersion,
+) -> Vec<String> {
+    keys.map(|key| key.to_raw_text(toml_version)).collect_vec()
+}
+
+fn to_dotted_keys(keys: &[String]) -> String {
+    keys.iter()
+        .map(|key| tombi_toml_text::to_key_string(key))
+        .join(".")
+}
+
+fn to_accessors(keys: &[String]) -> tombi_schema_store::Accessors {
+    keys.iter()
+        .map(|key| tombi_schema_store::Accessor::Key(key.clone()))
+        .collect_vec()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> DocumentMut {
+        textwrap::dedent(text)
+            .trim_start()
+            .parse()
+            .expect("TOML parsing failed")
+    }
+
+    fn dedent(text: &str) -> String {
+        textwrap::dedent(text).trim_start().to_string()
+    }
+
+    #[tokio::test]
+    async fn test_replace_value_keeps_comments() {
+        let mut document = parse(
+            r#"
+            # package leading comment
+            [package]
+            name = "tombi"  # name trailing comment
+            version = "0.1.0"  # version trailing comment
+            "#,
+        );
+
+        document
+            .insert(&["package", "version"], "0.2.0")
+            .await
+            .unwrap();
+
+        pretty_assertions::assert_eq!(
+            document.to_string(),
+            dedent(
+                r#"
+                # package leading comment
+                [package]
+                name = "tombi"  # name trailing comment
+                version = "0.2.0"  # version trailing comment
+                "#
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_insert_key_value_into_table() {
+        #[derive(Serialize)]
+        struct Dependency {
+            version: &'static str,
+            features: Vec<&'static str>,
+        }
+
+        let mut document = parse(
+            r#"
+            [dependencies]
+            itertools = "0.14"
+
+            [dev-dependencies]
+            pretty_assertions = "1.4"
+            "#,
+        );
+
+        document
+            .insert(
+                &["dependencies", "serde"],
+                &Dependency {
+                    version: "1.0",
+                    features: vec!["derive"],
+                },
+            )
+            .await
+            .unwrap();
+
+        pretty_assertions::assert_eq!(
+            document.to_string(),
+            dedent(
+                r#"
+                [dependencies]
+                itertools = "0.14"
+                serde = { version = "1.0", features = ["derive"] }
+
+                [dev-dependencies]
+                pretty_assertions = "1.4"
+                "#
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_insert_key_value_into_root() {
+        let mut document = parse(
+            r#"
+            #:schema ./schema.json
+
+            # table leading comment
+            [table]
+            key = 1
+            "#,
+        );
+
+        document.insert(&["name"], "tombi").await.unwrap();
+
+        pretty_assertions::assert_eq!(
+            document.to_string(),
+            dedent(
+                r#"
+                #:schema ./schema.json
+
+                name = "tombi"
+
+                # table leading comment
+                [table]
+                key = 1
+                "#
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_insert_new_table() {
+        let mut document = parse(
+            r#"
+            [package]
+            name = "tombi"
+            "#,
+        );
+
+        document
+            .insert(&["workspace", "dependencies", "serde"], "1.0")
+            .await
+            .unwrap();
+
+        pretty_assertions::assert_eq!(
+            document.to_string(),
+            dedent(
+                r#"
+                [package]
+                name = "tombi"
+
+                [workspace.dependencies]
+                serde = "1.0"
+                "#
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_insert_into_inline_table_and_dotted_keys() {
+        let mut document = parse(
+            r#"
+            serde = { version = "1.0" }
+            package.name = "tombi"
+            "#,
+        );
+
+        document
+            .insert(&["serde", "features"], &["derive"])
+            .await
+            .unwrap();
+        document
+            .insert(&["package", "version"], "0.1.0")
+            .await
+            .unwrap();
+
+        pretty_assertions::assert_eq!(
+            document.to_string(),
+            dedent(
+                r#"
+                serde = { version = "1.0", features = ["derive"] }
+                package.name = "tombi"
+                package.version = "0.1.0"
+                "#
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_replace_table_key_values() {
+        let mut document = parse(
+            r#"
+            # profile leading comment
+            [profile]  # profile trailing comment
+            opt-level = 0
+
+            [other]
+            key = 1
+            "#,
+        );
+
+        document
+            .insert(
+                &["profile"],
+                &tombi_hashmap::indexmap! {"opt-level" => 3, "lto" => 1},
+            )
+            .await
+            .unwrap();
+
+        pretty_assertions::assert_eq!(
+            document.to_string(),
+            dedent(
+                r#"
+                # profile leading comment
+                [profile]  # profile trailing comment
+                opt-level = 3
+                lto = 1
+
+                [other]
+                key = 1
+                "#
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_remove() {
+        let mut document = parse(
+            r#"
+            name = "tombi"
+            serde = { version = "1.0", features = ["derive"] }
+
+            [table]
+            key = 1
+
+            [table.sub]
+            key = 2
+
+            [other]
+            key = 3
+            "#,
+        );
+
+        assert!(document.remove(&["serde", "version"]).unwrap());
+        assert!(document.remove(&["table"]).unwrap());
+        assert!(!document.remove(&["unknown"]).unwrap());
+
+        pretty_assertions::assert_eq!(
+            document.to_string(),
+            dedent(
+                r#"
+                name = "tombi"
+                serde = { features = ["derive"] }
+
+                [other]
+                key = 3
+                "#
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get() {
+        let document = parse(
+            r#"
+            [package]
+            name = "tombi"
+            keywords = ["toml", "formatter"]
+            "#,
+        );
+
+        assert!(document.contains_key(&["package", "name"]));
+        assert!(!document.contains_key(&["package", "version"]));
+        pretty_assertions::assert_eq!(
+            document
+                .get::<Vec<String>>(&["package", "keywords"])
+                .unwrap(),
+            Some(vec!["toml".to_string(), "formatter".to_string()])
+        );
+        pretty_assertions::assert_eq!(
+            document.get::<String>(&["package", "version"]).unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_edits_keep_parse_result_and_document_tree() {
+        let mut document = parse(
+            r#"
+            [package]
+            name = "tombi"
+
+            [dependencies]
+            serde = "1.0"
+            "#,
+        );
+
+        document
+            .insert(&["package", "version"], "0.1.0")
+            .await
+            .unwrap();
+        document
+            .insert(&["dependencies", "serde"], "1.1")
+            .await
+            .unwrap();
+        document
+            .insert(&["dev-dependencies", "rstest"], "0.25")
+            .await
+            .unwrap();
+        document.remove(&["package", "name"]).unwrap();
+
+        pretty_assertions::assert_eq!(document.parsed, tombi_parser::parse(document.as_str()));
+        pretty_assertions::assert_eq!(
+            document.document_tree,
+            DocumentMut::parse(document.as_str(), document.toml_version())
+                .unwrap()
+                .document_tree
+        );
+        pretty_assertions::assert_eq!(
+            document.get::<String>(&["dependencies", "serde"]).unwrap(),
+            Some("1.1".to_string())
+        );
+        assert!(!document.contains_key(&["package", "name"]));
+    }
+
+    #[tokio::test]
+    async fn test_insert_errors() {
+        let mut document = parse(
+            r#"
+            name = "tombi"
+
+            [[bin]]
+            name = "tombi"
+            "#,
+        );
+
+        assert!(matches!(
+            document.insert(&["name", "first"], "tombi").await,
+            Err(Error::NotTable(_))
+        ));
+        assert!(matches!(
+            document.insert(&["bin", "path"], "src/main.rs").await,
+            Err(Error::ArrayOfTables(_))
+        ));
+        assert!(matches!(
+            document.insert(&[], "tombi").await,
+            Err(Error::EmptyKeys)
+        ));
+    }
+}
@@ -0,0 +1,27 @@
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Key path must not be empty")]
+    EmptyKeys,
+
+    #[error("{0} is not a table")]
+    NotTable(tombi_schema_store::Accessors),
+
+    #[error("{0} is an array of tables, whose tables cannot be edited by a key path")]
+    ArrayOfTables(tombi_schema_store::Accessors),
+
+    #[error(transparent)]
+    Serialize(#[from] crate::ser::Error),
+
+    #[error("Edit makes the document invalid: {0}")]
+    InvalidEdit(crate::de::Error),
+
+    #[error("{0} cannot be inserted as a new key-value")]
+    Uninsertable(tombi_schema_store::Accessors),
+
+    #[error("Formatted value does not start with `{prefix}`: {formatted}")]
+    UnexpectedFormat {
+        prefix: &'static str,
+        formatted: String,
+    },
+}
@@ -91,18 +91,26 @@ extern crate self as serde_tombi;
 pub mod config;
 mod de;
 mod document;
+pub mod edit;
 mod ser;
 
-pub use de::{Deserializer, from_document, from_str_async};
+#[cfg(not(feature = "wasm"))]
+pub use de::from_str_with_schema;
+pub use de::{
+    Deserializer, Spanned, from_document, from_reader_async, from_str_async,
+    from_str_with_schema_async,
+};
 pub use document::{
     Array, ArrayKind, Boolean, Document, Float, Integer, IntegerKind, Key, LocalDate,
     LocalDateTime, LocalTime, OffsetDateTime, String, StringKind, Table, TableKind, Value,
 };
+pub use edit::DocumentMut;
 
 pub use ser::{Serializer, to_document, to_string_async};
 pub use serde_tombi_macros::tombi;
 use std::fmt;
 use thiserror::Error;
+pub use tombi_schema_store::SchemaUri;
 
 #[doc(hidden)]
 pub mod private {
@@ -162,3 +170,52 @@ impl serde::de::Error for Error {
 
 /// A specialized `Result` type for serde_tombi operations.
 pub type Result<T> = std::result::Result<T, Error>;
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_tombi_schema_hints() {
+        #[crate::tombi(toml_version = "v1.1.0", table_keys_order = "schema")]
+        #[derive(serde::Serialize, schemars::JsonSchema)]
+        struct Config {
+            #[tombi(inline, table_keys_order = "ascending")]
+            dependencies: BTreeMap<String, String>,
+            #[tombi(array_values_order = "version-sort")]
+            versions: Vec<String>,
+        }
+
+        let schema = serde_json::to_value(schemars::schema_for!(Config)).unwrap();
+
+        pretty_assertions::assert_eq!(schema["x-tombi-toml-version"], "v1.1.0");
+        pretty_assertions::assert_eq!(schema["x-tombi-table-keys-order"], "schema");
+        pretty_assertions::assert_eq!(
+            schema["properties"]["dependencies"]["x-tombi-table-keys-order"],
+            "ascending"
+        );
+        pretty_assertions::assert_eq!(
+            schema["properties"]["versions"]["x-tombi-array-values-order"],
+            "version-sort"
+        );
+    }
+
+    #[test]
+    fn test_tombi_schema_hints_with_cfg_attr_derive() {
+        #[crate::tombi(table_keys_order = "ascending")]
+        #[derive(serde::Serialize)]
+        #[cfg_attr(test, derive(schemars::JsonSchema))]
+        struct Config {
+            #[tombi(array_values_order = "descending")]
+            names: Vec<String>,
+        }
+
+        let schema = serde_json::to_value(schemars::schema_for!(Config)).unwrap();
+
+        pretty_assertions::assert_eq!(schema["x-tombi-table-keys-order"], "ascending");
+        pretty_assertions::assert_eq!(
+            schema["properties"]["names"]["x-tombi-array-values-order"],
+            "descending"
+        );
+    }
+}
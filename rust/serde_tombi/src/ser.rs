@@ -1,7 +1,9 @@
+mod comment;
 mod error;
 
 use itertools::Either;
 use serde::Serialize;
+use tombi_ast::AstNode;
 use tombi_formatter::FormatOptions;
 use tombi_schema_store::SchemaStore;
 use tombi_toml_version::TomlVersion;
@@ -51,6 +53,14 @@ where
     Serializer::new().to_document(value)
 }
 
+/// Serialize the given data structure as a TOML value, or `None` if it has no value (e.g. `None`).
+pub(crate) fn to_value<T>(value: &T) -> Result<Option<tombi_document::Value>, crate::ser::Error>
+where
+    T: ?Sized + Serialize,
+{
+    value.serialize(&mut ValueSerializer { accessors: &[] })
+}
+
 // Actual serializer implementation
 #[derive(TypedBuilder)]
 pub struct Serializer<'a> {
@@ -65,6 +75,15 @@ pub struct Serializer<'a> {
 
     #[builder(default, setter(into, strip_option))]
     schema_store: Option<&'a tombi_schema_store::SchemaStore>,
+
+    /// The schema for [`Self::doc_comments`].
+    /// If not set, the schema associated with `source_path` is used.
+    #[builder(default, setter(into, strip_option))]
+    schema_uri: Option<&'a tombi_schema_store::SchemaUri>,
+
+    /// Write the schema descriptions as comments above each key and table header.
+    #[builder(default)]
+    doc_comments: bool,
 }
 
 impl Default for Serializer<'_> {
@@ -80,6 +99,8 @@ impl Serializer<'_> {
             config_path: None,
             source_path: None,
             schema_store: None,
+            schema_uri: None,
+            doc_comments: false,
         }
     }
 
@@ -143,14 +164,58 @@ impl Serializer<'_> {
             schema_store,
         );
 
-        match formatter.format(&toml_text).await {
-            Ok(formatted) => Ok(formatted),
+        let formatted = match formatter.format(&toml_text).await {
+            Ok(formatted) => formatted,
             Err(errors) => {
                 log::trace!("toml_text:\n{}", toml_text);
                 log::trace!("errors: {:?}", errors);
                 unreachable!("Document must be valid TOML.")
             }
+        };
+
+        if !self.doc_comments {
+            return Ok(formatted);
         }
+        let Some(document_schema) = self.get_document_schema(&formatted, schema_store).await?
+        else {
+            return Ok(formatted);
+        };
+        let Some(root_schema) = document_schema.as_current_schema() else {
+            return Ok(formatted);
+        };
+
+        Ok(comment::insert_doc_comments(
+            &formatted,
+            &root_schema,
+            TomlVersion::default(),
+            schema_store,
+        )
+        .await)
+    }
+
+    async fn get_document_schema(
+        &self,
+        toml_text: &str,
+        schema_store: &SchemaStore,
+    ) -> Result<Option<std::sync::Arc<tombi_schema_store::DocumentSchema>>, crate::ser::Error> {
+        if let Some(schema_uri) = self.schema_uri {
+            return match schema_store.try_get_document_schema(schema_uri).await? {
+                Some(document_schema) => Ok(Some(document_schema)),
+                None => Err(tombi_schema_store::Error::SchemaResourceNotFound {
+                    schema_uri: schema_uri.clone(),
+                }
+                .into()),
+            };
+        }
+
+        let root = tombi_ast::Root::cast(tombi_parser::parse(toml_text).syntax_node())
+            .expect("AST Root must be present");
+        let source_schema = schema_store
+            .resolve_source_schema_from_ast(&root, self.source_path.map(Either::Right))
+            .await
+            .map_err(|(error, _)| error)?;
+
+        Ok(source_schema.and_then(|source_schema| source_schema.root_schema))
     }
 }
 
@@ -1079,6 +1144,106 @@ zzz = "1.0"
         toml_text_assert_eq!(toml, expected);
     }
 
+    #[tokio::test]
+    async fn test_serialize_with_doc_comments() {
+        #[derive(Serialize)]
+        struct Config {
+            name: String,
+            server: Server,
+            plugins: Vec<Plugin>,
+        }
+
+        #[derive(Serialize)]
+        struct Server {
+            port: u16,
+        }
+
+        #[derive(Serialize)]
+        struct Plugin {
+            name: String,
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let schema_path = dir.path().join("config.schema.json");
+        std::fs::write(
+            &schema_path,
+            r##"{
+  "type": "object",
+  "properties": {
+    "name": { "type": "string", "description": "The name of the application." },
+    "server": {
+      "type": "object",
+      "description": "Server settings.\n\nApplied at startup.",
+      "properties": {
+        "port": { "type": "integer", "description": "The port to listen on." }
+      }
+    },
+    "plugins": {
+      "type": "array",
+      "description": "Plugins to load.",
+      "items": { "$ref": "#/definitions/plugin" }
+    }
+  },
+  "definitions": {
+    "plugin": {
+      "type": "object",
+      "properties": {
+        "name": { "type": "string", "description": "The plugin name." }
+      }
+    }
+  }
+}"##,
+        )
+        .unwrap();
+        let schema_uri = tombi_schema_store::SchemaUri::from_file_path(&schema_path).unwrap();
+
+        let config = Config {
+            name: "app".to_string(),
+            server: Server { port: 8080 },
+            plugins: vec![
+                Plugin {
+                    name: "a".to_string(),
+                },
+                Plugin {
+                    name: "b".to_string(),
+                },
+            ],
+        };
+
+        let schema_store = SchemaStore::new();
+        let toml = Serializer::builder()
+            .schema_store(&schema_store)
+            .schema_uri(&schema_uri)
+            .doc_comments(true)
+            .build()
+            .to_string_async(&config)
+            .await
+            .expect("TOML serialization failed");
+
+        let expected = r#"
+            # The name of the application.
+            name = "app"
+
+            # Server settings.
+            #
+            # Applied at startup.
+            [server]
+            # The port to listen on.
+            port = 8080
+
+            # Plugins to load.
+            [[plugins]]
+            # The plugin name.
+            name = "a"
+
+            [[plugins]]
+            # The plugin name.
+            name = "b"
+        "#;
+
+        toml_text_assert_eq!(toml, expected);
+    }
+
     #[tokio::test]
     async fn test_serialize_inline_table_attribute() {
         #[derive(Serialize)]
@@ -0,0 +1,208 @@
+use itertools::Itertools;
+use tombi_ast::AstNode;
+use tombi_schema_store::{CurrentSchema, SchemaAccessor, SchemaStore, SchemaView};
+use tombi_syntax::SyntaxKind::{COMMENT, LINE_BREAK, WHITESPACE};
+use tombi_toml_version::TomlVersion;
+
+/// Insert the descriptions of the schema as comments above each key and table header.
+///
+/// The header of an array of tables is commented only at its first occurrence.
+pub(super) async fn insert_doc_comments(
+    toml_text: &str,
+    root_schema: &CurrentSchema<'_>,
+    toml_version: TomlVersion,
+    schema_store: &SchemaStore,
+) -> String {
+    let root = tombi_ast::Root::cast(tombi_parser::parse(toml_text).syntax_node())
+        .expect("AST Root must be present");
+    let root_schema = root_schema.clone().into_owned();
+
+    let mut comments = Vec::new();
+    collect_key_value_comments(
+        &mut comments,
+        toml_text,
+        root.key_values(),
+        Some(&root_schema),
+        toml_version,
+        schema_store,
+    )
+    .await;
+
+    let mut array_of_tables_keys: Vec<Vec<String>> = Vec::new();
+    for table_or_array_of_table in root.table_or_array_of_tables() {
+        let (header, key_values, is_array_of_table) = match &table_or_array_of_table {
+            tombi_ast::TableOrArrayOfTable::Table(table) => {
+                (table.header(), table.key_values().collect_vec(), false)
+            }
+            tombi_ast::TableOrArrayOfTable::ArrayOfTable(array_of_table) => (
+                array_of_table.header(),
+                array_of_table.key_values().collect_vec(),
+                true,
+            ),
+        };
+        let Some(header) = header else {
+            continue;
+        };
+        let keys = header
+            .keys()
+            .map(|key| key.to_raw_text(toml_version))
+            .collect_vec();
+
+        let is_first_header = !(is_array_of_table && array_of_tables_keys.contains(&keys));
+        if is_array_of_table && is_first_header {
+            array_of_tables_keys.push(keys.clone());
+        }
+
+        let header_schema =
+            resolve_keys_schema(&root_schema, &keys, &array_of_tables_keys, schema_store).await;
+        if is_first_header
+            && let Some(header_schema) = &header_schema
+            && let Some(comment) =
+                doc_comment(toml_text, table_or_array_of_table.syntax(), header_schema)
+        {
+            comments.push(comment);
+        }
+
+        let body_schema = match header_schema {
+            Some(header_schema) if is_array_of_table => {
+                resolve_items_schema(&header_schema, schema_store).await
+            }
+            header_schema => header_schema,
+        };
+        collect_key_value_comments(
+            &mut comments,
+            toml_text,
+            key_values.into_iter(),
+            body_schema.as_ref(),
+            toml_version,
+            schema_store,
+        )
+        .await;
+    }
+
+    let mut toml_text = toml_text.to_string();
+    for (offset, comment) in comments
+        .into_iter()
+        .sorted_by_key(|(offset, _)| *offset)
+        .rev()
+    {
+        toml_text.insert_str(offset, &comment);
+    }
+    toml_text
+}
+
+async fn collect_key_value_comments(
+    comments: &mut Vec<(usize, String)>,
+    toml_text: &str,
+    key_values: impl Iterator<Item = tombi_ast::KeyValue>,
+    table_schema: Option<&CurrentSchema<'static>>,
+    toml_version: TomlVersion,
+    schema_store: &SchemaStore,
+) {
+    let Some(table_schema) = table_schema else {
+        return;
+    };
+
+    for key_value in key_values {
+        let Some(keys) = key_value.keys() else {
+            continue;
+        };
+        let keys = keys
+            .keys()
+            .map(|key| key.to_raw_text(toml_version))
+            .collect_vec();
+
+        if let Some(value_schema) =
+            resolve_keys_schema(table_schema, &keys, &[], schema_store).await
+            && let Some(comment) = doc_comment(toml_text, key_value.syntax(), &value_schema)
+        {
+            comments.push(comment);
+        }
+    }
+}
+
+/// Resolve the schema of `keys`, stepping into the items of the arrays of tables on the way.
+async fn resolve_keys_schema(
+    schema: &CurrentSchema<'static>,
+    keys: &[String],
+    array_of_tables_keys: &[Vec<String>],
+    schema_store: &SchemaStore,
+) -> Option<CurrentSchema<'static>> {
+    let mut current_schema = schema.clone();
+    for (index, key) in keys.iter().enumerate() {
+        let SchemaView::Table(table_schema) = current_schema.schema_view.as_ref() else {
+            return None;
+        };
+        current_schema = table_schema
+            .resolve_property_schema(
+                &SchemaAccessor::Key(key.clone()),
+                current_schema.schema_uri.clone(),
+                current_schema.definitions.clone(),
+                current_schema.strict,
+                schema_store,
+            )
+            .await
+            .ok()??;
+
+        if index + 1 < keys.len()
+            && array_of_tables_keys
+                .iter()
+                .any(|array_of_tables_keys| array_of_tables_keys[..] == keys[..=index])
+        {
+            current_schema = resolve_items_schema(&current_schema, schema_store).await?;
+        }
+    }
+
+    Some(current_schema)
+}
+
+async fn resolve_items_schema(
+    schema: &CurrentSchema<'static>,
+    schema_store: &SchemaStore,
+) -> Option<CurrentSchema<'static>> {
+    let SchemaView::Array(array_schema) = schema.schema_view.as_ref() else {
+        return None;
+    };
+    tombi_schema_store::resolve_schema_item(
+        array_schema.items.as_ref()?,
+        schema.schema_uri.clone(),
+        schema.definitions.clone(),
+        schema.strict,
+        schema_store,
+    )
+    .await
+    .ok()?
+}
+
+/// The description of `schema` as comment lines, and the offset to insert them at.
+fn doc_comment(
+    toml_text: &str,
+    node: &tombi_syntax::SyntaxNode,
+    schema: &CurrentSchema<'_>,
+) -> Option<(usize, String)> {
+    let description = schema.schema_view.description()?.trim();
+    if description.is_empty() {
+        return None;
+    }
+
+    let offset = node
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .find(|token| !matches!(token.kind(), WHITESPACE | LINE_BREAK | COMMENT))
+        .map(|token| usize::from(token.span().start))?;
+    let line_start = toml_text[..offset].rfind('\n').map_or(0, |index| index + 1);
+    let indent = &toml_text[line_start..offset];
+    if !indent.chars().all(|c| c == ' ' || c == '\t') {
+        return None;
+    }
+
+    let comment = description
+        .lines()
+        .map(|line| match line.trim_end() {
+            "" => format!("{indent}#\n"),
+            line => format!("{indent}# {line}\n"),
+        })
+        .join("");
+
+    Some((line_start, comment))
+}
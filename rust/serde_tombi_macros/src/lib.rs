@@ -3,15 +3,31 @@ use proc_macro_crate::{FoundCrate, crate_name};
 use proc_macro2::Span;
 use quote::quote;
 
+/// Adds Tombi specific behavior to a struct.
+///
+/// # Struct arguments
+///
+/// - `toml_version = "v1.1.0"`: adds `x-tombi-toml-version` to the JSON Schema of the struct.
+/// - `table_keys_order = "ascending"`: adds `x-tombi-table-keys-order` to the JSON Schema of the struct.
+///
+/// # Field attributes
+///
+/// - `#[tombi(inline)]`: serializes the field as an inline table or array.
+/// - `#[tombi(table_keys_order = "ascending")]`: adds `x-tombi-table-keys-order` to the JSON Schema of the field.
+/// - `#[tombi(array_values_order = "version-sort")]`: adds `x-tombi-array-values-order` to the JSON Schema of the field.
+///
+/// The JSON Schema keywords are added with `#[schemars(extend(...))]`,
+/// so the struct must also derive `schemars::JsonSchema`, either directly or with `#[cfg_attr(..., derive(...))]`.
 #[proc_macro_attribute]
-pub fn tombi(_attr: TokenStream, item: TokenStream) -> TokenStream {
-    match expand(item) {
+pub fn tombi(attr: TokenStream, item: TokenStream) -> TokenStream {
+    match expand(attr, item) {
         Ok(tokens) => tokens,
         Err(error) => error.into_compile_error().into(),
     }
 }
 
-fn expand(item: TokenStream) -> syn::Result<TokenStream> {
+fn expand(attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    let struct_schema_hints = parse_struct_schema_hints(attr)?;
     let mut item = syn::parse::<syn::Item>(item)?;
     let serde_tombi_path = serde_tombi_path()?;
 
@@ -30,9 +46,24 @@ fn expand(item: TokenStream) -> syn::Result<TokenStream> {
         Span::call_site(),
     );
     let helper_path = format!("{helper_mod_ident}::serialize_inline");
+    let json_schema_derive = find_json_schema_derive(&struct_item.attrs);
+
+    if !struct_schema_hints.is_empty() {
+        let attr = schema_hints_attr(&struct_schema_hints, json_schema_derive.as_ref())?;
+        struct_item.attrs.push(attr);
+    }
 
     for field in &mut struct_item.fields {
-        let inline = take_tombi_inline_attr(field)?;
+        let TombiAttrs {
+            inline,
+            schema_hints,
+        } = take_tombi_attrs(field)?;
+
+        if !schema_hints.is_empty() {
+            let attr = schema_hints_attr(&schema_hints, json_schema_derive.as_ref())?;
+            field.attrs.push(attr);
+        }
+
         if !inline {
             continue;
         }
@@ -74,19 +105,33 @@ fn expand(item: TokenStream) -> syn::Result<TokenStream> {
     .into())
 }
 
-fn take_tombi_inline_attr(field: &mut syn::Field) -> syn::Result<bool> {
-    let mut inline = false;
+#[derive(Default)]
+struct TombiAttrs {
+    inline: bool,
+    schema_hints: Vec<SchemaHint>,
+}
+
+fn take_tombi_attrs(field: &mut syn::Field) -> syn::Result<TombiAttrs> {
+    let mut tombi_attrs = TombiAttrs::default();
     let mut attrs = Vec::with_capacity(field.attrs.len());
 
     for attr in field.attrs.drain(..) {
         if attr.path().is_ident("tombi") {
             attr.parse_nested_meta(|meta| {
                 if meta.path.is_ident("inline") {
-                    inline = true;
-                    Ok(())
+                    tombi_attrs.inline = true;
+                } else if meta.path.is_ident("table_keys_order") {
+                    tombi_attrs
+                        .schema_hints
+                        .push(SchemaHint::table_keys_order(&meta)?);
+                } else if meta.path.is_ident("array_values_order") {
+                    tombi_attrs
+                        .schema_hints
+                        .push(SchemaHint::array_values_order(&meta)?);
                 } else {
-                    Err(meta.error("unknown tombi attribute"))
+                    return Err(meta.error("unknown tombi attribute"));
                 }
+                Ok(())
             })?;
         } else {
             attrs.push(attr);
@@ -94,7 +139,143 @@ fn take_tombi_inline_attr(field: &mut syn::Field) -> syn::Result<bool> {
     }
 
     field.attrs = attrs;
-    Ok(inline)
+    Ok(tombi_attrs)
+}
+
+fn parse_struct_schema_hints(attr: TokenStream) -> syn::Result<Vec<SchemaHint>> {
+    let mut schema_hints = Vec::new();
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("toml_version") {
+            schema_hints.push(SchemaHint::toml_version(&meta)?);
+        } else if meta.path.is_ident("table_keys_order") {
+            schema_hints.push(SchemaHint::table_keys_order(&meta)?);
+        } else {
+            return Err(meta.error("unknown tombi argument"));
+        }
+        Ok(())
+    });
+    syn::parse::Parser::parse(parser, attr)?;
+
+    Ok(schema_hints)
+}
+
+/// A `x-tombi-*` keyword to add to the JSON Schema.
+struct SchemaHint {
+    keyword: &'static str,
+    value: syn::LitStr,
+}
+
+impl SchemaHint {
+    fn toml_version(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Self> {
+        Self::parse(
+            meta,
+            tombi_x_keyword::X_TOMBI_TOML_VERSION,
+            |value| value.parse::<tombi_toml_version::TomlVersion>().is_ok(),
+            r#""v1.0.0" or "v1.1.0""#,
+        )
+    }
+
+    fn table_keys_order(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Self> {
+        Self::parse(
+            meta,
+            tombi_x_keyword::X_TOMBI_TABLE_KEYS_ORDER,
+            |value| tombi_x_keyword::TableKeysOrder::try_from(value).is_ok(),
+            r#""ascending", "descending", "schema" or "version-sort""#,
+        )
+    }
+
+    fn array_values_order(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Self> {
+        Self::parse(
+            meta,
+            tombi_x_keyword::X_TOMBI_ARRAY_VALUES_ORDER,
+            |value| tombi_x_keyword::ArrayValuesOrder::try_from(value).is_ok(),
+            r#""ascending", "descending" or "version-sort""#,
+        )
+    }
+
+    fn parse(
+        meta: &syn::meta::ParseNestedMeta,
+        keyword: &'static str,
+        is_valid: fn(&str) -> bool,
+        expected: &str,
+    ) -> syn::Result<Self> {
+        let value: syn::LitStr = meta.value()?.parse()?;
+        if !is_valid(&value.value()) {
+            return Err(syn::Error::new_spanned(
+                &value,
+                format!("invalid {keyword} value, expected {expected}"),
+            ));
+        }
+        Ok(Self { keyword, value })
+    }
+}
+
+/// The `schemars::JsonSchema` derive of the struct, with the `cfg_attr` predicate it is behind.
+struct JsonSchemaDerive {
+    cfg: Option<syn::Meta>,
+}
+
+fn find_json_schema_derive(attrs: &[syn::Attribute]) -> Option<JsonSchemaDerive> {
+    for attr in attrs {
+        if attr.path().is_ident("derive") {
+            if derives_json_schema(&attr.meta) {
+                return Some(JsonSchemaDerive { cfg: None });
+            }
+        } else if attr.path().is_ident("cfg_attr")
+            && let Ok((cfg, metas)) = attr.parse_args_with(|input: syn::parse::ParseStream| {
+                let cfg = input.parse::<syn::Meta>()?;
+                input.parse::<syn::Token![,]>()?;
+                let metas =
+                    syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated(
+                        input,
+                    )?;
+                Ok((cfg, metas))
+            })
+            && metas
+                .iter()
+                .any(|meta| meta.path().is_ident("derive") && derives_json_schema(meta))
+        {
+            return Some(JsonSchemaDerive { cfg: Some(cfg) });
+        }
+    }
+
+    None
+}
+
+fn derives_json_schema(meta: &syn::Meta) -> bool {
+    let Ok(list) = meta.require_list() else {
+        return false;
+    };
+    list.parse_args_with(syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated)
+        .is_ok_and(|paths| {
+            paths.iter().any(|path| {
+                path.segments
+                    .last()
+                    .is_some_and(|segment| segment.ident == "JsonSchema")
+            })
+        })
+}
+
+fn schema_hints_attr(
+    schema_hints: &[SchemaHint],
+    json_schema_derive: Option<&JsonSchemaDerive>,
+) -> syn::Result<syn::Attribute> {
+    let Some(json_schema_derive) = json_schema_derive else {
+        return Err(syn::Error::new_spanned(
+            &schema_hints[0].value,
+            "#[tombi(...)] JSON Schema keywords require #[derive(schemars::JsonSchema)]",
+        ));
+    };
+
+    let extends = schema_hints
+        .iter()
+        .map(|SchemaHint { keyword, value }| quote!(#keyword = #value));
+    let meta = quote!(schemars(extend(#(#extends),*)));
+
+    Ok(match &json_schema_derive.cfg {
+        Some(cfg) => syn::parse_quote!(#[cfg_attr(#cfg, #meta)]),
+        None => syn::parse_quote!(#[#meta]),
+    })
 }
 
 #[derive(Default)]
@@ -27,6 +27,13 @@ pub struct Args {
     #[arg(long, default_value_t = false)]
     error_on_warnings: bool,
 
+    /// Lint files one table at a time
+    ///
+    /// Memory stays proportional to the largest table, which helps with very large files.
+    /// Rules that need the whole document, such as required keys, are not checked.
+    #[arg(long, default_value_t = false)]
+    stream: bool,
+
     /// Quiet mode
     ///
     /// If `true`, the program will not print summary output messages.
@@ -143,17 +150,31 @@ where
                     return Ok(summary);
                 };
 
-                if lint_file(
-                    tokio::io::stdin(),
-                    printer,
-                    stdin_path,
-                    toml_version,
-                    &lint_options,
-                    &schema_store,
-                    args.error_on_warnings,
-                )
-                .await
-                {
+                let success = if args.stream {
+                    lint_file_stream(
+                        std::io::BufReader::new(std::io::stdin()),
+                        printer,
+                        stdin_path,
+                        toml_version,
+                        &lint_options,
+                        &schema_store,
+                        args.error_on_warnings,
+                    )
+                    .await
+                } else {
+                    lint_file(
+                        tokio::io::stdin(),
+                        printer,
+                        stdin_path,
+                        toml_version,
+                        &lint_options,
+                        &schema_store,
+                        args.error_on_warnings,
+                    )
+                    .await
+                };
+
+                if success {
                     summary.success_num += 1;
                 } else {
                     summary.error_num += 1;
@@ -184,16 +205,29 @@ where
                                     let schema_store = schema_store.clone();
 
                                     tasks.spawn(async move {
-                                        lint_file(
-                                            file,
-                                            printer,
-                                            Some(source_path.as_ref()),
-                                            toml_version,
-                                            &lint_options,
-                                            &schema_store,
-                                            args.error_on_warnings,
-                                        )
-                                        .await
+                                        if args.stream {
+                                            lint_file_stream(
+                                                std::io::BufReader::new(file.into_std().await),
+                                                printer,
+                                                Some(source_path.as_ref()),
+                                                toml_version,
+                                                &lint_options,
+                                                &schema_store,
+                                                args.error_on_warnings,
+                                            )
+                                            .await
+                                        } else {
+                                            lint_file(
+                                                file,
+                                                printer,
+                                                Some(source_path.as_ref()),
+                                                toml_version,
+                                                &lint_options,
+                                                &schema_store,
+                                                args.error_on_warnings,
+                                            )
+                                            .await
+                                        }
                                     });
                                 }
                                 Err(err) => {
@@ -248,7 +282,7 @@ where
 
 async fn lint_file<R, P>(
     mut reader: R,
-    mut printer: P,
+    printer: P,
     source_path: Option<&std::path::Path>,
     toml_version: TomlVersion,
     lint_options: &LintOptions,
@@ -277,6 +311,74 @@ where
         return true;
     };
 
+    print_diagnostics(diagnostics, printer, source_path, error_on_warnings)
+}
+
+async fn lint_file_stream<R, P>(
+    reader: R,
+    mut printer: P,
+    source_path: Option<&std::path::Path>,
+    toml_version: TomlVersion,
+    lint_options: &LintOptions,
+    schema_store: &tombi_schema_store::SchemaStore,
+    error_on_warnings: bool,
+) -> bool
+where
+    Diagnostic: Print<P>,
+    crate::Error: Print<P>,
+    P: Send,
+    R: std::io::BufRead + Send + 'static,
+{
+    match tombi_linter::Linter::new(
+        toml_version,
+        lint_options,
+        source_path.map(itertools::Either::Right),
+        schema_store,
+    )
+    .lint_stream(parse_stream_blocking(reader))
+    .await
+    {
+        Ok(Ok(())) => true,
+        Ok(Err(diagnostics)) => {
+            print_diagnostics(diagnostics, printer, source_path, error_on_warnings)
+        }
+        Err(err) => {
+            crate::Error::Io(err).print(&mut printer);
+            false
+        }
+    }
+}
+
+/// Parse `reader` on a blocking thread, since reading it would block the async workers.
+///
+/// The chunks are passed on one at a time, so that memory stays proportional to the largest table.
+fn parse_stream_blocking<R>(
+    reader: R,
+) -> impl futures::Stream<Item = std::io::Result<tombi_parser::Chunk>> + Send
+where
+    R: std::io::BufRead + Send + 'static,
+{
+    let (sender, mut receiver) = tokio::sync::mpsc::channel(1);
+    tokio::task::spawn_blocking(move || {
+        for chunk in tombi_parser::parse_stream(reader) {
+            if sender.blocking_send(chunk).is_err() {
+                break;
+            }
+        }
+    });
+
+    futures::stream::poll_fn(move |cx| receiver.poll_recv(cx))
+}
+
+fn print_diagnostics<P>(
+    diagnostics: Vec<Diagnostic>,
+    mut printer: P,
+    source_path: Option<&std::path::Path>,
+    error_on_warnings: bool,
+) -> bool
+where
+    Diagnostic: Print<P>,
+{
     let diagnostics = if let Some(source_path) = source_path {
         diagnostics
             .into_iter()